@@ -0,0 +1,84 @@
+//! Montgomery multiplication for x86_64 with BMI2 and ADX, selected at
+//! compile time by `-C target-feature=+bmi2,+adx` (or a `target-cpu` that
+//! has them). Each row of the product and of the reduction adds the low
+//! halves of its `mulx` products on one carry chain and the high halves
+//! on another, which `adcx`/`adox` can run interleaved.
+
+use core::arch::x86_64::{_addcarryx_u64, _mulx_u64};
+
+#[inline(always)]
+fn mulx(a: u64, b: u64) -> (u64, u64) {
+    let mut hi = 0;
+    // SAFETY: the module is only compiled with the `bmi2` target feature.
+    let lo = unsafe { _mulx_u64(a, b, &mut hi) };
+    (lo, hi)
+}
+
+#[inline(always)]
+fn adc(carry: u8, a: u64, b: u64, out: &mut u64) -> u8 {
+    // SAFETY: the module is only compiled with the `adx` target feature.
+    unsafe { _addcarryx_u64(carry, a, b, out) }
+}
+
+/// `t[..6] += a * b` for a four-limb `a`, with `t[5]` taking the carries.
+#[inline(always)]
+fn mul_add_row(t: &mut [u64; 6], a: &[u64; 4], b: u64) {
+    let mut lo_carry = 0;
+    let mut hi_carry = 0;
+    let (lo, mut prev_hi) = mulx(a[0], b);
+    lo_carry = adc(lo_carry, t[0], lo, &mut t[0]);
+    for j in 1..4 {
+        let (lo, hi) = mulx(a[j], b);
+        lo_carry = adc(lo_carry, t[j], lo, &mut t[j]);
+        hi_carry = adc(hi_carry, t[j], prev_hi, &mut t[j]);
+        prev_hi = hi;
+    }
+    lo_carry = adc(lo_carry, t[4], 0, &mut t[4]);
+    hi_carry = adc(hi_carry, t[4], prev_hi, &mut t[4]);
+    t[5] += lo_carry as u64 + hi_carry as u64;
+}
+
+/// Same contract as `mul_reduce_cios`: the Montgomery product of `this`
+/// and `by`, below twice the modulus.
+#[inline]
+pub(super) fn mul_reduce(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u128) {
+    let a = super::to_u64_limbs(this);
+    let b = super::to_u64_limbs(by);
+    let n = super::to_u64_limbs(modulus);
+    let inv = inv as u64;
+
+    let mut t = [0u64; 6];
+    for &b in b.iter() {
+        mul_add_row(&mut t, &a, b);
+        // t[0] becomes zero; shift it out
+        let m = t[0].wrapping_mul(inv);
+        mul_add_row(&mut t, &n, m);
+        debug_assert!(t[0] == 0);
+        t = [t[1], t[2], t[3], t[4], t[5], 0];
+    }
+    debug_assert!(t[4] == 0);
+
+    this[0] = (t[1] as u128) << 64 | t[0] as u128;
+    this[1] = (t[3] as u128) << 64 | t[2] as u128;
+}
+
+#[test]
+fn matches_cios() {
+    let q = [
+        0x97816a916871ca8d3c208c16d87cfd47,
+        0x30644e72e131a029b85045b68181585d,
+    ];
+    let inv = 0x9ede7d651eca6ac987d20782e4866389;
+    let mut a = [0x0fed_cba9_8765_4321_1234_5678_9abc_def0, q[1] - 1];
+    let mut b = [q[0] - 1, q[1]];
+    for _ in 0..100 {
+        let mut x = a;
+        let mut y = a;
+        mul_reduce(&mut x, &b, &q, inv);
+        super::mul_reduce_cios(&mut y, &b, &q, inv);
+        assert_eq!(x, y);
+        b = a;
+        a = x;
+        super::reduce_once(&mut a, &q);
+    }
+}
@@ -0,0 +1,154 @@
+//! Conversions to and from the `ark_bn254` types. Both crates keep field
+//! elements in Montgomery form, but the conversions go through canonical
+//! integers so they do not depend on either representation. Points from
+//! arkworks are checked like decoded points, since its unchecked
+//! constructors can produce values off the curve or outside the subgroup;
+//! the point at infinity maps to the projective identity and has no affine
+//! form here.
+
+use crate::arith::U256;
+use crate::{AffineG1, AffineG2, CurveError, Fq, Fq2, Fr, Group, G1, G2};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInt, PrimeField};
+use core::convert::TryFrom;
+
+impl From<Fr> for ark_bn254::Fr {
+    fn from(x: Fr) -> Self {
+        ark_bn254::Fr::from_bigint(BigInt(x.into_u256().to_u64_limbs()))
+            .expect("canonical value is below r; qed")
+    }
+}
+
+impl From<ark_bn254::Fr> for Fr {
+    fn from(x: ark_bn254::Fr) -> Self {
+        Fr::new(U256::from(x.into_bigint().0)).expect("canonical value is below r; qed")
+    }
+}
+
+impl From<Fq> for ark_bn254::Fq {
+    fn from(x: Fq) -> Self {
+        ark_bn254::Fq::from_bigint(BigInt(x.into_u256().to_u64_limbs()))
+            .expect("canonical value is below q; qed")
+    }
+}
+
+impl From<ark_bn254::Fq> for Fq {
+    fn from(x: ark_bn254::Fq) -> Self {
+        Fq::from_u256(U256::from(x.into_bigint().0)).expect("canonical value is below q; qed")
+    }
+}
+
+impl From<Fq2> for ark_bn254::Fq2 {
+    fn from(x: Fq2) -> Self {
+        ark_bn254::Fq2::new(x.real().into(), x.imaginary().into())
+    }
+}
+
+impl From<ark_bn254::Fq2> for Fq2 {
+    fn from(x: ark_bn254::Fq2) -> Self {
+        Fq2::new(x.c0.into(), x.c1.into())
+    }
+}
+
+impl From<AffineG1> for ark_bn254::G1Affine {
+    fn from(p: AffineG1) -> Self {
+        ark_bn254::G1Affine::new_unchecked(p.x().into(), p.y().into())
+    }
+}
+
+impl TryFrom<ark_bn254::G1Affine> for AffineG1 {
+    type Error = CurveError;
+
+    fn try_from(p: ark_bn254::G1Affine) -> Result<Self, CurveError> {
+        let (x, y) = p.xy().ok_or(CurveError::ToAffineConversion)?;
+        AffineG1::new(x.into(), y.into()).map_err(|_| CurveError::NotMember)
+    }
+}
+
+impl From<G1> for ark_bn254::G1Projective {
+    fn from(p: G1) -> Self {
+        AffineG1::from_jacobian(p).map_or_else(ark_bn254::G1Projective::default, |p| {
+            ark_bn254::G1Affine::from(p).into()
+        })
+    }
+}
+
+impl TryFrom<ark_bn254::G1Projective> for G1 {
+    type Error = CurveError;
+
+    fn try_from(p: ark_bn254::G1Projective) -> Result<Self, CurveError> {
+        let p = p.into_affine();
+        if p.is_zero() {
+            return Ok(G1::zero());
+        }
+        AffineG1::try_from(p).map(G1::from)
+    }
+}
+
+impl From<AffineG2> for ark_bn254::G2Affine {
+    fn from(p: AffineG2) -> Self {
+        ark_bn254::G2Affine::new_unchecked(p.x().into(), p.y().into())
+    }
+}
+
+impl TryFrom<ark_bn254::G2Affine> for AffineG2 {
+    type Error = CurveError;
+
+    fn try_from(p: ark_bn254::G2Affine) -> Result<Self, CurveError> {
+        let (x, y) = p.xy().ok_or(CurveError::ToAffineConversion)?;
+        AffineG2::new(x.into(), y.into()).map_err(|_| CurveError::NotMember)
+    }
+}
+
+impl From<G2> for ark_bn254::G2Projective {
+    fn from(p: G2) -> Self {
+        AffineG2::from_jacobian(p).map_or_else(ark_bn254::G2Projective::default, |p| {
+            ark_bn254::G2Affine::from(p).into()
+        })
+    }
+}
+
+impl TryFrom<ark_bn254::G2Projective> for G2 {
+    type Error = CurveError;
+
+    fn try_from(p: ark_bn254::G2Projective) -> Result<Self, CurveError> {
+        let p = p.into_affine();
+        if p.is_zero() {
+            return Ok(G2::zero());
+        }
+        AffineG2::try_from(p).map(G2::from)
+    }
+}
+
+#[test]
+fn round_trips() {
+    let s = Fr::from(0x1234_5678_9abc_def0u64) * -Fr::one();
+    let ark_s = ark_bn254::Fr::from(s);
+    assert_eq!(Fr::from(ark_s), s);
+    assert_eq!(ark_bn254::Fq::from(-Fq::one()), -ark_bn254::Fq::from(1u64));
+
+    let p = G1::one() * s;
+    let ark_p = ark_bn254::G1Projective::from(G1::one()) * ark_s;
+    assert_eq!(ark_bn254::G1Projective::from(p), ark_p);
+    assert_eq!(G1::try_from(ark_p), Ok(p));
+    assert_eq!(
+        ark_bn254::G1Affine::from(AffineG1::generator()),
+        ark_bn254::G1Affine::generator()
+    );
+    assert_eq!(
+        G1::try_from(ark_bn254::G1Projective::default()),
+        Ok(G1::zero())
+    );
+
+    let q = G2::one() * s;
+    let ark_q = ark_bn254::G2Projective::from(G2::one()) * ark_s;
+    assert_eq!(ark_bn254::G2Projective::from(q), ark_q);
+    assert_eq!(G2::try_from(ark_q), Ok(q));
+    assert_eq!(
+        ark_bn254::G2Affine::from(AffineG2::generator()),
+        ark_bn254::G2Affine::generator()
+    );
+
+    let off_curve = ark_bn254::G1Affine::new_unchecked(1u64.into(), 3u64.into());
+    assert_eq!(AffineG1::try_from(off_curve), Err(CurveError::NotMember));
+}
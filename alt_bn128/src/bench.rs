@@ -0,0 +1,152 @@
+//! Standardized workloads for tracking the performance of this crate
+//! across versions and targets from downstream CI. Inputs are fixed and
+//! built before the clock starts, so measurements of the same workload are
+//! comparable between runs.
+//!
+//! The clock is supplied by the caller, as for `MsmCostModel::calibrate`:
+//! a cycle counter in CKB-VM, a monotonic nanosecond counter natively.
+
+use crate::groth16::{self, Mode, Proof, VerifyingKey};
+use crate::{pairing, pairing_check, AffineG1, AffineG2, Fr, Group, G1, G2};
+use alloc::vec::Vec;
+use core::hint::black_box;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Workload {
+    /// One full pairing.
+    Pairing,
+    /// `pairing_check` over 8 pairs.
+    PairingCheck8,
+    /// A G1 MSM of 2^16 terms.
+    Msm16,
+    /// Verifying a Groth16 proof with 2 public inputs.
+    Groth16Verify,
+}
+
+impl Workload {
+    pub const ALL: [Workload; 4] = [
+        Workload::Pairing,
+        Workload::PairingCheck8,
+        Workload::Msm16,
+        Workload::Groth16Verify,
+    ];
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    pub workload: Workload,
+    /// Units of work performed: pairs, MSM terms or proofs.
+    pub operations: usize,
+    /// Clock ticks elapsed, in the unit of the caller's clock.
+    pub elapsed: u64,
+}
+
+/// A fixed, nonzero scalar for each index.
+fn scalar(i: u64) -> Fr {
+    let mut wide = [0u8; 64];
+    wide[..8].copy_from_slice(&(i + 1).to_le_bytes());
+    wide[32..40].copy_from_slice(&0x9e37_79b9_7f4a_7c15u64.wrapping_mul(i + 1).to_le_bytes());
+    Fr::from_bytes_wide(&wide)
+}
+
+fn timed<T, C: FnMut() -> u64>(clock: &mut C, f: impl FnOnce() -> T) -> u64 {
+    let start = clock();
+    black_box(f());
+    clock() - start
+}
+
+fn pairs(n: usize) -> Vec<(G1, G2)> {
+    let mut out: Vec<(G1, G2)> = (0..n as u64)
+        .map(|i| (G1::one() * scalar(2 * i), G2::one() * scalar(2 * i + 1)))
+        .collect();
+    // make the product the identity, so the check runs to completion
+    let sum = (1..n).fold(Fr::zero(), |acc, i| {
+        acc + scalar(2 * i as u64) * scalar(2 * i as u64 + 1)
+    });
+    out[0] = (-(G1::one() * sum), G2::one());
+    out
+}
+
+fn groth16_verify<C: FnMut() -> u64>(clock: &mut C) -> u64 {
+    let g1 = |s: Fr| AffineG1::from_jacobian(G1::one() * s).expect("scalar is nonzero; qed");
+    let g2 = |s: Fr| AffineG2::from_jacobian(G2::one() * s).expect("scalar is nonzero; qed");
+    let [alpha, beta, gamma, delta, a, b] = [0, 1, 2, 3, 4, 5].map(scalar);
+    let ic_s = [scalar(6), scalar(7), scalar(8)];
+    let ic = ic_s.map(g1);
+    let vk = VerifyingKey {
+        alpha_g1: g1(alpha),
+        beta_g2: g2(beta),
+        gamma_g2: g2(gamma),
+        delta_g2: g2(delta),
+        ic: &ic,
+    };
+    let inputs = [scalar(9), scalar(10)];
+    let x = ic_s[0] + inputs[0] * ic_s[1] + inputs[1] * ic_s[2];
+    let c = (a * b - alpha * beta - x * gamma) * delta.inverse().expect("scalar is nonzero; qed");
+    let proof = Proof {
+        a: G1::one() * a,
+        b: G2::one() * b,
+        c: G1::one() * c,
+    };
+    timed(clock, || {
+        groth16::verify(&vk, &proof, &inputs, Mode::Strict).expect("proof is valid; qed")
+    })
+}
+
+/// Runs `workload` once.
+pub fn run<C: FnMut() -> u64>(workload: Workload, mut clock: C) -> Measurement {
+    let (operations, elapsed) = match workload {
+        Workload::Pairing => {
+            let (p, q) = (G1::one() * scalar(0), G2::one() * scalar(1));
+            (1, timed(&mut clock, || pairing(p, q)))
+        }
+        Workload::PairingCheck8 => {
+            let pairs = pairs(8);
+            let elapsed = timed(&mut clock, || {
+                assert!(pairing_check(&pairs), "pairs multiply to one; qed")
+            });
+            (8, elapsed)
+        }
+        Workload::Msm16 => {
+            const N: usize = 1 << 16;
+            let mut points = Vec::with_capacity(N);
+            let mut p = G1::one();
+            for _ in 0..N {
+                points
+                    .push(AffineG1::from_jacobian(p).expect("multiples below r are nonzero; qed"));
+                p += G1::one();
+            }
+            let scalars: Vec<Fr> = (0..N as u64).map(scalar).collect();
+            (N, timed(&mut clock, || G1::msm(&points, &scalars)))
+        }
+        Workload::Groth16Verify => (1, groth16_verify(&mut clock)),
+    };
+    Measurement {
+        workload,
+        operations,
+        elapsed,
+    }
+}
+
+/// Runs every workload once, in the order of `Workload::ALL`.
+pub fn run_all<C: FnMut() -> u64>(mut clock: C) -> [Measurement; 4] {
+    Workload::ALL.map(|w| run(w, &mut clock))
+}
+
+#[test]
+fn workloads_complete() {
+    let mut ticks = 0;
+    let mut clock = || {
+        ticks += 1;
+        ticks
+    };
+    for w in [
+        Workload::Pairing,
+        Workload::PairingCheck8,
+        Workload::Groth16Verify,
+    ] {
+        let m = run(w, &mut clock);
+        assert_eq!(m.workload, w);
+        assert_eq!(m.elapsed, 1);
+    }
+}
@@ -0,0 +1,222 @@
+//! Fixed-capacity caches for work that verifiers repeat across calls.
+//!
+//! `PreparedCache` holds prepared pairing inputs for recurring verifying
+//! keys or committees. Its entries are tagged with a caller-defined epoch
+//! and are never evicted implicitly: a full cache rejects inserts until the
+//! caller removes entries or drops a range of epochs. `CachedMul` memoizes
+//! scalar multiplications.
+
+use crate::{AffineG1, Fr, G2Prepared, Gt, G1};
+use sha2::{Digest, Sha256};
+
+/// A cached value: a prepared G2 point or a precomputed pairing such as the
+/// `e(alpha, beta)` term of a Groth16 verifying key.
+#[allow(clippy::large_enum_variant)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Prepared {
+    G2(G2Prepared),
+    Gt(Gt),
+}
+
+impl From<G2Prepared> for Prepared {
+    fn from(p: G2Prepared) -> Self {
+        Prepared::G2(p)
+    }
+}
+
+impl From<Gt> for Prepared {
+    fn from(p: Gt) -> Self {
+        Prepared::Gt(p)
+    }
+}
+
+/// Returned by `insert` when the key is new and every slot is taken.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CacheFull;
+
+struct Entry<K> {
+    key: K,
+    epoch: u64,
+    value: Prepared,
+}
+
+pub struct PreparedCache<K, const N: usize> {
+    entries: [Option<Entry<K>>; N],
+}
+
+impl<K: PartialEq, const N: usize> Default for PreparedCache<K, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PartialEq, const N: usize> PreparedCache<K, N> {
+    pub fn new() -> Self {
+        PreparedCache {
+            entries: core::array::from_fn(|_| None),
+        }
+    }
+
+    fn position(&self, key: &K) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|e| e.as_ref().is_some_and(|e| e.key == *key))
+    }
+
+    pub fn get(&self, key: &K) -> Option<&Prepared> {
+        self.position(key)
+            .and_then(|i| self.entries[i].as_ref())
+            .map(|e| &e.value)
+    }
+
+    pub fn get_g2(&self, key: &K) -> Option<&G2Prepared> {
+        match self.get(key) {
+            Some(Prepared::G2(p)) => Some(p),
+            _ => None,
+        }
+    }
+
+    pub fn get_gt(&self, key: &K) -> Option<&Gt> {
+        match self.get(key) {
+            Some(Prepared::Gt(p)) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Inserts or replaces the value for `key`.
+    pub fn insert<V: Into<Prepared>>(
+        &mut self,
+        key: K,
+        epoch: u64,
+        value: V,
+    ) -> Result<(), CacheFull> {
+        let slot = match self.position(&key) {
+            Some(i) => i,
+            None => match self.entries.iter().position(Option::is_none) {
+                Some(i) => i,
+                None => return Err(CacheFull),
+            },
+        };
+        self.entries[slot] = Some(Entry {
+            key,
+            epoch,
+            value: value.into(),
+        });
+        Ok(())
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<Prepared> {
+        self.position(key)
+            .and_then(|i| self.entries[i].take())
+            .map(|e| e.value)
+    }
+
+    /// Drops every entry inserted with an epoch below `epoch` and returns
+    /// how many were dropped.
+    pub fn evict_before(&mut self, epoch: u64) -> usize {
+        let mut evicted = 0;
+        for e in self.entries.iter_mut() {
+            if e.as_ref().is_some_and(|e| e.epoch < epoch) {
+                *e = None;
+                evicted += 1;
+            }
+        }
+        evicted
+    }
+
+    pub fn clear(&mut self) {
+        for e in self.entries.iter_mut() {
+            *e = None;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.iter().filter(|e| e.is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A bounded memo of G1 scalar multiplications, for workloads where the
+/// same `(point, scalar)` pairs recur, such as re-verifying gossiped proofs.
+///
+/// Entries are keyed by a SHA-256 digest of the canonical encodings of the
+/// inputs and replaced first-in first-out once all `N` slots are used.
+pub struct CachedMul<const N: usize> {
+    entries: [Option<([u8; 32], G1)>; N],
+    next: usize,
+}
+
+impl<const N: usize> Default for CachedMul<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> CachedMul<N> {
+    pub fn new() -> Self {
+        CachedMul {
+            entries: [None; N],
+            next: 0,
+        }
+    }
+
+    fn digest(point: &G1, scalar: &Fr) -> [u8; 32] {
+        let mut buf = [0u8; 96];
+        if let Some(p) = AffineG1::from_jacobian(*point) {
+            p.x()
+                .to_big_endian(&mut buf[..32])
+                .expect("slice is 32 bytes; qed");
+            p.y()
+                .to_big_endian(&mut buf[32..64])
+                .expect("slice is 32 bytes; qed");
+        }
+        scalar
+            .into_u256()
+            .to_big_endian(&mut buf[64..])
+            .expect("slice is 32 bytes; qed");
+        let mut h = Sha256::new();
+        h.update(b"alt_bn128 CachedMul");
+        h.update(buf);
+        h.finalize().into()
+    }
+
+    /// `point * scalar`, computed at most once while the entry stays cached.
+    pub fn mul(&mut self, point: G1, scalar: Fr) -> G1 {
+        let key = Self::digest(&point, &scalar);
+        if let Some((_, r)) = self.entries.iter().flatten().find(|(k, _)| *k == key) {
+            return *r;
+        }
+        let r = point * scalar;
+        if N > 0 {
+            self.entries[self.next] = Some((key, r));
+            self.next = (self.next + 1) % N;
+        }
+        r
+    }
+
+    pub fn clear(&mut self) {
+        self.entries = [None; N];
+        self.next = 0;
+    }
+}
+
+#[test]
+fn cache_eviction() {
+    use crate::{Group, G2};
+
+    let q = G2::one().prepare().unwrap();
+    let mut cache = PreparedCache::<u32, 2>::new();
+    assert!(cache.insert(1, 10, q).is_ok());
+    assert!(cache.insert(2, 11, Gt::one()).is_ok());
+    assert!(cache.insert(3, 11, q).is_err());
+    assert!(cache.get_g2(&1) == Some(&q));
+    assert!(cache.get_g2(&2).is_none());
+
+    assert_eq!(cache.evict_before(11), 1);
+    assert!(cache.get(&1).is_none());
+    assert!(cache.insert(3, 12, q).is_ok());
+    assert_eq!(cache.len(), 2);
+}
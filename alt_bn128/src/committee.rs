@@ -0,0 +1,154 @@
+//! Verification of BLS signatures aggregated by a subset of a fixed
+//! committee, as done by light clients tracking a sync committee.
+//!
+//! Keys are aggregated with `sum_selected`, so a whole committee costs one
+//! bucket sum and two pairings. The fast aggregation path is only sound if
+//! every committee key came with a proof of possession; checking those is
+//! left to whoever assembles the committee. `message` is the message already
+//! hashed to the signature group.
+
+#[cfg(feature = "verify-twice")]
+use crate::redundant;
+use crate::{pairing_check, AffineG1, AffineG2, Group, G1, G2};
+use core::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The bitmap is not exactly `ceil(committee size / 8)` bytes.
+    BitmapLength,
+    /// A bit past the end of the committee is set.
+    BitmapPadding,
+    NoParticipants,
+    InvalidSignature,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::BitmapLength => "participation bitmap has the wrong length",
+            Error::BitmapPadding => "participation bitmap sets a bit past the committee",
+            Error::NoParticipants => "no committee member participated",
+            Error::InvalidSignature => "invalid signature",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Validates the participation bitmap and returns the number of set bits.
+fn participants(committee_size: usize, bitmap: &[u8]) -> Result<usize, Error> {
+    if bitmap.len() != committee_size.div_ceil(8) {
+        return Err(Error::BitmapLength);
+    }
+    if !committee_size.is_multiple_of(8) {
+        let last = bitmap[bitmap.len() - 1];
+        if last >> (committee_size % 8) != 0 {
+            return Err(Error::BitmapPadding);
+        }
+    }
+    let count = bitmap.iter().map(|b| b.count_ones() as usize).sum();
+    if count == 0 {
+        return Err(Error::NoParticipants);
+    }
+    Ok(count)
+}
+
+#[cfg(feature = "verify-twice")]
+fn naive_sum<P, G>(pubkeys: &[P], bitmap: &[u8]) -> G
+where
+    P: Copy + Into<G>,
+    G: Group,
+{
+    let mut sum = G::zero();
+    for (i, pk) in pubkeys.iter().enumerate() {
+        if bitmap[i / 8] >> (i % 8) & 1 == 1 {
+            sum = sum + (*pk).into();
+        }
+    }
+    sum
+}
+
+/// Verifies `signature` in G2 over `message` against the aggregate of the
+/// G1 keys selected by `bitmap`. Returns the number of participants, so the
+/// caller can apply its own quorum rule.
+pub fn verify_g1_committee(
+    pubkeys: &[AffineG1],
+    bitmap: &[u8],
+    message: G2,
+    signature: G2,
+) -> Result<usize, Error> {
+    let count = participants(pubkeys.len(), bitmap)?;
+    let apk = G1::sum_selected(pubkeys, bitmap);
+    // e(apk, H(m)) == e(g1, sig)
+    if !pairing_check(&[(apk, message), (-G1::one(), signature)]) {
+        return Err(Error::InvalidSignature);
+    }
+    #[cfg(feature = "verify-twice")]
+    {
+        // aggregate again one key at a time and scale both pairs by rho
+        let apk = naive_sum(pubkeys, bitmap);
+        let rho = redundant::randomizer(b"committee-g1", &[apk], bitmap);
+        if !pairing_check(&[(apk * rho, message), (G1::one() * -rho, signature)]) {
+            return Err(Error::InvalidSignature);
+        }
+    }
+    Ok(count)
+}
+
+/// Verifies `signature` in G1 over `message` against the aggregate of the
+/// G2 keys selected by `bitmap`. Returns the number of participants.
+pub fn verify_g2_committee(
+    pubkeys: &[AffineG2],
+    bitmap: &[u8],
+    message: G1,
+    signature: G1,
+) -> Result<usize, Error> {
+    let count = participants(pubkeys.len(), bitmap)?;
+    let apk = G2::sum_selected(pubkeys, bitmap);
+    // e(sig, g2) == e(H(m), apk)
+    if !pairing_check(&[(signature, G2::one()), (-message, apk)]) {
+        return Err(Error::InvalidSignature);
+    }
+    #[cfg(feature = "verify-twice")]
+    {
+        let apk = naive_sum(pubkeys, bitmap);
+        let rho = redundant::randomizer(b"committee-g2", &[signature, message], bitmap);
+        if !pairing_check(&[(signature * rho, G2::one()), (message * -rho, apk)]) {
+            return Err(Error::InvalidSignature);
+        }
+    }
+    Ok(count)
+}
+
+#[test]
+fn committee_signature() {
+    use crate::Fr;
+
+    let secrets = ["3", "5", "7", "11", "13", "17", "19", "23", "29"];
+    let mut pubkeys = [AffineG2::from_jacobian(G2::one()).unwrap(); 9];
+    for (pk, s) in pubkeys.iter_mut().zip(secrets.iter()) {
+        *pk = AffineG2::from_jacobian(G2::one() * Fr::from_str(s).unwrap()).unwrap();
+    }
+    let message = G1::one() * Fr::from_str("1234567").unwrap();
+    // members 0, 2 and 8 sign
+    let bitmap = [0b0000_0101, 0b1];
+    let sk = Fr::from_str("3").unwrap() + Fr::from_str("7").unwrap() + Fr::from_str("29").unwrap();
+    let signature = message * sk;
+
+    assert_eq!(
+        verify_g2_committee(&pubkeys, &bitmap, message, signature),
+        Ok(3)
+    );
+    assert_eq!(
+        verify_g2_committee(&pubkeys, &[0b0000_0111, 0b1], message, signature),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verify_g2_committee(&pubkeys, &[0b0000_0101, 0b11], message, signature),
+        Err(Error::BitmapPadding)
+    );
+    assert_eq!(
+        verify_g2_committee(&pubkeys, &[0b0000_0101], message, signature),
+        Err(Error::BitmapLength)
+    );
+}
@@ -0,0 +1,122 @@
+//! Differential testing against a second BN254 implementation, for fuzz
+//! targets that feed the same raw bytes to both.
+//!
+//! Inputs use the EIP-196/197 precompile formats, so any implementation of
+//! those precompiles can be plugged in through `Reference`.
+
+use crate::ethereum;
+
+/// A second implementation of the precompiles. Each method returns `None`
+/// when it rejects the input.
+pub trait Reference {
+    fn g1_add(&self, input: &[u8]) -> Option<[u8; 64]>;
+    fn g1_mul(&self, input: &[u8]) -> Option<[u8; 64]>;
+    fn pairing_check(&self, input: &[u8]) -> Option<bool>;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    G1Add,
+    G1Mul,
+    Pairing,
+}
+
+/// The result of one operation on one implementation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Rejected,
+    Point([u8; 64]),
+    Bool(bool),
+}
+
+/// Outputs of the two implementations for an input on which they disagree.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub operation: Operation,
+    pub ours: Outcome,
+    pub reference: Outcome,
+}
+
+fn point(r: Option<[u8; 64]>) -> Outcome {
+    r.map_or(Outcome::Rejected, Outcome::Point)
+}
+
+/// Runs `operation` on `input` through this crate and `reference` and
+/// returns the common outcome, or both outcomes if they differ.
+#[allow(clippy::result_large_err)]
+pub fn compare<R: Reference>(
+    reference: &R,
+    operation: Operation,
+    input: &[u8],
+) -> Result<Outcome, Mismatch> {
+    let (ours, theirs) = match operation {
+        Operation::G1Add => {
+            let ours = ethereum::alt_bn128_add(input).ok();
+            (point(ours), point(reference.g1_add(input)))
+        }
+        Operation::G1Mul => {
+            let ours = ethereum::alt_bn128_mul(input).ok();
+            (point(ours), point(reference.g1_mul(input)))
+        }
+        Operation::Pairing => {
+            let ours = ethereum::alt_bn128_pairing(input).ok();
+            let bool = |r: Option<bool>| r.map_or(Outcome::Rejected, Outcome::Bool);
+            (bool(ours), bool(reference.pairing_check(input)))
+        }
+    };
+    if ours == theirs {
+        Ok(ours)
+    } else {
+        Err(Mismatch {
+            operation,
+            ours,
+            reference: theirs,
+        })
+    }
+}
+
+/// Runs every operation on `input`, stopping at the first mismatch. Lets a
+/// single fuzz target cover all precompiles.
+#[allow(clippy::result_large_err)]
+pub fn compare_all<R: Reference>(reference: &R, input: &[u8]) -> Result<(), Mismatch> {
+    for operation in [Operation::G1Add, Operation::G1Mul, Operation::Pairing] {
+        compare(reference, operation, input)?;
+    }
+    Ok(())
+}
+
+/// Panics with both outcomes if the implementations disagree.
+pub fn assert_agree<R: Reference>(reference: &R, input: &[u8]) {
+    if let Err(m) = compare_all(reference, input) {
+        panic!(
+            "{:?} diverges: ours {:?}, reference {:?}",
+            m.operation, m.ours, m.reference
+        );
+    }
+}
+
+#[test]
+fn reports_mismatch() {
+    struct RejectsAll;
+    impl Reference for RejectsAll {
+        fn g1_add(&self, _: &[u8]) -> Option<[u8; 64]> {
+            None
+        }
+        fn g1_mul(&self, _: &[u8]) -> Option<[u8; 64]> {
+            None
+        }
+        fn pairing_check(&self, _: &[u8]) -> Option<bool> {
+            None
+        }
+    }
+
+    let m = compare_all(&RejectsAll, &[]).unwrap_err();
+    assert_eq!(m.operation, Operation::G1Add);
+    assert_eq!(m.ours, Outcome::Point([0; 64]));
+    assert_eq!(m.reference, Outcome::Rejected);
+    // 100 bytes is not a valid pairing input for either side
+    assert_eq!(
+        compare(&RejectsAll, Operation::Pairing, &[0; 100]),
+        Ok(Outcome::Rejected)
+    );
+}
@@ -0,0 +1,43 @@
+//! The source of randomness taken by every randomized API in the crate:
+//! sampling, blinding and randomized batch checks. It is a single method,
+//! so that targets without `rand_core`, such as on-chain scripts or bare
+//! metal firmware, can plug in whatever they have.
+//!
+//! With the `rand` feature every `rand_core::RngCore` is a source.
+
+/// Fills buffers with uniformly random bytes. There is no way to report
+/// failure: a source that cannot produce entropy must panic rather than
+/// return predictable bytes.
+pub trait EntropySource {
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+#[cfg(feature = "rand")]
+impl<R: rand_core::RngCore + ?Sized> EntropySource for R {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::RngCore::fill_bytes(self, dest)
+    }
+}
+
+/// Adapts a closure.
+pub struct FnEntropy<F>(pub F);
+
+impl<F: FnMut(&mut [u8])> EntropySource for FnEntropy<F> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        (self.0)(dest)
+    }
+}
+
+/// Adapts a C-style entropy function, the shape of embedded HAL and VM
+/// syscall interfaces: it fills `len` bytes at `buf` and returns zero on
+/// success. Any other return value panics.
+pub struct SyscallEntropy(pub unsafe extern "C" fn(buf: *mut u8, len: usize) -> i32);
+
+impl EntropySource for SyscallEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // SAFETY: `dest` is valid for writes of `dest.len()` bytes, which
+        // is the contract of the wrapped function.
+        let ret = unsafe { (self.0)(dest.as_mut_ptr(), dest.len()) };
+        assert!(ret == 0, "entropy syscall failed with {}", ret);
+    }
+}
@@ -1,130 +1,320 @@
-use crate::{arith::U256, pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+use crate::{arith::U256, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+use core::fmt;
 
-pub struct Error(pub &'static str);
+pub mod words;
 
-fn read_fr(buf: &[u8]) -> Result<Fr, Error> {
-    Fr::from_slice(buf).map_err(|_| Error("invalid fr"))
+/// How go-ethereum classifies a rejected precompile input. Every kind
+/// fails the call the same way; the classes matter to runtimes that log
+/// or surface the reason.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The input length does not fit the call.
+    Length,
+    /// A coordinate or scalar is not below its modulus.
+    NotCanonical,
+    /// A point is not on the curve or, for G2, not in the prime-order
+    /// subgroup.
+    MalformedPoint,
 }
 
-fn read_pt(buf: &[u8]) -> Result<G1, Error> {
-    let px = Fq::from_slice(&buf[0..32]).map_err(|_| Error("invalid pt"))?;
-    let py = Fq::from_slice(&buf[32..64]).map_err(|_| Error("invalid pt"))?;
+impl ErrorKind {
+    /// The error string go-ethereum reports for this kind.
+    pub fn geth_message(&self) -> &'static str {
+        match self {
+            ErrorKind::Length => "bad elliptic curve pairing size",
+            ErrorKind::NotCanonical => "bn256: coordinate exceeds modulus",
+            ErrorKind::MalformedPoint => "bn256: malformed point",
+        }
+    }
+}
+
+/// A rejected precompile input. `offset` is the position in the input of
+/// the first byte of the offending value, or the input length for errors
+/// about the length itself.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub message: &'static str,
+    pub offset: usize,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl Error {
+    fn at(kind: ErrorKind, message: &'static str, offset: usize) -> Self {
+        Error {
+            kind,
+            message,
+            offset,
+        }
+    }
+}
+
+fn read_fr(buf: &[u8], offset: usize) -> Result<Fr, Error> {
+    Fr::from_slice(buf).map_err(|_| Error::at(ErrorKind::Length, "invalid fr", offset))
+}
+
+fn read_fq(buf: &[u8], offset: usize, message: &'static str) -> Result<Fq, Error> {
+    Fq::from_slice(&buf[offset..offset + 32])
+        .map_err(|_| Error::at(ErrorKind::NotCanonical, message, offset))
+}
+
+/// Reads a G1 point at `offset` of `buf`.
+pub(crate) fn read_pt(buf: &[u8], offset: usize) -> Result<G1, Error> {
+    let px = read_fq(buf, offset, "invalid pt x coordinate")?;
+    let py = read_fq(buf, offset + 32, "invalid pt y coordinate")?;
     Ok(if px == Fq::zero() && py == Fq::zero() {
         G1::zero()
     } else {
         AffineG1::new(px, py)
-            .map_err(|_| Error("invalid pt"))?
+            .map_err(|_| {
+                Error::at(
+                    ErrorKind::MalformedPoint,
+                    "invalid pt - not on curve",
+                    offset,
+                )
+            })?
             .into()
     })
 }
 
-pub fn alt_bn128_add(data: &[u8], output: &mut [u8; 64]) -> Result<(), Error> {
-    let mut buffer = [0u8; 128];
-    if data.len() < 128 {
-        buffer[0..data.len()].copy_from_slice(&data);
+/// Most pairs `validate_pairing_input` accepts, bounding the buffer
+/// `parse_pairing_input` needs. `alt_bn128_pairing` has no limit.
+pub const MAX_PAIRING_PAIRS: usize = 16;
+
+/// Copies `data` into a zero-padded buffer, truncating extra input as the
+/// precompiles do.
+fn padded<const N: usize>(data: &[u8]) -> [u8; N] {
+    let mut buffer = [0u8; N];
+    let len = core::cmp::min(data.len(), N);
+    buffer[0..len].copy_from_slice(&data[0..len]);
+    buffer
+}
+
+/// Parses the input of `alt_bn128_add` without adding.
+pub fn parse_add_input(data: &[u8]) -> Result<(G1, G1), Error> {
+    let buffer = padded::<128>(data);
+    Ok((read_pt(&buffer, 0)?, read_pt(&buffer, 64)?))
+}
+
+/// Parses the input of `alt_bn128_mul` without multiplying.
+pub fn parse_mul_input(data: &[u8]) -> Result<(G1, Fr), Error> {
+    let buffer = padded::<96>(data);
+    Ok((read_pt(&buffer, 0)?, read_fr(&buffer[64..96], 64)?))
+}
+
+/// Checks the shape of a pairing input of `len` bytes and returns the
+/// number of pairs, without reading it.
+pub fn validate_pairing_input(len: usize) -> Result<usize, Error> {
+    let pairs = pair_count(len)?;
+    if pairs > MAX_PAIRING_PAIRS {
+        return Err(Error::at(ErrorKind::Length, "Too many pairs", len));
+    }
+    Ok(pairs)
+}
+
+/// The number of 192-byte pairs in `len` bytes.
+fn pair_count(len: usize) -> Result<usize, Error> {
+    if len % 192 != 0 {
+        return Err(Error::at(
+            ErrorKind::Length,
+            "Invalid input length, must be multiple of 192 (3 * (32*2))",
+            len,
+        ));
+    }
+    Ok(len / 192)
+}
+
+/// Reads a G2 point at `offset` of `data`, coefficients imaginary first.
+pub(crate) fn read_g2(data: &[u8], offset: usize) -> Result<G2, Error> {
+    let x_imaginary = read_fq(data, offset, "Invalid b argument x imaginary coeff")?;
+    let x_real = read_fq(data, offset + 32, "Invalid b argument x real coeff")?;
+    let y_imaginary = read_fq(data, offset + 64, "Invalid b argument y imaginary coeff")?;
+    let y_real = read_fq(data, offset + 96, "Invalid b argument y real coeff")?;
+
+    let x = Fq2::new(x_real, x_imaginary);
+    let y = Fq2::new(y_real, y_imaginary);
+    Ok(if x.is_zero() && y.is_zero() {
+        G2::zero()
     } else {
-        buffer[0..128].copy_from_slice(&data[0..128]);
+        G2::from(AffineG2::new(x, y).map_err(|_| {
+            Error::at(
+                ErrorKind::MalformedPoint,
+                "Invalid b argument - not on curve",
+                offset,
+            )
+        })?)
+    })
+}
+
+/// EVM encodings, which put the imaginary coefficient of an Fq2 element
+/// first: the reverse of `Fq2::new(real, imaginary)`.
+impl Fq2 {
+    /// Reads `imaginary || real` as big-endian words, rejecting
+    /// coefficients not below p.
+    pub fn from_evm_slice(bytes: &[u8; 64]) -> Result<Fq2, Error> {
+        let imaginary = read_fq(bytes, 0, "invalid imaginary coeff")?;
+        let real = read_fq(bytes, 32, "invalid real coeff")?;
+        Ok(Fq2::new(real, imaginary))
     }
-    let p1 = read_pt(&buffer[0..64])?;
-    let p2 = read_pt(&buffer[64..128])?;
 
-    let mut buffer = [0u8; 64];
-    if let Some(sum) = AffineG1::from_jacobian(p1 + p2) {
-        sum.x().to_big_endian(&mut buffer[0..32]).unwrap();
-        sum.y().to_big_endian(&mut buffer[32..64]).unwrap();
+    pub fn to_evm_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&words::fq_to_word(self.imaginary()));
+        bytes[32..].copy_from_slice(&words::fq_to_word(self.real()));
+        bytes
+    }
+}
+
+impl AffineG2 {
+    /// Reads `x || y` in the encoding of `Fq2::from_evm_slice`, as the
+    /// pairing precompile does. All zeros, the EVM's point at infinity,
+    /// has no affine form and is rejected, so the policy is always
+    /// `DecodePolicy::default()`: canonical coordinates and a subgroup
+    /// check.
+    pub fn from_evm_slice(bytes: &[u8; 128]) -> Result<AffineG2, Error> {
+        AffineG2::from_jacobian(read_g2(bytes, 0)?).ok_or(Error::at(
+            ErrorKind::MalformedPoint,
+            "point at infinity",
+            0,
+        ))
+    }
+
+    pub fn to_evm_bytes(&self) -> [u8; 128] {
+        let mut bytes = [0u8; 128];
+        bytes[..64].copy_from_slice(&self.x().to_evm_bytes());
+        bytes[64..].copy_from_slice(&self.y().to_evm_bytes());
+        bytes
     }
-    *output = buffer;
-    Ok(())
 }
 
-pub fn alt_bn128_mul(data: &[u8], output: &mut [u8; 64]) -> Result<(), Error> {
-    let mut buffer = [0u8; 96];
-    if data.len() < 96 {
-        buffer[0..data.len()].copy_from_slice(&data);
+/// Reads the pair starting at `offset` of `data`. `a` is checked before
+/// `b` is read, so the first error matches go-ethereum's.
+fn read_pair(data: &[u8], offset: usize) -> Result<(G1, G2), Error> {
+    let a_x = read_fq(data, offset, "Invalid a argument x coordinate")?;
+    let a_y = read_fq(data, offset + 32, "Invalid a argument y coordinate")?;
+    let a = if a_x.is_zero() && a_y.is_zero() {
+        G1::zero()
     } else {
-        buffer[0..96].copy_from_slice(&data[0..96]);
+        G1::from(AffineG1::new(a_x, a_y).map_err(|_| {
+            Error::at(
+                ErrorKind::MalformedPoint,
+                "Invalid a argument - not on curve",
+                offset,
+            )
+        })?)
+    };
+    let b = read_g2(data, offset + 64)?;
+    Ok((a, b))
+}
+
+/// Parses and validates the input of `alt_bn128_pairing` into `out`
+/// without computing any pairing, returning the filled prefix of `out`.
+pub fn parse_pairing_input<'a>(
+    data: &[u8],
+    out: &'a mut [(G1, G2)],
+) -> Result<&'a [(G1, G2)], Error> {
+    let elements = validate_pairing_input(data.len())?;
+    if out.len() < elements {
+        return Err(Error::at(
+            ErrorKind::Length,
+            "Output buffer too small",
+            data.len(),
+        ));
+    }
+    for (i, pair) in out[..elements].iter_mut().enumerate() {
+        *pair = read_pair(data, i * 192)?;
     }
-    let pt = read_pt(&buffer[0..64])?;
-    let fr = read_fr(&buffer[64..96])?;
+    Ok(&out[0..elements])
+}
+
+/// The 64-byte encoding of `p`, zero for the point at infinity.
+pub(crate) fn encode_g1(p: G1) -> [u8; 64] {
     let mut buffer = [0u8; 64];
-    if let Some(sum) = AffineG1::from_jacobian(pt * fr) {
-        sum.x().to_big_endian(&mut buffer[0..32]).unwrap();
-        sum.y().to_big_endian(&mut buffer[32..64]).unwrap();
+    if let Some(p) = AffineG1::from_jacobian(p) {
+        p.x().to_big_endian(&mut buffer[0..32]).unwrap();
+        p.y().to_big_endian(&mut buffer[32..64]).unwrap();
     }
-    *output = buffer;
-    Ok(())
+    buffer
 }
 
-pub fn alt_bn128_pairing(data: &[u8], output: &mut [u8; 32]) -> Result<(), Error> {
-    if data.len() % 192 != 0 {
-        return Err(Error(
-            "Invalid input length, must be multiple of 192 (3 * (32*2))",
-        ));
+/// The ECADD precompile (EIP-196, address 0x06). Input shorter than 128
+/// bytes is padded with zeros and longer input is ignored.
+pub fn alt_bn128_add(data: &[u8]) -> Result<[u8; 64], Error> {
+    let (p1, p2) = parse_add_input(data)?;
+    Ok(encode_g1(p1 + p2))
+}
+
+/// The ECMUL precompile (EIP-196, address 0x07), padded to 96 bytes like
+/// `alt_bn128_add`. The scalar may be any 256-bit value.
+pub fn alt_bn128_mul(data: &[u8]) -> Result<[u8; 64], Error> {
+    let (pt, fr) = parse_mul_input(data)?;
+    Ok(encode_g1(pt * fr))
+}
+
+/// The ECPAIRING precompile (EIP-197, address 0x08): whether the product
+/// of the pairings is one, true for empty input. Any number of pairs is
+/// accepted; they are decoded as the Miller loops consume them, and an
+/// invalid pair fails the call even if it follows valid ones.
+pub fn alt_bn128_pairing(data: &[u8]) -> Result<bool, Error> {
+    let mut error = None;
+    let pairs = (0..pair_count(data.len())?)
+        .map_while(|i| read_pair(data, i * 192).map_err(|e| error = Some(e)).ok());
+    let f = crate::miller_loop_batch_iter(pairs);
+    match error {
+        Some(e) => Err(e),
+        None => Ok(f.final_exponentiation() == Some(Gt::one())),
     }
+}
+
+/// The 32-byte word `alt_bn128_pairing` returns to the EVM.
+pub fn pairing_output(ok: bool) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    U256::from(ok as u64)
+        .to_big_endian(&mut word)
+        .expect("word is 32 bytes; qed");
+    word
+}
 
-    let elements = data.len() / 192; // (a, b_a, b_b - each 64-byte affine coordinates)
-    let ret_val = if data.len() == 0 {
-        U256::one()
+/// Gas of `alt_bn128_add`: 150 since EIP-1108, 500 before.
+pub fn add_gas(pre_eip1108: bool) -> u64 {
+    if pre_eip1108 {
+        500
     } else {
-        let mut vals = [(G1::default(), G2::default()); 16];
-        for idx in 0..elements {
-            let a_x = Fq::from_slice(&data[idx * 192..idx * 192 + 32])
-                .map_err(|_| Error("Invalid a argument x coordinate"))?;
-
-            let a_y = Fq::from_slice(&data[idx * 192 + 32..idx * 192 + 64])
-                .map_err(|_| Error("Invalid a argument y coordinate"))?;
-
-            let b_a_y = Fq::from_slice(&data[idx * 192 + 64..idx * 192 + 96])
-                .map_err(|_| Error("Invalid b argument imaginary coeff x coordinate"))?;
-
-            let b_a_x = Fq::from_slice(&data[idx * 192 + 96..idx * 192 + 128])
-                .map_err(|_| Error("Invalid b argument imaginary coeff y coordinate"))?;
-
-            let b_b_y = Fq::from_slice(&data[idx * 192 + 128..idx * 192 + 160])
-                .map_err(|_| Error("Invalid b argument real coeff x coordinate"))?;
-
-            let b_b_x = Fq::from_slice(&data[idx * 192 + 160..idx * 192 + 192])
-                .map_err(|_| Error("Invalid b argument real coeff y coordinate"))?;
-
-            let b_a = Fq2::new(b_a_x, b_a_y);
-            let b_b = Fq2::new(b_b_x, b_b_y);
-            let b = if b_a.is_zero() && b_b.is_zero() {
-                G2::zero()
-            } else {
-                G2::from(
-                    AffineG2::new(b_a, b_b)
-                        .map_err(|_| Error("Invalid b argument - not on curve"))?,
-                )
-            };
-            let a = if a_x.is_zero() && a_y.is_zero() {
-                G1::zero()
-            } else {
-                G1::from(
-                    AffineG1::new(a_x, a_y)
-                        .map_err(|_| Error("Invalid a argument - not on curve"))?,
-                )
-            };
-            vals[idx] = (a, b);
-        }
+        150
+    }
+}
 
-        let mul = pairing_batch(&vals[0..elements]);
+/// Gas of `alt_bn128_mul`: 6000 since EIP-1108, 40000 before.
+pub fn mul_gas(pre_eip1108: bool) -> u64 {
+    if pre_eip1108 {
+        40_000
+    } else {
+        6_000
+    }
+}
 
-        if mul == Gt::one() {
-            U256::one()
-        } else {
-            U256::zero()
-        }
+/// Gas of `alt_bn128_pairing` on `len` bytes of input: 45000 plus 34000
+/// per pair since EIP-1108, 100000 plus 80000 per pair before. Like
+/// go-ethereum, the pairs are counted by `len / 192`, and the gas is
+/// charged before the length is validated.
+pub fn pairing_gas(len: usize, pre_eip1108: bool) -> u64 {
+    let (base, per_pair) = if pre_eip1108 {
+        (100_000, 80_000)
+    } else {
+        (45_000, 34_000)
     };
-
-    ret_val
-        .to_big_endian(output)
-        .expect("Cannot fail since 0..32 is 32-byte length");
-
-    Ok(())
+    base + per_pair * (len / 192) as u64
 }
 
 pub mod ut {
-    use super::{alt_bn128_add, alt_bn128_mul, alt_bn128_pairing};
+    use super::{alt_bn128_add, alt_bn128_mul, alt_bn128_pairing, pairing_output};
 
     pub fn hex2bin(s: &str, output: &mut [u8]) {
         for i in (0..s.len()).step_by(2) {
@@ -195,9 +385,8 @@ pub mod ut {
     pub fn test_alt_bn128_add() {
         for (inputs, expect) in &ALT_BN128_ADD_CASE {
             let mut buf0 = [0x00; 1024];
-            let mut buf1 = [0x00; 64];
             hex2bin(inputs, &mut buf0[..]);
-            assert!(alt_bn128_add(&buf0[0..inputs.len() / 2], &mut buf1).is_ok());
+            let buf1 = alt_bn128_add(&buf0[0..inputs.len() / 2]).unwrap();
             hex2bin(expect, &mut buf0[..]);
             assert_eq!(buf0[0..64], buf1[..]);
         }
@@ -206,9 +395,8 @@ pub mod ut {
     pub fn test_alt_bn128_mul() {
         for (inputs, expect) in &ALT_BN128_MUL_CASE {
             let mut buf0 = [0x00; 1024];
-            let mut buf1 = [0x00; 64];
             hex2bin(inputs, &mut buf0[..]);
-            assert!(alt_bn128_mul(&buf0[0..inputs.len() / 2], &mut buf1).is_ok());
+            let buf1 = alt_bn128_mul(&buf0[0..inputs.len() / 2]).unwrap();
             hex2bin(expect, &mut buf0[..]);
             assert_eq!(buf0[0..64], buf1[..]);
         }
@@ -217,11 +405,79 @@ pub mod ut {
     pub fn test_alt_bn128_pairing() {
         for (inputs, expect) in &ALT_BN128_PAIRING_CASE {
             let mut buf0 = [0x00; 4096];
-            let mut buf1 = [0x00; 32];
             hex2bin(inputs, &mut buf0[..]);
-            assert!(alt_bn128_pairing(&buf0[0..inputs.len() / 2], &mut buf1).is_ok());
+            let buf1 = pairing_output(alt_bn128_pairing(&buf0[0..inputs.len() / 2]).unwrap());
             hex2bin(expect, &mut buf0[..]);
             assert_eq!(buf0[0..32], buf1[..]);
         }
     }
 }
+
+#[test]
+fn pairing_input_error_offset() {
+    let mut data = [0u8; 384];
+    // the real coefficient of b.y in the second pair is not below p
+    data[192 + 160..].copy_from_slice(&[0xff; 32]);
+    let mut out = [(G1::default(), G2::default()); 2];
+    let err = parse_pairing_input(&data, &mut out).err().unwrap();
+    assert_eq!(err.offset, 352);
+    assert_eq!(err.message, "Invalid b argument y real coeff");
+}
+
+#[test]
+fn precompile_edge_cases() {
+    // more pairs than `MAX_PAIRING_PAIRS`, all at infinity
+    assert_eq!(alt_bn128_pairing(&[0u8; 192 * 17]), Ok(true));
+    assert_eq!(
+        alt_bn128_pairing(&[0u8; 100]).err().unwrap().kind,
+        ErrorKind::Length
+    );
+    assert_eq!(alt_bn128_add(&[]), Ok([0u8; 64]));
+
+    // (1, 3) is not on the curve, and b.x overflows: a is reported
+    let mut data = [0u8; 192];
+    data[31] = 1;
+    data[63] = 3;
+    data[64..96].copy_from_slice(&[0xff; 32]);
+    let err = alt_bn128_pairing(&data).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ErrorKind::MalformedPoint, 0));
+    data[63] = 2;
+    let err = alt_bn128_pairing(&data).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ErrorKind::NotCanonical, 64));
+    assert_eq!(err.kind.geth_message(), "bn256: coordinate exceeds modulus");
+
+    // the modulus itself is not a canonical coordinate
+    let mut data = [0u8; 64];
+    Fq::modulus().to_big_endian(&mut data[..32]).unwrap();
+    assert_eq!(
+        alt_bn128_mul(&data).err().unwrap().kind,
+        ErrorKind::NotCanonical
+    );
+}
+
+#[test]
+fn gas_schedule() {
+    assert_eq!((add_gas(false), add_gas(true)), (150, 500));
+    assert_eq!((mul_gas(false), mul_gas(true)), (6_000, 40_000));
+    assert_eq!(pairing_gas(0, false), 45_000);
+    assert_eq!(pairing_gas(2 * 192, false), 113_000);
+    assert_eq!(pairing_gas(2 * 192 + 100, true), 260_000);
+}
+
+#[test]
+fn evm_g2_encoding() {
+    let p = AffineG2::from_jacobian(G2::one() * Fr::from_str("1234").unwrap()).unwrap();
+    let bytes = p.to_evm_bytes();
+    let mut x_imaginary = [0u8; 32];
+    p.x().imaginary().to_big_endian(&mut x_imaginary).unwrap();
+    assert_eq!(bytes[..32], x_imaginary);
+    assert!(AffineG2::from_evm_slice(&bytes) == Ok(p));
+    assert!(G2::from(p) == words::g2_from_words(&words::split_words(&bytes).unwrap()).unwrap());
+
+    let mut x = [0u8; 64];
+    x.copy_from_slice(&bytes[..64]);
+    assert!(Fq2::from_evm_slice(&x) == Ok(p.x()));
+    assert!(AffineG2::from_evm_slice(&[0u8; 128]).is_err());
+    x[32..].copy_from_slice(&[0xff; 32]);
+    assert_eq!(Fq2::from_evm_slice(&x).err().unwrap().offset, 32);
+}
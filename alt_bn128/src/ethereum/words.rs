@@ -0,0 +1,164 @@
+//! Conversions between the public types and 32-byte EVM words, in the
+//! order the precompiles and Solidity ABI use: integers big-endian, G1 as
+//! `x, y`, G2 as `x.imaginary, x.real, y.imaginary, y.real`, and the point
+//! at infinity as zero words.
+//!
+//! Error offsets are byte positions in the words passed in, as if they
+//! were one calldata buffer.
+
+use super::{read_g2, read_pt, Error, ErrorKind};
+use crate::arith::U256;
+use crate::{AffineG1, AffineG2, Fq, Fr, Group, G1, G2};
+use core::convert::TryInto;
+
+pub type Word = [u8; 32];
+
+pub const FR_WORDS: usize = 1;
+pub const G1_WORDS: usize = 2;
+pub const G2_WORDS: usize = 4;
+
+pub(super) fn fq_to_word(x: Fq) -> Word {
+    let mut word = [0u8; 32];
+    x.to_big_endian(&mut word).expect("word is 32 bytes; qed");
+    word
+}
+
+/// The canonical value of `x`, not its internal representation.
+pub fn fr_to_word(x: &Fr) -> Word {
+    let mut word = [0u8; 32];
+    x.into_u256()
+        .to_big_endian(&mut word)
+        .expect("word is 32 bytes; qed");
+    word
+}
+
+pub fn g1_to_words(p: &G1) -> [Word; G1_WORDS] {
+    match AffineG1::from_jacobian(*p) {
+        None => [[0u8; 32]; G1_WORDS],
+        Some(p) => [fq_to_word(p.x()), fq_to_word(p.y())],
+    }
+}
+
+pub fn g2_to_words(p: &G2) -> [Word; G2_WORDS] {
+    match AffineG2::from_jacobian(*p) {
+        None => [[0u8; 32]; G2_WORDS],
+        Some(p) => [
+            fq_to_word(p.x().imaginary()),
+            fq_to_word(p.x().real()),
+            fq_to_word(p.y().imaginary()),
+            fq_to_word(p.y().real()),
+        ],
+    }
+}
+
+/// Rejects values not below r rather than reducing them.
+pub fn fr_from_word(word: &Word) -> Result<Fr, Error> {
+    U256::from_slice(word)
+        .ok()
+        .and_then(Fr::new)
+        .ok_or(Error::at(ErrorKind::NotCanonical, "invalid fr", 0))
+}
+
+/// Copies `N` words into one buffer, so the byte-level readers can parse
+/// them.
+fn join<const N: usize, const B: usize>(words: &[Word; N]) -> [u8; B] {
+    let mut bytes = [0u8; B];
+    join_words(words, &mut bytes);
+    bytes
+}
+
+pub fn g1_from_words(words: &[Word; G1_WORDS]) -> Result<G1, Error> {
+    read_pt(&join::<G1_WORDS, 64>(words), 0)
+}
+
+/// Checks that the point is in the prime-order subgroup.
+pub fn g2_from_words(words: &[Word; G2_WORDS]) -> Result<G2, Error> {
+    read_g2(&join::<G2_WORDS, 128>(words), 0)
+}
+
+/// Splits calldata of exactly `N` words.
+pub fn split_words<const N: usize>(bytes: &[u8]) -> Result<[Word; N], Error> {
+    if bytes.len() != 32 * N {
+        return Err(Error::at(
+            ErrorKind::Length,
+            "Invalid input length",
+            bytes.len(),
+        ));
+    }
+    let mut words = [[0u8; 32]; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(32)) {
+        word.copy_from_slice(chunk);
+    }
+    Ok(words)
+}
+
+/// Writes `words` to the start of `out`, which must hold them.
+pub fn join_words(words: &[Word], out: &mut [u8]) {
+    for (chunk, word) in out[..32 * words.len()].chunks_exact_mut(32).zip(words) {
+        chunk.copy_from_slice(word);
+    }
+}
+
+/// Applies `parse` to consecutive groups of `W` words.
+fn parse_array<T: Copy, const W: usize, const N: usize>(
+    words: &[Word],
+    zero: T,
+    parse: impl Fn(&[Word; W]) -> Result<T, Error>,
+) -> Result<[T; N], Error> {
+    if words.len() != W * N {
+        return Err(Error::at(
+            ErrorKind::Length,
+            "Invalid input length",
+            32 * words.len(),
+        ));
+    }
+    let mut out = [zero; N];
+    for (i, (o, group)) in out.iter_mut().zip(words.chunks_exact(W)).enumerate() {
+        let group = group.try_into().expect("chunk has W words; qed");
+        *o = parse(group).map_err(|e| Error::at(e.kind, e.message, e.offset + 32 * W * i))?;
+    }
+    Ok(out)
+}
+
+/// Parses `N` scalars from exactly `N` words.
+pub fn frs_from_words<const N: usize>(words: &[Word]) -> Result<[Fr; N], Error> {
+    parse_array::<_, FR_WORDS, N>(words, Fr::zero(), |w| fr_from_word(&w[0]))
+}
+
+/// Parses `N` G1 points from exactly `N * G1_WORDS` words.
+pub fn g1s_from_words<const N: usize>(words: &[Word]) -> Result<[G1; N], Error> {
+    parse_array::<_, G1_WORDS, N>(words, G1::zero(), g1_from_words)
+}
+
+/// Parses `N` G2 points from exactly `N * G2_WORDS` words.
+pub fn g2s_from_words<const N: usize>(words: &[Word]) -> Result<[G2; N], Error> {
+    parse_array::<_, G2_WORDS, N>(words, G2::zero(), g2_from_words)
+}
+
+#[test]
+fn round_trip() {
+    let points = [
+        G1::one(),
+        G1::zero(),
+        G1::one() * Fr::from_str("7").unwrap(),
+    ];
+    let mut words = [[0u8; 32]; 3 * G1_WORDS];
+    for (p, w) in points.iter().zip(words.chunks_exact_mut(G1_WORDS)) {
+        w.copy_from_slice(&g1_to_words(p));
+    }
+    assert_eq!(g1s_from_words::<3>(&words), Ok(points));
+
+    let q = G2::one();
+    assert!(g2_from_words(&g2_to_words(&q)) == Ok(q));
+
+    let k = Fr::from_str("123456789").unwrap();
+    assert_eq!(frs_from_words::<1>(&[fr_to_word(&k)]), Ok([k]));
+    assert_eq!(
+        fr_from_word(&[0xff; 32]).err().unwrap(),
+        Error::at(ErrorKind::NotCanonical, "invalid fr", 0)
+    );
+
+    // the y coordinate of the second point is not below p
+    words[3] = [0xff; 32];
+    assert_eq!(g1s_from_words::<3>(&words).err().unwrap().offset, 96);
+}
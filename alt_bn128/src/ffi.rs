@@ -0,0 +1,282 @@
+//! `extern "C"` entry points for C code, such as CKB scripts linking the
+//! `alt_bn128_staticlib` archive. Every function takes fixed-size
+//! big-endian buffers and returns `ALT_BN128_OK` or an error code:
+//!
+//! - field elements are 32 bytes and must be below the modulus;
+//! - G1 points are `x || y`, 64 bytes, and G2 points `x || y` with each
+//!   coordinate imaginary part first, 128 bytes, as in EIP-197; all zeros
+//!   is the point at infinity;
+//! - scalars for point multiplication are any 32-byte integer, reduced
+//!   modulo r as by the ECMUL precompile.
+//!
+//! Outputs are written only on success, and may overlap the inputs. The
+//! declarations are in `alt_bn128_staticlib/include/alt_bn128.h`.
+
+use crate::arith::U256;
+use crate::{ethereum, AffineG2, Fq, Fr, Group, G2};
+use core::slice;
+
+pub const ALT_BN128_OK: u32 = 0;
+/// An input is not a canonical field element or a point of the group, or
+/// has the wrong length.
+pub const ALT_BN128_INVALID_INPUT: u32 = 1;
+/// `alt_bn128_pairing_check`: the input is valid but the product of the
+/// pairings is not one.
+pub const ALT_BN128_PAIRING_FAILED: u32 = 2;
+/// The inverse of zero was requested.
+pub const ALT_BN128_NOT_INVERTIBLE: u32 = 3;
+
+unsafe fn input<const N: usize>(p: *const u8) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    core::ptr::copy_nonoverlapping(p, bytes.as_mut_ptr(), N);
+    bytes
+}
+
+unsafe fn output<const N: usize>(bytes: [u8; N], out: *mut u8) -> u32 {
+    core::ptr::copy_nonoverlapping(bytes.as_ptr(), out, N);
+    ALT_BN128_OK
+}
+
+fn read_fr(bytes: [u8; 32]) -> Option<Fr> {
+    Fr::new(U256::from_slice(&bytes).expect("slice is 32 bytes; qed"))
+}
+
+fn write_fr(x: Fr) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    x.into_u256()
+        .to_big_endian(&mut out)
+        .expect("buffer is 32 bytes; qed");
+    out
+}
+
+fn read_fq(bytes: [u8; 32]) -> Option<Fq> {
+    Fq::from_slice(&bytes).ok()
+}
+
+fn write_fq(x: Fq) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    x.to_big_endian(&mut out).expect("buffer is 32 bytes; qed");
+    out
+}
+
+fn read_g2(bytes: [u8; 128]) -> Option<G2> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Some(G2::zero());
+    }
+    AffineG2::from_evm_slice(&bytes).ok().map(G2::from)
+}
+
+fn write_g2(p: G2) -> [u8; 128] {
+    AffineG2::from_jacobian(p).map_or([0u8; 128], |p| p.to_evm_bytes())
+}
+
+/// Exports `$name(a, b, out)` computing `$op` on two field elements.
+macro_rules! binary {
+    ($($name:ident, $read:ident, $write:ident, $op:tt);*) => {
+        $(
+            /// # Safety
+            ///
+            /// `a` and `b` must be readable for 32 bytes and `out` writable
+            /// for 32 bytes.
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(a: *const u8, b: *const u8, out: *mut u8) -> u32 {
+                match ($read(input(a)), $read(input(b))) {
+                    (Some(a), Some(b)) => output($write(a $op b), out),
+                    _ => ALT_BN128_INVALID_INPUT,
+                }
+            }
+        )*
+    };
+}
+
+binary!(
+    alt_bn128_fr_add, read_fr, write_fr, +;
+    alt_bn128_fr_sub, read_fr, write_fr, -;
+    alt_bn128_fr_mul, read_fr, write_fr, *;
+    alt_bn128_fq_add, read_fq, write_fq, +;
+    alt_bn128_fq_sub, read_fq, write_fq, -;
+    alt_bn128_fq_mul, read_fq, write_fq, *
+);
+
+/// Exports `$name(a, out)` computing the inverse of a field element.
+macro_rules! inverse {
+    ($($name:ident, $read:ident, $write:ident);*) => {
+        $(
+            /// # Safety
+            ///
+            /// `a` must be readable for 32 bytes and `out` writable for 32
+            /// bytes.
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(a: *const u8, out: *mut u8) -> u32 {
+                match $read(input(a)).map(|a| a.inverse()) {
+                    Some(Some(a)) => output($write(a), out),
+                    Some(None) => ALT_BN128_NOT_INVERTIBLE,
+                    None => ALT_BN128_INVALID_INPUT,
+                }
+            }
+        )*
+    };
+}
+
+inverse!(
+    alt_bn128_fr_inverse, read_fr, write_fr;
+    alt_bn128_fq_inverse, read_fq, write_fq
+);
+
+/// `a + b` in G1.
+///
+/// # Safety
+///
+/// `a` and `b` must be readable for 64 bytes and `out` writable for 64
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn alt_bn128_g1_add(a: *const u8, b: *const u8, out: *mut u8) -> u32 {
+    let mut data = [0u8; 128];
+    data[..64].copy_from_slice(&input::<64>(a));
+    data[64..].copy_from_slice(&input::<64>(b));
+    match ethereum::alt_bn128_add(&data) {
+        Ok(sum) => output(sum, out),
+        Err(_) => ALT_BN128_INVALID_INPUT,
+    }
+}
+
+/// `p * scalar` in G1.
+///
+/// # Safety
+///
+/// `p` must be readable for 64 bytes, `scalar` for 32 bytes and `out`
+/// writable for 64 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn alt_bn128_g1_mul(p: *const u8, scalar: *const u8, out: *mut u8) -> u32 {
+    let mut data = [0u8; 96];
+    data[..64].copy_from_slice(&input::<64>(p));
+    data[64..].copy_from_slice(&input::<32>(scalar));
+    match ethereum::alt_bn128_mul(&data) {
+        Ok(product) => output(product, out),
+        Err(_) => ALT_BN128_INVALID_INPUT,
+    }
+}
+
+/// `a + b` in G2.
+///
+/// # Safety
+///
+/// `a` and `b` must be readable for 128 bytes and `out` writable for 128
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn alt_bn128_g2_add(a: *const u8, b: *const u8, out: *mut u8) -> u32 {
+    match (read_g2(input(a)), read_g2(input(b))) {
+        (Some(a), Some(b)) => output(write_g2(a + b), out),
+        _ => ALT_BN128_INVALID_INPUT,
+    }
+}
+
+/// `p * scalar` in G2.
+///
+/// # Safety
+///
+/// `p` must be readable for 128 bytes, `scalar` for 32 bytes and `out`
+/// writable for 128 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn alt_bn128_g2_mul(p: *const u8, scalar: *const u8, out: *mut u8) -> u32 {
+    let scalar = Fr::from_slice(&input::<32>(scalar)).expect("slice is 32 bytes; qed");
+    match read_g2(input(p)) {
+        Some(p) => output(write_g2(p * scalar), out),
+        None => ALT_BN128_INVALID_INPUT,
+    }
+}
+
+/// Whether the product of the pairings of the `data_len / 192` pairs at
+/// `data`, each a G1 point followed by a G2 point, is one: the check of
+/// the ECPAIRING precompile. `data_len` must be a multiple of 192.
+///
+/// # Safety
+///
+/// `data` must be readable for `data_len` bytes; it may be null if
+/// `data_len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn alt_bn128_pairing_check(data: *const u8, data_len: u32) -> u32 {
+    let data = if data_len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, data_len as usize)
+    };
+    match ethereum::alt_bn128_pairing(data) {
+        Ok(true) => ALT_BN128_OK,
+        Ok(false) => ALT_BN128_PAIRING_FAILED,
+        Err(_) => ALT_BN128_INVALID_INPUT,
+    }
+}
+
+#[test]
+fn round_trips() {
+    use crate::AffineG1;
+
+    let s = write_fr(Fr::from(6u64));
+    let t = write_fr(Fr::from(7u64));
+    let mut out = [0u8; 32];
+    unsafe {
+        assert_eq!(
+            alt_bn128_fr_mul(s.as_ptr(), t.as_ptr(), out.as_mut_ptr()),
+            0
+        );
+        assert_eq!(out, write_fr(Fr::from(42u64)));
+        // in place
+        assert_eq!(alt_bn128_fr_inverse(out.as_ptr(), out.as_mut_ptr()), 0);
+        assert_eq!(read_fr(out), Fr::from(42u64).inverse());
+        assert_eq!(
+            alt_bn128_fq_inverse([0u8; 32].as_ptr(), out.as_mut_ptr()),
+            ALT_BN128_NOT_INVERTIBLE
+        );
+        let too_big = [0xffu8; 32];
+        assert_eq!(
+            alt_bn128_fq_add(too_big.as_ptr(), t.as_ptr(), out.as_mut_ptr()),
+            ALT_BN128_INVALID_INPUT
+        );
+    }
+
+    let g1 = AffineG1::generator();
+    let mut p = [0u8; 64];
+    p[..32].copy_from_slice(&write_fq(g1.x()));
+    p[32..].copy_from_slice(&write_fq(g1.y()));
+    let q = write_g2(G2::one());
+    let mut p6 = [0u8; 64];
+    let mut q7 = [0u8; 128];
+    let mut sum = [0u8; 128];
+    unsafe {
+        assert_eq!(alt_bn128_g1_mul(p.as_ptr(), s.as_ptr(), p6.as_mut_ptr()), 0);
+        assert_eq!(alt_bn128_g2_mul(q.as_ptr(), t.as_ptr(), q7.as_mut_ptr()), 0);
+        assert_eq!(
+            alt_bn128_g2_add(q7.as_ptr(), q.as_ptr(), sum.as_mut_ptr()),
+            0
+        );
+    }
+    assert_eq!(read_g2(sum), Some(G2::one() * Fr::from(8u64)));
+
+    // e(6 g1, 7 g2) * e(-42 g1, g2) = 1
+    let mut data = [0u8; 384];
+    data[..64].copy_from_slice(&p6);
+    data[64..192].copy_from_slice(&q7);
+    data[256..].copy_from_slice(&q);
+    let s42 = write_fr(-Fr::from(42u64));
+    let mut off_curve = p;
+    off_curve[63] ^= 1;
+    unsafe {
+        let p42 = data[192..].as_mut_ptr();
+        assert_eq!(alt_bn128_g1_mul(p.as_ptr(), s42.as_ptr(), p42), 0);
+        assert_eq!(alt_bn128_pairing_check(data.as_ptr(), 384), ALT_BN128_OK);
+        assert_eq!(
+            alt_bn128_pairing_check(data.as_ptr(), 192),
+            ALT_BN128_PAIRING_FAILED
+        );
+        assert_eq!(
+            alt_bn128_pairing_check(data.as_ptr(), 100),
+            ALT_BN128_INVALID_INPUT
+        );
+        assert_eq!(alt_bn128_pairing_check(core::ptr::null(), 0), ALT_BN128_OK);
+        assert_eq!(
+            alt_bn128_g1_add(p.as_ptr(), off_curve.as_ptr(), p6.as_mut_ptr()),
+            ALT_BN128_INVALID_INPUT
+        );
+    }
+}
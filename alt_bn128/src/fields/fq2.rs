@@ -1,6 +1,7 @@
 use crate::arith::{U256, U512};
 use crate::fields::{const_fq, FieldElement, Fq};
-use core::ops::{Add, Mul, Neg, Sub};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 #[inline]
 fn fq_non_residue() -> Fq {
@@ -39,8 +40,31 @@ pub struct Fq2 {
     c1: Fq,
 }
 
+impl ConditionallySelectable for Fq2 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fq2 {
+            c0: Fq::conditional_select(&a.c0, &b.c0, choice),
+            c1: Fq::conditional_select(&a.c1, &b.c1, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for Fq2 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Fq2 {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
 impl Fq2 {
-    pub fn new(c0: Fq, c1: Fq) -> Self {
+    pub const fn new(c0: Fq, c1: Fq) -> Self {
         Fq2 { c0: c0, c1: c1 }
     }
 
@@ -66,6 +90,26 @@ impl Fq2 {
         }
     }
 
+    /// `c0 - c1 u`, the image under the `q`-power Frobenius.
+    pub fn conjugate(&self) -> Self {
+        Fq2 {
+            c0: self.c0,
+            c1: -self.c1,
+        }
+    }
+
+    /// `self * self.conjugate()`, which is `c0^2 + c1^2` as `u^2 = -1`.
+    pub fn norm(&self) -> Fq {
+        self.c0.squared() + self.c1.squared()
+    }
+
+    /// Whether `self` is a square, zero included: exactly when its norm is
+    /// a square in Fq.
+    pub fn is_quadratic_residue(&self) -> bool {
+        let norm = self.norm();
+        norm.is_zero() || norm.pow_p_minus_1_div_2() == Fq::one()
+    }
+
     pub fn real(&self) -> &Fq {
         &self.c0
     }
@@ -163,6 +207,29 @@ impl Add for Fq2 {
     }
 }
 
+impl AddAssign for Fq2 {
+    #[inline]
+    fn add_assign(&mut self, other: Fq2) {
+        self.c0 += other.c0;
+        self.c1 += other.c1;
+    }
+}
+
+impl SubAssign for Fq2 {
+    #[inline]
+    fn sub_assign(&mut self, other: Fq2) {
+        self.c0 -= other.c0;
+        self.c1 -= other.c1;
+    }
+}
+
+impl MulAssign for Fq2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Fq2) {
+        *self = *self * other;
+    }
+}
+
 impl Neg for Fq2 {
     type Output = Fq2;
 
@@ -261,3 +328,17 @@ fn sqrt_fq2() {
             .is_none()
     );
 }
+
+#[test]
+fn conjugate_norm_residue() {
+    let x = Fq2::new(Fq::from_str("3").unwrap(), Fq::from_str("5").unwrap());
+    assert_eq!(x.conjugate(), x.frobenius_map(1));
+    assert_eq!(x * x.conjugate(), Fq2::new(x.norm(), Fq::zero()));
+    assert_eq!(x.norm(), Fq::from_str("34").unwrap());
+
+    assert!(x.squared().is_quadratic_residue());
+    assert!(Fq2::zero().is_quadratic_residue());
+    let y = Fq2::new(Fq::from_str("1").unwrap(), Fq::from_str("2").unwrap());
+    assert!(!y.is_quadratic_residue());
+    assert_eq!(y.sqrt().is_some(), y.is_quadratic_residue());
+}
@@ -41,7 +41,30 @@ fn frobenius_coeffs_c1(n: usize) -> Fq2 {
                 740282956577754197,
             ]),
         ),
-        _ => unimplemented!(),
+        4 => Fq2::new(
+            const_fq([
+                8183898218631979349,
+                12014359695528440611,
+                12263358156045030468,
+                3187210487005268291,
+            ]),
+            Fq::zero(),
+        ),
+        5 => Fq2::new(
+            const_fq([
+                17949863938634605489,
+                5148119255485697554,
+                15902609273764175669,
+                2831252980527631332,
+            ]),
+            const_fq([
+                6700319054286102411,
+                5454567456687495308,
+                5752045687574716953,
+                3465882465137625146,
+            ]),
+        ),
+        _ => unreachable!(),
     }
 }
 fn frobenius_coeffs_c2(n: usize) -> Fq2 {
@@ -84,7 +107,30 @@ fn frobenius_coeffs_c2(n: usize) -> Fq2 {
                 1660844386505564338,
             ]),
         ),
-        _ => unimplemented!(),
+        4 => Fq2::new(
+            const_fq([
+                3697675806616062876,
+                9065277094688085689,
+                6918009208039626314,
+                2775033306905974752,
+            ]),
+            Fq::zero(),
+        ),
+        5 => Fq2::new(
+            const_fq([
+                9526275334892870614,
+                3598394558150331826,
+                4855778377848021065,
+                1428894834119860196,
+            ]),
+            const_fq([
+                17242383170257025652,
+                3067616562948804634,
+                9489108065570926352,
+                2682193869530181283,
+            ]),
+        ),
+        _ => unreachable!(),
     }
 }
 
@@ -121,6 +167,19 @@ impl Fq6 {
         }
     }
 
+    /// `self * (b0 + b1 v)`, with five `Fq2` multiplications instead of
+    /// six.
+    pub fn mul_by_01(&self, b0: Fq2, b1: Fq2) -> Self {
+        let a_a = self.c0 * b0;
+        let b_b = self.c1 * b1;
+
+        Fq6 {
+            c0: (self.c2 * b1).mul_by_nonresidue() + a_a,
+            c1: (self.c0 + self.c1) * (b0 + b1) - a_a - b_b,
+            c2: self.c2 * b0 + b_b,
+        }
+    }
+
     pub fn frobenius_map(&self, power: usize) -> Self {
         Fq6 {
             c0: self.c0.frobenius_map(power),
@@ -0,0 +1,68 @@
+//! Fixed-base multiplication through precomputed comb tables: 64 points
+//! per base, after which a multiplication costs 43 doublings and at most
+//! 43 additions instead of a full variable-base multiplication. The
+//! tables of the standard generators ship with the crate.
+
+use crate::groups::{self, CombTable, G1Params, G2Params};
+use crate::{AffineG1, AffineG2, Fr, MemoryBudget, OverBudget, G1, G2};
+
+macro_rules! precomputed_table {
+    ($table:ident, $g:ident, $affine:ident, $params:ident, $generator:ident) => {
+        pub struct $table(CombTable<$params>);
+
+        impl $table {
+            /// Memory taken by a table.
+            pub const TABLE_BYTES: usize = core::mem::size_of::<CombTable<$params>>();
+
+            pub fn new(base: &$affine) -> Self {
+                $table(groups::comb_table(&base.0))
+            }
+
+            /// `new`, failing if `TABLE_BYTES` exceeds `budget`.
+            pub fn new_within(base: &$affine, budget: &MemoryBudget) -> Result<Self, OverBudget> {
+                if !budget.fits(Self::TABLE_BYTES) {
+                    return Err(OverBudget {
+                        required: Self::TABLE_BYTES,
+                    });
+                }
+                Ok(Self::new(base))
+            }
+
+            /// The shipped table of the standard generator.
+            pub fn generator() -> &'static Self {
+                static TABLE: $table = $table(groups::$generator);
+                &TABLE
+            }
+
+            /// `base * scalar`.
+            pub fn mul(&self, scalar: Fr) -> $g {
+                let scalar = scalar.into_u256();
+                $g(groups::comb_msm(core::iter::once((&self.0, &scalar))))
+            }
+        }
+    };
+}
+
+precomputed_table!(G1PrecomputedTable, G1, AffineG1, G1Params, G1_GENERATOR);
+precomputed_table!(G2PrecomputedTable, G2, AffineG2, G2Params, G2_GENERATOR);
+
+#[test]
+fn shipped_tables() {
+    use crate::Group;
+
+    let g1 = G1PrecomputedTable::new(&AffineG1::generator());
+    let g2 = G2PrecomputedTable::new(&AffineG2::generator());
+    for (a, b) in g1.0.iter().zip(G1PrecomputedTable::generator().0.iter()) {
+        assert_eq!(a, b);
+    }
+    for (a, b) in g2.0.iter().zip(G2PrecomputedTable::generator().0.iter()) {
+        assert!(a == b);
+    }
+
+    let k = -Fr::from_str("123456789123456789").unwrap();
+    assert_eq!(G1PrecomputedTable::generator().mul(k), G1::one() * k);
+    assert!(G2PrecomputedTable::generator().mul(k) == G2::one() * k);
+    let p = AffineG1::from_jacobian(G1::one() * k).unwrap();
+    assert_eq!(G1PrecomputedTable::new(&p).mul(k), G1::from(p) * k);
+    assert!(G1PrecomputedTable::new_within(&p, &MemoryBudget::new(1024)).is_err());
+}
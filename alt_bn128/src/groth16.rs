@@ -0,0 +1,318 @@
+//! Groth16 proof verification.
+
+use crate::arith::U256;
+use crate::groups::{self, CombTable, GroupElement};
+use crate::{
+    pairing_check, AffineG1, AffineG2, DecodePolicy, Fq, Fq2, Fr, Group, MemoryBudget, G1, G2,
+};
+use core::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The number of public inputs does not match the verifying key.
+    InputCount,
+    /// The verifying key has more public inputs than a
+    /// `PreparedPublicInputs` can hold.
+    Capacity,
+    /// The comb tables of a `PreparedPublicInputs` exceed the memory
+    /// budget.
+    OverBudget,
+    /// Malformed proof bytes or a point that is not in its group.
+    Encoding,
+    /// A proof element is the point at infinity (strict mode only).
+    Identity,
+    InvalidProof,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::InputCount => "wrong number of public inputs",
+            Error::Capacity => "too many public inputs",
+            Error::OverBudget => "public input tables exceed the memory budget",
+            Error::Encoding => "malformed proof encoding",
+            Error::Identity => "proof element is the point at infinity",
+            Error::InvalidProof => "invalid proof",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// How much a proof is checked beyond the pairing equation.
+///
+/// `Strict` rejects proof elements at infinity and re-checks group
+/// membership. Combined with `DecodePolicy::default()` for `Proof::from_bytes`
+/// a valid proof then has a single byte encoding, which matters where proof
+/// bytes are used as identifiers.
+///
+/// `Mode` is applied after decoding and is independent of the
+/// `DecodePolicy` a proof was read with: it also covers proofs assembled
+/// from points directly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Checks the pairing equation only. Proof elements at infinity are
+    /// accepted, and no point is re-checked for curve or subgroup
+    /// membership, so a proof read with `DecodePolicy::permissive()` may
+    /// carry a `b` outside the prime-order subgroup.
+    Lenient,
+    Strict,
+}
+
+/// A Groth16 verifying key. `ic` holds one point per public input plus
+/// the constant term at index 0.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct VerifyingKey<'a> {
+    pub alpha_g1: AffineG1,
+    pub beta_g2: AffineG2,
+    pub gamma_g2: AffineG2,
+    pub delta_g2: AffineG2,
+    pub ic: &'a [AffineG1],
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub a: G1,
+    pub b: G2,
+    pub c: G1,
+}
+
+fn read_g1(bytes: &[u8], policy: &DecodePolicy) -> Result<G1, Error> {
+    let x = policy.read_fq(&bytes[..32]).map_err(|_| Error::Encoding)?;
+    let y = policy.read_fq(&bytes[32..]).map_err(|_| Error::Encoding)?;
+    if x.is_zero() && y.is_zero() {
+        return if policy.allow_infinity {
+            Ok(G1::zero())
+        } else {
+            Err(Error::Identity)
+        };
+    }
+    AffineG1::new(x, y)
+        .map(Into::into)
+        .map_err(|_| Error::Encoding)
+}
+
+fn read_g2(bytes: &[u8], policy: &DecodePolicy) -> Result<G2, Error> {
+    let mut c = [Fq::zero(); 4];
+    for (c, chunk) in c.iter_mut().zip(bytes.chunks(32)) {
+        *c = policy.read_fq(chunk).map_err(|_| Error::Encoding)?;
+    }
+    let (x, y) = (Fq2::new(c[1], c[0]), Fq2::new(c[3], c[2]));
+    if x.is_zero() && y.is_zero() {
+        return if policy.allow_infinity {
+            Ok(G2::zero())
+        } else {
+            Err(Error::Identity)
+        };
+    }
+    policy.g2(x, y).map(Into::into).map_err(|_| Error::Encoding)
+}
+
+impl Proof {
+    /// Parses the 256-byte EVM layout `a.x || a.y || b.x || b.y || c.x || c.y`
+    /// with 32-byte big-endian coordinates and G2 coordinates given
+    /// imaginary part first.
+    pub fn from_bytes(bytes: &[u8], policy: &DecodePolicy) -> Result<Self, Error> {
+        if bytes.len() != 256 {
+            return Err(Error::Encoding);
+        }
+        Ok(Proof {
+            a: read_g1(&bytes[..64], policy)?,
+            b: read_g2(&bytes[64..192], policy)?,
+            c: read_g1(&bytes[192..], policy)?,
+        })
+    }
+
+    /// Rejects proof elements at infinity or off their group. Points built
+    /// through the checked constructors are always in their group; ones
+    /// decoded with a relaxed `DecodePolicy` or assembled with the raw
+    /// coordinate setters may not be.
+    fn check_strict(&self) -> Result<(), Error> {
+        if self.a.is_zero() || self.b.is_zero() || self.c.is_zero() {
+            return Err(Error::Identity);
+        }
+        let valid_g1 =
+            |p: G1| AffineG1::from_jacobian(p).is_some_and(|p| AffineG1::new(p.x(), p.y()).is_ok());
+        let valid_b =
+            AffineG2::from_jacobian(self.b).is_some_and(|p| AffineG2::new(p.x(), p.y()).is_ok());
+        if !valid_g1(self.a) || !valid_g1(self.c) || !valid_b {
+            return Err(Error::Encoding);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> VerifyingKey<'a> {
+    /// `ic[0] + sum(inputs[i] * ic[i + 1])`.
+    pub fn public_inputs(&self, inputs: &[Fr]) -> Result<G1, Error> {
+        if self.ic.is_empty() || inputs.len() != self.ic.len() - 1 {
+            return Err(Error::InputCount);
+        }
+        Ok(G1::from(self.ic[0]) + G1::msm(&self.ic[1..], inputs))
+    }
+}
+
+/// The `ic` points of a verifying key with fixed-base comb tables, for
+/// verifiers checking many proofs of one circuit. Holds up to `N` public
+/// inputs; each table takes 64 G1 points.
+pub struct PreparedPublicInputs<const N: usize> {
+    constant: G1,
+    tables: [CombTable<groups::G1Params>; N],
+    len: usize,
+}
+
+impl<const N: usize> PreparedPublicInputs<N> {
+    pub fn new(vk: &VerifyingKey) -> Result<Self, Error> {
+        if vk.ic.is_empty() {
+            return Err(Error::InputCount);
+        }
+        if vk.ic.len() - 1 > N {
+            return Err(Error::Capacity);
+        }
+        let mut tables = [[groups::G1::zero(); 1 << groups::COMB_TEETH]; N];
+        for (table, p) in tables.iter_mut().zip(vk.ic[1..].iter()) {
+            *table = groups::comb_table(&p.0);
+        }
+        Ok(PreparedPublicInputs {
+            constant: vk.ic[0].into(),
+            tables,
+            len: vk.ic.len() - 1,
+        })
+    }
+
+    /// Memory taken by the tables of a `PreparedPublicInputs<N>`.
+    pub const TABLE_BYTES: usize = core::mem::size_of::<[CombTable<groups::G1Params>; N]>();
+
+    /// `new`, failing with `Error::OverBudget` if `TABLE_BYTES` exceeds
+    /// `budget`. The tables are sized by `N`, so pick the smallest `N`
+    /// that holds the circuit's inputs.
+    pub fn new_within(vk: &VerifyingKey, budget: &MemoryBudget) -> Result<Self, Error> {
+        if !budget.fits(Self::TABLE_BYTES) {
+            return Err(Error::OverBudget);
+        }
+        Self::new(vk)
+    }
+
+    /// Same as `VerifyingKey::public_inputs`.
+    pub fn public_inputs(&self, inputs: &[Fr]) -> Result<G1, Error> {
+        if inputs.len() != self.len {
+            return Err(Error::InputCount);
+        }
+        let mut scalars = [U256::zero(); N];
+        for (s, x) in scalars.iter_mut().zip(inputs.iter()) {
+            *s = x.into_u256();
+        }
+        let terms = self.tables[..self.len].iter().zip(scalars.iter());
+        Ok(self.constant + G1(groups::comb_msm(terms)))
+    }
+}
+
+/// Checks `e(a, b) == e(alpha, beta) * e(vk_x, gamma) * e(c, delta)` for
+/// `vk_x = vk.public_inputs(inputs)`, after the checks of `mode`.
+pub fn verify(vk: &VerifyingKey, proof: &Proof, inputs: &[Fr], mode: Mode) -> Result<(), Error> {
+    let vk_x = vk.public_inputs(inputs)?;
+    check(vk, proof, vk_x, mode)
+}
+
+/// `verify` with the public input term evaluated through `prepared`, which
+/// must have been built from `vk`.
+pub fn verify_prepared<const N: usize>(
+    vk: &VerifyingKey,
+    prepared: &PreparedPublicInputs<N>,
+    proof: &Proof,
+    inputs: &[Fr],
+    mode: Mode,
+) -> Result<(), Error> {
+    let vk_x = prepared.public_inputs(inputs)?;
+    check(vk, proof, vk_x, mode)
+}
+
+fn check(vk: &VerifyingKey, proof: &Proof, vk_x: G1, mode: Mode) -> Result<(), Error> {
+    if mode == Mode::Strict {
+        proof.check_strict()?;
+    }
+    let pairs = [
+        (-proof.a, proof.b),
+        (vk.alpha_g1.into(), vk.beta_g2.into()),
+        (vk_x, vk.gamma_g2.into()),
+        (proof.c, vk.delta_g2.into()),
+    ];
+    if pairing_check(&pairs) {
+        Ok(())
+    } else {
+        Err(Error::InvalidProof)
+    }
+}
+
+#[test]
+fn verify_synthetic_proof() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let g1 = |s: Fr| AffineG1::from_jacobian(G1::one() * s).unwrap();
+    let g2 = |s: Fr| AffineG2::from_jacobian(G2::one() * s).unwrap();
+
+    // a trapdoor-built key and proof: a * b == alpha * beta + x * gamma + c * delta
+    let (alpha, beta, gamma, delta) = (fr("3"), fr("5"), fr("7"), fr("11"));
+    let ic_s = [fr("13"), fr("17"), fr("19")];
+    let ic = [g1(ic_s[0]), g1(ic_s[1]), g1(ic_s[2])];
+    let vk = VerifyingKey {
+        alpha_g1: g1(alpha),
+        beta_g2: g2(beta),
+        gamma_g2: g2(gamma),
+        delta_g2: g2(delta),
+        ic: &ic,
+    };
+    let inputs = [fr("23"), -fr("29")];
+    let x = ic_s[0] + inputs[0] * ic_s[1] + inputs[1] * ic_s[2];
+    let (a, b) = (fr("31"), fr("37"));
+    let c = (a * b - alpha * beta - x * gamma) * delta.inverse().unwrap();
+    let proof = Proof {
+        a: G1::one() * a,
+        b: G2::one() * b,
+        c: G1::one() * c,
+    };
+    let strict = Mode::Strict;
+
+    let prepared = PreparedPublicInputs::<4>::new(&vk).unwrap();
+    assert_eq!(prepared.public_inputs(&inputs), vk.public_inputs(&inputs));
+    assert_eq!(verify(&vk, &proof, &inputs, strict), Ok(()));
+    assert_eq!(
+        verify_prepared(&vk, &prepared, &proof, &inputs, strict),
+        Ok(())
+    );
+    assert_eq!(
+        verify(&vk, &proof, &[inputs[1], inputs[0]], strict),
+        Err(Error::InvalidProof)
+    );
+    assert_eq!(
+        verify(&vk, &proof, &inputs[..1], strict),
+        Err(Error::InputCount)
+    );
+
+    // vk_x = 0, so a = alpha, b = beta, c = 0 satisfies the equation
+    let zero_ic = [g1(fr("1")), g1(-fr("1"))];
+    let weak_vk = VerifyingKey { ic: &zero_ic, ..vk };
+    let weak = Proof {
+        a: vk.alpha_g1.into(),
+        b: vk.beta_g2.into(),
+        c: G1::zero(),
+    };
+    assert_eq!(verify(&weak_vk, &weak, &[fr("1")], Mode::Lenient), Ok(()));
+    assert_eq!(
+        verify(&weak_vk, &weak, &[fr("1")], strict),
+        Err(Error::Identity)
+    );
+
+    let zero = Proof {
+        a: G1::zero(),
+        b: G2::zero(),
+        c: G1::zero(),
+    };
+    assert_eq!(
+        Proof::from_bytes(&[0u8; 256], &DecodePolicy::evm()),
+        Ok(zero)
+    );
+    assert_eq!(
+        Proof::from_bytes(&[0u8; 256], &DecodePolicy::default()),
+        Err(Error::Identity)
+    );
+}
@@ -0,0 +1,58 @@
+use crate::arith::U256;
+use crate::groups::{AffineG, GroupElement, GroupParams, G};
+
+/// Number of teeth of the fixed-base comb; a table holds 2^TEETH points.
+pub const COMB_TEETH: usize = 6;
+
+/// Distance in bits between the teeth, covering scalars below 2^256.
+const COMB_SPACING: usize = 256usize.div_ceil(COMB_TEETH);
+
+/// `table[idx] = sum(base * 2^(j * COMB_SPACING))` over the set bits `j`
+/// of `idx`.
+pub type CombTable<P> = [G<P>; 1 << COMB_TEETH];
+
+pub fn comb_table<P: GroupParams>(base: &AffineG<P>) -> CombTable<P> {
+    let mut teeth = [G::<P>::zero(); COMB_TEETH];
+    let mut t = base.to_jacobian();
+    for (j, tooth) in teeth.iter_mut().enumerate() {
+        if j > 0 {
+            for _ in 0..COMB_SPACING {
+                t = t.double();
+            }
+        }
+        *tooth = t;
+    }
+
+    let mut table = [G::<P>::zero(); 1 << COMB_TEETH];
+    for idx in 1..table.len() {
+        let j = idx.trailing_zeros() as usize;
+        table[idx] = table[idx & (idx - 1)] + teeth[j];
+    }
+    table
+}
+
+/// `sum(s * base)` over precomputed comb tables, sharing `COMB_SPACING`
+/// doublings across all terms and costing one addition per term and
+/// column.
+pub fn comb_msm<'a, P, I>(terms: I) -> G<P>
+where
+    P: GroupParams + 'a,
+    I: Iterator<Item = (&'a CombTable<P>, &'a U256)> + Clone,
+{
+    let mut acc = G::<P>::zero();
+    for i in (0..COMB_SPACING).rev() {
+        acc = acc.double();
+        for (table, s) in terms.clone() {
+            let mut idx = 0;
+            for j in 0..COMB_TEETH {
+                if s.get_bit(i + j * COMB_SPACING).unwrap_or(false) {
+                    idx |= 1 << j;
+                }
+            }
+            if idx != 0 {
+                acc = acc + table[idx];
+            }
+        }
+    }
+    acc
+}
@@ -0,0 +1,124 @@
+use crate::arith::U256;
+use crate::fields::{FieldElement, Fr};
+use crate::groups::{AffineG, GroupElement, GroupParams, G};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// Digits of the regular signed 4-bit recoding of an odd scalar below
+/// 2^254; the top digit ends up at most 5.
+const DIGITS: usize = 64;
+
+impl<P: GroupParams> ConditionallySelectable for G<P>
+where
+    P::Base: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        G {
+            x: P::Base::conditional_select(&a.x, &b.x, choice),
+            y: P::Base::conditional_select(&a.y, &b.y, choice),
+            z: P::Base::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+/// Compares `x1 z2^2 == x2 z1^2` and `y1 z2^3 == y2 z1^3`, treating any two
+/// points at infinity as equal.
+impl<P: GroupParams> ConstantTimeEq for G<P>
+where
+    P::Base: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let z1_squared = self.z.squared();
+        let z2_squared = other.z.squared();
+        let x = (self.x * z2_squared).ct_eq(&(other.x * z1_squared));
+        let y = (self.y * z2_squared * other.z).ct_eq(&(other.y * z1_squared * self.z));
+        let zero1 = self.z.ct_eq(&P::Base::zero());
+        let zero2 = other.z.ct_eq(&P::Base::zero());
+        (zero1 & zero2) | (!zero1 & !zero2 & x & y)
+    }
+}
+
+impl<P: GroupParams> ConstantTimeEq for AffineG<P>
+where
+    P::Base: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y)
+    }
+}
+
+/// Recodes an odd `k` into digits `d_i` in `{±1, ±3, ..., ±15}` with
+/// `k = sum(d_i * 16^i)`. Every digit is nonzero, so the ladder below
+/// performs the same operations for every scalar.
+fn recode(mut k: U256) -> [i8; DIGITS] {
+    let mut digits = [0i8; DIGITS];
+    for d in digits.iter_mut().take(DIGITS - 1) {
+        // d = (k mod 32) - 16 is odd, and (k - d) / 16 is odd again
+        *d = (k.0[0] & 31) as i8 - 16;
+        k.0[0] = (k.0[0] & !31) | 16;
+        k.0[0] = (k.0[0] >> 4) | (k.0[1] << 124);
+        k.0[1] >>= 4;
+    }
+    digits[DIGITS - 1] = k.0[0] as i8;
+    digits
+}
+
+/// `±table[(|d| - 1) / 2]`, reading every entry.
+fn select<P: GroupParams>(table: &[G<P>; 8], d: i8) -> G<P>
+where
+    P::Base: ConditionallySelectable,
+{
+    let sign = d >> 7;
+    let index = (((d ^ sign) - sign) as u8 - 1) / 2;
+    let mut out = table[0];
+    for (j, t) in table.iter().enumerate() {
+        out.conditional_assign(t, (j as u8).ct_eq(&index));
+    }
+    let negated = G {
+        x: out.x,
+        y: -out.y,
+        z: out.z,
+    };
+    G::conditional_select(&out, &negated, Choice::from((sign & 1) as u8))
+}
+
+/// Scalar multiplication whose sequence of group and field operations does
+/// not depend on the scalar: a fixed 4-bit signed window over odd
+/// multiples, with table lookups by masking. An even `k` is replaced by the
+/// odd `r - k` and the result negated.
+///
+/// The Jacobian addition formula branches on the point at infinity and on
+/// doubling, neither of which occurs for a nonzero `p` and scalar; a zero
+/// scalar or point is still handled, but not in constant time. The final
+/// conversion to affine coordinates, if any, is up to the caller.
+pub fn mul_ct<P: GroupParams>(p: &G<P>, k: &Fr) -> G<P>
+where
+    P::Base: ConditionallySelectable,
+{
+    let k = U256::from(*k);
+    let modulus = Fr::modulus();
+    let mut r_minus_k = modulus;
+    r_minus_k.sub(&k, &modulus);
+    let even = Choice::from((!k.0[0] & 1) as u8);
+    let digits = recode(U256::conditional_select(&k, &r_minus_k, even));
+
+    let mut table = [*p; 8];
+    let double = p.double();
+    for j in 1..8 {
+        table[j] = table[j - 1] + double;
+    }
+
+    let mut acc = select(&table, digits[DIGITS - 1]);
+    for &d in digits[..DIGITS - 1].iter().rev() {
+        for _ in 0..4 {
+            acc = acc.double();
+        }
+        acc = acc + select(&table, d);
+    }
+
+    let negated = G {
+        x: acc.x,
+        y: -acc.y,
+        z: acc.z,
+    };
+    G::conditional_select(&acc, &negated, even)
+}
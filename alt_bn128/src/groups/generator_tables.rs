@@ -0,0 +1,4108 @@
+//! Comb tables of the standard generators, as produced by `comb_table`
+//! and normalized to affine coordinates. Generated; `shipped_tables`
+//! checks them against `comb_table`.
+
+use crate::fields::{const_fq, Fq2};
+use crate::groups::{CombTable, G1Params, G2Params, G};
+
+pub const G1_GENERATOR: CombTable<G1Params> = [
+    G {
+        x: const_fq([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        y: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+        z: const_fq([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+        y: const_fq([
+            0xa6ba871b8b1e1b3a,
+            0x14f1d651eb8e167b,
+            0xccdd46def0f28c58,
+            0x1c14ef83340fbe5e,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x868b948cb2670956,
+            0x6f9eb021190adcdf,
+            0x755e49327b530212,
+            0x2e22a1ee1d2398ec,
+        ]),
+        y: const_fq([
+            0x49d914bc9643754d,
+            0xeb5510df2fac1319,
+            0x2e3ea12ad51b0d92,
+            0x238d8dffb3b71aea,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x756fc640f56c3582,
+            0x7b54e60313ca9ba7,
+            0x875c80dbf12214c6,
+            0x0b014ecafc03a86c,
+        ]),
+        y: const_fq([
+            0x9c489d8c20aba99e,
+            0x1d7ff95ed608a2d0,
+            0x89c7e1da5913c8fe,
+            0x1c831ee891691810,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x53fcfb62feecb442,
+            0x4f136bf24e6a627d,
+            0xbe81e14a9731e33e,
+            0x1516456050dabe5a,
+        ]),
+        y: const_fq([
+            0x0923b4758bd32808,
+            0x9c2bae570c315a28,
+            0x2ca820d347012c67,
+            0x097f5a3cec506902,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xdd540a4406ca7b6e,
+            0xb9fc017e665eb7ad,
+            0x7967472b0c91a9f0,
+            0x2d2c7ff4b7bfef41,
+        ]),
+        y: const_fq([
+            0xf3e8e01dc00d47d9,
+            0xf8f03e0b198dcda8,
+            0x3f76e1a4163b1dbc,
+            0x1d787ba09a8e7e15,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x09e1730f855a5ec8,
+            0x561101d13f0b1967,
+            0x307c227d0cf31deb,
+            0x09c7ae10eb3ca105,
+        ]),
+        y: const_fq([
+            0x147fd5e0c91e776b,
+            0x45f820ec99b02c70,
+            0x0dbc4cbbefa194b0,
+            0x0ffb261864453d31,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x04c0f403aac546d3,
+            0x9dc29432760f89f1,
+            0xbec332e81be7aa0a,
+            0x2b0fcd7150a2d72b,
+        ]),
+        y: const_fq([
+            0x6666a76e60b1c79b,
+            0xbbb7e0ada2b68d9e,
+            0xdeb43fae7ba7452c,
+            0x1d1db0aab22c9526,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xe401d58f99ffa52b,
+            0xa41a0dfee62504a4,
+            0xeab6281db6e51629,
+            0x0f49f6227def5eea,
+        ]),
+        y: const_fq([
+            0xa82ceafdd2a25670,
+            0xe85d0a88080327f0,
+            0x07b8cc6f068a47c7,
+            0x15c9a5fd66ded9b2,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x0cfbbc9e8e843ea8,
+            0xaf3682308a5c79dd,
+            0xcff58c063a9a89db,
+            0x21c2aad15d847d85,
+        ]),
+        y: const_fq([
+            0x95f4b0f394e3e6e8,
+            0xa36032d0da550938,
+            0x3ebc15e59fb5a5ae,
+            0x1b7aaba8d9f42444,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x13fd59c25bfd263f,
+            0x16478ad4ba223052,
+            0xbf0c30daa6b6e460,
+            0x0ef903dab263ef1a,
+        ]),
+        y: const_fq([
+            0x600180a54c37a493,
+            0x123be8bfbd16530c,
+            0x3c0095b876823566,
+            0x1b3faf0adbdb3b0f,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x1c01200fc5a6e529,
+            0xe3af4a66d0b3056a,
+            0xaafdd816f0820683,
+            0x2d9e55b9f3c45eb1,
+        ]),
+        y: const_fq([
+            0x869ce64dce9c9b6d,
+            0x11c7cee98748efa3,
+            0x7f703057359bebe1,
+            0x238e8dcdfd29281f,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x9576af996a1c2082,
+            0x53d2693f73ca125b,
+            0x84d9ea306fbb136e,
+            0x2b184327f14d5d2e,
+        ]),
+        y: const_fq([
+            0x0e95d78b8f7db43d,
+            0x69f4ccdc0b31846e,
+            0x185de3d79060fa90,
+            0x2e34427990e6ebf8,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x2f16e01a40bd22cb,
+            0x5e831fef0379472c,
+            0xf52393344076bda2,
+            0x2fbd4f4fa60cfe59,
+        ]),
+        y: const_fq([
+            0x151c2f743683bf8f,
+            0x27c9ff1a517a1a5c,
+            0xbbcdd9aca51bed56,
+            0x089c9ffb7631407b,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x9856a6f85c3bce3d,
+            0xf6f6509ee06c9ed0,
+            0x931fb04d16395456,
+            0x0445a99b6bfd4f45,
+        ]),
+        y: const_fq([
+            0x4cbd8a46b0d9a544,
+            0x45fceca463f467ec,
+            0xab5b7762112f16d7,
+            0x1d22531184bee641,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x6bf071932d7a72b3,
+            0x271bf4b32a2e9627,
+            0x50669a28677abf94,
+            0x0a95714abad3901a,
+        ]),
+        y: const_fq([
+            0x01799bc1cf6f54f3,
+            0x34ea39c931c2449f,
+            0xfaf2bc16be67641d,
+            0x2bfae641bff0f7b2,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x91783c7fb1fb3529,
+            0xe6021388ad22d727,
+            0x6f5cc1abae2d6ae0,
+            0x16c6ec3d9833386a,
+        ]),
+        y: const_fq([
+            0x2a6f1f186954f824,
+            0x99880012fbeca9bf,
+            0x11a4930fd77171cd,
+            0x03c1703b39a234a3,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x6ef2e7585f33379a,
+            0x1de4a9c9b9951cfe,
+            0x805d2b3668aefcd4,
+            0x157696679c7c33a4,
+        ]),
+        y: const_fq([
+            0xc4f38fe1d3fa81b2,
+            0xec25627f73d92a4e,
+            0x0fac58547f51538b,
+            0x0ad4db99305fcbc4,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x76abdd2b279e9835,
+            0x9b66bf9f6ed7160b,
+            0x8d6a43795208a98e,
+            0x2745f49a328de5e3,
+        ]),
+        y: const_fq([
+            0x7e7e27dfaaebc722,
+            0x9de5e5277d7ac976,
+            0x57b1f4c13b2b4dda,
+            0x1519f0e72bbdd67a,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xc61679ce0dbed744,
+            0x0b27160050f2a912,
+            0x48368990986e11f3,
+            0x0d5cd7577a7f3d4d,
+        ]),
+        y: const_fq([
+            0xe8a94fce1667ec04,
+            0xb9d4ea40fefc065b,
+            0xb168669a7acbf752,
+            0x2bef23122952452f,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x1feb1543ed34b00e,
+            0x11914f3cb1b97894,
+            0xb0986970af00f444,
+            0x1e9a0e658695d8f9,
+        ]),
+        y: const_fq([
+            0x1f27f5fb52b3ab69,
+            0x03231a855ecc6ae5,
+            0x5c625a8cebfdfc7f,
+            0x1cc89e5ce60b5778,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x75527d2ddedac69d,
+            0xeffde7d7ac6b183b,
+            0xda7c22c6b8d5a2b8,
+            0x23923ffcb86ed286,
+        ]),
+        y: const_fq([
+            0x1d40d5082967cc68,
+            0xc7beef35e6f767b2,
+            0x05fafddac3547043,
+            0x1523ed79023a4aa3,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xfce02b9daafadcdb,
+            0xd29da84c561da610,
+            0xb69d6c5b55aa352c,
+            0x2736ac2472153fed,
+        ]),
+        y: const_fq([
+            0x80af11bfb19329ed,
+            0xf5dc59270cb7d8fe,
+            0x9df9d3f7425c7c79,
+            0x010cd171d24df850,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x6b4ca2216e2f8cfd,
+            0xfaedf1d7198b4e81,
+            0x0d589d7a56cccfbc,
+            0x0514aa902660ee20,
+        ]),
+        y: const_fq([
+            0xd1748c90af9a55b4,
+            0x46b88e4a7dfa2cf9,
+            0x38c75f2a7a6e943b,
+            0x05c4705fe1611dc6,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xed323ee78da25fc2,
+            0xeee2dad30a502aed,
+            0xe63d49bc4c8be729,
+            0x0a466d562c93a809,
+        ]),
+        y: const_fq([
+            0x9be53cca8d9ae784,
+            0x81b2d3f1853b7508,
+            0x27d430eb3c6b5820,
+            0x30338b60fd4dff59,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x0438e37a6de128e6,
+            0xa15fea731e6efa15,
+            0xde806a25094385dc,
+            0x00d047d703e29a8b,
+        ]),
+        y: const_fq([
+            0x82285cd39365bee8,
+            0x3c1baf28c35bccd9,
+            0xe4f5f23f9eda3368,
+            0x082474d03802931a,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xb45e020358e70f26,
+            0x3d8242f4cb43335e,
+            0xc47f79db76f41865,
+            0x1d07f161c1a36670,
+        ]),
+        y: const_fq([
+            0xb4fda0a71508c0ca,
+            0x8653ccd15bbb9e21,
+            0x297c396fa11a32bc,
+            0x25164a7fb54a6b45,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x2ba9ee5a80ff326b,
+            0x8af2c22586bea6aa,
+            0x673aaefc3c09a56b,
+            0x19a570aab2c81df9,
+        ]),
+        y: const_fq([
+            0xda4685427cae48e7,
+            0xb0ef342e35d5f077,
+            0x7125f14412005452,
+            0x2ac528447d019c2d,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x56c544809e92a0d8,
+            0x9bfd37ba430a71f8,
+            0x117229b667768094,
+            0x2af16a48bf4069bd,
+        ]),
+        y: const_fq([
+            0x9bf6bb763990130b,
+            0x738b3e9b0b9ad027,
+            0xd52b21fcbf07c102,
+            0x0a0fcf6aa98782a8,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xd533d1c3ca5a0694,
+            0x399e837795662ed9,
+            0x7c477e629f8c9b2c,
+            0x06e561f39a289116,
+        ]),
+        y: const_fq([
+            0xee04e42afcdd6625,
+            0x82cb9ac2ba3d28b0,
+            0xc1f4114f0e42d8ca,
+            0x15b4327e42dcf4de,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xc6f4128d2263077d,
+            0xa2b521ca52c33a3e,
+            0x69a9b557826e06ff,
+            0x2ff7df489205ca43,
+        ]),
+        y: const_fq([
+            0xcd389f6a54ecfeb7,
+            0xcd1a6959750b913a,
+            0xb930fe1c3166daf5,
+            0x11709506f0178205,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x314ace5af0ac7a7e,
+            0x405e22c6031cc41a,
+            0xc6a9aa471b0f877b,
+            0x2c112fefbc053f6f,
+        ]),
+        y: const_fq([
+            0x794a8e39c7455434,
+            0xa0bea94453d634d5,
+            0xe824d9c36738114b,
+            0x2249c2df9f04843a,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x418f0553ed149e72,
+            0x4e7edba6915ce783,
+            0x33432df73aa9175a,
+            0x02e5a83722209b03,
+        ]),
+        y: const_fq([
+            0x10f16cce8ee01abe,
+            0xf0a3845b151f1732,
+            0x448462aac06c4688,
+            0x15d9e8dae271ff0c,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xbcf8a9bd3fb5f1b6,
+            0x8317702333962a09,
+            0x6b46149a7d35ec14,
+            0x2b96b8e18673fa30,
+        ]),
+        y: const_fq([
+            0xb791fdcf37c883e0,
+            0x0e822b70b3871add,
+            0x755994513aa5351c,
+            0x30101168f78ab0dd,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x3ba44273b646eea8,
+            0x6d25c8cf6fee379f,
+            0x65768b710ae0eb53,
+            0x0f143e81058e8f4c,
+        ]),
+        y: const_fq([
+            0xd369c7bd09d32005,
+            0x77522b088cab2dd3,
+            0x6f864e04599ebd49,
+            0x2ee98ece05a05a1f,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xffad9315fc1c6930,
+            0xecea6a59a274c1d2,
+            0xe0bffc0417354283,
+            0x10a81858c1be2d65,
+        ]),
+        y: const_fq([
+            0x963ca56f21afac65,
+            0x4ab9108b52330eb9,
+            0x5f1e977b324ba049,
+            0x2f680cae1ff19925,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x7c67e89d41a7b787,
+            0x16b46c6a99e9c775,
+            0x3d906586764182a0,
+            0x0d330a5ede0b1b7b,
+        ]),
+        y: const_fq([
+            0x6b655c9997a2d64d,
+            0xdbfd238c526f81bd,
+            0xcaa0c14080053c7c,
+            0x2d97c5b2257128c9,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xf83c5556b96020f5,
+            0x253f6217b296aee9,
+            0xcf4000308dce08a0,
+            0x20d0466b108a6417,
+        ]),
+        y: const_fq([
+            0xb2131635396d6096,
+            0x0ef71085ae71be46,
+            0x54259db961b4b97d,
+            0x2721edd4441a1cd7,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x6a7896ad74cecdfa,
+            0xe585a7b1f298dab8,
+            0x11772d5470945f83,
+            0x173ce465e6b649b1,
+        ]),
+        y: const_fq([
+            0xcc2800a2df9f4c9a,
+            0xc751216718c015ba,
+            0xc634713e7c76133d,
+            0x00525eda07fa78c2,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x54c88fdfbe23adc2,
+            0x406c36e18bd47842,
+            0xb0cf4720b8d33072,
+            0x26c724897c732aa9,
+        ]),
+        y: const_fq([
+            0x006a05041418da63,
+            0x60f22754cf327235,
+            0x2050f00d0325b37b,
+            0x2d9263a998dfaed6,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x83eca2d5def0c635,
+            0xb299addfece1da05,
+            0x3f15a46b74f5536a,
+            0x2cd8b181b70871de,
+        ]),
+        y: const_fq([
+            0x664cf1a9474548b7,
+            0x0f466f0b42ac6add,
+            0x64cfc0b9264e07b4,
+            0x2b9e6b10e35ff32e,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xea60525af74fb8da,
+            0x858bae41bfd4952f,
+            0xbf431a3383a95c76,
+            0x29e59c36c1488662,
+        ]),
+        y: const_fq([
+            0xa3ed6c7fdacdc0bc,
+            0x0ac127a70d40db0d,
+            0x78d5ceabae384316,
+            0x19d55899e3ffbe9b,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xaa2732dc97782244,
+            0x696629ae1d812fdc,
+            0x3ff382a2a47505da,
+            0x16f2c1085be36633,
+        ]),
+        y: const_fq([
+            0xf55ead3ef8338b14,
+            0xab07b66fc2d582e5,
+            0xdc9ec3bd92d366ed,
+            0x207150e813d119a3,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xa2b2effd753a2f80,
+            0xf3c597ff644bf530,
+            0x762f7f35e8079032,
+            0x08900f4713fbd837,
+        ]),
+        y: const_fq([
+            0xdfff93712212490d,
+            0x5615a9cad08c1143,
+            0xc4395dc72f01cd33,
+            0x0041a1c07c386512,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xdce6aa0d2b1e2c94,
+            0x501d3726191435c6,
+            0xa95218d6f2d7011a,
+            0x0821855d480ce087,
+        ]),
+        y: const_fq([
+            0xea5365a9819a7e4f,
+            0x675b7e4df30bc220,
+            0xf0a7e13d8d959740,
+            0x2f3b7e19be9ab4f2,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xb977d2025b8dd549,
+            0x02a15e3c00fd6902,
+            0x1a1f2cbe2c4d8655,
+            0x0745646def8132a4,
+        ]),
+        y: const_fq([
+            0x400a983e43741a7a,
+            0x59d87a4183b6d912,
+            0x9a1fd51169da6489,
+            0x0932c189d338fff5,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xe561b5b60285ccf4,
+            0xab9f9b5fcef1bd1d,
+            0x4e07735891b2c015,
+            0x2afb7d5798dac5e7,
+        ]),
+        y: const_fq([
+            0x649df68e2d5826b1,
+            0x14753f8ecc6584d1,
+            0x2958e6dc899ec54c,
+            0x170657516891bccb,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x4f029bebf2982a23,
+            0x72dc468244886369,
+            0xb604b74a9644bde2,
+            0x255954791c9a6304,
+        ]),
+        y: const_fq([
+            0x7766de5ba9e5802d,
+            0xf79d95d53e3c054f,
+            0x205577ca5a01376a,
+            0x2c4935e35feec222,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xca7d0492f4509397,
+            0x2e597b6f2ff8f485,
+            0x5d379b6bb88e175e,
+            0x251346e53f66a7c0,
+        ]),
+        y: const_fq([
+            0xe82200483ffcab49,
+            0xeb2567a17e7f35fc,
+            0x8d288cc3f6a34200,
+            0x09c478ab33e1c476,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x6f5d5aaf34e5e748,
+            0x149c6982d7343248,
+            0x5e41d64b06649d1f,
+            0x22d6af7c635f92ec,
+        ]),
+        y: const_fq([
+            0xc056c1437ca494e5,
+            0x000b309476b03195,
+            0xa09da8269b4d51fa,
+            0x0269ff492b473627,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x011874dbec81d308,
+            0x8913b4a877fee87b,
+            0x4239c9c21bef4789,
+            0x12769671536b655d,
+        ]),
+        y: const_fq([
+            0x37ae074fcf8a34ed,
+            0xe8332357061f89f8,
+            0x41c051acc6f29b2a,
+            0x0f1293a579b833ca,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x0f437ca5c408d47b,
+            0x3fb7b6fac3bbce7d,
+            0x1b4161d71b5988b8,
+            0x24ccf87edae0243b,
+        ]),
+        y: const_fq([
+            0x7cd62d6d5bad1a95,
+            0x2a4a91ff07af6fef,
+            0xb022745da3257af8,
+            0x26d1b723e4f94271,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xcf0945e997a75c51,
+            0x42a3575961c6e8ff,
+            0x736e5798f604b5ee,
+            0x18df49e5f5e9975f,
+        ]),
+        y: const_fq([
+            0x009895b26f0062f6,
+            0x578063bb77291916,
+            0x3e7a1c352c8d3e45,
+            0x24afe95da71cc651,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x3b0235e4797e0a84,
+            0x4a8de66cedb9ff50,
+            0x7054ac79a3332b90,
+            0x1243322a9680b039,
+        ]),
+        y: const_fq([
+            0xcec8bd8b633a3033,
+            0x919217d6588ecd33,
+            0x08703e337f5a1b3a,
+            0x101154e495550949,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x790a6d9f8430b6a1,
+            0xfc27a9026012e0cd,
+            0xfdf65d85bed5b7aa,
+            0x258ccc7b80c64965,
+        ]),
+        y: const_fq([
+            0xdbaa4eabbebbfbd8,
+            0xaf97a008b27e8ae1,
+            0x5adbc5573d6b0761,
+            0x053175f455c5b138,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x072d8bd763c25439,
+            0x13552975fdca7bf9,
+            0xbf9b240c9302ebc4,
+            0x1d0e219f13a45a00,
+        ]),
+        y: const_fq([
+            0xbd078040f6574396,
+            0x737c17f218859003,
+            0x09d950ed205b4e82,
+            0x223a220e1795a585,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xf3ad6c399002ce40,
+            0x027e2a9dc0e1a386,
+            0xec53699953fdc9d6,
+            0x1a78e0aa746dba45,
+        ]),
+        y: const_fq([
+            0x9bdb832e260ad147,
+            0x527a01a79e2453dc,
+            0x3951dc7bef6a05fe,
+            0x05c22845090287cb,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xc684f90b4159e43d,
+            0xcc9124e242b5658a,
+            0x20fb2077ec224278,
+            0x27e66adeccabdbf6,
+        ]),
+        y: const_fq([
+            0x37a4073571acb05f,
+            0x8f7bd4c4a977471e,
+            0xa948ee0ae4b33163,
+            0x0813f61afe725236,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xb6d672bc0c416fc6,
+            0xd36eedfd5fbc6797,
+            0x67c83c99c93eadaf,
+            0x02f36365d61f20b8,
+        ]),
+        y: const_fq([
+            0xf974580c42f130bc,
+            0x4ec0836f5bf13385,
+            0xa83af5bd6a0b554f,
+            0x292943400394ad5c,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x22fa395311054279,
+            0x1625701cbbd34b96,
+            0x0ac11bbd68ba1a32,
+            0x1acede1baa51a437,
+        ]),
+        y: const_fq([
+            0xddce543597863b7a,
+            0x83c9a06ed9469c48,
+            0xe8af5d464307b4e2,
+            0x0fc4241ec30a6d9f,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x3dcfda49af83ab98,
+            0x6064264baf36b121,
+            0x78bdd280717f57ff,
+            0x00c32a99c1aafed6,
+        ]),
+        y: const_fq([
+            0x517608f41cc18c70,
+            0x0a28a0a284f5d17f,
+            0x893f203d2f0677f9,
+            0x1036ae3e18a4b50e,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0xfc8cfc7ec14643f6,
+            0xf2965a0fc22458de,
+            0xbfe7175aca693a16,
+            0x2e632ce116111e30,
+        ]),
+        y: const_fq([
+            0x9938f794c0998194,
+            0x23f309d027bf086e,
+            0x23a1da6f605f45b7,
+            0x176444d0a4583efe,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x025700b62e3b5c61,
+            0x5302b53930e9d00b,
+            0x51a54aa71138f137,
+            0x0f813533e06cf081,
+        ]),
+        y: const_fq([
+            0x3a00d964a9f017f3,
+            0x94a3e8341e99e3f3,
+            0x66598aa3a61ff87d,
+            0x0ae877430b450ba2,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+    G {
+        x: const_fq([
+            0x53b89458067e3eab,
+            0xf1d06097c4b8a775,
+            0x1dbdae3d84e3d6e3,
+            0x2ab7b9d21a42dd3d,
+        ]),
+        y: const_fq([
+            0x8eba330d83bb7a17,
+            0xb6ea6ee56993509a,
+            0x039406451c9a2a88,
+            0x0c82f3d9debe274f,
+        ]),
+        z: const_fq([
+            0xd35d438dc58f0d9d,
+            0x0a78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0x0e0a77c19a07df2f,
+        ]),
+    },
+];
+
+pub const G2_GENERATOR: CombTable<G2Params> = [
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x8e83b5d102bc2026,
+                0xdceb1935497b0172,
+                0xfbb8264797811adf,
+                0x19573841af96503b,
+            ]),
+            const_fq([
+                0xafb4737da84c6140,
+                0x6043dd5a5802d8c4,
+                0x09e950fc52a02f86,
+                0x14fef0833aea7b6b,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x619dfa9d886be9f6,
+                0xfe7fd297f59e9b78,
+                0xff9e1a62231b7dfe,
+                0x28fd7eebae9e4206,
+            ]),
+            const_fq([
+                0x64095b56c71856ee,
+                0xdc57f922327d3cbb,
+                0x55f935be33351076,
+                0x0da4a0e693fd6482,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xb1f6855aa8f6e816,
+                0xf95febde85f8b951,
+                0xa83745a6b3ba8491,
+                0x152fc8b3d196ec66,
+            ]),
+            const_fq([
+                0x8cadaa5c7d69be91,
+                0x8761cd036a03bc40,
+                0xa5cad7747e2f36bc,
+                0x1ccca20dfbf02f6a,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x4623a1b622195486,
+                0x2a1ffbb7e8573845,
+                0x558ecb7f04be8e5c,
+                0x0754698664d8bbc2,
+            ]),
+            const_fq([
+                0x6604e0e2d3eaed09,
+                0x810bc1a72b31bc38,
+                0x6e5ca0fef43df373,
+                0x16b356748836ed11,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x3a834082a3589733,
+                0xfe524eae09a15c3e,
+                0x1d2baccbe3047c3b,
+                0x1006a605912fe0a5,
+            ]),
+            const_fq([
+                0x4d6f79e5e4618e5e,
+                0x2404edf53cdeb664,
+                0x731fc51f528d8c8f,
+                0x297006a9b59731d2,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x201cebf0da4f81df,
+                0x13b23b36842474b0,
+                0xf5643cd218c60479,
+                0x0f1560b1c2d80651,
+            ]),
+            const_fq([
+                0xacc95105ad361486,
+                0x6e83ed748aaf6ee9,
+                0x3c35a56e35e4d89f,
+                0x0b6312221e4eb900,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xc7d06d25276dbf9c,
+                0x6c64d4f7fc5569dd,
+                0x3bbdb644cfed0c85,
+                0x0b0fc18eccd4af04,
+            ]),
+            const_fq([
+                0x5717c98f00bc6d52,
+                0x9a64e11b77670f71,
+                0x1abfedb9e3effd7f,
+                0x064fccfe7807bdba,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xf43a8d7cd1ce4c4c,
+                0x8eeaa1e7a98d5a64,
+                0x3afa05a36e3b1214,
+                0x07e1f993239d8919,
+            ]),
+            const_fq([
+                0x8dd5c590dc45966f,
+                0x06cfda820dde3730,
+                0x3d36d2e74c96a76b,
+                0x1142c982ef344658,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x3fee70c74c566e0c,
+                0x59a4b27e49db4c47,
+                0x10cd7a6119fccf9d,
+                0x171cbcc952ab3ff0,
+            ]),
+            const_fq([
+                0x55b71c7e761cbc14,
+                0xbf96f0a5ed31e119,
+                0x84ec7bbc23661a50,
+                0x2dae51cbfab0b5f1,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x09d9da17d8cad0df,
+                0x372a9cefd8c4014b,
+                0x484671fe98aaff00,
+                0x07a961fed3b798bc,
+            ]),
+            const_fq([
+                0x97e34e00fc8f0eeb,
+                0x050d18379cf2fa0d,
+                0x1c5b5bb136a22e5b,
+                0x183888be618b8b44,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xb8ca3f789b8cbe9b,
+                0x8df6b417434b51f3,
+                0xe66d93acafdf9fab,
+                0x263fd6c390bb170f,
+            ]),
+            const_fq([
+                0xf389a801bcb5528f,
+                0xba7af55ef07f58ce,
+                0x46eea0fc05c8386d,
+                0x14e5feb1d47e58d3,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x1c01f147ab396a43,
+                0x49efcaa293d39de0,
+                0x010be537b1d25b48,
+                0x1bfdbfd1e317e1c5,
+            ]),
+            const_fq([
+                0x9706daad8a793128,
+                0x8ec617ff5307a498,
+                0xd209e10fdf35f678,
+                0x1ed0cfeba6d31196,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x5954ab18390414e2,
+                0x6afb76327cda0226,
+                0x805c976433ff0f1f,
+                0x086ea8e94f798294,
+            ]),
+            const_fq([
+                0xfe3fbfb94cd866fe,
+                0xb5cb4136fe368ba0,
+                0xb1414c43dd6f4ce9,
+                0x1d67043c0bb0b233,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x04c7f269ca9738a8,
+                0xb96e1cb9191c4c41,
+                0xbd1cff33d5ce4f9c,
+                0x095a2ef324b892a0,
+            ]),
+            const_fq([
+                0x38b4d9de8c30f5ed,
+                0xa93fc370d459eaee,
+                0x29e1b36f5fc9ed26,
+                0x0851ff31b9f5ee27,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x63210e92ed1b0b53,
+                0x47bedfe2d59db4bd,
+                0xfc16fef32b23a365,
+                0x1354b294fe206833,
+            ]),
+            const_fq([
+                0x4e9b5f4e3f67e49b,
+                0xa42ee0b9d9f20b2c,
+                0x9985d9009f52bf26,
+                0x169b8dd754635681,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x7dac9c6627aaa27f,
+                0x9fad1bb817704081,
+                0x564f5e3002f63f1e,
+                0x290f763255c5756e,
+            ]),
+            const_fq([
+                0x0c849926693fe111,
+                0xad5078152edb012a,
+                0xf8a56a6f9c836b9b,
+                0x0f7dacbc9259813e,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x67a3c9c251ffbb51,
+                0xe62cc8af1fb4fc99,
+                0x4f21ab55ac355efd,
+                0x0313e34254d2df49,
+            ]),
+            const_fq([
+                0xe30c4de07dc9ea7c,
+                0xd62adec4984718c2,
+                0xa66faa078d4b82df,
+                0x09284600d2fcc2c0,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xc154be18e83a2a60,
+                0x2508c8c5de78f4c2,
+                0x5c87b6c2185f7db6,
+                0x1a3eb1df2717e3e2,
+            ]),
+            const_fq([
+                0xc9de0c8110b24fba,
+                0x4de36e78db20ff31,
+                0xa15d3f3071d24013,
+                0x01bfa1e47a62320b,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x0b6dd7ccc4d9c695,
+                0xaf5b069171c24dd3,
+                0x890f16d5fd058669,
+                0x12a1d7ae30881127,
+            ]),
+            const_fq([
+                0xd14c0759d6c8776f,
+                0x47af60bdf0a36a62,
+                0xd946eea40a1346bd,
+                0x1f6e971397be8b53,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x513731d9f6719eae,
+                0x79dc0f13419e89b3,
+                0x01c892fb0e35565e,
+                0x0f1387f1aea47dff,
+            ]),
+            const_fq([
+                0xda836d0717d5713c,
+                0x347483612b1cc08e,
+                0x1cee81c6654d6b06,
+                0x29c2ea099c1a69db,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x6d22f1430ad2e907,
+                0x057ce8bdb9416c7f,
+                0x295d4434800e2f9a,
+                0x0846114663d9f49b,
+            ]),
+            const_fq([
+                0xe9c4f328653c5e34,
+                0x6e1f4b1674b6668d,
+                0x55375d5acf0779d0,
+                0x2c6858124e3ac703,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xd1e06c1fb07ed6e5,
+                0x23ee3df858d3bba3,
+                0xd57f5cab2a456668,
+                0x02c4fc63688318b8,
+            ]),
+            const_fq([
+                0x0f5614ea94e0fc3f,
+                0x125c7d6f0bf6dd53,
+                0xd18664b0f997e4be,
+                0x02d2e5de565bf8fd,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x17538fade7bd10d6,
+                0x19e922d2cfc304c0,
+                0xf48f415c687132dd,
+                0x019351561b682dc1,
+            ]),
+            const_fq([
+                0xf872e0426124205f,
+                0x81f9083c3085a31b,
+                0x500aa52390f49525,
+                0x01a644c4f68bc623,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xe964dabe650101ff,
+                0x62bafbbf55b24add,
+                0x0a81db3506ce1a34,
+                0x0f0010e0a2c6c7ab,
+            ]),
+            const_fq([
+                0x16a422809034f377,
+                0xb571fa114bf15e20,
+                0x4e927885586c82e7,
+                0x26f017ce95b26150,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x118dcae32732ba88,
+                0x38e7beaf2504e97e,
+                0x4ddf26e0622b88fd,
+                0x014ae08142601397,
+            ]),
+            const_fq([
+                0x3b6a4e79a2e243e7,
+                0x692398f1227c32bc,
+                0x882befa2550cdda5,
+                0x2e315b8e2a416d89,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x9a7755841496ba88,
+                0x57423285da92b058,
+                0x97bdf132241fb7bc,
+                0x062a1948dd5cf256,
+            ]),
+            const_fq([
+                0xedca0af7e3b18a67,
+                0x3546a87e984eaa30,
+                0x497faffb46673e17,
+                0x0c61b3749a649e18,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xc1c386ec688c56ea,
+                0x2d586993f30b09da,
+                0x9ce82e7585afed7b,
+                0x2f48167280669116,
+            ]),
+            const_fq([
+                0x6e0273670eb2d746,
+                0xe6da66225420627c,
+                0x2a8afc10768aff85,
+                0x1e72c03dc19a1d24,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x0bb47da8938340f9,
+                0xaffe3c4226b9e04e,
+                0xb636f2094bbb694d,
+                0x070486999c548bf9,
+            ]),
+            const_fq([
+                0x6bbc3802d6865565,
+                0xff43028f1e47e54c,
+                0x6e35dafdd47887ea,
+                0x16fe6de1dd74fa7f,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x7fdcc9a4d42e9e4d,
+                0x2605aa5cddaee571,
+                0x0b592d392057bda0,
+                0x2862076cf6c9bc34,
+            ]),
+            const_fq([
+                0xa588911c1ce06aa5,
+                0x2208adb46c478595,
+                0x2f2863e4acfb5d3e,
+                0x12bba1c607eac175,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x59f30d32d4da247c,
+                0xc243978b8b2d4037,
+                0x44a8586a98e51d42,
+                0x14638689cc0a5398,
+            ]),
+            const_fq([
+                0x038458a41492531e,
+                0x76e9b50b41414618,
+                0x1f0ce5fca90c6e84,
+                0x056182d66a21ffb9,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x1b4e0284e3f8df67,
+                0xbff89340e961fad8,
+                0x1044399291c8f997,
+                0x11f18fa2d038a3a9,
+            ]),
+            const_fq([
+                0x0d3fd8b61f0984d0,
+                0x9945e0de68806c87,
+                0x4946252bd44b4766,
+                0x1d5833e3d0a10a25,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x8df7e86210da7c3f,
+                0x3d986a55e996ab45,
+                0xac05c4f00514ad4a,
+                0x1c4d8e90a8c824dc,
+            ]),
+            const_fq([
+                0xf322ccdf23340478,
+                0x19702da0e4934008,
+                0x8f4ad244591adc20,
+                0x298dee35166acb34,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x481eaa3fc20fcce8,
+                0x07809f89dfcb5102,
+                0x6c0df9f09501a6f8,
+                0x03cfb7960cd49ab1,
+            ]),
+            const_fq([
+                0x26543bb1de8fc5f9,
+                0xaa1863bd263fce9b,
+                0xf966c71e9c515523,
+                0x100dd601ae71d555,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xc337475317a41ab6,
+                0xce2f891a64c364f2,
+                0xa94e6aeb3eb96612,
+                0x201006e5b5951aee,
+            ]),
+            const_fq([
+                0xa5c72b6b29c19e33,
+                0x82a1d4c7ea6f3095,
+                0x3f63d808de7c9e90,
+                0x016d8b714929b077,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xecd4817cd3a6b1b4,
+                0x0d393b74eac3aede,
+                0x26612dd266e5ff8c,
+                0x1a931a15973a6ebd,
+            ]),
+            const_fq([
+                0x7770559fd4b5d5ad,
+                0x7186a8f0e6eb99f9,
+                0x2cac5b413ebed0a6,
+                0x0fd4417356d5f33c,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x2ea56578557776a5,
+                0x5046678cb057eb5c,
+                0xf32871cbcc56a387,
+                0x2f4a69b8513d5896,
+            ]),
+            const_fq([
+                0xd782612a713c3ddf,
+                0x4ea3a1dfbfd3cf9e,
+                0x02a10114b895ae76,
+                0x2be01ee834a5afe1,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xa035dd15cb934e8b,
+                0xcc432a7426f66837,
+                0x72719c682fffba84,
+                0x1cab2b58c90fe3db,
+            ]),
+            const_fq([
+                0xd119e9ab0ed4d777,
+                0xd02978b04f761219,
+                0x9ea0da00e526c281,
+                0x04a7fe4fc9584a65,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x674409042440ea13,
+                0x5bce0ef690d23e78,
+                0xa82eeadcac7a7733,
+                0x294a7932826b2cd0,
+            ]),
+            const_fq([
+                0x9f422aab120a8ee6,
+                0xe087a0f36973429d,
+                0xe6aadcf0ce9b6054,
+                0x08c216e8e284fbec,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xccfbaf72ed8606ec,
+                0xcd38df63b5ea745c,
+                0xa39880ae2c527dce,
+                0x1d102533a2f22ef4,
+            ]),
+            const_fq([
+                0x33c84e9b158c3d06,
+                0x6131e2159f091595,
+                0x419e76ad1fa873f6,
+                0x1903a4aba62ffad7,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xec4b71e3b99d4d85,
+                0xfd6e90566c52c7bc,
+                0x0c55295d1cd1cb9e,
+                0x263b6a05e3d23e2e,
+            ]),
+            const_fq([
+                0xadbff39b382bbd0e,
+                0xdbe9752de07de5bf,
+                0xcdf58a68ee50e07c,
+                0x025a564db929208c,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xcb0bf78dcef2dc1f,
+                0xac9661facba03ba1,
+                0x1e2d680d12ab44fb,
+                0x2280352dbba8a4a0,
+            ]),
+            const_fq([
+                0xa73dfd71c179bc7c,
+                0xf6b0e934bedf0199,
+                0xa37bf1c81ae1b4e0,
+                0x199c3a7e34ded246,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x605b48fa2e46a03f,
+                0x4e9d1c71cf3cfc6b,
+                0x691f1fc94c5ed208,
+                0x0ac4488d76093e9c,
+            ]),
+            const_fq([
+                0xc38512d5e35b374d,
+                0xf2a0b3bb687e829e,
+                0x805ddf7730bbf031,
+                0x21774f3c3b9e4b0a,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x7df972d11fda92ff,
+                0xf09ce40e3a24696f,
+                0x132a83ae756ef0e0,
+                0x2f2c84f58d6f1f7b,
+            ]),
+            const_fq([
+                0x0705ce9f20c7983f,
+                0x87ea33226b51de7d,
+                0x5cce0fe389e5f35c,
+                0x2949cd82cd78d988,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x3d22a3c0bcb48e40,
+                0x71ffdccb427eeb37,
+                0x773267e724a98c68,
+                0x275d51f4d8794bd0,
+            ]),
+            const_fq([
+                0xbb22462daba1ebdd,
+                0xf451926a9a2ede3b,
+                0xa643611e48b0691c,
+                0x1ce46dc492b6926c,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x4d08c4959a887fbb,
+                0x6507b2a1c774072c,
+                0xb483009926e3376c,
+                0x1ab374dbc50b7a8f,
+            ]),
+            const_fq([
+                0x885c925ec2374c5d,
+                0xbba551db6454a5b8,
+                0xfe99a0a5565f6d0d,
+                0x1ba7c19ad8fa2eec,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xa8cfbbc827fc72b3,
+                0xffab8d34a52a4f3d,
+                0x27972e992391690a,
+                0x1c270c78fca5df2d,
+            ]),
+            const_fq([
+                0xd31d621faa2e741f,
+                0xf115d814d3a1f594,
+                0xf8363394a260ab2a,
+                0x2af81cf820273608,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x10d6060b7347cd9a,
+                0x23fe2231f384c0f1,
+                0x438e56a2058824f9,
+                0x03662387efabf138,
+            ]),
+            const_fq([
+                0x34c806b6d0130849,
+                0x3bb8e66c11ae8537,
+                0x6ea163e3a1bb37f6,
+                0x194b915cb6fbe002,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xafbffba084c77145,
+                0xfa591f4026c81b95,
+                0x04d7ed7e04189278,
+                0x0e7666b62a4e37cc,
+            ]),
+            const_fq([
+                0x874c6e2536a1e7cc,
+                0xe70888f86ab301d9,
+                0x91cd15208bc8551e,
+                0x0dc917c565caf75e,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x7c48f5abd4694693,
+                0x885eda5892f9dd5a,
+                0x432dbfb73f5cce8b,
+                0x1b606ed78b570ba9,
+            ]),
+            const_fq([
+                0xab90b93198368f0a,
+                0x22fbc9652ee1411d,
+                0x6c47a98e0331de58,
+                0x00acd63539fc8056,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xb687df72a4870034,
+                0x52512866b6c152cf,
+                0x8adcf2326f6201b5,
+                0x2e8241e8c9f42126,
+            ]),
+            const_fq([
+                0x2ed16a19a834d674,
+                0x11a53e4a9020b620,
+                0x1d74840cd6263fa8,
+                0x15d5a5de33f09172,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x253b09a5b8544689,
+                0xc391494c9075f64b,
+                0x9b82e350e38c2909,
+                0x03d9d09052fca56c,
+            ]),
+            const_fq([
+                0xcdd9e5847ef69270,
+                0x47c11523ddd46610,
+                0x080349f8b8dfae0c,
+                0x1e292e70e0bceec9,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x5dfeed2b0a778cc3,
+                0x8875ccde829e5b96,
+                0x9594658f022c3ec6,
+                0x1b9ff7f42de1b365,
+            ]),
+            const_fq([
+                0x4c9100ac1411782b,
+                0xfe487e4f2846f832,
+                0x3f7114b453fc1f31,
+                0x08729c87338bff8a,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x8913a378f9df8f96,
+                0x896dfce0e8daf42e,
+                0xc52f0b9902a3d3ea,
+                0x13e266c8c5d28fd2,
+            ]),
+            const_fq([
+                0x29305f3195e44dc7,
+                0x0c680eee75232889,
+                0x705cf4d408b434ca,
+                0x0da669dced941b63,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x92807f3e3fc27797,
+                0x8cf04a4e59c449b6,
+                0x0a5619740435a1dd,
+                0x25c1980649bb26a9,
+            ]),
+            const_fq([
+                0xa5e2dc2a06f6dedf,
+                0xd9d7f42bbb66fad8,
+                0x4684c5aa2335ecc0,
+                0x0aa55b5945ddd4fc,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x2b73c2bf8d567724,
+                0xb5544695152b6901,
+                0x4078fbbd4422dce2,
+                0x276e75f6583f3eed,
+            ]),
+            const_fq([
+                0x81441c7c1dd586db,
+                0x64e45833a92e58e7,
+                0x6699771fd39c9c41,
+                0x0e815465d7a482cd,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x8e03aef2a9c708a4,
+                0x6e11589c9cd30afb,
+                0x13af91125adc6313,
+                0x0b6fb7f5dd873517,
+            ]),
+            const_fq([
+                0x918d59faf4de613a,
+                0x7046d39d5d94a37c,
+                0x25457eff5c659288,
+                0x1c55b929ec381681,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x785516fb712695ba,
+                0x95670a07cd2a71aa,
+                0xcca512fa8cafff9f,
+                0x2f7e3c9c17573339,
+            ]),
+            const_fq([
+                0xa6c9a9c4fd9d54b2,
+                0x2909040de99e7598,
+                0xfc395ad40a9d191b,
+                0x1d1e8211f463a00f,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x3f0dbdda8ba8e1bc,
+                0x343289418f3542a9,
+                0xfaa5baa6535b6671,
+                0x031cbd0f465ffbd5,
+            ]),
+            const_fq([
+                0x1f45965a9cc8a738,
+                0x15ac7625e7598bef,
+                0x3e0455630f62a795,
+                0x1e0ef4d909468b98,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x5866931ed9bb2855,
+                0x1392a7a81f36b42b,
+                0x841d3d68268b1b14,
+                0x1300ad7de01ce714,
+            ]),
+            const_fq([
+                0xa8749b9a5cb7c101,
+                0x22517071356f04a1,
+                0x6be6ad2e520f570a,
+                0x127f4cc18abc7929,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xed6f48ef41af84e0,
+                0x01eccddade5167f5,
+                0x1ec4f00efaec08e2,
+                0x069c55982e09fd8e,
+            ]),
+            const_fq([
+                0x29643d18672d7948,
+                0x486adcf38ddc829c,
+                0x527e311dd82f55a4,
+                0x207b708b5d7bd4c3,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xd95a45408c0e4081,
+                0x7a3399f26063dd02,
+                0x3ae11e0fce7f6a6c,
+                0x03dccd702b0c8f72,
+            ]),
+            const_fq([
+                0x164439d9b803a93c,
+                0x29b200e24b0f03f8,
+                0x6566268a3421c338,
+                0x09c538bcfeae03c3,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x8159cc8d14f2c4e9,
+                0x55c8cda87c8b58e8,
+                0x73152614f67df587,
+                0x042074f9eca836c7,
+            ]),
+            const_fq([
+                0x109e6667f321f950,
+                0xa2a73f635d98a96f,
+                0x873536eb407681db,
+                0x25e95c7c7bab742a,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xd9ab29eb08989152,
+                0x62dd86be8b4914e0,
+                0xa7956b20aaacdd24,
+                0x21a3f3e0bfe9fdf6,
+            ]),
+            const_fq([
+                0xa2487b102362c800,
+                0xe3c8722aff6c6122,
+                0x997745ace43dee71,
+                0x204fe690d65030b0,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xc40325fb3fa17a17,
+                0xcc3d5da6ee85a48a,
+                0x83d76961cc2cf3ba,
+                0x2b279a85e65ae5d8,
+            ]),
+            const_fq([
+                0x6ac5fcdbc21fa70a,
+                0x6b6ca275674168ab,
+                0x1ebcbff8510d295e,
+                0x12488d9ffa8f5153,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x0416f281e696dbfa,
+                0xb4ba902d744f28db,
+                0x35e823b212dabf0d,
+                0x238232aafcb1b68a,
+            ]),
+            const_fq([
+                0xff3351c20aa222a7,
+                0xfffca7ff3e213902,
+                0xd6fd00310c521177,
+                0x08355c4643f0c95f,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xa4f3daa6208338d6,
+                0x03b836677512d92c,
+                0x37cf1d3af4746f7f,
+                0x016a3e23832a79c2,
+            ]),
+            const_fq([
+                0xe498107d88c77675,
+                0x132544618cff752d,
+                0xb216f5d4185bb059,
+                0x29eff3e32662349a,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xe0e537a7bfe9f4b2,
+                0xb7eeb426aabd57b3,
+                0x17ef5fab9789cc9f,
+                0x01c42abd44e178c5,
+            ]),
+            const_fq([
+                0xf9039aa1ce6d2f64,
+                0x60d4800be6ad805e,
+                0x9a653d5a588f1023,
+                0x147f9d71078d1031,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x1ec194a5f261f1f8,
+                0x2091376d973cd64b,
+                0x7df03c0e71a03f71,
+                0x0474c6a19e5682cd,
+            ]),
+            const_fq([
+                0x6c23b9c1155bc944,
+                0x53a43a2682def3e6,
+                0x3bc02c92e14760d6,
+                0x1f5f1d88b3e4c2b2,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x76dd2046664b0081,
+                0xb4da9a17d6a34bf0,
+                0xb013ac074f2985fb,
+                0x2a8866f6ccdb6277,
+            ]),
+            const_fq([
+                0x5ee02e439402d9dd,
+                0xecbe63ef2cf77590,
+                0x5c263c712b49fa6b,
+                0x1aa8c8da89167b0b,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xa3d45cfbaa3767a9,
+                0x7520e7c90e5130e7,
+                0x056aebd8b03b2ff1,
+                0x06954f1a8102b83c,
+            ]),
+            const_fq([
+                0xca83e384fa34edad,
+                0xcb5aa54729c1f274,
+                0x8ba6697f8579bb5a,
+                0x100dc89d81597f0b,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x717489c3330456b5,
+                0x39dec2f47e45672c,
+                0xdd7a940c80a0c1c5,
+                0x2ffd16080ce9af27,
+            ]),
+            const_fq([
+                0x61e824c1de35421b,
+                0xcafda7771f7c3280,
+                0xcc1e264550333f7c,
+                0x148ed671f2abd552,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xba77f2bc38d5cb79,
+                0x5c751298518c9233,
+                0xee21acc47773564a,
+                0x177d66370f51073a,
+            ]),
+            const_fq([
+                0xce1ca71fa8c035d3,
+                0x56ea33fad46b79cd,
+                0x482b3a5d278ddc8a,
+                0x2ab802c02848890e,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xd300f68f6a1f7df9,
+                0xe23168d79cbbf2f7,
+                0x938b68da6b3c8966,
+                0x0adb13085634f8d4,
+            ]),
+            const_fq([
+                0x053bf80f7c3ac610,
+                0x5e9588a48f6240c3,
+                0xc3333bccc8d110cc,
+                0x1065632b63a67619,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x1105a9d2bd94c577,
+                0x8869fab3068dca17,
+                0xc9cc9ca5554a8b5a,
+                0x0ab032ccbde6c421,
+            ]),
+            const_fq([
+                0xc23eaec065640b81,
+                0xd3dff71aab60ebc3,
+                0x0d10a6517e9ad64f,
+                0x092cacb93ca5b7ef,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x13c4e0991dac12cd,
+                0xdb8268d0a83bbaef,
+                0xdb37698022ccd569,
+                0x1dd82eabc931ac09,
+            ]),
+            const_fq([
+                0x47b11af0de8f5cf2,
+                0x4b4541c3920d793b,
+                0x6f019c11be1c8822,
+                0x1ac4157f0489dce9,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xc5c30d399f19b951,
+                0x71ff66585beb6395,
+                0x131a77ecd9aadcb2,
+                0x1d8654db9e711302,
+            ]),
+            const_fq([
+                0xe3914840b179301a,
+                0xb5a803390aa7b073,
+                0xa4bc0f902813e93c,
+                0x1ea997c7d0eb94f8,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xf7ca314e5015dab4,
+                0x1219a0008e49bca5,
+                0x54c38bec79b57ed6,
+                0x195b18228f846d91,
+            ]),
+            const_fq([
+                0x32164facb6c7a45f,
+                0x91f9b74a5885dbe3,
+                0x87c041202e9bbcce,
+                0x0257a1e4a6716d52,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x2e63fc075f814105,
+                0x40905ff26515818b,
+                0xc1b5bd3450c264cc,
+                0x1e8a99cc4136c798,
+            ]),
+            const_fq([
+                0x0bdd726dc7fac55a,
+                0xbb2614104d80ff77,
+                0xf1578ead05018112,
+                0x0be475a700d37eb4,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xe9f6e419e8802abf,
+                0xe0a84ffdc1da98f5,
+                0x3d760c74e395824e,
+                0x11e8e4749829c91b,
+            ]),
+            const_fq([
+                0x00848bdcc2a55734,
+                0xa153552813328d93,
+                0x0a22b9f43af87e62,
+                0x09c249072785ee15,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x94f8872bb042e217,
+                0x21d144cdae494b4f,
+                0x23ac43ed8fb95f25,
+                0x009d760f8ba4f8a0,
+            ]),
+            const_fq([
+                0x85a75af7dc8ebedc,
+                0x277b4165d109c18e,
+                0x24cd487cd73dbcb9,
+                0x0feeffc9afbaf62a,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x18b22e45b53e0324,
+                0x9887ba106917cb21,
+                0x8b00559ae0551440,
+                0x037122bae25aacd7,
+            ]),
+            const_fq([
+                0x87c173b83773110b,
+                0x983a52c2c8261d67,
+                0xfe609f540fbe55d8,
+                0x0ba0bb2e72f5beff,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x824743e3fe0b322c,
+                0xeee5375bb059321b,
+                0x320354d9e13e5299,
+                0x2ce23a4b6ee531fa,
+            ]),
+            const_fq([
+                0xca0f8f789b2e5303,
+                0x56b77f747b994040,
+                0x241495a5cb44f3d9,
+                0x1d862107772b0145,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xf91b8858d3f8f454,
+                0x9c89ab7a893a66fd,
+                0x44855ca777ae7df5,
+                0x2498ce2c5ea6bd9f,
+            ]),
+            const_fq([
+                0x44c3d9b897b7cc83,
+                0x84753c767512a8fa,
+                0x675fb96ddcae6a74,
+                0x2952fccb3df2621f,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x37ce127bc85bbc2a,
+                0x44890ec017efeddf,
+                0x3d054a8fa752d2bc,
+                0x13bd526614ce3a6f,
+            ]),
+            const_fq([
+                0xe5919a8b6b4aacb8,
+                0x6c689846e09e6c7b,
+                0x28869c09cd52b7fe,
+                0x11c1a9ae8c9e729e,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xbbbcd5a7288a776e,
+                0xc69b18564e1052bc,
+                0x3b86228e00c3e391,
+                0x10fdacfbaba7a301,
+            ]),
+            const_fq([
+                0x676e61780b1fea90,
+                0x4af4186a88e33964,
+                0xcb71881d8dd6e3e8,
+                0x015bd79867f42e1e,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xf87b1fb536b84fff,
+                0x350ef4b36be2a267,
+                0xb3bdafb1e0a78c62,
+                0x22067480a1c4bc67,
+            ]),
+            const_fq([
+                0x1d3417e7de5417ae,
+                0x875ad090379a5a46,
+                0x70b543f1893cf84f,
+                0x047d1317be8da312,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xe9b2a42142023cb3,
+                0x1da65763a8f399ce,
+                0x7780f7375dbc3019,
+                0x2477f8a526d3658c,
+            ]),
+            const_fq([
+                0x92d419f9a346e389,
+                0xfde3a1d8d1b482e0,
+                0x9e7e4da2e80573d8,
+                0x0284635f10511576,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xb8452478ea48d1ca,
+                0xaf2f3fe4db56fe79,
+                0x652b1ec38f0113b6,
+                0x0c6e0f9acdbe2f23,
+            ]),
+            const_fq([
+                0xcee03753da666af3,
+                0x9e7835b721a178ec,
+                0x514e3e19354fd27a,
+                0x2bce8e4be373ac43,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x628a5eff5e7c4397,
+                0x6ae3ac4199eaaecd,
+                0x7ae520fca0792c5a,
+                0x201d6b91bf4b0e6b,
+            ]),
+            const_fq([
+                0x9d92a394fa7eb5e2,
+                0xc1ede8ca11c98859,
+                0x2742eb77a17318b1,
+                0x24ea90662eabcbe7,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x331090e534431546,
+                0x1ab7f95dff747e7a,
+                0xcb9dfcab24b94935,
+                0x17a2468ec174384c,
+            ]),
+            const_fq([
+                0x655b3f3f1f929245,
+                0xd830689f0c2d8d52,
+                0x63f2acbfcb5117fd,
+                0x1314ae4228a5bdbe,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xd31dc818514f68bc,
+                0xf1f92cd8dc711398,
+                0x69fa336ce9e4e48f,
+                0x1e43f533c148bc64,
+            ]),
+            const_fq([
+                0xdfbd78ea8d4c2153,
+                0x46c5562e29ab34ac,
+                0xe9b5143d3c99c19e,
+                0x2c4080be26e47770,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x8a172ba38caa72dd,
+                0xb775ef4d2a1e8dbb,
+                0x4cfca383acd40a08,
+                0x03d5f9dfa4c81915,
+            ]),
+            const_fq([
+                0x5cabc54f8a391b67,
+                0x480582358e202bc7,
+                0x22245142c3d62379,
+                0x057ba7421ba12328,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xb5d953cb98c91b2b,
+                0x9a25afc5250cdbe2,
+                0x7946e370f4addeaa,
+                0x0a04bb35993d2a2d,
+            ]),
+            const_fq([
+                0x9acad3be9d5b6d9b,
+                0xe3709d8e2f269c94,
+                0xef7d89c2ed14d4e5,
+                0x204a84492d0c5575,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x104542cbf4f3544a,
+                0x1a556d3cc5e7602b,
+                0x4192414b6ff98525,
+                0x1f7b7c45ef875191,
+            ]),
+            const_fq([
+                0x4b0c7aa3fe710922,
+                0xa57d59b0b1225fb0,
+                0x53a36b326b66ae08,
+                0x0c4fe2b48bff760e,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x7508c627862feec8,
+                0x358be7323f301b64,
+                0x9e27503130a6e4a3,
+                0x14678b89642cc2c5,
+            ]),
+            const_fq([
+                0x238035d37d218e0b,
+                0xc661bf5559d562d9,
+                0x28415cc95646585a,
+                0x09a28f11517026af,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xfa6c9b787e46a877,
+                0x91beca96aa388538,
+                0xc23876450abc3e5e,
+                0x0fa39294df8f3b99,
+            ]),
+            const_fq([
+                0x46eb5d579bf0200f,
+                0x9241444fe7e89923,
+                0xdde0ba26ee43d7c6,
+                0x1bc630ea3216d8fd,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x87a1a84e5a20514e,
+                0x014608c3d955b225,
+                0xcde3ebfac0fdc770,
+                0x29dfdbd5004eacd1,
+            ]),
+            const_fq([
+                0xc8af0daf4b2e39a5,
+                0x2bda5c6abc887497,
+                0xe83ba8be38a12f54,
+                0x199583430c4ec93e,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x7ff2a50edecebafe,
+                0x9cf0fa921efe7fc4,
+                0xceae1b54ff5f02cf,
+                0x068574807da8ca8a,
+            ]),
+            const_fq([
+                0xb1c94070d675e195,
+                0x635ab186a04f4259,
+                0xaa5da1ad8512bde2,
+                0x02f67532b0b16131,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x48ac284898714f09,
+                0xb4091b226dc1fb38,
+                0x32a7545ea3d5df83,
+                0x15564d49406deb60,
+            ]),
+            const_fq([
+                0x91b4f2c12f971f38,
+                0x949eba1e18795821,
+                0x73255e6ef7cc32ec,
+                0x1d9ff2f76ed5d72f,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xd73ae7acec4e0fbf,
+                0x22379df68f5e473a,
+                0xa68c1ed5d2eb75ef,
+                0x08c883b7b72fc2fa,
+            ]),
+            const_fq([
+                0x8c1a64d7223e3474,
+                0x34c622e930c11a01,
+                0x4f23048d29dba2ae,
+                0x0d6bbca73e63ed38,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xbbe632d406fe4e27,
+                0x1a4fceedb20bfab6,
+                0x60265f2e3a9ea74c,
+                0x1e49d2e212168371,
+            ]),
+            const_fq([
+                0xc41db8de065883ec,
+                0x157700b506a36427,
+                0x6bf005bca052e00e,
+                0x1309b9ead977c751,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xbdf2fbb2b95b8955,
+                0xe01dc23805a49c9b,
+                0x581f658e4b7e14ab,
+                0x1a54347d84eb4fcb,
+            ]),
+            const_fq([
+                0x180420191c38d2ae,
+                0x7a522816b00b9c1a,
+                0x78190d4964b0d308,
+                0x0323fd37306dae69,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xabc88f24b9bd636f,
+                0x2b8e1efb25de7ea8,
+                0x5ad267acc3c98387,
+                0x0ba651ee752f3ab0,
+            ]),
+            const_fq([
+                0x0ecc940b60a3fd91,
+                0x7f14931eab5ab29f,
+                0xa683084f6a2395a9,
+                0x232afbe98f9dce9d,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x74e09c6c3fc5b1a0,
+                0xcb610754b4f8900d,
+                0x1d3e9e5b5bea8b66,
+                0x1bd0e7bbb4853258,
+            ]),
+            const_fq([
+                0x6080a7d18fcee27d,
+                0x08ce8254d5b7c1e0,
+                0xa0a16cab3b4e630e,
+                0x11ba3c2adad8625d,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x0d0499c00e295e8f,
+                0xd758f4658c7e9219,
+                0x251357d06542297d,
+                0x17b1dd688a9eddd9,
+            ]),
+            const_fq([
+                0xd1df50e4c61b63fd,
+                0x48e64f837a0bda96,
+                0x79af2e25177272bc,
+                0x0e6fcea4f5ef1ef3,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x9487eb41112ad93f,
+                0x9b40988479adfe22,
+                0x5b738312b7b26b7c,
+                0x0bfe72c95d96920b,
+            ]),
+            const_fq([
+                0xc7d58b039764517d,
+                0x80330a954b2d3b1f,
+                0x0a4ab8d1a3e672c1,
+                0x1387f20855966c2d,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x4683e11be8df0dd0,
+                0x815cb8b5fef25719,
+                0x6fed3cc494ec3300,
+                0x12e7de63cbb8543a,
+            ]),
+            const_fq([
+                0xdc1d15bbefd7af87,
+                0xca04506f9ff27a95,
+                0xca69305277ada927,
+                0x1a8874ab82a6100a,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x7cace9ca7953def1,
+                0xe569c90f4d605b9d,
+                0xd9d30290d6f2edb1,
+                0x04dcdf1a0ef73eb2,
+            ]),
+            const_fq([
+                0xf60ed79ccfaaca34,
+                0x8a1a0e9c93e612b9,
+                0x52493129f14e3fe5,
+                0x0a5440439226ea2d,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xd8708a89bee86f43,
+                0xc53db368dbf716d8,
+                0xe6dcea627a4774d7,
+                0x1eefe4ce467269a3,
+            ]),
+            const_fq([
+                0x88cf49408ac8f940,
+                0x1e6b38c1a7afc64a,
+                0x1d5d5b3cbdbcd907,
+                0x25119f13729b84b2,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xe0688ab0cbadb1eb,
+                0x1b1b813a7a0ab7c7,
+                0x6b127d5b636a2b42,
+                0x2d0d1be5f189e98a,
+            ]),
+            const_fq([
+                0x74ac3bc8894f7bca,
+                0x911cf970a4a38327,
+                0x260cba320b7b373b,
+                0x151143257402a0ce,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xdbf407f3f6adeaca,
+                0xafc48b550bac7d25,
+                0x9199947a2c831322,
+                0x076d38e4434e6048,
+            ]),
+            const_fq([
+                0x38355802c35e802d,
+                0x210f6714a8ba433f,
+                0x8f5989402758236a,
+                0x140fa1cf9c937c7e,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x8878975ac5eb7629,
+                0xad654f0e10101a97,
+                0xd193312435ca44c5,
+                0x04559ecf172d40ed,
+            ]),
+            const_fq([
+                0xaa0f5ab38722b53f,
+                0x29fcef264ecd94b5,
+                0x8b8f5029d6451f32,
+                0x03f894f5e14b4308,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x3a6ae1d80c684e40,
+                0x0e31517a1dd2f37e,
+                0xb805c6b30a3f6ba9,
+                0x114ac7c3681cdc03,
+            ]),
+            const_fq([
+                0x5ecfb4ba11d28e5d,
+                0x8cc3557bbe0095a6,
+                0x41e271c85bb5e345,
+                0x1ea6d23d4c32911a,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xba5cfeb383abe55c,
+                0xf7aa7b8a9502a6f2,
+                0x5d2a21d2df2978dc,
+                0x1098396eec457d7c,
+            ]),
+            const_fq([
+                0xf547c692bcba06fc,
+                0xa7d2078c50bd3a15,
+                0xaf94c68767ebeb8b,
+                0x248fc1b572a81b02,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xc66d3c10fe4f82f0,
+                0xba9d5c994660f8f3,
+                0x0cfb88a5e49ae1f3,
+                0x2d7a3d7372778806,
+            ]),
+            const_fq([
+                0x84e372d0ea382daa,
+                0x6aa49f53b3d1ce3c,
+                0xfd078544fe3575fd,
+                0x1f86aa05dc3cfcfc,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x4bfbb00aa721adf0,
+                0xa115644f7f883788,
+                0xb409a5e542c7afcc,
+                0x258dca9c60dd40b3,
+            ]),
+            const_fq([
+                0xf767176595977713,
+                0x7e2d67768b8d4fa7,
+                0xd969e07ad9a04e10,
+                0x028c15687acf703f,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xf55e40eb15f97b20,
+                0xde33b1e2865581a7,
+                0x3a521df562d47f3a,
+                0x2e55f505ba8b6941,
+            ]),
+            const_fq([
+                0x1bad7c10b5df5c45,
+                0xc4cc80f7ea5a3d5e,
+                0x8fad8cf3be1cab87,
+                0x0a97e50a13852963,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x9ae9d2f36d0c92ca,
+                0xde9560eebcdfc09f,
+                0xf43e4bab03fa388a,
+                0x1615eb9a722cd86b,
+            ]),
+            const_fq([
+                0x4fbe1df752c9279b,
+                0x7e9dc188aa6ffacf,
+                0xff38399c34231912,
+                0x2a4b3c6ce054573e,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x5b3511ecd9b7d847,
+                0x685722fec4fa07d9,
+                0x173a18d4ef96d280,
+                0x0b829d05271752bc,
+            ]),
+            const_fq([
+                0xe37792bca6b7d8cf,
+                0x43475b48dcfd98cc,
+                0xcecff7ccfede74c5,
+                0x2d621315932c4aac,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x331f9e18ac586949,
+                0xb79c976d01d256f2,
+                0xda21ed74c5dbe579,
+                0x2cdc4fb66a92e83d,
+            ]),
+            const_fq([
+                0x12b9b3929f504e6b,
+                0x4d7cd75197581fe1,
+                0xb0f416ead41aec5b,
+                0x0b746bcc8ed25b28,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xd3215227c1c6f569,
+                0x91656b8485bfa873,
+                0x87f4de999e303831,
+                0x2e2b90de929f551e,
+            ]),
+            const_fq([
+                0x7c1203cd3256cfc7,
+                0x80531614beeaae0b,
+                0x3eb5500545253df9,
+                0x2238f6dfb7051410,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xbbf2989ef7ac3462,
+                0xeeff4a1e0cb03bb2,
+                0x411c2167a63db12c,
+                0x04d596ff1fc9d9d7,
+            ]),
+            const_fq([
+                0x0cd31af635f4901f,
+                0x01c3f08eaa1a439e,
+                0xd7215e8fbdd661a6,
+                0x1a15464779fbdc3a,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0x111f6a1a3a16751a,
+                0x82cd2fe7fd6c2fb9,
+                0x719ba1173bbd047e,
+                0x14ff3a83bb160048,
+            ]),
+            const_fq([
+                0x5086471096798912,
+                0x0eb444741dde935a,
+                0xe6daacc5dec076fa,
+                0x004179e9e1d036d0,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0x39c6b32f8f7c5bb3,
+                0xb3ef7245e30d2306,
+                0x0373809886117dd0,
+                0x225145ed8f7fd00f,
+            ]),
+            const_fq([
+                0xfe07cb962d915398,
+                0x3cf527197800a952,
+                0x3f76a2da83dced81,
+                0x2f689525614536a6,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+    G {
+        x: Fq2::new(
+            const_fq([
+                0xbd5b897ff33a7230,
+                0x6e112445b4a5f6dd,
+                0x4f5a681afb45ce52,
+                0x0aeba7e3fb5e4737,
+            ]),
+            const_fq([
+                0xc09762078c780d37,
+                0xae89f385a22a5721,
+                0xff7dab132598d163,
+                0x228c0f72c963728e,
+            ]),
+        ),
+        y: Fq2::new(
+            const_fq([
+                0xdd7278e08ef0d735,
+                0xf235569a6e2e9de8,
+                0x3f46b3acb90685d5,
+                0x10f317ba64a76037,
+            ]),
+            const_fq([
+                0x992d3a80be7f4bd9,
+                0xad3d1b9581df10bf,
+                0x5fa0dc89a742bddb,
+                0x1e93aa41097148b9,
+            ]),
+        ),
+        z: Fq2::new(
+            const_fq([
+                0xd35d438dc58f0d9d,
+                0x0a78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0x0e0a77c19a07df2f,
+            ]),
+            const_fq([
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+                0x0000000000000000,
+            ]),
+        ),
+    },
+];
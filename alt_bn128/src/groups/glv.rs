@@ -0,0 +1,151 @@
+//! GLV and GLS scalar multiplication. Both groups have an endomorphism
+//! `phi` that acts on the prime-order subgroup as multiplication by a
+//! fixed `lambda`, so `k * p = k1 * p + k2 * phi(p)` for halves `k1`, `k2`
+//! of about 128 bits, which halves the number of doublings.
+//!
+//! - G1: `phi(x, y) = (beta * x, y)` for a cube root of unity `beta` in
+//!   Fq, with `lambda` a cube root of unity modulo r. `k` is decomposed
+//!   with the short lattice basis `(a1, -b1)`, `(a2, b2)` of
+//!   `{(u, v) : u + v * lambda = 0 mod r}`.
+//! - G2: `phi` is the untwist-Frobenius-twist map `psi`, with
+//!   `lambda = p mod r = 6u^2`. This is only about 127 bits, so `k1` and
+//!   `k2` are the remainder and quotient of `k` divided by it.
+
+use crate::arith::{U256, U512};
+use crate::fields::{const_fq, Fq, Fr};
+use crate::groups::{twist_mul_by_q_x, twist_mul_by_q_y, GroupElement, GroupParams, G};
+
+/// Sign and magnitude of one half of a decomposed scalar.
+pub type Half = (bool, u128);
+
+/// The cube root of unity in Fq matching `G1_LAMBDA`.
+fn beta() -> Fq {
+    const_fq([
+        0x71930c11d782e155,
+        0xa6bb947cffbe3323,
+        0xaa303344d4741444,
+        0x2c3b3f0d26594943,
+    ])
+}
+
+/// `2u + 1`, both `a1` and `b2` of the lattice basis.
+const G1_A1: u128 = 9931322734385697763;
+const G1_B1: u128 = 147946756881789319000765030803803410728;
+const G1_A2: u128 = 147946756881789319010696353538189108491;
+
+/// `6u^2`, the eigenvalue of `psi` on G2.
+const G2_LAMBDA: u128 = 0x6f4d8248eeb859fbf83e9682e87cfd46;
+
+fn fr(x: u128) -> Fr {
+    Fr::new(U256([x, 0])).expect("128-bit value is below the modulus; qed")
+}
+
+/// `round(k * b / r)`.
+fn round_div(k: &U256, b: u128) -> Fr {
+    let modulus = Fr::modulus();
+    let half = U256([modulus.0[0] >> 1 | modulus.0[1] << 127, modulus.0[1] >> 1]);
+    let (q, _) = U512::new(k, &half, &U256([b, 0])).divrem(&modulus);
+    Fr::new(q.expect("k * b / r is below 2^128; qed")).expect("quotient is below 2^128; qed")
+}
+
+/// The shortest representative of `x` modulo r.
+fn signed(x: Fr) -> Half {
+    let (negative, magnitude) = if U256::from(x) > U256::from(-x) {
+        (true, U256::from(-x))
+    } else {
+        (false, U256::from(x))
+    };
+    debug_assert!(magnitude.0[1] == 0);
+    (negative, magnitude.0[0])
+}
+
+/// `k = k1 + k2 * lambda` modulo r with `|k1|, |k2| < 2^127`.
+pub fn decompose_g1(k: Fr) -> [Half; 2] {
+    let k_int = U256::from(k);
+    let c1 = round_div(&k_int, G1_A1);
+    let c2 = round_div(&k_int, G1_B1);
+    let k1 = k - c1 * fr(G1_A1) - c2 * fr(G1_A2);
+    let k2 = c1 * fr(G1_B1) - c2 * fr(G1_A1);
+    [signed(k1), signed(k2)]
+}
+
+/// `k = k1 + k2 * 6u^2` with `0 <= k1, k2 < 2^127`.
+pub fn decompose_g2(k: Fr) -> [Half; 2] {
+    let (q, r) = U256::from(k)
+        .divrem(&U256([G2_LAMBDA, 0]))
+        .expect("divisor is nonzero; qed");
+    [(false, r.0[0]), (false, q.0[0])]
+}
+
+pub fn endomorphism_g1<P: GroupParams<Base = Fq>>(p: &G<P>) -> G<P> {
+    G {
+        x: p.x * beta(),
+        y: p.y,
+        z: p.z,
+    }
+}
+
+/// `psi` in Jacobian coordinates; Frobenius commutes with the division by
+/// powers of `z`.
+pub fn endomorphism_g2<P: GroupParams<Base = crate::fields::Fq2>>(p: &G<P>) -> G<P> {
+    G {
+        x: twist_mul_by_q_x() * p.x.frobenius_map(1),
+        y: twist_mul_by_q_y() * p.y.frobenius_map(1),
+        z: p.z.frobenius_map(1),
+    }
+}
+
+/// `k1 * p + k2 * q` by joint double-and-add over the 128 bits of the
+/// halves.
+pub fn mul_halves<P: GroupParams>(p: G<P>, q: G<P>, halves: [Half; 2]) -> G<P> {
+    let [(n1, k1), (n2, k2)] = halves;
+    let p = if n1 { -p } else { p };
+    let q = if n2 { -q } else { q };
+    let both = p + q;
+    let bits = 128 - (k1 | k2).leading_zeros();
+    let mut acc = G::zero();
+    for i in (0..bits).rev() {
+        acc = acc.double();
+        match ((k1 >> i) & 1, (k2 >> i) & 1) {
+            (1, 1) => acc = acc + both,
+            (1, 0) => acc = acc + p,
+            (0, 1) => acc = acc + q,
+            _ => {}
+        }
+    }
+    acc
+}
+
+#[test]
+fn decompositions() {
+    use crate::fields::FieldElement;
+    use crate::groups::{G1, G2};
+
+    let lambda_g1 = Fr::new(U256([
+        0x5bfc41088d8daaa78b17ea66b99c90dd,
+        0xb3c4d79d41a91758,
+    ]))
+    .unwrap();
+    let scalars = [
+        Fr::zero(),
+        Fr::one(),
+        -Fr::one(),
+        Fr::from_str("123456789123456789123456789123456789").unwrap(),
+        -Fr::from_str("98765432198765432198765432198765432198765").unwrap(),
+    ];
+    let value = |h: Half| if h.0 { -fr(h.1) } else { fr(h.1) };
+    for k in scalars.iter() {
+        let [k1, k2] = decompose_g1(*k);
+        assert!(k1.1 >> 127 == 0 && k2.1 >> 127 == 0);
+        assert!(value(k1) + value(k2) * lambda_g1 == *k);
+        let [k1, k2] = decompose_g2(*k);
+        assert!(value(k1) + value(k2) * fr(G2_LAMBDA) == *k);
+        assert_eq!(G1::one() * *k, G1::one().mul_u256(&U256::from(*k)));
+        assert_eq!(G2::one() * *k, G2::one().mul_u256(&U256::from(*k)));
+    }
+
+    let p = G1::one();
+    assert_eq!(endomorphism_g1(&p), p.mul_u256(&U256::from(lambda_g1)));
+    let q = G2::one().double();
+    assert_eq!(endomorphism_g2(&q), q.mul_u256(&U256([G2_LAMBDA, 0])));
+}
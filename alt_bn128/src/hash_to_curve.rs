@@ -0,0 +1,421 @@
+//! Hashing to the curve following RFC 9380: `expand_message_xmd` with
+//! SHA-256, hashing to field elements, and the Shallue-van de Woestijne
+//! (SvdW) map, which applies to both BN254 groups since their curves have
+//! `a = 0`.
+//!
+//! There is no simplified SWU map. SSWU needs `a != 0` and is used through
+//! an isogeny from a curve with `a != 0` (an 11-isogeny for BLS12-381 G1).
+//! The G1 curve has j-invariant 0, and a rational isogeny to a curve with
+//! another j-invariant must have a degree dividing the conductor
+//! `sqrt((4p - t^2) / 3)`, whose smallest prime factor is 59; there is no
+//! 11-isogeny. No SSWU suite is specified for BN254 and libraries
+//! hashing to BN254 use SvdW, as here.
+
+use crate::arith::U256;
+use crate::fields::{self, FieldElement};
+use crate::{Fq, Fq2, Fr, G1, G2};
+use core::fmt;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The requested output is empty or longer than `expand_message_xmd`
+    /// allows (255 hash blocks).
+    OutputLength,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::OutputLength => f.write_str("invalid expand_message_xmd output length"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Constants of the SvdW map (RFC 9380, section 6.6.1) for a curve
+/// `y^2 = x^3 + b`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SvdwConstants<F> {
+    pub z: F,
+    pub c1: F,
+    pub c2: F,
+    pub c3: F,
+    pub c4: F,
+}
+
+fn fq(s: &str) -> fields::Fq {
+    fields::Fq::from_str(s).expect("embedded constant is a valid Fq; qed")
+}
+
+lazy_static::lazy_static! {
+    // Z is the first of 1, -1, 2, -2, ... accepted by find_z_svdw, for
+    // both curves.
+    static ref G1_SVDW: SvdwConstants<fields::Fq> = SvdwConstants {
+        z: fields::Fq::one(),
+        c1: fq("4"),
+        c2: fq("10944121435919637611123202872628637544348155578648911831344518947322613104291"),
+        c3: fq("8815841940592487685674414971303048083897117035520822607866"),
+        c4: fq("7296080957279758407415468581752425029565437052432607887563012631548408736189"),
+    };
+
+    static ref G2_SVDW: SvdwConstants<fields::Fq2> = SvdwConstants {
+        z: fields::Fq2::one(),
+        c1: fields::Fq2::new(
+            fq("19485874751759354771024239261021720505790618469301721065564631296452457478374"),
+            fq("266929791119991161246907387137283842545076965332900288569378510910307636690"),
+        ),
+        c2: fields::Fq2::new(
+            fq("10944121435919637611123202872628637544348155578648911831344518947322613104291"),
+            fields::Fq::zero(),
+        ),
+        c3: fields::Fq2::new(
+            fq("18992192239972082890849143911285057164064277369389217330423471574879236301292"),
+            fq("21819008332247140148575583693947636719449476128975323941588917397607662637108"),
+        ),
+        c4: fields::Fq2::new(
+            fq("10499238450719652342378357227399831140106360636427411350395554762472100376473"),
+            fq("6940174569119770192419592065569379906172001098655407502803841283667998553941"),
+        ),
+    };
+}
+
+/// `expand_message_xmd` (RFC 9380, section 5.3.1) with SHA-256, filling
+/// `out`. Domain separation tags longer than 255 bytes are hashed first.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], out: &mut [u8]) -> Result<(), Error> {
+    let len = out.len();
+    let mut chunks = out.chunks_mut(32);
+    xmd_blocks(msg, dst, len, |b| {
+        if let Some(chunk) = chunks.next() {
+            chunk.copy_from_slice(&b[..chunk.len()]);
+        }
+    })
+}
+
+/// Runs `expand_message_xmd` for `len` bytes of output, passing each
+/// 32-byte block to `f` in order; the last block may extend past `len`.
+fn xmd_blocks<F: FnMut(&[u8; 32])>(
+    msg: &[u8],
+    dst: &[u8],
+    len: usize,
+    mut f: F,
+) -> Result<(), Error> {
+    let ell = len.div_ceil(32);
+    if len == 0 || ell > 255 {
+        return Err(Error::OutputLength);
+    }
+
+    let hashed_dst: [u8; 32];
+    let dst = if dst.len() > 255 {
+        let mut h = Sha256::new();
+        h.update(b"H2C-OVERSIZE-DST-");
+        h.update(dst);
+        hashed_dst = h.finalize().into();
+        &hashed_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let mut h = Sha256::new();
+    h.update([0u8; 64]);
+    h.update(msg);
+    h.update((len as u16).to_be_bytes());
+    h.update([0u8]);
+    h.update(dst);
+    h.update(dst_len);
+    let b_0: [u8; 32] = h.finalize().into();
+
+    let mut b_i = [0u8; 32];
+    for i in 0..ell {
+        let mut h = Sha256::new();
+        for (x, y) in b_i.iter_mut().zip(b_0.iter()) {
+            *x ^= y;
+        }
+        h.update(b_i);
+        h.update([i as u8 + 1]);
+        h.update(dst);
+        h.update(dst_len);
+        b_i = h.finalize().into();
+        f(&b_i);
+    }
+    Ok(())
+}
+
+/// `L = 48` bytes per base field element: `ceil((254 + 128) / 8)` for
+/// 128-bit security, as in the BN254 suites of other libraries.
+const L: usize = 48;
+
+fn fq_from_wide(bytes: &[u8]) -> fields::Fq {
+    let mut buf = [0u8; 64];
+    buf[64 - L..].copy_from_slice(bytes);
+    fields::Fq::interpret(&buf)
+}
+
+/// `hash_to_field` (RFC 9380, section 5.2) producing two Fq elements.
+pub fn hash_to_field_fq(msg: &[u8], dst: &[u8]) -> [Fq; 2] {
+    let mut bytes = [0u8; 2 * L];
+    expand_message_xmd(msg, dst, &mut bytes).expect("96 bytes is a valid length; qed");
+    [Fq(fq_from_wide(&bytes[..L])), Fq(fq_from_wide(&bytes[L..]))]
+}
+
+/// `hash_to_field` (RFC 9380, section 5.2) into the scalar field, filling
+/// `out` with independent elements using `L = 48` bytes each. Fails if
+/// `out` is empty or holds more than 170 elements.
+pub fn hash_to_field_fr(msg: &[u8], dst: &[u8], out: &mut [Fr]) -> Result<(), Error> {
+    let len = out.len() * L;
+    let mut wide = [0u8; 64];
+    let mut filled = 0;
+    let mut elements = out.iter_mut();
+    xmd_blocks(msg, dst, len, |block| {
+        for &b in block.iter() {
+            wide[64 - L + filled] = b;
+            filled += 1;
+            if filled == L {
+                if let Some(e) = elements.next() {
+                    *e = Fr::from_bytes_wide(&wide);
+                }
+                filled = 0;
+            }
+        }
+    })
+}
+
+/// `hash_to_field` (RFC 9380, section 5.2) producing two Fq2 elements.
+pub fn hash_to_field_fq2(msg: &[u8], dst: &[u8]) -> [Fq2; 2] {
+    let mut bytes = [0u8; 4 * L];
+    expand_message_xmd(msg, dst, &mut bytes).expect("192 bytes is a valid length; qed");
+    let element = |b: &[u8]| {
+        Fq2(fields::Fq2::new(
+            fq_from_wide(&b[..L]),
+            fq_from_wide(&b[L..]),
+        ))
+    };
+    [element(&bytes[..2 * L]), element(&bytes[2 * L..])]
+}
+
+/// Field operations the SvdW map needs beyond `FieldElement`.
+trait SvdwField: FieldElement {
+    fn is_square(&self) -> bool;
+    fn sgn0(&self) -> bool;
+    fn sqrt(&self) -> Option<Self>;
+}
+
+fn fq_is_odd(x: &fields::Fq) -> bool {
+    U256::from(*x).0[0] & 1 == 1
+}
+
+impl SvdwField for fields::Fq {
+    fn is_square(&self) -> bool {
+        self.is_zero() || self.pow_p_minus_1_div_2() == fields::Fq::one()
+    }
+
+    fn sgn0(&self) -> bool {
+        fq_is_odd(self)
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        fields::Fq::sqrt(self)
+    }
+}
+
+impl SvdwField for fields::Fq2 {
+    fn is_square(&self) -> bool {
+        self.is_quadratic_residue()
+    }
+
+    fn sgn0(&self) -> bool {
+        fq_is_odd(self.real()) || (self.real().is_zero() && fq_is_odd(self.imaginary()))
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        fields::Fq2::sqrt(self)
+    }
+}
+
+/// `map_to_curve_svdw` (RFC 9380, section 6.6.1) on `y^2 = x^3 + b`. All
+/// three candidate x coordinates and both square tests are computed for
+/// every input, so the sequence of field operations does not depend on `u`.
+fn map_to_curve_svdw<F: SvdwField>(u: F, b: F, c: &SvdwConstants<F>) -> (F, F) {
+    let g = |x: F| x.squared() * x + b;
+
+    let tv1 = u.squared() * c.c1;
+    let tv2 = F::one() + tv1;
+    let tv1 = F::one() - tv1;
+    let tv3 = (tv1 * tv2).inverse().unwrap_or_else(F::zero);
+    let tv4 = u * tv1 * tv3 * c.c3;
+
+    let x1 = c.c2 - tv4;
+    let x2 = c.c2 + tv4;
+    let x3 = (tv2.squared() * tv3).squared() * c.c4 + c.z;
+    let e1 = g(x1).is_square();
+    let e2 = g(x2).is_square();
+    let x = if e1 {
+        x1
+    } else if e2 {
+        x2
+    } else {
+        x3
+    };
+
+    let y = g(x)
+        .sqrt()
+        .expect("one of the three candidates is always on the curve; qed");
+    let y = if u.sgn0() == y.sgn0() { y } else { -y };
+    (x, y)
+}
+
+/// The SvdW constants used by `map_to_curve_g1`.
+pub fn svdw_constants_g1() -> SvdwConstants<Fq> {
+    let c = &*G1_SVDW;
+    SvdwConstants {
+        z: Fq(c.z),
+        c1: Fq(c.c1),
+        c2: Fq(c.c2),
+        c3: Fq(c.c3),
+        c4: Fq(c.c4),
+    }
+}
+
+/// Maps a field element to G1 with the SvdW map. G1 has cofactor one, so
+/// no clearing is needed.
+pub fn map_to_curve_g1(u: Fq) -> G1 {
+    let (x, y) = map_to_curve_svdw(u.0, G1::b().0, &G1_SVDW);
+    G1::new(Fq(x), Fq(y), Fq::one())
+}
+
+/// `hash_to_curve` (RFC 9380, section 3) for the suite
+/// `BN254G1_XMD:SHA-256_SVDW_RO_`.
+pub fn hash_to_curve_g1(msg: &[u8], dst: &[u8]) -> G1 {
+    let u = hash_to_field_fq(msg, dst);
+    map_to_curve_g1(u[0]) + map_to_curve_g1(u[1])
+}
+
+/// The SvdW constants used by `map_to_curve_g2`.
+pub fn svdw_constants_g2() -> SvdwConstants<Fq2> {
+    let c = &*G2_SVDW;
+    SvdwConstants {
+        z: Fq2(c.z),
+        c1: Fq2(c.c1),
+        c2: Fq2(c.c2),
+        c3: Fq2(c.c3),
+        c4: Fq2(c.c4),
+    }
+}
+
+/// Maps a field element to a point on the twist curve with the SvdW map.
+/// The result is generally not in the prime-order subgroup; pass it
+/// through `clear_cofactor_g2` before using it as a G2 element.
+pub fn map_to_curve_g2(u: Fq2) -> G2 {
+    let (x, y) = map_to_curve_svdw(u.0, G2::b().0, &G2_SVDW);
+    G2::new(Fq2(x), Fq2(y), Fq2::one())
+}
+
+/// Multiplies a twist point by the cofactor `2p - r`, landing in G2.
+pub fn clear_cofactor_g2(p: G2) -> G2 {
+    p.mul_by_cofactor()
+}
+
+/// `hash_to_curve` (RFC 9380, section 3) into G2 with the SvdW map,
+/// clearing the cofactor by multiplication with `2p - r`. Other libraries
+/// may clear the cofactor with a different multiple of it and then produce
+/// different points.
+pub fn hash_to_curve_g2(msg: &[u8], dst: &[u8]) -> G2 {
+    let u = hash_to_field_fq2(msg, dst);
+    clear_cofactor_g2(map_to_curve_g2(u[0]) + map_to_curve_g2(u[1]))
+}
+
+#[test]
+fn mul_by_cofactor() {
+    use crate::Group;
+
+    // 2p - r, the order of the twist divided by r
+    let cofactor = U256::from([
+        0x345f2299c0f9fa8d,
+        0x06ceecda572a2489,
+        0xb85045b68181585e,
+        0x30644e72e131a029,
+    ]);
+    for i in 1..4u64 {
+        let p = map_to_curve_g2(Fq2::new(Fq::from(i), Fq::from(7u64)));
+        assert!(!p.0.is_in_subgroup());
+        let q = p.mul_by_cofactor();
+        assert_eq!(q, G2(p.0.mul_u256(&cofactor)));
+        assert!(q.0.is_in_subgroup());
+        assert_eq!(q.double(), q + q);
+    }
+    assert_eq!(G1::one().double(), G1::one() * Fr::from(2u64));
+    assert!(G2::zero().mul_by_cofactor().is_zero());
+}
+
+#[test]
+fn hash_to_g2_vectors() {
+    // RFC 9380, appendix K.1
+    let mut out = [0u8; 32];
+    expand_message_xmd(b"", b"QUUX-V01-CS02-with-expander-SHA256-128", &mut out).unwrap();
+    let mut expected = [0u8; 32];
+    crate::ethereum::ut::hex2bin(
+        "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+        &mut expected,
+    );
+    assert_eq!(out, expected);
+
+    // computed with an independent Python implementation of the map
+    let fq2 = |a: &str, b: &str| Fq2::new(Fq::from_str(a).unwrap(), Fq::from_str(b).unwrap());
+    let u = hash_to_field_fq2(b"abc", b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_");
+    assert_eq!(
+        u[0],
+        fq2(
+            "15963713818282906360305918686195491545577210390832157279818305179904408824931",
+            "2166278439352519416731010325104738631510195416620895094682522641528929475020"
+        )
+    );
+    let q = crate::AffineG2::from_jacobian(map_to_curve_g2(u[0])).unwrap();
+    assert_eq!(
+        q.x(),
+        fq2(
+            "16872093352184426853297847012752141646605261411290781565485515569233955899058",
+            "20482288690411193526247554560661659739533735966007371008469181348051437821826"
+        )
+    );
+    assert_eq!(
+        q.y(),
+        fq2(
+            "427035866446275812154335387235552457760650543923113579505536211797911740485",
+            "14849552243024588631071292176876897701191437999604860450422231174965236442203"
+        )
+    );
+
+    let p = crate::AffineG2::from_jacobian(hash_to_curve_g2(b"abc", b"dst")).unwrap();
+    assert!(crate::AffineG2::new(p.x(), p.y()).is_ok());
+}
+
+#[test]
+fn hash_to_g1_vectors() {
+    // BN254G1_XMD:SHA-256_SVDW_RO_ vectors shared with gnark-crypto
+    let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+    let p = crate::AffineG1::from_jacobian(hash_to_curve_g1(b"", dst)).unwrap();
+    assert_eq!(
+        p.x(),
+        Fq::from_str(
+            "4790658965958450548702669593570794336562317867247372723806336874591549759110"
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        p.y(),
+        Fq::from_str(
+            "1163238807669877429342450210709044731909255047583162173012265677391336920021"
+        )
+        .unwrap()
+    );
+    let p = crate::AffineG1::from_jacobian(hash_to_curve_g1(b"abc", dst)).unwrap();
+    assert_eq!(
+        p.x(),
+        Fq::from_str(
+            "16267524812466668166267883771992486438338357688076900798565538061554532963281"
+        )
+        .unwrap()
+    );
+}
@@ -0,0 +1,217 @@
+//! Building blocks for inner-product arguments (Bulletproofs and IPA
+//! polynomial commitments) over G1: nothing-up-my-sleeve generator vectors,
+//! vector Pedersen commitments, and the folding steps of the argument.
+//!
+//! A round of the argument halves vectors `a`, `b`, `G` and `H` with a
+//! challenge `x`: the prover sends `cross_terms`, then folds `a` and `H`
+//! by `(x, x^-1)` and `b` and `G` by `(x^-1, x)`. A verifier need not fold
+//! the generators round by round; `fold_coefficients` gives the weight of
+//! each original generator in the final one, for a single MSM.
+//!
+//! The commitments and cross terms take the prover's secret vectors and
+//! multiply them in constant time, one term at a time; only the challenge
+//! folds and a verifier's final MSM are variable time.
+
+use crate::hash_to_curve::hash_to_curve_g1;
+use crate::{AffineG1, Fr, Group, G1};
+use sha2::{Digest, Sha256};
+
+/// Domain separation tag of `generator`.
+pub const GENERATOR_DST: &[u8] = b"BN254G1_XMD:SHA-256_SVDW_RO_IPAGenerators";
+
+/// Points folded per batch normalization in `fold_generators`.
+const CHUNK: usize = 64;
+
+/// Generator `index` of the family `label`: `hash_to_curve_g1` of
+/// `SHA-256(label) || index` with the index as big-endian u64, so nobody
+/// knows a discrete logarithm relation between any two generators.
+pub fn generator(label: &[u8], index: u64) -> AffineG1 {
+    let mut msg = [0u8; 40];
+    msg[..32].copy_from_slice(&Sha256::digest(label));
+    msg[32..].copy_from_slice(&index.to_be_bytes());
+    AffineG1::from_jacobian(hash_to_curve_g1(&msg, GENERATOR_DST))
+        .expect("hash_to_curve reaches the identity with negligible probability; qed")
+}
+
+/// Generators with independent discrete logarithms for committing to
+/// vectors of up to `g.len()` scalars.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Generators<'a> {
+    pub g: &'a [AffineG1],
+    pub h: &'a [AffineG1],
+    /// Carries the inner product in `commit_inner_product`.
+    pub u: AffineG1,
+    /// Carries the blinding factor.
+    pub blinding: AffineG1,
+}
+
+impl<'a> Generators<'a> {
+    /// Fills `g` and `h` from the family `label` and returns them with
+    /// `u` and `blinding` of the same family. `g[i]` and `h[i]` are
+    /// generators `2i` and `2i + 1`, and `u` and `blinding` the last two,
+    /// so longer vectors extend shorter ones.
+    pub fn derive(label: &[u8], g: &'a mut [AffineG1], h: &'a mut [AffineG1]) -> Self {
+        for (i, p) in g.iter_mut().enumerate() {
+            *p = generator(label, 2 * i as u64);
+        }
+        for (i, p) in h.iter_mut().enumerate() {
+            *p = generator(label, 2 * i as u64 + 1);
+        }
+        Generators {
+            g,
+            h,
+            u: generator(label, u64::MAX - 1),
+            blinding: generator(label, u64::MAX),
+        }
+    }
+
+    /// The vector Pedersen commitment `<a, g> + blinding * self.blinding`.
+    /// Panics if `a` is longer than `g`.
+    pub fn commit(&self, a: &[Fr], blinding: Fr) -> G1 {
+        assert!(a.len() <= self.g.len(), "too few generators");
+        msm_ct(self.g, a) + G1::from(self.blinding).mul_ct(blinding)
+    }
+
+    /// `<a, g> + <b, h> + <a, b> u + blinding * self.blinding`, the
+    /// statement of an inner-product argument. Panics if `a` and `b`
+    /// differ in length or are longer than the generators.
+    pub fn commit_inner_product(&self, a: &[Fr], b: &[Fr], blinding: Fr) -> G1 {
+        assert!(b.len() <= self.h.len(), "too few generators");
+        self.commit(a, blinding) + msm_ct(self.h, b) + G1::from(self.u).mul_ct(inner_product(a, b))
+    }
+}
+
+/// `<scalars, points>` with a constant-time multiplication per term, for
+/// secret scalars. Extra points are ignored.
+fn msm_ct(points: &[AffineG1], scalars: &[Fr]) -> G1 {
+    points
+        .iter()
+        .zip(scalars)
+        .fold(G1::zero(), |acc, (p, s)| acc + G1::from(*p).mul_ct(*s))
+}
+
+/// `<a, b>`. Panics if the lengths differ.
+pub fn inner_product(a: &[Fr], b: &[Fr]) -> Fr {
+    assert_eq!(a.len(), b.len(), "vectors differ in length");
+    a.iter()
+        .zip(b)
+        .fold(Fr::zero(), |acc, (a, b)| acc + *a * *b)
+}
+
+/// The cross terms `(L, R)` of a round on `a` and `b`, of even length `n`,
+/// with the first `n` generators: with `lo`/`hi` the halves,
+/// `L = <a_lo, g_hi> + <b_hi, h_lo> + <a_lo, b_hi> u` and
+/// `R = <a_hi, g_lo> + <b_lo, h_hi> + <a_hi, b_lo> u`.
+pub fn cross_terms(generators: &Generators, a: &[Fr], b: &[Fr]) -> (G1, G1) {
+    let n = a.len();
+    assert!(n.is_multiple_of(2), "odd vector length");
+    assert!(b.len() == n, "vectors differ in length");
+    let half = n / 2;
+    let (g, h, u) = (generators.g, generators.h, G1::from(generators.u));
+    let (a_lo, a_hi) = a.split_at(half);
+    let (b_lo, b_hi) = b.split_at(half);
+    let l =
+        msm_ct(&g[half..n], a_lo) + msm_ct(&h[..half], b_hi) + u.mul_ct(inner_product(a_lo, b_hi));
+    let r =
+        msm_ct(&g[..half], a_hi) + msm_ct(&h[half..n], b_lo) + u.mul_ct(inner_product(a_hi, b_lo));
+    (l, r)
+}
+
+/// Folds `v` of even length in place to `left * v_lo + right * v_hi` and
+/// returns the folded half.
+pub fn fold_scalars(v: &mut [Fr], left: Fr, right: Fr) -> &mut [Fr] {
+    assert!(v.len().is_multiple_of(2), "odd vector length");
+    let (lo, hi) = v.split_at_mut(v.len() / 2);
+    for (x, y) in lo.iter_mut().zip(hi.iter()) {
+        *x = left * *x + right * *y;
+    }
+    lo
+}
+
+/// `fold_scalars` for generators, normalizing the folded points in
+/// batches. Folding independent generators cannot reach the identity
+/// without a discrete logarithm relation between them; panics if it does.
+pub fn fold_generators(v: &mut [AffineG1], left: Fr, right: Fr) -> &mut [AffineG1] {
+    assert!(v.len().is_multiple_of(2), "odd vector length");
+    let (lo, hi) = v.split_at_mut(v.len() / 2);
+    for (lo, hi) in lo.chunks_mut(CHUNK).zip(hi.chunks(CHUNK)) {
+        let mut folded = [G1::zero(); CHUNK];
+        for ((f, x), y) in folded.iter_mut().zip(lo.iter()).zip(hi) {
+            *f = G1::from(*x) * left + G1::from(*y) * right;
+        }
+        let mut affine = [None; CHUNK];
+        G1::batch_normalize_into(&folded[..lo.len()], &mut affine);
+        for (x, p) in lo.iter_mut().zip(affine.iter()) {
+            *x = p.expect("folded generators are independent; qed");
+        }
+    }
+    lo
+}
+
+/// The weight of each of `out.len() = 2^k` points in the single point left
+/// after `k` rounds of `fold_generators`, round `j` folding by
+/// `factors[j] = (left, right)`: the product over the rounds of `right`
+/// where the bit of the index halved away in that round is set, and of
+/// `left` where it is clear.
+pub fn fold_coefficients(factors: &[(Fr, Fr)], out: &mut [Fr]) {
+    assert!(
+        out.len() == 1 << factors.len(),
+        "output length is not 2^rounds"
+    );
+    out[0] = Fr::one();
+    let mut size = 1;
+    // the last round halves away the lowest bit
+    for &(left, right) in factors.iter().rev() {
+        for i in 0..size {
+            out[i + size] = out[i] * right;
+            out[i] *= left;
+        }
+        size *= 2;
+    }
+}
+
+#[test]
+fn inner_product_argument() {
+    let fr = |i: u64| Fr::from(i);
+    let mut g_init = [AffineG1::generator(); 8];
+    let mut h_init = [AffineG1::generator(); 8];
+    let gens = Generators::derive(b"test", &mut g_init, &mut h_init);
+    assert_eq!(gens.g[1], generator(b"test", 2));
+    assert_ne!(gens.g[0], generator(b"other", 0));
+    let (mut g, mut h) = ([AffineG1::generator(); 8], [AffineG1::generator(); 8]);
+    g.copy_from_slice(gens.g);
+    h.copy_from_slice(gens.h);
+
+    let mut a = [fr(1), fr(2), fr(3), fr(4), fr(5), fr(6), fr(7), fr(8)];
+    let mut b = [fr(9), fr(1), fr(8), fr(2), fr(7), fr(3), fr(6), fr(4)];
+    let mut p = gens.commit_inner_product(&a, &b, Fr::zero());
+    assert_eq!(
+        gens.commit(&a, fr(3)) - G1::from(gens.blinding) * fr(3),
+        G1::msm(&g, &a)
+    );
+
+    // the prover halves everything three times
+    let challenges = [fr(11), fr(12), fr(13)];
+    let (mut a, mut b, mut g, mut h) = (&mut a[..], &mut b[..], &mut g[..], &mut h[..]);
+    let mut factors = [(Fr::zero(), Fr::zero()); 3];
+    for (x, f) in challenges.iter().zip(factors.iter_mut()) {
+        let round = Generators { g, h, ..gens };
+        let (l, r) = cross_terms(&round, a, b);
+        let x_inv = x.inverse().unwrap();
+        p = l * (*x * *x) + p + r * (x_inv * x_inv);
+        a = fold_scalars(a, *x, x_inv);
+        b = fold_scalars(b, x_inv, *x);
+        g = fold_generators(g, x_inv, *x);
+        h = fold_generators(h, *x, x_inv);
+        *f = (x_inv, *x);
+        let round = Generators { g, h, ..gens };
+        assert_eq!(p, round.commit_inner_product(a, b, Fr::zero()));
+    }
+
+    // the verifier rebuilds the final generators with one MSM each
+    let mut s = [Fr::zero(); 8];
+    fold_coefficients(&factors, &mut s);
+    assert_eq!(G1::from(g[0]), G1::msm(gens.g, &s));
+    s.reverse();
+    assert_eq!(G1::from(h[0]), G1::msm(gens.h, &s));
+}
@@ -0,0 +1,221 @@
+//! `defmt::Format` for the field, group and error types, for logging from
+//! targets too small for `core::fmt`. Field elements print as canonical
+//! big-endian hex, points as affine coordinates or `infinity`, which costs
+//! an inversion per Jacobian point, and errors as their `Display` messages.
+
+use crate::arith::{self, U256};
+use crate::ethereum::{self, ErrorKind};
+use crate::{
+    AffineG1, AffineG2, Component, CurveError, Field, FieldError, Fq, Fq2, Fr, GroupError,
+    HexError, ValidationError, G1, G2,
+};
+use defmt::{write, Format, Formatter};
+
+fn hex(f: Formatter, x: U256) {
+    let [lo, hi] = x.0;
+    write!(
+        f,
+        "0x{=u64:016x}{=u64:016x}{=u64:016x}{=u64:016x}",
+        (hi >> 64) as u64,
+        hi as u64,
+        (lo >> 64) as u64,
+        lo as u64
+    )
+}
+
+impl Format for Fr {
+    fn format(&self, f: Formatter) {
+        hex(f, self.into_u256())
+    }
+}
+
+impl Format for Fq {
+    fn format(&self, f: Formatter) {
+        hex(f, self.into_u256())
+    }
+}
+
+impl Format for Fq2 {
+    fn format(&self, f: Formatter) {
+        write!(f, "{} + {} * i", self.real(), self.imaginary())
+    }
+}
+
+impl Format for AffineG1 {
+    fn format(&self, f: Formatter) {
+        write!(f, "G1({}, {})", self.x(), self.y())
+    }
+}
+
+impl Format for AffineG2 {
+    fn format(&self, f: Formatter) {
+        write!(f, "G2({}, {})", self.x(), self.y())
+    }
+}
+
+impl Format for G1 {
+    fn format(&self, f: Formatter) {
+        match AffineG1::from_jacobian(*self) {
+            Some(p) => p.format(f),
+            None => write!(f, "G1(infinity)"),
+        }
+    }
+}
+
+impl Format for G2 {
+    fn format(&self, f: Formatter) {
+        match AffineG2::from_jacobian(*self) {
+            Some(p) => p.format(f),
+            None => write!(f, "G2(infinity)"),
+        }
+    }
+}
+
+impl Format for Field {
+    fn format(&self, f: Formatter) {
+        match self {
+            Field::Fr => write!(f, "Fr"),
+            Field::Fq => write!(f, "Fq"),
+            Field::Fq2 => write!(f, "Fq2"),
+            Field::Gt => write!(f, "Gt"),
+        }
+    }
+}
+
+impl Format for Component {
+    fn format(&self, f: Formatter) {
+        match self {
+            Component::X => write!(f, "x"),
+            Component::Y => write!(f, "y"),
+            Component::XReal => write!(f, "real part of x"),
+            Component::XImaginary => write!(f, "imaginary part of x"),
+            Component::YReal => write!(f, "real part of y"),
+            Component::YImaginary => write!(f, "imaginary part of y"),
+        }
+    }
+}
+
+impl Format for FieldError {
+    fn format(&self, f: Formatter) {
+        match *self {
+            FieldError::InputLength {
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} encoding must be {=usize} bytes, got {=usize}",
+                field, expected, actual
+            ),
+            FieldError::OutputLength {
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} output buffer must be {=usize} bytes, got {=usize}",
+                field, expected, actual
+            ),
+            FieldError::InvalidU512Encoding => write!(f, "invalid 512-bit integer encoding"),
+            FieldError::NotMember => write!(f, "value is not below the field modulus"),
+            FieldError::CoefficientNotMember { imaginary, offset } => write!(
+                f,
+                "{=str} coefficient at byte {=usize} is not below the field modulus",
+                if imaginary { "imaginary" } else { "real" },
+                offset
+            ),
+        }
+    }
+}
+
+impl Format for CurveError {
+    fn format(&self, f: Formatter) {
+        match self {
+            CurveError::InvalidEncoding => write!(f, "invalid point encoding"),
+            CurveError::NotMember => write!(f, "point is not in the group"),
+            CurveError::Field(e) => write!(f, "invalid coordinate: {}", e),
+            CurveError::ToAffineConversion => {
+                write!(f, "point at infinity has no affine coordinates")
+            }
+            CurveError::InvalidLength { expected, actual } => write!(
+                f,
+                "point encoding must be {=usize} bytes, got {=usize}",
+                expected, actual
+            ),
+            CurveError::Coordinate {
+                component,
+                offset,
+                error,
+            } => write!(
+                f,
+                "invalid {} at byte {=usize}: {}",
+                component, offset, error
+            ),
+        }
+    }
+}
+
+impl Format for GroupError {
+    fn format(&self, f: Formatter) {
+        match self {
+            GroupError::NotOnCurve => write!(f, "point is not on the curve"),
+            GroupError::NotInSubgroup => write!(f, "point is not in the prime-order subgroup"),
+        }
+    }
+}
+
+impl Format for arith::Error {
+    fn format(&self, f: Formatter) {
+        match self {
+            arith::Error::InvalidLength { expected, actual } => write!(
+                f,
+                "integer encoding must be {=usize} bytes, got {=usize}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl Format for ValidationError {
+    fn format(&self, f: Formatter) {
+        match self {
+            ValidationError::CoordinateNotInField { component } => {
+                write!(f, "{} is not below the field modulus", component)
+            }
+            ValidationError::ZNotInField => write!(f, "z is not below the field modulus"),
+            ValidationError::Infinity => write!(f, "point is at infinity"),
+            ValidationError::NotOnCurve => write!(f, "point is not on the curve"),
+            ValidationError::NotInSubgroup => {
+                write!(f, "point is not in the prime-order subgroup")
+            }
+        }
+    }
+}
+
+impl Format for HexError {
+    fn format(&self, f: Formatter) {
+        match self {
+            HexError::Length { expected, actual } => write!(
+                f,
+                "hex encoding must be {=usize} digits, got {=usize}",
+                expected, actual
+            ),
+            HexError::Character { offset } => write!(f, "invalid hex digit at {=usize}", offset),
+            HexError::Decimal => write!(f, "invalid decimal field element"),
+            HexError::Field(e) => e.format(f),
+            HexError::Curve(e) => e.format(f),
+        }
+    }
+}
+
+impl Format for ErrorKind {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=str}", self.geth_message())
+    }
+}
+
+impl Format for ethereum::Error {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=str} at byte {=usize}", self.message, self.offset)
+    }
+}
@@ -0,0 +1,176 @@
+use crate::arith::U256;
+use crate::{AffineG1, Fr, Group, G1};
+use core::ops::{Add, Sub};
+
+/// Pedersen commitment generators. `h` must have an unknown discrete
+/// logarithm with respect to `g` for the commitments to be binding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Params {
+    pub g: AffineG1,
+    pub h: AffineG1,
+}
+
+/// The secrets behind a commitment `value * g + blinding * h`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Opening {
+    pub value: Fr,
+    pub blinding: Fr,
+}
+
+impl Params {
+    pub fn new(g: AffineG1, h: AffineG1) -> Self {
+        Params { g, h }
+    }
+
+    /// Computes `value * g + blinding * h`, multiplying both secrets in
+    /// constant time.
+    pub fn commit(&self, value: Fr, blinding: Fr) -> G1 {
+        G1::from(self.g).mul_ct(value) + G1::from(self.h).mul_ct(blinding)
+    }
+
+    /// Checks that `opening` opens `commitment`.
+    pub fn verify_open(&self, commitment: G1, opening: &Opening) -> bool {
+        self.commit(opening.value, opening.blinding) == commitment
+    }
+
+    /// Re-blinds a commitment to `commitment + delta * h`. The result
+    /// commits to the same value and opens with `Opening::rerandomize(delta)`.
+    pub fn rerandomize(&self, commitment: G1, delta: Fr) -> G1 {
+        rerandomize(commitment, self.h, delta)
+    }
+}
+
+impl Opening {
+    pub fn new(value: Fr, blinding: Fr) -> Self {
+        Opening { value, blinding }
+    }
+
+    /// The opening of a commitment rerandomized by `delta`.
+    pub fn rerandomize(&self, delta: Fr) -> Self {
+        Opening {
+            value: self.value,
+            blinding: self.blinding + delta,
+        }
+    }
+}
+
+/// Commitments are additively homomorphic: `commit(a) + commit(b)` opens
+/// with `a + b`.
+impl Add for Opening {
+    type Output = Opening;
+
+    fn add(self, other: Opening) -> Opening {
+        Opening {
+            value: self.value + other.value,
+            blinding: self.blinding + other.blinding,
+        }
+    }
+}
+
+impl Sub for Opening {
+    type Output = Opening;
+
+    fn sub(self, other: Opening) -> Opening {
+        Opening {
+            value: self.value - other.value,
+            blinding: self.blinding - other.blinding,
+        }
+    }
+}
+
+/// Shifts an arbitrary point by `delta * h`, i.e. `p + delta * h`, with
+/// `delta` multiplied in constant time.
+pub fn rerandomize(p: G1, h: AffineG1, delta: Fr) -> G1 {
+    p + G1::from(h).mul_ct(delta)
+}
+
+/// Domain separation tag of the Pedersen hash generators.
+pub const HASH_DST: &[u8] = b"BN254G1_XMD:SHA-256_SVDW_RO_PedersenHash";
+
+/// Four-bit windows per generator.
+const WINDOWS: usize = 50;
+
+/// The generator of segment `index`: `hash_to_curve_g1` of
+/// `"PedersenGenerator_"` followed by `index` zero-padded to 32 decimal
+/// digits.
+pub fn hash_generator(index: usize) -> G1 {
+    let mut msg = *b"PedersenGenerator_00000000000000000000000000000000";
+    let mut i = index;
+    for d in msg.iter_mut().rev() {
+        if i == 0 {
+            break;
+        }
+        *d = b'0' + (i % 10) as u8;
+        i /= 10;
+    }
+    crate::hash_to_curve::hash_to_curve_g1(&msg, HASH_DST)
+}
+
+/// A windowed Pedersen hash onto G1. The bits of `data`, least significant
+/// first within each byte, are cut into segments of 50 windows of 4 bits
+/// `b0..b3`; window `j` of a segment contributes
+/// `(1 + b0 + 2*b1 + 4*b2) * (-1)^b3 * 2^(5*j)` to the segment's scalar,
+/// which multiplies the segment's `hash_generator`. Missing bits of the
+/// last window are zero.
+///
+/// This is not circomlib's Pedersen hash, which works over Baby Jubjub
+/// with Blake-256 generators; digests from the two are unrelated.
+pub fn hash(data: &[u8]) -> G1 {
+    let bits = data.len() * 8;
+    let bit = |i: usize| i < bits && data[i / 8] >> (i % 8) & 1 == 1;
+    let shift = Fr::new(U256::from(32)).expect("32 is below r; qed");
+    let mut acc = G1::zero();
+    for (segment, start) in (0..bits).step_by(4 * WINDOWS).enumerate() {
+        let mut scalar = Fr::zero();
+        let mut factor = Fr::one();
+        for w in (start..bits).step_by(4).take(WINDOWS) {
+            let d = 1 + bit(w) as u64 + 2 * bit(w + 1) as u64 + 4 * bit(w + 2) as u64;
+            let mut digit = Fr::new(U256::from(d)).expect("digits are below r; qed");
+            if bit(w + 3) {
+                digit = -digit;
+            }
+            scalar += digit * factor;
+            factor *= shift;
+        }
+        acc += hash_generator(segment) * scalar;
+    }
+    acc
+}
+
+#[test]
+fn homomorphic_open() {
+    let params = Params::new(
+        AffineG1::generator(),
+        AffineG1::from_jacobian(hash_generator(0)).unwrap(),
+    );
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let a = Opening::new(fr("10"), fr("12345"));
+    let b = Opening::new(fr("32"), fr("67890"));
+    let ca = params.commit(a.value, a.blinding);
+    let cb = params.commit(b.value, b.blinding);
+    assert!(params.verify_open(ca, &a));
+    assert!(!params.verify_open(ca, &b));
+    assert!(params.verify_open(ca + cb, &(a + b)));
+    assert!(params.verify_open(ca - cb, &(a - b)));
+
+    let delta = fr("5");
+    assert!(params.verify_open(params.rerandomize(ca, delta), &a.rerandomize(delta)));
+}
+
+#[test]
+fn windowed_hash() {
+    let g0 = hash_generator(0);
+    assert_ne!(g0, hash_generator(1));
+    assert_eq!(hash(&[]), G1::zero());
+    // windows 0b0001 and 0b0000: 2 + 1 * 32
+    assert_eq!(hash(&[0x01]), g0 * Fr::from_str("34").unwrap());
+    // 0b1000 negates the window
+    assert_eq!(hash(&[0x08]), -g0 + g0 * Fr::from_str("32").unwrap());
+    // 200 bits fill the first segment
+    let mut data = [0u8; 26];
+    data[25] = 1;
+    assert_eq!(
+        hash(&data) - hash(&data[..25]),
+        hash_generator(1) * Fr::from_str("34").unwrap()
+    );
+}
@@ -0,0 +1,294 @@
+//! Sigma-protocol proofs about exponents in G1, made non-interactive with a
+//! `Transcript`. In a prime-order group a proof of exponentiation reduces to
+//! a proof of knowledge of the exponent, optionally tied to a Pedersen
+//! commitment of that exponent.
+
+use crate::pedersen;
+#[cfg(feature = "verify-twice")]
+use crate::redundant;
+use crate::transcript::Transcript;
+use crate::{AffineG1, Fr, Group, G1};
+
+/// Proof of knowledge of `x` such that `result = x * base`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PokeProof {
+    pub a: G1,
+    pub z: Fr,
+}
+
+/// Proof that `result = x * base` for the `x` committed to in
+/// `exponent_commitment = x * g + r * h`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PoeProof {
+    pub a: G1,
+    pub b: G1,
+    pub z: Fr,
+    pub s: Fr,
+}
+
+fn poke_challenge(transcript: &mut Transcript, base: &G1, result: &G1, a: &G1) -> Fr {
+    transcript.append_g1(b"poke-base", base);
+    transcript.append_g1(b"poke-result", result);
+    transcript.append_g1(b"poke-a", a);
+    transcript.challenge_scalar(b"poke-challenge")
+}
+
+fn poe_challenge(
+    transcript: &mut Transcript,
+    params: &pedersen::Params,
+    base: &G1,
+    result: &G1,
+    exponent_commitment: &G1,
+    proof_a: &G1,
+    proof_b: &G1,
+) -> Fr {
+    transcript.append_g1(b"poe-g", &params.g.into());
+    transcript.append_g1(b"poe-h", &params.h.into());
+    transcript.append_g1(b"poe-base", base);
+    transcript.append_g1(b"poe-result", result);
+    transcript.append_g1(b"poe-commitment", exponent_commitment);
+    transcript.append_g1(b"poe-a", proof_a);
+    transcript.append_g1(b"poe-b", proof_b);
+    transcript.challenge_scalar(b"poe-challenge")
+}
+
+/// Whether `sum(scalars[i] * points[i])` is the identity, with one MSM.
+/// Points at infinity contribute nothing and are dropped.
+fn msm_is_zero<const N: usize>(points: [G1; N], scalars: [Fr; N]) -> bool {
+    let mut affine = [None; N];
+    G1::batch_normalize_into(&points, &mut affine);
+    let mut bases = [AffineG1::generator(); N];
+    let mut terms = [Fr::zero(); N];
+    let mut n = 0;
+    for (p, s) in affine.iter().zip(scalars.iter()) {
+        if let Some(p) = p {
+            bases[n] = *p;
+            terms[n] = *s;
+            n += 1;
+        }
+    }
+    G1::msm(&bases[..n], &terms[..n]).is_zero()
+}
+
+/// Proves knowledge of `exponent` for `exponent * base`. `nonce` must be
+/// uniformly random and never reused. The exponent and nonce are
+/// multiplied in constant time.
+pub fn prove_poke(transcript: &mut Transcript, base: G1, exponent: Fr, nonce: Fr) -> PokeProof {
+    let result = base.mul_ct(exponent);
+    let a = base.mul_ct(nonce);
+    let c = poke_challenge(transcript, &base, &result, &a);
+    PokeProof {
+        a,
+        z: nonce + c * exponent,
+    }
+}
+
+/// Checks `z * base == a + c * result`.
+pub fn verify_poke(transcript: &mut Transcript, base: G1, result: G1, proof: &PokeProof) -> bool {
+    let c = poke_challenge(transcript, &base, &result, &proof.a);
+    if !msm_is_zero([base, result, proof.a], [proof.z, -c, -Fr::one()]) {
+        return false;
+    }
+    #[cfg(feature = "verify-twice")]
+    if !recheck_poke(base, result, c, proof) {
+        return false;
+    }
+    true
+}
+
+/// Proves that `exponent * base` uses the exponent opened by `opening`.
+/// Both nonces must be uniformly random and never reused. The opening and
+/// nonces are multiplied in constant time.
+pub fn prove_poe(
+    transcript: &mut Transcript,
+    params: &pedersen::Params,
+    base: G1,
+    opening: &pedersen::Opening,
+    nonces: (Fr, Fr),
+) -> PoeProof {
+    let result = base.mul_ct(opening.value);
+    let commitment = params.commit(opening.value, opening.blinding);
+    let a = base.mul_ct(nonces.0);
+    let b = params.commit(nonces.0, nonces.1);
+    let c = poe_challenge(transcript, params, &base, &result, &commitment, &a, &b);
+    PoeProof {
+        a,
+        b,
+        z: nonces.0 + c * opening.value,
+        s: nonces.1 + c * opening.blinding,
+    }
+}
+
+/// Checks `z * base == a + c * result` and
+/// `z * g + s * h == b + c * exponent_commitment` as one MSM, adding the
+/// second equation with a weight squeezed from a copy of the transcript.
+pub fn verify_poe(
+    transcript: &mut Transcript,
+    params: &pedersen::Params,
+    base: G1,
+    result: G1,
+    exponent_commitment: G1,
+    proof: &PoeProof,
+) -> bool {
+    let c = poe_challenge(
+        transcript,
+        params,
+        &base,
+        &result,
+        &exponent_commitment,
+        &proof.a,
+        &proof.b,
+    );
+    let w = transcript.clone().challenge_scalar(b"poe-weight");
+    let points = [
+        base,
+        result,
+        proof.a,
+        params.g.into(),
+        params.h.into(),
+        proof.b,
+        exponent_commitment,
+    ];
+    let scalars = [
+        proof.z,
+        -c,
+        -Fr::one(),
+        w * proof.z,
+        w * proof.s,
+        -w,
+        -(w * c),
+    ];
+    if w.is_zero() || !msm_is_zero(points, scalars) {
+        return false;
+    }
+    #[cfg(feature = "verify-twice")]
+    if !recheck_poe(params, base, result, exponent_commitment, c, proof) {
+        return false;
+    }
+    true
+}
+
+/// The PoKE equation again, scaled by a randomizer and computed with
+/// separate scalar multiplications.
+#[cfg(feature = "verify-twice")]
+fn recheck_poke(base: G1, result: G1, c: Fr, proof: &PokeProof) -> bool {
+    let rho = redundant::randomizer(b"poke", &[base, result, proof.a], &[]);
+    base * (proof.z * rho) - result * (c * rho) == proof.a * rho
+}
+
+/// `recheck_poke` for both PoE equations.
+#[cfg(feature = "verify-twice")]
+fn recheck_poe(
+    params: &pedersen::Params,
+    base: G1,
+    result: G1,
+    exponent_commitment: G1,
+    c: Fr,
+    proof: &PoeProof,
+) -> bool {
+    let points = [base, result, exponent_commitment, proof.a, proof.b];
+    let rho = redundant::randomizer(b"poe", &points, &[]);
+    base * (proof.z * rho) - result * (c * rho) == proof.a * rho
+        && params.commit(proof.z * rho, proof.s * rho) - exponent_commitment * (c * rho)
+            == proof.b * rho
+}
+
+#[test]
+fn poke_round_trip() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let base = G1::one() * fr("9");
+    let x = fr("123456789");
+    let proof = prove_poke(&mut Transcript::new(b"poke test"), base, x, fr("555"));
+    let verify = |base, result, proof: &PokeProof| {
+        verify_poke(&mut Transcript::new(b"poke test"), base, result, proof)
+    };
+    assert!(verify(base, base * x, &proof));
+    // wrong statement
+    assert!(!verify(base, base * (x + Fr::one()), &proof));
+    // wrong base
+    assert!(!verify(G1::one(), base * x, &proof));
+    // tampered proof
+    let tampered = PokeProof {
+        a: proof.a,
+        z: proof.z + Fr::one(),
+    };
+    assert!(!verify(base, base * x, &tampered));
+    let tampered = PokeProof {
+        a: proof.a + G1::one(),
+        z: proof.z,
+    };
+    assert!(!verify(base, base * x, &tampered));
+    // another transcript yields another challenge
+    assert!(!verify_poke(
+        &mut Transcript::new(b"other"),
+        base,
+        base * x,
+        &proof
+    ));
+}
+
+#[test]
+fn poe_round_trip() {
+    use crate::pedersen::hash_generator;
+
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let params = pedersen::Params::new(
+        AffineG1::generator(),
+        AffineG1::from_jacobian(hash_generator(0)).unwrap(),
+    );
+    let base = hash_generator(1);
+    let opening = pedersen::Opening::new(fr("31337"), fr("4242"));
+    let mut t = Transcript::new(b"poe test");
+    let proof = prove_poe(&mut t, &params, base, &opening, (fr("77"), fr("88")));
+    let commitment = params.commit(opening.value, opening.blinding);
+    let result = base * opening.value;
+    let verify = |base, result, commitment, proof: &PoeProof| {
+        let mut t = Transcript::new(b"poe test");
+        verify_poe(&mut t, &params, base, result, commitment, proof)
+    };
+    assert!(verify(base, result, commitment, &proof));
+    // wrong statement: another result, or a commitment to another value
+    assert!(!verify(base, result + base, commitment, &proof));
+    let other = params.commit(opening.value + Fr::one(), opening.blinding);
+    assert!(!verify(base, result, other, &proof));
+    // wrong base
+    assert!(!verify(G1::one(), result, commitment, &proof));
+    // tampered proof, each field in turn
+    let mut tampered = [proof; 4];
+    tampered[0].a += G1::one();
+    tampered[1].b += G1::one();
+    tampered[2].z += Fr::one();
+    tampered[3].s += Fr::one();
+    for t in &tampered {
+        assert!(!verify(base, result, commitment, t));
+    }
+}
+
+#[cfg(feature = "verify-twice")]
+#[test]
+fn second_check() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let base = G1::one() * fr("9");
+    let x = fr("123456789");
+    let result = base * x;
+    let proof = prove_poke(&mut Transcript::new(b"poke test"), base, x, fr("555"));
+
+    let calls = redundant::calls();
+    assert!(verify_poke(
+        &mut Transcript::new(b"poke test"),
+        base,
+        result,
+        &proof
+    ));
+    assert!(redundant::calls() > calls);
+
+    let c = poke_challenge(&mut Transcript::new(b"poke test"), &base, &result, &proof.a);
+    assert!(recheck_poke(base, result, c, &proof));
+    assert!(!recheck_poke(base, result + base, c, &proof));
+    assert!(!recheck_poke(base, result, c + Fr::one(), &proof));
+    let tampered = PokeProof {
+        a: proof.a,
+        z: proof.z + Fr::one(),
+    };
+    assert!(!recheck_poke(base, result, c, &tampered));
+}
@@ -0,0 +1,221 @@
+//! Dense univariate polynomials over Fr, for the polynomial layer of
+//! commitment schemes, PLONK-style verifiers and secret sharing.
+//!
+//! Coefficients are stored constant term first with trailing zeros
+//! trimmed, so the zero polynomial has no coefficients and two equal
+//! polynomials have equal representations. The coefficient slices are
+//! the ones `kzg` commits to.
+
+use crate::{kzg, Fr};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Polynomial {
+    coeffs: Vec<Fr>,
+}
+
+impl Polynomial {
+    pub fn new(mut coeffs: Vec<Fr>) -> Self {
+        while coeffs.last().is_some_and(Fr::is_zero) {
+            coeffs.pop();
+        }
+        Polynomial { coeffs }
+    }
+
+    pub fn from_coeffs(coeffs: &[Fr]) -> Self {
+        Self::new(coeffs.to_vec())
+    }
+
+    pub fn zero() -> Self {
+        Polynomial { coeffs: Vec::new() }
+    }
+
+    pub fn constant(c: Fr) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// The coefficients, constant term first, without trailing zeros.
+    pub fn coeffs(&self) -> &[Fr] {
+        &self.coeffs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn evaluate(&self, z: Fr) -> Fr {
+        kzg::evaluate(&self.coeffs, z)
+    }
+
+    pub fn mul_scalar(&self, c: Fr) -> Self {
+        Self::new(self.coeffs.iter().map(|a| *a * c).collect())
+    }
+
+    /// `(X - x_0) * ... * (X - x_{n-1})`, which vanishes exactly on
+    /// `points`.
+    pub fn vanishing(points: &[Fr]) -> Self {
+        let mut coeffs = vec![Fr::zero(); points.len() + 1];
+        coeffs[0] = Fr::one();
+        for (i, x) in points.iter().enumerate() {
+            // multiply the first i + 1 coefficients by (X - x)
+            for j in (0..=i + 1).rev() {
+                let shifted = if j > 0 { coeffs[j - 1] } else { Fr::zero() };
+                coeffs[j] = shifted - *x * coeffs[j];
+            }
+        }
+        Polynomial { coeffs }
+    }
+
+    /// Quotient and remainder of the division by `divisor`, with the
+    /// remainder of lower degree than the divisor. `None` if the divisor
+    /// is zero.
+    pub fn div_rem(&self, divisor: &Polynomial) -> Option<(Polynomial, Polynomial)> {
+        let lead = divisor.coeffs.last()?;
+        let lead_inv = lead.inverse().expect("leading coefficient is nonzero; qed");
+        let d = divisor.coeffs.len();
+        if self.coeffs.len() < d {
+            return Some((Polynomial::zero(), self.clone()));
+        }
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![Fr::zero(); rem.len() - d + 1];
+        for i in (0..quot.len()).rev() {
+            let q = rem[i + d - 1] * lead_inv;
+            quot[i] = q;
+            for (r, c) in rem[i..i + d].iter_mut().zip(divisor.coeffs.iter()) {
+                *r -= q * *c;
+            }
+        }
+        rem.truncate(d - 1);
+        Some((Polynomial::new(quot), Polynomial::new(rem)))
+    }
+
+    /// The polynomial of degree below `xs.len()` through every
+    /// `(xs[i], ys[i])`, by Lagrange interpolation. `None` if the slices
+    /// differ in length or two points coincide.
+    pub fn interpolate(xs: &[Fr], ys: &[Fr]) -> Option<Self> {
+        if xs.len() != ys.len() {
+            return None;
+        }
+        let vanishing = Polynomial::vanishing(xs);
+        // w_i = prod_{j != i} (x_i - x_j), the derivative of the vanishing
+        // polynomial at x_i
+        let mut weights: Vec<Fr> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, xi)| {
+                xs.iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .fold(Fr::one(), |acc, (_, xj)| acc * (*xi - *xj))
+            })
+            .collect();
+        if weights.iter().any(Fr::is_zero) {
+            return None;
+        }
+        Fr::batch_inverse(&mut weights);
+
+        let mut coeffs = vec![Fr::zero(); xs.len()];
+        for ((x, y), w) in xs.iter().zip(ys).zip(weights) {
+            // vanishing / (X - x) by synthetic division, scaled by y / w
+            let scale = *y * w;
+            let mut q = Fr::zero();
+            for (c, v) in coeffs.iter_mut().zip(vanishing.coeffs[1..].iter()).rev() {
+                q = *v + *x * q;
+                *c += scale * q;
+            }
+        }
+        Some(Polynomial::new(coeffs))
+    }
+}
+
+impl<'a> Add for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: &'a Polynomial) -> Polynomial {
+        let (long, short) = if self.coeffs.len() >= other.coeffs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        for (c, s) in coeffs.iter_mut().zip(short.coeffs.iter()) {
+            *c += *s;
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        Polynomial {
+            coeffs: self.coeffs.iter().map(|c| -*c).collect(),
+        }
+    }
+}
+
+impl<'a> Sub for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: &'a Polynomial) -> Polynomial {
+        self + &-other
+    }
+}
+
+/// Schoolbook multiplication.
+impl<'a> Mul for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: &'a Polynomial) -> Polynomial {
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut coeffs = vec![Fr::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (c, b) in coeffs[i..].iter_mut().zip(other.coeffs.iter()) {
+                *c += *a * *b;
+            }
+        }
+        Polynomial { coeffs }
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let p = Polynomial::from_coeffs(&[fr("3"), fr("0"), fr("2"), Fr::zero()]);
+    assert_eq!(p.degree(), Some(2));
+    let q = Polynomial::from_coeffs(&[-fr("1"), fr("1")]);
+    let z = fr("12345");
+    assert_eq!((&p * &q).evaluate(z), p.evaluate(z) * q.evaluate(z));
+    assert_eq!((&p + &q).evaluate(z), p.evaluate(z) + q.evaluate(z));
+    assert!((&p - &p).is_zero());
+
+    let (quot, rem) = (&(&p * &q) + &Polynomial::constant(fr("7")))
+        .div_rem(&q)
+        .unwrap();
+    assert_eq!(quot, p);
+    assert_eq!(rem, Polynomial::constant(fr("7")));
+    assert!(p.div_rem(&Polynomial::zero()).is_none());
+
+    let xs = [fr("1"), fr("2"), fr("5"), -fr("4")];
+    let v = Polynomial::vanishing(&xs);
+    assert_eq!(v.degree(), Some(4));
+    assert!(xs.iter().all(|x| v.evaluate(*x).is_zero()));
+
+    let ys = xs.map(|x| p.evaluate(x));
+    assert_eq!(Polynomial::interpolate(&xs, &ys), Some(p.clone()));
+    assert_eq!(
+        Polynomial::interpolate(&xs[..3], &[fr("9"); 3]),
+        Some(Polynomial::constant(fr("9")))
+    );
+    assert!(Polynomial::interpolate(&[fr("1"), fr("1")], &[fr("1"), fr("2")]).is_none());
+}
@@ -0,0 +1,121 @@
+//! Uniform sampling from an `EntropySource`. Field elements reduce 512
+//! random bits, which leaves a bias below 2^-258 and, unlike rejection
+//! sampling, takes a fixed number of RNG calls. Points are random multiples
+//! of the generator; both groups have prime order, so they are uniform.
+//!
+//! `mul_blinded` is a cheaper alternative to `mul_ct` for targets without
+//! constant-time guarantees: it re-randomizes the Jacobian `Z` coordinate
+//! and splits the scalar as `k = (k - m) + m` for a random `m`, so neither
+//! the intermediate coordinates nor the scalar bits processed correlate
+//! with the secret across calls. It masks against simple power and timing
+//! analysis but, unlike `mul_ct`, does not remove secret-dependent
+//! branches.
+
+use crate::entropy::EntropySource;
+use crate::fields::FieldElement;
+use crate::groups::{GroupParams, G};
+use crate::{fields, Fq, Fr, G1PrecomputedTable, G2PrecomputedTable, G1, G2};
+
+fn wide<E: EntropySource + ?Sized>(rng: &mut E) -> [u8; 64] {
+    let mut buf = [0u8; 64];
+    rng.fill_bytes(&mut buf);
+    buf
+}
+
+impl Fr {
+    pub fn random<E: EntropySource + ?Sized>(rng: &mut E) -> Self {
+        Fr::from_bytes_wide(&wide(rng))
+    }
+}
+
+impl Fq {
+    pub fn random<E: EntropySource + ?Sized>(rng: &mut E) -> Self {
+        Fq(fields::Fq::interpret(&wide(rng)))
+    }
+}
+
+/// A nonzero base field element; zero is replaced by one, which happens
+/// with negligible probability.
+fn nonzero(lambda: fields::Fq) -> fields::Fq {
+    if lambda.is_zero() {
+        fields::Fq::one()
+    } else {
+        lambda
+    }
+}
+
+/// The same point as `(lambda^2 x, lambda^3 y, lambda z)`.
+fn rescale<P: GroupParams>(p: &G<P>, lambda: P::Base) -> G<P> {
+    let lambda_squared = lambda.squared();
+    G::new(
+        *p.x() * lambda_squared,
+        *p.y() * lambda_squared * lambda,
+        *p.z() * lambda,
+    )
+}
+
+/// `(p * (k - m)) + (p * m)` for a fresh random `m`, after re-randomizing
+/// the coordinates of `p`.
+fn mul_blinded<P: GroupParams, E: EntropySource + ?Sized>(
+    p: &G<P>,
+    lambda: P::Base,
+    k: Fr,
+    rng: &mut E,
+) -> G<P> {
+    let p = rescale(p, lambda);
+    let m = Fr::random(rng);
+    p * (k - m).0 + p * m.0
+}
+
+impl G1 {
+    pub fn random<E: EntropySource + ?Sized>(rng: &mut E) -> Self {
+        G1PrecomputedTable::generator().mul(Fr::random(rng))
+    }
+
+    /// `self * scalar` with projective and scalar blinding; see the module
+    /// documentation.
+    pub fn mul_blinded<E: EntropySource + ?Sized>(&self, scalar: Fr, rng: &mut E) -> G1 {
+        let lambda = nonzero(Fq::random(rng).0);
+        G1(mul_blinded(&self.0, lambda, scalar, rng))
+    }
+}
+
+impl G2 {
+    pub fn random<E: EntropySource + ?Sized>(rng: &mut E) -> Self {
+        G2PrecomputedTable::generator().mul(Fr::random(rng))
+    }
+
+    /// G2 counterpart of `G1::mul_blinded`. The coordinates are rescaled by
+    /// a random element of the base field `Fq`, a subfield of `Fq2`.
+    pub fn mul_blinded<E: EntropySource + ?Sized>(&self, scalar: Fr, rng: &mut E) -> G2 {
+        let lambda = nonzero(Fq::random(rng).0);
+        G2(mul_blinded(
+            &self.0,
+            fields::Fq2::new(lambda, fields::Fq::zero()),
+            scalar,
+            rng,
+        ))
+    }
+}
+
+#[test]
+fn mul_blinded_matches_mul() {
+    use crate::entropy::FnEntropy;
+    use crate::Group;
+
+    let mut state = 1u64;
+    let mut rng = FnEntropy(|dest: &mut [u8]| {
+        for b in dest.iter_mut() {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            *b = (state >> 56) as u8;
+        }
+    });
+    let k = Fr::from_str("123456789123456789").unwrap();
+    for _ in 0..3 {
+        assert_eq!(G1::one().mul_blinded(k, &mut rng), G1::one() * k);
+        assert!(G2::one().mul_blinded(k, &mut rng) == G2::one() * k);
+    }
+    assert!(G1::zero().mul_blinded(k, &mut rng).is_zero());
+}
@@ -0,0 +1,38 @@
+//! Second, independently randomized evaluation of the verification
+//! equations of `poe` and `committee` (`verify-twice` feature); other
+//! verifiers check once. A single glitch that skips or corrupts the
+//! first check is unlikely to also satisfy an equation scaled by a fresh
+//! randomizer and computed through different scalar multiplications.
+
+use crate::transcript::Transcript;
+use crate::{Fr, G1};
+#[cfg(test)]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(test)]
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// How many randomizers have been drawn, so tests can tell that a second
+/// check ran.
+#[cfg(test)]
+pub(crate) fn calls() -> usize {
+    CALLS.load(Ordering::Relaxed)
+}
+
+/// A nonzero randomizer bound to the points and bytes being verified.
+pub(crate) fn randomizer(label: &[u8], points: &[G1], bytes: &[u8]) -> Fr {
+    #[cfg(test)]
+    CALLS.fetch_add(1, Ordering::Relaxed);
+    let mut t = Transcript::new(b"alt_bn128 verify-twice");
+    t.append_message(b"label", label);
+    for p in points {
+        t.append_g1(b"point", p);
+    }
+    t.append_message(b"bytes", bytes);
+    let rho = t.challenge_scalar(b"rho");
+    if rho.is_zero() {
+        Fr::one()
+    } else {
+        rho
+    }
+}
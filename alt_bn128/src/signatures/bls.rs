@@ -0,0 +1,286 @@
+//! BLS signatures with public keys in G2 and signatures in G1: a
+//! signature on `m` is `sk * H(m)` for `H = hash_to_curve_g1` under `DST`,
+//! and verifies if `e(sig, g2) == e(H(m), pk)`.
+//!
+//! `PublicKey` wraps an `AffineG2`, whose constructors check the prime
+//! order subgroup, so a key that reaches `verify` is never of small order.
+//! G1 has prime order and needs no such check.
+//!
+//! Signatures on one message aggregate by addition and verify against the
+//! sum of the keys. That is only sound if every key came with a proof of
+//! possession, otherwise a rogue key can cancel the others. Signatures
+//! on distinct messages aggregate the same way and verify with one
+//! pairing per signer, without that requirement.
+//!
+//! For t-of-n threshold signing, a dealer gives signer `i` the share
+//! `f(i)` of a secret polynomial `f` of degree `t - 1`. Any `t` partial
+//! signatures, made with the shares as ordinary secret keys, combine by
+//! Lagrange interpolation at zero into the signature of `f(0)`, which
+//! verifies under the group key `f(0) * g2`.
+
+use crate::hash_to_curve::hash_to_curve_g1;
+use crate::{
+    miller_loop_batch_iter, pairing_check, AffineG2, EntropySource, Fr, Group, Gt, G1, G2,
+};
+use core::fmt;
+
+/// The domain separation tag of `hash_to_message`.
+pub const DST: &[u8] = b"BLS_SIG_BN254G1_XMD:SHA-256_SVDW_RO_NUL_";
+
+/// Largest number of partial signatures `combine` takes, bounding its
+/// stack buffer.
+pub const MAX_SIGNERS: usize = 256;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Different numbers of messages and public keys.
+    Length,
+    /// Two messages of an aggregate are equal.
+    DuplicateMessage,
+    InvalidSignature,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::Length => "numbers of messages and public keys differ",
+            Error::DuplicateMessage => "aggregate contains a duplicate message",
+            Error::InvalidSignature => "invalid signature",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct SecretKey(Fr);
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct PublicKey(pub AffineG2);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature(pub G1);
+
+/// `H(m)`, the point a message is signed as.
+pub fn hash_to_message(message: &[u8]) -> G1 {
+    hash_to_curve_g1(message, DST)
+}
+
+impl SecretKey {
+    /// `None` for zero, whose public key would be the identity.
+    pub fn new(sk: Fr) -> Option<Self> {
+        if sk.is_zero() {
+            return None;
+        }
+        Some(SecretKey(sk))
+    }
+
+    pub fn random<E: EntropySource + ?Sized>(rng: &mut E) -> Self {
+        loop {
+            if let Some(sk) = Self::new(Fr::random(rng)) {
+                return sk;
+            }
+        }
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        let pk = G2::one().mul_ct(self.0);
+        PublicKey(AffineG2::from_jacobian(pk).expect("secret key is nonzero; qed"))
+    }
+
+    /// `sk * H(message)`, in constant time.
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        Signature(hash_to_message(message).mul_ct(self.0))
+    }
+}
+
+impl PublicKey {
+    /// The key that verifies aggregates of signatures by all of `keys` on
+    /// one message; `None` if there are no keys or they cancel out.
+    pub fn aggregate(keys: &[PublicKey]) -> Option<PublicKey> {
+        let sum = keys.iter().fold(G2::zero(), |acc, pk| acc + G2::from(pk.0));
+        AffineG2::from_jacobian(sum).map(PublicKey)
+    }
+}
+
+impl Signature {
+    /// The sum of the signatures.
+    pub fn aggregate(signatures: &[Signature]) -> Signature {
+        Signature(signatures.iter().fold(G1::zero(), |acc, s| acc + s.0))
+    }
+}
+
+/// Checks `signature` on `message` under `public_key`, with two pairings.
+pub fn verify(public_key: &PublicKey, message: &[u8], signature: &Signature) -> Result<(), Error> {
+    let h = hash_to_message(message);
+    // e(sig, g2) == e(H(m), pk)
+    if pairing_check(&[(signature.0, G2::one()), (-h, public_key.0.into())]) {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// Checks an aggregate of signatures by `public_keys[i]` on the distinct
+/// `messages[i]`: `e(sig, g2) == prod e(H(m_i), pk_i)`, as one pairing
+/// product over any number of signers.
+pub fn verify_aggregate(
+    messages: &[&[u8]],
+    public_keys: &[PublicKey],
+    signature: &Signature,
+) -> Result<(), Error> {
+    if messages.len() != public_keys.len() {
+        return Err(Error::Length);
+    }
+    for (i, m) in messages.iter().enumerate() {
+        if messages[..i].contains(m) {
+            return Err(Error::DuplicateMessage);
+        }
+    }
+    let pairs = messages
+        .iter()
+        .zip(public_keys)
+        .map(|(m, pk)| (-hash_to_message(m), G2::from(pk.0)));
+    let f = miller_loop_batch_iter(core::iter::once((signature.0, G2::one())).chain(pairs));
+    if f.final_exponentiation() == Some(Gt::one()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// The share `f(index)` of signer `index` for the dealer's polynomial
+/// `f`, given by its coefficients, constant term first. `None` for index
+/// zero, which is the group secret, or a zero share.
+pub fn key_share(polynomial: &[Fr], index: u64) -> Option<SecretKey> {
+    if index == 0 {
+        return None;
+    }
+    let x = Fr::from(index);
+    SecretKey::new(
+        polynomial
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, c| acc * x + *c),
+    )
+}
+
+/// The Lagrange coefficient of `index` for interpolating at zero from
+/// the shares of `indices`. `None` unless `index` occurs exactly once, or
+/// if the indices contain zero or a duplicate.
+pub fn lagrange_coefficient(indices: &[u64], index: u64) -> Option<Fr> {
+    let x = Fr::from(index);
+    let mut num = Fr::one();
+    let mut den = Fr::one();
+    let mut found = false;
+    for &j in indices {
+        if j == index {
+            if found {
+                return None;
+            }
+            found = true;
+            continue;
+        }
+        let xj = Fr::from(j);
+        // prod x_j / (x_j - x_i)
+        num *= xj;
+        den *= xj - x;
+    }
+    if !found || num.is_zero() || x.is_zero() {
+        return None;
+    }
+    den.inverse().map(|d| num * d)
+}
+
+/// Combines the partial signatures of distinct signers, given with their
+/// indices, into the signature of the group secret. Exactly `t` of them
+/// are needed; with fewer the result is not a valid signature. `None` if
+/// an index is zero or repeated.
+pub fn combine(partials: &[(u64, Signature)]) -> Option<Signature> {
+    let mut indices = [0u64; MAX_SIGNERS];
+    let indices = indices.get_mut(..partials.len())?;
+    for (i, (index, _)) in indices.iter_mut().zip(partials) {
+        *i = *index;
+    }
+    let mut sum = G1::zero();
+    for (index, sig) in partials {
+        sum += sig.0 * lagrange_coefficient(indices, *index)?;
+    }
+    Some(Signature(sum))
+}
+
+#[test]
+fn sign_and_verify() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let sks = [fr("123"), fr("456"), fr("789")].map(|s| SecretKey::new(s).unwrap());
+    let pks = sks.map(|sk| sk.public_key());
+    let sig = sks[0].sign(b"hello");
+    assert_eq!(verify(&pks[0], b"hello", &sig), Ok(()));
+    assert_eq!(
+        verify(&pks[0], b"hellO", &sig),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verify(&pks[1], b"hello", &sig),
+        Err(Error::InvalidSignature)
+    );
+    assert!(SecretKey::new(Fr::zero()).is_none());
+
+    let sigs = sks.map(|sk| sk.sign(b"block 7"));
+    let apk = PublicKey::aggregate(&pks).unwrap();
+    let asig = Signature::aggregate(&sigs);
+    assert_eq!(verify(&apk, b"block 7", &asig), Ok(()));
+    let partial = PublicKey::aggregate(&pks[..2]).unwrap();
+    assert_eq!(
+        verify(&partial, b"block 7", &asig),
+        Err(Error::InvalidSignature)
+    );
+    assert!(PublicKey::aggregate(&[]).is_none());
+}
+
+#[test]
+fn aggregate_distinct_messages() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let sks = [fr("11"), fr("22"), fr("33")].map(|s| SecretKey::new(s).unwrap());
+    let pks = sks.map(|sk| sk.public_key());
+    let messages: [&[u8]; 3] = [b"a", b"b", b"c"];
+    let mut sigs = [Signature(G1::zero()); 3];
+    for ((sig, sk), m) in sigs.iter_mut().zip(sks.iter()).zip(messages.iter()) {
+        *sig = sk.sign(m);
+    }
+    let asig = Signature::aggregate(&sigs);
+    assert_eq!(verify_aggregate(&messages, &pks, &asig), Ok(()));
+    assert_eq!(
+        verify_aggregate(&[b"a", b"c", b"b"], &pks, &asig),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verify_aggregate(&[b"a", b"a", b"c"], &pks, &asig),
+        Err(Error::DuplicateMessage)
+    );
+    assert_eq!(
+        verify_aggregate(&messages[..2], &pks, &asig),
+        Err(Error::Length)
+    );
+}
+
+#[test]
+fn threshold() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    // 2-of-n with f(x) = 1000 + 77x
+    let f = [fr("1000"), fr("77")];
+    let group_key = SecretKey::new(f[0]).unwrap().public_key();
+    let partial = |i| (i, key_share(&f, i).unwrap().sign(b"checkpoint"));
+
+    let sig = combine(&[partial(2), partial(5)]).unwrap();
+    assert_eq!(verify(&group_key, b"checkpoint", &sig), Ok(()));
+    let sig = combine(&[partial(4), partial(1), partial(3)]).unwrap();
+    assert_eq!(verify(&group_key, b"checkpoint", &sig), Ok(()));
+    let sig = combine(&[partial(4)]).unwrap();
+    assert_eq!(
+        verify(&group_key, b"checkpoint", &sig),
+        Err(Error::InvalidSignature)
+    );
+    assert!(combine(&[partial(2), partial(2)]).is_none());
+    assert!(key_share(&f, 0).is_none());
+}
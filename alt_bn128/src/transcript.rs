@@ -0,0 +1,210 @@
+use crate::hasher::{FieldHasher, Sha256Hasher};
+use crate::{AffineG1, Fr, G1};
+use sha2::digest::consts::U32;
+use sha2::{Digest, Sha256};
+
+const FR_SEQUENCE_DST: &[u8] = b"alt_bn128 FrSequence v1";
+const COMMIT_POINTS_DST: &[u8] = b"alt_bn128 commit_points v1";
+
+/// Big-endian affine `x || y`, with the point at infinity encoded as 64
+/// zero bytes.
+pub(crate) fn g1_bytes(point: &G1) -> [u8; 64] {
+    let mut buf = [0u8; 64];
+    if let Some(p) = AffineG1::from_jacobian(*point) {
+        p.x()
+            .to_big_endian(&mut buf[..32])
+            .expect("slice is 32 bytes; qed");
+        p.y()
+            .to_big_endian(&mut buf[32..])
+            .expect("slice is 32 bytes; qed");
+    }
+    buf
+}
+
+/// A binding commitment to a list of points:
+/// `D(dst || len(label) || label || len(points) || x_0 || y_0 || ...)` with
+/// lengths as big-endian u64 and coordinates as in `Transcript::append_g1`.
+pub fn commit_points<D: Digest<OutputSize = U32>>(label: &[u8], points: &[AffineG1]) -> [u8; 32] {
+    let mut h = D::new();
+    h.update(COMMIT_POINTS_DST);
+    h.update((label.len() as u64).to_be_bytes());
+    h.update(label);
+    h.update((points.len() as u64).to_be_bytes());
+    for p in points {
+        h.update(g1_bytes(&(*p).into()));
+    }
+    h.finalize().into()
+}
+
+/// A deterministic stream of scalars derived from a 32-byte seed, typically
+/// the digest of a protocol transcript, so that prover and verifier derive
+/// identical batching coefficients without an RNG.
+///
+/// Element `i` is `hash_bytes(dst || seed || i)` with `i` a big-endian u64.
+/// With the default `Sha256Hasher` that is
+/// `interpret(H(dst || seed || i || 0) || H(dst || seed || i || 1))`, i.e.
+/// 512 bits reduced modulo r, which makes the bias negligible.
+#[derive(Clone, Debug)]
+pub struct FrSequence<H = Sha256Hasher> {
+    seed: [u8; 32],
+    counter: u64,
+    hasher: H,
+}
+
+impl FrSequence {
+    pub fn new(seed: [u8; 32]) -> Self {
+        FrSequence::with_hasher(seed, Sha256Hasher::new())
+    }
+}
+
+impl<H: FieldHasher> FrSequence<H> {
+    pub fn with_hasher(seed: [u8; 32], hasher: H) -> Self {
+        FrSequence {
+            seed,
+            counter: 0,
+            hasher,
+        }
+    }
+}
+
+impl<H: FieldHasher> Iterator for FrSequence<H> {
+    type Item = Fr;
+
+    fn next(&mut self) -> Option<Fr> {
+        let mut buf = [0u8; FR_SEQUENCE_DST.len() + 40];
+        let (dst, rest) = buf.split_at_mut(FR_SEQUENCE_DST.len());
+        dst.copy_from_slice(FR_SEQUENCE_DST);
+        rest[..32].copy_from_slice(&self.seed);
+        rest[32..].copy_from_slice(&self.counter.to_be_bytes());
+        self.counter += 1;
+        Some(self.hasher.hash_bytes(&buf))
+    }
+}
+
+/// A Fiat-Shamir transcript over SHA-256. Every absorbed item is framed
+/// with its label and length, and every challenge is absorbed back into
+/// the state, so the sequence of messages is unambiguous.
+///
+/// The state is always SHA-256; `H` only maps squeezed seeds to challenge
+/// scalars, through `FrSequence`.
+#[derive(Clone)]
+pub struct Transcript<H = Sha256Hasher> {
+    state: Sha256,
+    hasher: H,
+}
+
+impl Transcript {
+    pub fn new(label: &[u8]) -> Self {
+        Transcript::with_hasher(label, Sha256Hasher::new())
+    }
+}
+
+impl<H: FieldHasher + Clone> Transcript<H> {
+    pub fn with_hasher(label: &[u8], hasher: H) -> Self {
+        let mut t = Transcript {
+            state: Sha256::new(),
+            hasher,
+        };
+        t.append_message(b"dom-sep", label);
+        t
+    }
+
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.state.update((label.len() as u64).to_be_bytes());
+        self.state.update(label);
+        self.state.update((message.len() as u64).to_be_bytes());
+        self.state.update(message);
+    }
+
+    /// Absorbs a scalar as its 32-byte big-endian canonical encoding.
+    pub fn append_scalar(&mut self, label: &[u8], scalar: &Fr) {
+        let mut buf = [0u8; 32];
+        scalar
+            .into_u256()
+            .to_big_endian(&mut buf)
+            .expect("buffer is 32 bytes; qed");
+        self.append_message(label, &buf);
+    }
+
+    /// Absorbs a point as big-endian affine `x || y`, with the point at
+    /// infinity encoded as 64 zero bytes.
+    pub fn append_g1(&mut self, label: &[u8], point: &G1) {
+        self.append_message(label, &g1_bytes(point));
+    }
+
+    /// Absorbs a list of points as its SHA-256 `commit_points` digest.
+    pub fn append_points(&mut self, label: &[u8], points: &[AffineG1]) {
+        self.append_message(label, &commit_points::<Sha256>(label, points));
+    }
+
+    /// Squeezes a challenge scalar and ratchets the state with it.
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> Fr {
+        let mut h = self.state.clone();
+        h.update((label.len() as u64).to_be_bytes());
+        h.update(label);
+        let seed: [u8; 32] = h.finalize().into();
+        self.append_message(b"challenge", &seed);
+        FrSequence::with_hasher(seed, self.hasher.clone())
+            .next()
+            .expect("FrSequence is infinite; qed")
+    }
+}
+
+#[test]
+fn fr_sequence_known_answers() {
+    // H(dst || seed || i) for the seed 0x00..0x1f, computed independently
+    let mut seed = [0u8; 32];
+    for (i, b) in seed.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let expected = [
+        "14072597142144300929862604800089761871986016929257368182815594929548863886605",
+        "13937700291097682240993336182062338026094883977426414544344574711022299635911",
+        "8425018346430962688964771760810322200365420982324349813773603433037836654686",
+    ];
+    for (x, e) in FrSequence::new(seed).zip(expected.iter()) {
+        assert_eq!(x, Fr::from_str(e).unwrap());
+    }
+}
+
+#[test]
+fn challenge_binds_the_transcript() {
+    use crate::Group;
+
+    let mut t = Transcript::new(b"test");
+    t.append_message(b"m", b"hello");
+    let c = t.challenge_scalar(b"c");
+    let kat = "13163446935298018264147679589937854621896593577976291604690698728025320473314";
+    assert_eq!(c, Fr::from_str(kat).unwrap());
+    // challenges are absorbed, so the next one differs
+    let kat = "6019134063961741440962503438505424435811460878620200978891985306494248548079";
+    assert_eq!(t.challenge_scalar(b"c"), Fr::from_str(kat).unwrap());
+
+    let challenge = |messages: &[(&[u8], &[u8])], points: &[G1], label: &[u8]| {
+        let mut t = Transcript::new(b"test");
+        for (l, m) in messages {
+            t.append_message(l, m);
+        }
+        for p in points {
+            t.append_g1(b"point", p);
+        }
+        t.challenge_scalar(label)
+    };
+    let (a, b): (&[u8], &[u8]) = (b"a", b"b");
+    let points = [G1::one(), G1::one() * Fr::from(2u64)];
+    let messages = [(a, &b"1"[..]), (b, &b"2"[..])];
+    let base = challenge(&messages, &points, b"c");
+    assert_eq!(base, challenge(&messages, &points, b"c"));
+    // message order
+    assert_ne!(base, challenge(&[(b, b"2"), (a, b"1")], &points, b"c"));
+    // message labels and framing
+    assert_ne!(base, challenge(&[(b, b"1"), (b, b"2")], &points, b"c"));
+    assert_ne!(base, challenge(&[(b"a1", b""), (b, b"2")], &points, b"c"));
+    // challenge label
+    assert_ne!(base, challenge(&messages, &points, b"d"));
+    // each appended point, including the point at infinity
+    assert_ne!(base, challenge(&messages, &[points[1], points[0]], b"c"));
+    assert_ne!(base, challenge(&messages, &[points[0], G1::zero()], b"c"));
+    assert_ne!(base, challenge(&messages, &[G1::zero(), points[1]], b"c"));
+    assert_ne!(base, challenge(&messages, &points[..1], b"c"));
+}
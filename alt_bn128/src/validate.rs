@@ -0,0 +1,212 @@
+//! Explicit validation of Jacobian points, for diagnosing malformed
+//! inputs. Points built by this crate's arithmetic and decoders are always
+//! valid; points assembled from raw coordinates, e.g. with `G1::new`, are
+//! not checked until they reach `validate`.
+
+use crate::{fields, Component, Fq, Fq2, G1, G2};
+use core::fmt;
+
+/// Why a point failed `validate`, in the order the checks run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The stored value of an `x` or `y` coordinate is not below the
+    /// field modulus.
+    CoordinateNotInField {
+        component: Component,
+    },
+    /// The `Z` coordinate is not below the field modulus.
+    ZNotInField,
+    /// `Z` is zero: the point at infinity, which is rejected so that a
+    /// degenerate input cannot pass for a real one.
+    Infinity,
+    NotOnCurve,
+    /// On the curve but outside the prime-order subgroup (G2 only).
+    NotInSubgroup,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::CoordinateNotInField { component } => {
+                write!(f, "{} is not below the field modulus", component)
+            }
+            ValidationError::ZNotInField => f.write_str("z is not below the field modulus"),
+            ValidationError::Infinity => f.write_str("point is at infinity"),
+            ValidationError::NotOnCurve => f.write_str("point is not on the curve"),
+            ValidationError::NotInSubgroup => {
+                f.write_str("point is not in the prime-order subgroup")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+fn fq_in_field(x: Fq) -> bool {
+    *x.0.raw() < fields::Fq::modulus()
+}
+
+fn fq2_in_field(x: Fq2) -> Option<bool> {
+    if !fq_in_field(x.real()) {
+        Some(false)
+    } else if !fq_in_field(x.imaginary()) {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+impl G1 {
+    /// See `groups::G::is_on_curve`: true for the point at infinity.
+    pub fn is_on_curve(&self) -> bool {
+        self.0.is_on_curve()
+    }
+
+    /// Checks that this is a finite point of G1 with canonical
+    /// coordinates, reporting the first failure.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        for (c, component) in [(self.x(), Component::X), (self.y(), Component::Y)] {
+            if !fq_in_field(c) {
+                return Err(ValidationError::CoordinateNotInField { component });
+            }
+        }
+        if !fq_in_field(self.z()) {
+            return Err(ValidationError::ZNotInField);
+        }
+        if self.z().is_zero() {
+            return Err(ValidationError::Infinity);
+        }
+        if !self.is_on_curve() {
+            return Err(ValidationError::NotOnCurve);
+        }
+        Ok(())
+    }
+}
+
+impl G2 {
+    /// See `G1::is_on_curve`.
+    pub fn is_on_curve(&self) -> bool {
+        self.0.is_on_curve()
+    }
+
+    /// Whether `r * self` is the identity.
+    pub fn is_in_subgroup(&self) -> bool {
+        self.0.is_in_subgroup()
+    }
+
+    /// `G1::validate`, additionally checking the prime-order subgroup.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let coords = [
+            (self.x(), Component::XReal, Component::XImaginary),
+            (self.y(), Component::YReal, Component::YImaginary),
+        ];
+        for (c, real, imaginary) in coords {
+            if let Some(imag) = fq2_in_field(c) {
+                let component = if imag { imaginary } else { real };
+                return Err(ValidationError::CoordinateNotInField { component });
+            }
+        }
+        if fq2_in_field(self.z()).is_some() {
+            return Err(ValidationError::ZNotInField);
+        }
+        if self.z().is_zero() {
+            return Err(ValidationError::Infinity);
+        }
+        if !self.is_on_curve() {
+            return Err(ValidationError::NotOnCurve);
+        }
+        if !self.is_in_subgroup() {
+            return Err(ValidationError::NotInSubgroup);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn validation_errors() {
+    use crate::arith::U256;
+    use crate::Group;
+
+    assert_eq!(G1::one().validate(), Ok(()));
+    assert_eq!(G2::one().validate(), Ok(()));
+    assert_eq!(G1::zero().validate(), Err(ValidationError::Infinity));
+    assert!(G1::zero().is_on_curve());
+
+    let off = G1::new(Fq::one(), Fq::one(), Fq::one());
+    assert!(!off.is_on_curve());
+    assert_eq!(off.validate(), Err(ValidationError::NotOnCurve));
+
+    // the modulus itself as a stored coordinate
+    let x = Fq(fields::const_fq([
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]));
+    let unreduced = G1::new(x, Fq::one(), Fq::one());
+    assert_eq!(
+        unreduced.validate(),
+        Err(ValidationError::CoordinateNotInField {
+            component: Component::X
+        })
+    );
+
+    // the twist has a large cofactor, so a point with small real x is
+    // almost certainly outside the subgroup
+    let mut found = false;
+    for i in 1..64u64 {
+        let x = Fq2::new(Fq::from_u256(U256::from(i)).unwrap(), Fq::zero());
+        let rhs = x * x * x + G2::b();
+        if let Some(y) = rhs.sqrt() {
+            let p = G2::new(x, y, Fq2::one());
+            assert!(p.is_on_curve());
+            if !p.is_in_subgroup() {
+                assert_eq!(p.validate(), Err(ValidationError::NotInSubgroup));
+                found = true;
+                break;
+            }
+        }
+    }
+    assert!(found);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn error_messages() {
+    use crate::{CurveError, Field, FieldError, Fr};
+    use alloc::string::ToString;
+
+    let e = Fr::from_slice(&[0u8; 31]).unwrap_err();
+    assert_eq!(
+        e,
+        FieldError::InputLength {
+            field: Field::Fr,
+            expected: 32,
+            actual: 31
+        }
+    );
+    assert_eq!(e.to_string(), "Fr encoding must be 32 bytes, got 31");
+    let e = Fq2::from_slice(&[0u8; 65]).unwrap_err();
+    assert_eq!(e.to_string(), "Fq2 encoding must be 64 bytes, got 65");
+
+    let curve = CurveError::Coordinate {
+        component: Component::YImaginary,
+        offset: 96,
+        error: FieldError::NotMember,
+    };
+    assert_eq!(
+        curve.to_string(),
+        "invalid imaginary part of y at byte 96: value is not below the field modulus"
+    );
+    let source = core::error::Error::source(&curve).expect("coordinate errors have a source; qed");
+    assert_eq!(source.to_string(), FieldError::NotMember.to_string());
+    assert_eq!(
+        ValidationError::NotInSubgroup.to_string(),
+        "point is not in the prime-order subgroup"
+    );
+
+    let e = crate::arith::U512::from_slice(&[0u8; 32]).unwrap_err();
+    assert_eq!(e.to_string(), "expected 64 bytes, got 32");
+    let e = crate::ethereum::alt_bn128_pairing(&[0u8; 191]).unwrap_err();
+    assert_eq!(e.to_string(), alloc::format!("{} at byte 191", e.message));
+}
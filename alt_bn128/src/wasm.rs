@@ -0,0 +1,130 @@
+//! JavaScript bindings through `wasm-bindgen`, using the encodings of the
+//! Ethereum precompiles so a browser checks exactly what a contract calling
+//! them would:
+//!
+//! - `pairingCheck(input)` is the ECPAIRING check, over 192-byte pairs;
+//! - `g1Msm(points, scalars)` and `g2Msm(points, scalars)` take the points
+//!   as concatenated 64-byte (G1) or 128-byte (G2) encodings, all zeros
+//!   for the point at infinity, and the scalars as 32-byte big-endian
+//!   integers reduced modulo r as by ECMUL, and return the encoding of the
+//!   sum.
+//!
+//! Invalid input throws a JavaScript string with the go-ethereum message.
+
+use crate::ethereum::{self, Error, ErrorKind};
+use crate::{AffineG1, AffineG2, Fr, G1, G2};
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+fn throw(e: Error) -> JsValue {
+    JsValue::from_str(e.message)
+}
+
+/// Splits `scalars` into 32-byte scalars, one per `width`-byte point of
+/// `points`.
+fn read_scalars(points: &[u8], width: usize, scalars: &[u8]) -> Result<Vec<Fr>, Error> {
+    if !points.len().is_multiple_of(width) || scalars.len() != points.len() / width * 32 {
+        return Err(Error {
+            kind: ErrorKind::Length,
+            message: "points and scalars lengths do not match",
+            offset: points.len(),
+        });
+    }
+    Ok(scalars
+        .chunks(32)
+        .map(|s| Fr::from_slice(s).expect("chunk is 32 bytes; qed"))
+        .collect())
+}
+
+fn g1_msm_bytes(points: &[u8], scalars: &[u8]) -> Result<[u8; 64], Error> {
+    let scalars = read_scalars(points, 64, scalars)?;
+    let mut bases = Vec::with_capacity(scalars.len());
+    let mut terms = Vec::with_capacity(scalars.len());
+    for (i, s) in scalars.into_iter().enumerate() {
+        if let Some(p) = AffineG1::from_jacobian(ethereum::read_pt(points, i * 64)?) {
+            bases.push(p);
+            terms.push(s);
+        }
+    }
+    Ok(ethereum::encode_g1(G1::msm(&bases, &terms)))
+}
+
+fn g2_msm_bytes(points: &[u8], scalars: &[u8]) -> Result<[u8; 128], Error> {
+    let scalars = read_scalars(points, 128, scalars)?;
+    let mut bases = Vec::with_capacity(scalars.len());
+    let mut terms = Vec::with_capacity(scalars.len());
+    for (i, s) in scalars.into_iter().enumerate() {
+        if let Some(p) = AffineG2::from_jacobian(ethereum::read_g2(points, i * 128)?) {
+            bases.push(p);
+            terms.push(s);
+        }
+    }
+    Ok(AffineG2::from_jacobian(G2::msm(&bases, &terms)).map_or([0u8; 128], |p| p.to_evm_bytes()))
+}
+
+/// Whether the product of the pairings of the 192-byte pairs in `input` is
+/// one, as returned by the ECPAIRING precompile.
+#[wasm_bindgen(js_name = pairingCheck)]
+pub fn pairing_check(input: &[u8]) -> Result<bool, JsValue> {
+    ethereum::alt_bn128_pairing(input).map_err(throw)
+}
+
+/// `sum(scalars[i] * points[i])` in G1, as 64 bytes.
+#[wasm_bindgen(js_name = g1Msm)]
+pub fn g1_msm(points: &[u8], scalars: &[u8]) -> Result<Vec<u8>, JsValue> {
+    g1_msm_bytes(points, scalars)
+        .map(|p| p.to_vec())
+        .map_err(throw)
+}
+
+/// `sum(scalars[i] * points[i])` in G2, as 128 bytes.
+#[wasm_bindgen(js_name = g2Msm)]
+pub fn g2_msm(points: &[u8], scalars: &[u8]) -> Result<Vec<u8>, JsValue> {
+    g2_msm_bytes(points, scalars)
+        .map(|p| p.to_vec())
+        .map_err(throw)
+}
+
+#[test]
+fn msm_encodings() {
+    use crate::Group;
+
+    let g1 = ethereum::encode_g1(G1::one());
+    let g2 = AffineG2::from_jacobian(G2::one())
+        .expect("generator is finite; qed")
+        .to_evm_bytes();
+    let mut scalars = [0u8; 64];
+    scalars[31] = 2;
+    scalars[63] = 5;
+
+    let mut points = [0u8; 128];
+    points[..64].copy_from_slice(&g1);
+    // the second point is infinity
+    assert_eq!(
+        g1_msm_bytes(&points, &scalars),
+        Ok(ethereum::encode_g1(G1::one() * Fr::from(2u64)))
+    );
+    points[64..].copy_from_slice(&g1);
+    assert_eq!(
+        g1_msm_bytes(&points, &scalars),
+        Ok(ethereum::encode_g1(G1::one() * Fr::from(7u64)))
+    );
+    assert_eq!(
+        g1_msm_bytes(&points, &scalars[..32]).map_err(|e| e.kind),
+        Err(ErrorKind::Length)
+    );
+    points[127] ^= 1;
+    assert_eq!(
+        g1_msm_bytes(&points, &scalars).map_err(|e| e.offset),
+        Err(64)
+    );
+
+    let mut points = [0u8; 256];
+    points[..128].copy_from_slice(&g2);
+    points[128..].copy_from_slice(&g2);
+    let expected = AffineG2::from_jacobian(G2::one() * Fr::from(7u64))
+        .expect("7 g2 is finite; qed")
+        .to_evm_bytes();
+    assert_eq!(g2_msm_bytes(&points, &scalars), Ok(expected));
+    assert_eq!(g2_msm_bytes(&[], &[]), Ok([0u8; 128]));
+}
@@ -0,0 +1,252 @@
+//! Width-`w` non-adjacent form: signed digits that are zero or odd with
+//! absolute value below `2^(w-1)`, such that any `w` consecutive digits
+//! contain at most one nonzero. Multiplication then needs one addition
+//! per nonzero digit, about `254 / (w + 1)` of them, from a table of the
+//! `2^(w-2)` odd multiples of the point.
+//!
+//! `Gt::multi_pow` interleaves the digits of several exponents, so the
+//! squarings are shared among all bases.
+
+use crate::fields::{self, FieldElement};
+use crate::groups::{GroupElement, GroupParams, G};
+use crate::{Fr, Gt, G1, G2};
+
+/// Largest supported window. The table of odd multiples lives on the
+/// stack, so this bounds it to 64 points.
+pub const MAX_WNAF_WINDOW: usize = 8;
+
+/// Digits of a scalar, least significant first.
+#[derive(Copy, Clone)]
+pub struct Wnaf {
+    digits: [i8; 256],
+    len: usize,
+    window: usize,
+}
+
+impl Wnaf {
+    /// The digits without trailing zeros; empty for zero.
+    pub fn digits(&self) -> &[i8] {
+        &self.digits[..self.len]
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+}
+
+impl Fr {
+    /// Recodes the canonical value of `self`. Panics unless
+    /// `2 <= window <= MAX_WNAF_WINDOW`.
+    pub fn to_wnaf(&self, window: usize) -> Wnaf {
+        assert!(
+            (2..=MAX_WNAF_WINDOW).contains(&window),
+            "wNAF window must be between 2 and {}",
+            MAX_WNAF_WINDOW
+        );
+        let mut k = self.into_u256();
+        let mut out = Wnaf {
+            digits: [0; 256],
+            len: 0,
+            window,
+        };
+        let modulus = 1u128 << window;
+        while !k.is_zero() {
+            let mut d = 0;
+            if k.0[0] & 1 == 1 {
+                d = (k.0[0] & (modulus - 1)) as i32;
+                if d >= (modulus >> 1) as i32 {
+                    d -= modulus as i32;
+                }
+                // clears the low `window` bits; k < 2^254, so no overflow
+                if d > 0 {
+                    k.0[0] -= d as u128;
+                } else {
+                    let (low, carry) = k.0[0].overflowing_add(d.unsigned_abs() as u128);
+                    k.0[0] = low;
+                    k.0[1] += carry as u128;
+                }
+            }
+            out.digits[out.len] = d as i8;
+            out.len += 1;
+            k.0[0] = (k.0[0] >> 1) | (k.0[1] << 127);
+            k.0[1] >>= 1;
+        }
+        out
+    }
+}
+
+/// `sum(d_i * 2^i) * p`.
+fn mul<P: GroupParams>(p: &G<P>, naf: &Wnaf) -> G<P> {
+    let mut table = [*p; 1 << (MAX_WNAF_WINDOW - 2)];
+    let size = 1 << (naf.window - 2);
+    let double = p.double();
+    for j in 1..size {
+        table[j] = table[j - 1] + double;
+    }
+
+    let mut acc = G::zero();
+    for &d in naf.digits().iter().rev() {
+        acc = acc.double();
+        if d > 0 {
+            acc = acc + table[(d as usize) / 2];
+        } else if d < 0 {
+            acc = acc - table[(-d as usize) / 2];
+        }
+    }
+    acc
+}
+
+impl G1 {
+    /// `self * scalar` by `scalar.to_wnaf(window)`.
+    pub fn mul_wnaf(&self, scalar: Fr, window: usize) -> G1 {
+        G1(mul(&self.0, &scalar.to_wnaf(window)))
+    }
+
+    /// Multiplies by a scalar that was recoded already, e.g. to reuse the
+    /// digits across several points.
+    pub fn mul_by_wnaf(&self, naf: &Wnaf) -> G1 {
+        G1(mul(&self.0, naf))
+    }
+}
+
+impl G2 {
+    pub fn mul_wnaf(&self, scalar: Fr, window: usize) -> G2 {
+        G2(mul(&self.0, &scalar.to_wnaf(window)))
+    }
+
+    pub fn mul_by_wnaf(&self, naf: &Wnaf) -> G2 {
+        G2(mul(&self.0, naf))
+    }
+}
+
+/// Window of the exponents in `Gt::multi_pow`: four odd powers per base.
+const MULTI_POW_WINDOW: usize = 4;
+
+/// Bases per pass of `Gt::multi_pow`, bounding its stack tables.
+const MULTI_POW_CHUNK: usize = 8;
+
+fn multi_pow_chunk(bases: &[Gt], exps: &[Fr], cyclotomic: bool) -> fields::Fq12 {
+    const SIZE: usize = 1 << (MULTI_POW_WINDOW - 2);
+    let one = fields::Fq12::one();
+    let mut pos = [[one; SIZE]; MULTI_POW_CHUNK];
+    let mut neg = [[one; SIZE]; MULTI_POW_CHUNK];
+    let mut nafs = [Fr::zero().to_wnaf(MULTI_POW_WINDOW); MULTI_POW_CHUNK];
+    let mut len = 0;
+    for (i, (b, e)) in bases.iter().zip(exps).enumerate() {
+        // b, b^3, b^5, ... and their inverses
+        let square = b.0 * b.0;
+        pos[i][0] = b.0;
+        for j in 1..SIZE {
+            pos[i][j] = pos[i][j - 1] * square;
+        }
+        for (n, p) in neg[i].iter_mut().zip(pos[i].iter()) {
+            *n = if cyclotomic {
+                p.unitary_inverse()
+            } else {
+                p.inverse().expect("elements of Gt are nonzero; qed")
+            };
+        }
+        nafs[i] = e.to_wnaf(MULTI_POW_WINDOW);
+        len = len.max(nafs[i].digits().len());
+    }
+
+    let mut acc = one;
+    for k in (0..len).rev() {
+        acc = if cyclotomic {
+            acc.cyclotomic_squared()
+        } else {
+            acc * acc
+        };
+        for (i, naf) in nafs[..bases.len()].iter().enumerate() {
+            match naf.digits().get(k) {
+                Some(&d) if d > 0 => acc = acc * pos[i][d as usize / 2],
+                Some(&d) if d < 0 => acc = acc * neg[i][(-d) as usize / 2],
+                _ => {}
+            }
+        }
+    }
+    acc
+}
+
+impl Gt {
+    /// `prod(bases[i]^exps[i])` by interleaved wNAF, sharing one chain of
+    /// squarings per 8 bases. Uses cyclotomic squarings when every base is
+    /// in the cyclotomic subgroup, as pairing outputs are. Panics if the
+    /// slices differ in length.
+    pub fn multi_pow(bases: &[Gt], exps: &[Fr]) -> Gt {
+        assert_eq!(bases.len(), exps.len(), "one exponent per base");
+        let cyclotomic = bases.iter().all(|b| b.0.is_cyclotomic());
+        let mut acc = fields::Fq12::one();
+        for (b, e) in bases
+            .chunks(MULTI_POW_CHUNK)
+            .zip(exps.chunks(MULTI_POW_CHUNK))
+        {
+            acc = acc * multi_pow_chunk(b, e, cyclotomic);
+        }
+        Gt(acc)
+    }
+}
+
+#[test]
+fn wnaf_matches_mul() {
+    use crate::arith::U256;
+    use crate::Group;
+
+    let scalars = [
+        Fr::zero(),
+        Fr::one(),
+        -Fr::one(),
+        Fr::from_str("255").unwrap(),
+        Fr::from_str("123456789123456789123456789123456789").unwrap(),
+    ];
+    for k in scalars.iter() {
+        for window in 2..=MAX_WNAF_WINDOW {
+            let naf = k.to_wnaf(window);
+            let mut value = Fr::zero();
+            for &d in naf.digits().iter().rev() {
+                value = value + value;
+                let magnitude = Fr::new(U256([d.unsigned_abs() as u128, 0])).unwrap();
+                value = if d < 0 {
+                    value - magnitude
+                } else {
+                    value + magnitude
+                };
+            }
+            assert_eq!(value, *k);
+            for w in naf.digits().windows(window) {
+                assert!(w.iter().filter(|d| **d != 0).count() <= 1);
+            }
+            assert_eq!(G1::one().mul_wnaf(*k, window), G1::one() * *k);
+        }
+        assert!(G2::one().mul_wnaf(*k, 5) == G2::one() * *k);
+    }
+}
+
+#[test]
+fn multi_pow_matches_pow() {
+    use crate::{pairing, Group};
+
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let mut bases = [Gt::one(); 10];
+    let mut exps = [Fr::zero(); 10];
+    let mut k = fr("987654321987654321");
+    for (i, (b, e)) in bases.iter_mut().zip(exps.iter_mut()).enumerate() {
+        *b = pairing(G1::one() * k, G2::one());
+        *e = if i == 3 { Fr::zero() } else { -k * k };
+        k = k * k + Fr::one();
+    }
+    let expected = bases
+        .iter()
+        .zip(exps.iter())
+        .fold(Gt::one(), |acc, (b, e)| acc * b.pow(*e));
+    assert_eq!(Gt::multi_pow(&bases, &exps), expected);
+    assert_eq!(Gt::multi_pow(&[], &[]), Gt::one());
+
+    // a Miller loop value is outside the cyclotomic subgroup
+    let p = crate::AffineG1::from_jacobian(G1::one()).unwrap();
+    let f = crate::miller_loop(&p, &G2::one().prepare().unwrap());
+    assert_eq!(
+        Gt::multi_pow(&[f, bases[0]], &[exps[0], exps[1]]),
+        f.pow(exps[0]) * bases[0].pow(exps[1])
+    );
+}
@@ -0,0 +1,871 @@
+//! The zkcrypto `ff`, `group` and `pairing` traits, so that generic code
+//! written against them, such as bellman-style provers and MSM crates, can
+//! run on this crate through the `Bn256` engine.
+//!
+//! `Fr` and `Fq` implement `PrimeField` with the canonical little-endian
+//! integer as `Repr`, and `G1` and `G2` are the projective curve types.
+//! The traits need a few types this crate does not otherwise have:
+//!
+//! - `G1Affine` and `G2Affine`, affine points that, unlike `AffineG1` and
+//!   `AffineG2`, can be the point at infinity;
+//! - `Gt`, the target group written additively as `group::Group` wants:
+//!   `+` multiplies, `-` inverts and `* Fr` exponentiates;
+//! - `G2Prepared` and `MillerLoopResult` for `MultiMillerLoop`.
+//!
+//! `GroupEncoding` is the gnark compressed encoding of
+//! `G1::to_bytes_compressed_be`, and `UncompressedEncoding` the EIP-197
+//! encoding of the precompiles, with the point at infinity all zero. The
+//! unchecked decoders perform the full checks.
+
+use crate::{AffineG1, AffineG2, Fq, Fr, Group as _, G1, G2};
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use ff::{Field, PrimeField};
+use group::prime::{PrimeCurve, PrimeCurveAffine, PrimeGroup};
+use group::{Curve, Group, GroupEncoding, UncompressedEncoding};
+use pairing::{Engine, MultiMillerLoop, PairingCurveAffine};
+use rand_core::RngCore;
+use subtle::{Choice, CtOption};
+
+fn ct<T>(x: Option<T>, default: T) -> CtOption<T> {
+    let is_some = Choice::from(x.is_some() as u8);
+    CtOption::new(x.unwrap_or(default), is_some)
+}
+
+/// Implements `Field` and `PrimeField` for `$t`. The constants are the
+/// limbs of Montgomery forms, as taken by `from_raw`.
+macro_rules! prime_field {
+    (
+        $t:ident,
+        modulus: $modulus:expr,
+        s: $s:expr,
+        one: $one:expr,
+        two_inv: $two_inv:expr,
+        generator: $generator:expr,
+        root_of_unity: $root:expr,
+        root_of_unity_inv: $root_inv:expr,
+        delta: $delta:expr
+    ) => {
+        impl Field for $t {
+            const ZERO: Self = $t::from_raw([0; 4]);
+            const ONE: Self = $t::from_raw($one);
+
+            fn random(mut rng: impl RngCore) -> Self {
+                $t::random(&mut rng)
+            }
+
+            fn square(&self) -> Self {
+                $t::square(self)
+            }
+
+            fn double(&self) -> Self {
+                $t::double(self)
+            }
+
+            fn invert(&self) -> CtOption<Self> {
+                ct(self.inverse(), Self::ZERO)
+            }
+
+            /// Variable time, as `sqrt_ratio`.
+            fn sqrt(&self) -> CtOption<Self> {
+                ct($t::sqrt(self), Self::ZERO)
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+                ff::helpers::sqrt_ratio_generic(num, div)
+            }
+        }
+
+        impl PrimeField for $t {
+            type Repr = [u8; 32];
+
+            fn from_repr(repr: [u8; 32]) -> CtOption<Self> {
+                ct($t::from_le_slice(&repr).ok(), Self::ZERO)
+            }
+
+            fn to_repr(&self) -> [u8; 32] {
+                let mut out = [0u8; 32];
+                self.to_little_endian(&mut out)
+                    .expect("buffer is 32 bytes; qed");
+                out
+            }
+
+            fn is_odd(&self) -> Choice {
+                Choice::from(self.to_repr()[0] & 1)
+            }
+
+            const MODULUS: &'static str = $modulus;
+            const NUM_BITS: u32 = 254;
+            const CAPACITY: u32 = 253;
+            const TWO_INV: Self = $t::from_raw($two_inv);
+            const MULTIPLICATIVE_GENERATOR: Self = $t::from_raw($generator);
+            const S: u32 = $s;
+            const ROOT_OF_UNITY: Self = $t::from_raw($root);
+            const ROOT_OF_UNITY_INV: Self = $t::from_raw($root_inv);
+            const DELTA: Self = $t::from_raw($delta);
+        }
+    };
+}
+
+prime_field!(
+    Fr,
+    modulus: "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+    s: 28,
+    one: [
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ],
+    two_inv: [
+        0x783c14d81ffffffe,
+        0xaf982f6f0c8d1edd,
+        0x8f5f7492fcfd4f45,
+        0x1f37631a3d9cbfac,
+    ],
+    // 5
+    generator: [
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ],
+    root_of_unity: [
+        0x636e735580d13d9c,
+        0xa22bf3742445ffd6,
+        0x56452ac01eb203d8,
+        0x1860ef942963f9e7,
+    ],
+    root_of_unity_inv: [
+        0x89bcc016584bb683,
+        0xe8d9887f0164a50c,
+        0x755e95cb795eda3d,
+        0x0f572b871323b130,
+    ],
+    delta: [
+        0xb1132acfdd0ede60,
+        0xbb4b2bd501254442,
+        0xb3559919ba247a31,
+        0x1a7adfe2c8b1068c,
+    ]
+);
+
+prime_field!(
+    Fq,
+    modulus: "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+    s: 1,
+    one: [
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ],
+    two_inv: [
+        0x87bee7d24f060572,
+        0xd0fd2add2f1c6ae5,
+        0x8f5f7492fcfd4f44,
+        0x1f37631a3d9cbfac,
+    ],
+    // 3
+    generator: [
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ],
+    // -1
+    root_of_unity: [
+        0x68c3488912edefaa,
+        0x8d087f6872aabf4f,
+        0x51e1a24709081231,
+        0x2259d6b14729c0fa,
+    ],
+    root_of_unity_inv: [
+        0x68c3488912edefaa,
+        0x8d087f6872aabf4f,
+        0x51e1a24709081231,
+        0x2259d6b14729c0fa,
+    ],
+    // 9
+    delta: [
+        0xf60647ce410d7ff7,
+        0x2f3d6f4dd31bd011,
+        0x2943337e3940c6d1,
+        0x1d9598e8a7e39857,
+    ]
+);
+
+/// Declares a byte array newtype, since `GroupEncoding::Repr` must be
+/// `Default` and arrays longer than 32 bytes are not.
+macro_rules! encoding {
+    ($name:ident, $n:expr) => {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        pub struct $name(pub [u8; $n]);
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name([0; $n])
+            }
+        }
+
+        impl From<[u8; $n]> for $name {
+            fn from(bytes: [u8; $n]) -> Self {
+                $name(bytes)
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsMut<[u8]> for $name {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+    };
+}
+
+encoding!(G1Uncompressed, 64);
+encoding!(G2Compressed, 64);
+encoding!(G2Uncompressed, 128);
+
+/// A G1 point in affine coordinates, or the point at infinity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct G1Affine(Option<AffineG1>);
+
+/// A G2 point in affine coordinates, or the point at infinity.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct G2Affine(Option<AffineG2>);
+
+impl fmt::Debug for G2Affine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            None => f.write_str("G2Affine(infinity)"),
+            Some(p) => f
+                .debug_struct("G2Affine")
+                .field("x", &p.x())
+                .field("y", &p.y())
+                .finish(),
+        }
+    }
+}
+
+/// Implements the `group` traits for the projective type `$g` and the
+/// affine type `$affine`, which wraps an optional `$inner`.
+macro_rules! curve {
+    ($g:ident, $affine:ident, $inner:ident, $repr:ty) => {
+        impl From<$g> for $affine {
+            fn from(p: $g) -> Self {
+                $affine($inner::from_jacobian(p))
+            }
+        }
+
+        impl From<$affine> for $g {
+            fn from(p: $affine) -> Self {
+                p.0.map_or($g::zero(), $g::from)
+            }
+        }
+
+        impl From<$inner> for $affine {
+            fn from(p: $inner) -> Self {
+                $affine(Some(p))
+            }
+        }
+
+        /// `None` for the point at infinity.
+        impl From<$affine> for Option<$inner> {
+            fn from(p: $affine) -> Self {
+                p.0
+            }
+        }
+
+        impl Group for $g {
+            type Scalar = Fr;
+
+            fn random(mut rng: impl RngCore) -> Self {
+                $g::random(&mut rng)
+            }
+
+            fn identity() -> Self {
+                $g::zero()
+            }
+
+            fn generator() -> Self {
+                $g::one()
+            }
+
+            fn is_identity(&self) -> Choice {
+                Choice::from(self.is_zero() as u8)
+            }
+
+            fn double(&self) -> Self {
+                $g::double(self)
+            }
+        }
+
+        impl Curve for $g {
+            type AffineRepr = $affine;
+
+            /// Shares one inversion per chunk, as `batch_normalize_into`.
+            fn batch_normalize(p: &[Self], q: &mut [$affine]) {
+                assert_eq!(p.len(), q.len());
+                let mut buf = [None; 64];
+                for (p, q) in p.chunks(64).zip(q.chunks_mut(64)) {
+                    $g::batch_normalize_into(p, &mut buf[..p.len()]);
+                    for (q, b) in q.iter_mut().zip(buf.iter()) {
+                        *q = $affine(*b);
+                    }
+                }
+            }
+
+            fn to_affine(&self) -> $affine {
+                $affine::from(*self)
+            }
+        }
+
+        impl PrimeGroup for $g {}
+
+        impl PrimeCurve for $g {
+            type Affine = $affine;
+        }
+
+        impl GroupEncoding for $g {
+            type Repr = $repr;
+
+            fn from_bytes(bytes: &$repr) -> CtOption<Self> {
+                ct(
+                    $g::from_bytes_compressed_be(bytes.as_ref()).ok(),
+                    $g::zero(),
+                )
+            }
+
+            fn from_bytes_unchecked(bytes: &$repr) -> CtOption<Self> {
+                Self::from_bytes(bytes)
+            }
+
+            fn to_bytes(&self) -> $repr {
+                <$repr>::from(self.to_bytes_compressed_be())
+            }
+        }
+
+        impl GroupEncoding for $affine {
+            type Repr = $repr;
+
+            fn from_bytes(bytes: &$repr) -> CtOption<Self> {
+                ct(
+                    $g::from_bytes_compressed_be(bytes.as_ref())
+                        .ok()
+                        .map($affine::from),
+                    $affine(None),
+                )
+            }
+
+            fn from_bytes_unchecked(bytes: &$repr) -> CtOption<Self> {
+                Self::from_bytes(bytes)
+            }
+
+            fn to_bytes(&self) -> $repr {
+                $g::from(*self).to_bytes()
+            }
+        }
+
+        impl PrimeCurveAffine for $affine {
+            type Scalar = Fr;
+            type Curve = $g;
+
+            fn identity() -> Self {
+                $affine(None)
+            }
+
+            fn generator() -> Self {
+                $affine(Some($inner::generator()))
+            }
+
+            fn is_identity(&self) -> Choice {
+                Choice::from(self.0.is_none() as u8)
+            }
+
+            fn to_curve(&self) -> $g {
+                $g::from(*self)
+            }
+        }
+
+        impl Neg for $affine {
+            type Output = $affine;
+
+            fn neg(self) -> $affine {
+                $affine(self.0.map(|mut p| {
+                    p.set_y(-p.y());
+                    p
+                }))
+            }
+        }
+
+        impl Mul<Fr> for $affine {
+            type Output = $g;
+
+            fn mul(self, other: Fr) -> $g {
+                $g::from(self) * other
+            }
+        }
+
+        impl<'a> Mul<&'a Fr> for $affine {
+            type Output = $g;
+
+            fn mul(self, other: &'a Fr) -> $g {
+                $g::from(self) * *other
+            }
+        }
+
+        impl Add<$affine> for $g {
+            type Output = $g;
+
+            fn add(self, other: $affine) -> $g {
+                other.0.map_or(self, |q| self + q)
+            }
+        }
+
+        impl Sub<$affine> for $g {
+            type Output = $g;
+
+            fn sub(self, other: $affine) -> $g {
+                other.0.map_or(self, |q| self - q)
+            }
+        }
+
+        impl<'a> Add<&'a $affine> for $g {
+            type Output = $g;
+
+            fn add(self, other: &'a $affine) -> $g {
+                self + *other
+            }
+        }
+
+        impl<'a> Sub<&'a $affine> for $g {
+            type Output = $g;
+
+            fn sub(self, other: &'a $affine) -> $g {
+                self - *other
+            }
+        }
+
+        impl AddAssign<$affine> for $g {
+            fn add_assign(&mut self, other: $affine) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign<$affine> for $g {
+            fn sub_assign(&mut self, other: $affine) {
+                *self = *self - other;
+            }
+        }
+
+        impl<'a> AddAssign<&'a $affine> for $g {
+            fn add_assign(&mut self, other: &'a $affine) {
+                *self = *self + *other;
+            }
+        }
+
+        impl<'a> SubAssign<&'a $affine> for $g {
+            fn sub_assign(&mut self, other: &'a $affine) {
+                *self = *self - *other;
+            }
+        }
+    };
+}
+
+curve!(G1, G1Affine, AffineG1, [u8; 32]);
+curve!(G2, G2Affine, AffineG2, G2Compressed);
+
+impl UncompressedEncoding for G1Affine {
+    type Uncompressed = G1Uncompressed;
+
+    fn from_uncompressed(bytes: &G1Uncompressed) -> CtOption<Self> {
+        let decode = |bytes: &[u8; 64]| {
+            if bytes.iter().all(|b| *b == 0) {
+                return Some(G1Affine(None));
+            }
+            let x = Fq::from_slice(&bytes[..32]).ok()?;
+            let y = Fq::from_slice(&bytes[32..]).ok()?;
+            AffineG1::new(x, y).ok().map(G1Affine::from)
+        };
+        ct(decode(&bytes.0), G1Affine(None))
+    }
+
+    fn from_uncompressed_unchecked(bytes: &G1Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed(bytes)
+    }
+
+    fn to_uncompressed(&self) -> G1Uncompressed {
+        let mut out = [0u8; 64];
+        if let Some(p) = self.0 {
+            p.x()
+                .to_big_endian(&mut out[..32])
+                .expect("slice is 32 bytes; qed");
+            p.y()
+                .to_big_endian(&mut out[32..])
+                .expect("slice is 32 bytes; qed");
+        }
+        G1Uncompressed(out)
+    }
+}
+
+impl UncompressedEncoding for G2Affine {
+    type Uncompressed = G2Uncompressed;
+
+    fn from_uncompressed(bytes: &G2Uncompressed) -> CtOption<Self> {
+        let p = if bytes.0.iter().all(|b| *b == 0) {
+            Some(G2Affine(None))
+        } else {
+            AffineG2::from_evm_slice(&bytes.0).ok().map(G2Affine::from)
+        };
+        ct(p, G2Affine(None))
+    }
+
+    fn from_uncompressed_unchecked(bytes: &G2Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed(bytes)
+    }
+
+    fn to_uncompressed(&self) -> G2Uncompressed {
+        G2Uncompressed(self.0.map_or([0u8; 128], |p| p.to_evm_bytes()))
+    }
+}
+
+/// The target group written additively; see the module documentation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gt(crate::Gt);
+
+impl From<crate::Gt> for Gt {
+    fn from(x: crate::Gt) -> Self {
+        Gt(x)
+    }
+}
+
+impl From<Gt> for crate::Gt {
+    fn from(x: Gt) -> Self {
+        x.0
+    }
+}
+
+impl Add for Gt {
+    type Output = Gt;
+
+    // the group law of the target group is its multiplication
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: Gt) -> Gt {
+        Gt(self.0 * other.0)
+    }
+}
+
+impl Neg for Gt {
+    type Output = Gt;
+
+    fn neg(self) -> Gt {
+        Gt(self.0.inverse().expect("Gt elements are nonzero; qed"))
+    }
+}
+
+impl Sub for Gt {
+    type Output = Gt;
+
+    fn sub(self, other: Gt) -> Gt {
+        self + -other
+    }
+}
+
+impl Mul<Fr> for Gt {
+    type Output = Gt;
+
+    fn mul(self, other: Fr) -> Gt {
+        Gt(self.0.pow(other))
+    }
+}
+
+/// The by-reference and compound forms of `$tr` for `Gt`.
+macro_rules! gt_ops {
+    ($($tr:ident, $method:ident, $assign:ident, $assign_method:ident, $rhs:ident);*) => {
+        $(
+            impl<'a> $tr<&'a $rhs> for Gt {
+                type Output = Gt;
+
+                fn $method(self, other: &'a $rhs) -> Gt {
+                    $tr::$method(self, *other)
+                }
+            }
+
+            impl $assign<$rhs> for Gt {
+                fn $assign_method(&mut self, other: $rhs) {
+                    *self = $tr::$method(*self, other);
+                }
+            }
+
+            impl<'a> $assign<&'a $rhs> for Gt {
+                fn $assign_method(&mut self, other: &'a $rhs) {
+                    *self = $tr::$method(*self, *other);
+                }
+            }
+        )*
+    };
+}
+
+gt_ops!(
+    Add, add, AddAssign, add_assign, Gt;
+    Sub, sub, SubAssign, sub_assign, Gt;
+    Mul, mul, MulAssign, mul_assign, Fr
+);
+
+impl Sum for Gt {
+    fn sum<I: Iterator<Item = Gt>>(iter: I) -> Gt {
+        iter.fold(Gt::identity(), |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Gt> for Gt {
+    fn sum<I: Iterator<Item = &'a Gt>>(iter: I) -> Gt {
+        iter.fold(Gt::identity(), |acc, x| acc + *x)
+    }
+}
+
+impl Group for Gt {
+    type Scalar = Fr;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        Gt::generator() * Fr::random(&mut rng)
+    }
+
+    fn identity() -> Self {
+        Gt(crate::Gt::one())
+    }
+
+    /// `e(G1::one(), G2::one())`.
+    fn generator() -> Self {
+        Gt(crate::pairing(G1::one(), G2::one()))
+    }
+
+    fn is_identity(&self) -> Choice {
+        Choice::from((self.0 == crate::Gt::one()) as u8)
+    }
+
+    fn double(&self) -> Self {
+        *self + *self
+    }
+}
+
+/// A G2 point with its line coefficients precomputed; see
+/// `crate::G2Prepared`.
+#[derive(Copy, Clone)]
+pub struct G2Prepared(Option<crate::G2Prepared>);
+
+impl From<G2Affine> for G2Prepared {
+    fn from(q: G2Affine) -> Self {
+        G2Prepared(q.0.map(|q| {
+            G2::from(q)
+                .prepare()
+                .expect("affine points are not at infinity; qed")
+        }))
+    }
+}
+
+/// A product of Miller loops, written additively like `Gt`.
+#[derive(Copy, Clone, Debug)]
+pub struct MillerLoopResult(crate::Gt);
+
+impl Default for MillerLoopResult {
+    fn default() -> Self {
+        MillerLoopResult(crate::Gt::one())
+    }
+}
+
+impl Add for MillerLoopResult {
+    type Output = MillerLoopResult;
+
+    // the group law of the target group is its multiplication
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: MillerLoopResult) -> MillerLoopResult {
+        MillerLoopResult(self.0 * other.0)
+    }
+}
+
+impl<'a> Add<&'a MillerLoopResult> for MillerLoopResult {
+    type Output = MillerLoopResult;
+
+    fn add(self, other: &'a MillerLoopResult) -> MillerLoopResult {
+        self + *other
+    }
+}
+
+impl AddAssign for MillerLoopResult {
+    fn add_assign(&mut self, other: MillerLoopResult) {
+        *self = *self + other;
+    }
+}
+
+impl<'a> AddAssign<&'a MillerLoopResult> for MillerLoopResult {
+    fn add_assign(&mut self, other: &'a MillerLoopResult) {
+        *self = *self + *other;
+    }
+}
+
+impl pairing::MillerLoopResult for MillerLoopResult {
+    type Gt = Gt;
+
+    fn final_exponentiation(&self) -> Gt {
+        Gt(self
+            .0
+            .final_exponentiation()
+            .expect("Miller loop values are nonzero; qed"))
+    }
+}
+
+/// The `pairing::Engine` of this crate's curve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bn256;
+
+impl Engine for Bn256 {
+    type Fr = Fr;
+    type G1 = G1;
+    type G1Affine = G1Affine;
+    type G2 = G2;
+    type G2Affine = G2Affine;
+    type Gt = Gt;
+
+    fn pairing(p: &G1Affine, q: &G2Affine) -> Gt {
+        match (p.0, q.0) {
+            (Some(p), Some(q)) => Gt(crate::pairing(p.into(), q.into())),
+            _ => Gt::identity(),
+        }
+    }
+}
+
+impl MultiMillerLoop for Bn256 {
+    type G2Prepared = G2Prepared;
+    type Result = MillerLoopResult;
+
+    fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> MillerLoopResult {
+        let f = terms
+            .iter()
+            .fold(crate::Gt::one(), |f, (p, q)| match (p.0, q.0.as_ref()) {
+                (Some(p), Some(q)) => f * crate::miller_loop(&p, q),
+                _ => f,
+            });
+        MillerLoopResult(f)
+    }
+}
+
+impl PairingCurveAffine for G1Affine {
+    type Pair = G2Affine;
+    type PairingResult = Gt;
+
+    fn pairing_with(&self, other: &G2Affine) -> Gt {
+        Bn256::pairing(self, other)
+    }
+}
+
+impl PairingCurveAffine for G2Affine {
+    type Pair = G1Affine;
+    type PairingResult = Gt;
+
+    fn pairing_with(&self, other: &G1Affine) -> Gt {
+        Bn256::pairing(other, self)
+    }
+}
+
+#[test]
+fn trait_laws() {
+    use pairing::MillerLoopResult as _;
+
+    // the field constants
+    for x in [Fr::TWO_INV, Fr::ROOT_OF_UNITY, Fr::DELTA] {
+        assert!(bool::from(x.is_odd()) != bool::from(x.is_even()));
+    }
+    assert_eq!(Fr::TWO_INV.double(), Fr::ONE);
+    assert_eq!(Fr::MULTIPLICATIVE_GENERATOR, Fr::from(5u64));
+    assert_eq!(Fr::ROOT_OF_UNITY * Fr::ROOT_OF_UNITY_INV, Fr::ONE);
+    assert_eq!(Field::pow(&Fr::ROOT_OF_UNITY, [1u64 << Fr::S]), Fr::ONE);
+    assert_ne!(
+        Field::pow(&Fr::ROOT_OF_UNITY, [1u64 << (Fr::S - 1)]),
+        Fr::ONE
+    );
+    assert_eq!(
+        Field::pow(&Fr::MULTIPLICATIVE_GENERATOR, [1u64 << Fr::S]),
+        Fr::DELTA
+    );
+    assert_eq!(Fq::TWO_INV.double(), Fq::ONE);
+    assert_eq!(Fq::MULTIPLICATIVE_GENERATOR, Fq::from(3u64));
+    assert_eq!(Fq::ROOT_OF_UNITY, -Fq::ONE);
+    assert_eq!(Fq::DELTA, Fq::from(9u64));
+    assert_eq!(Fq::ZERO, Fq::zero());
+    assert_eq!(
+        Fr::from_str_vartime(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616"
+        ),
+        Some(-Fr::ONE)
+    );
+
+    let x = Fr::from(1234567u64);
+    assert_eq!(Fr::from_repr(x.to_repr()).unwrap(), x);
+    assert_eq!(x.to_repr()[..3], [0x87, 0xd6, 0x12]);
+    assert!(bool::from(Fr::from_repr([0xff; 32]).is_none()));
+    assert_eq!(x.invert().unwrap() * x, Fr::ONE);
+    assert!(bool::from(Fr::ZERO.invert().is_none()));
+    let (is_square, root) = Fr::sqrt_ratio(&x.square(), &Fr::from(4u64));
+    assert!(bool::from(is_square));
+    assert_eq!(root.square() * Fr::from(4u64), x.square());
+    let (is_square, root) = Fq::sqrt_ratio(&-Fq::ONE, &Fq::ONE);
+    assert!(!bool::from(is_square));
+    assert_eq!(root.square(), Fq::ROOT_OF_UNITY * -Fq::ONE);
+
+    // encodings
+    let p = G1::generator() * x;
+    assert_eq!(G1::from_bytes(&p.to_bytes()).unwrap(), p);
+    let a = p.to_affine();
+    assert_eq!(
+        G1Affine::from_uncompressed(&a.to_uncompressed()).unwrap(),
+        a
+    );
+    assert!(G1Affine::identity().to_uncompressed() == G1Uncompressed::default());
+    let q = G2::generator() * x;
+    assert!(G2::from_bytes(&q.to_bytes()).unwrap() == q);
+    let b = G2Affine::from(q);
+    assert!(G2Affine::from_uncompressed(&b.to_uncompressed()).unwrap() == b);
+    assert!(bool::from(
+        G2Affine::from_bytes(&G2::identity().to_bytes())
+            .unwrap()
+            .is_identity()
+    ));
+
+    // groups and the pairing
+    assert_eq!(p + G1Affine::identity(), p);
+    assert_eq!(p + -a, G1::identity());
+    assert_eq!(a * x, p * x);
+    let mut normalized = [G1Affine::identity(); 3];
+    <G1 as Curve>::batch_normalize(&[p, G1::identity(), p.double()], &mut normalized);
+    assert_eq!(normalized, [a, G1Affine::identity(), (p + p).to_affine()]);
+
+    let g = Gt::generator();
+    assert_eq!(Bn256::pairing(&a, &G2Affine::generator()), g * x);
+    assert_eq!(b.pairing_with(&G1Affine::generator()), g * x);
+    assert_eq!(g - g, Gt::identity());
+    assert_eq!(Bn256::pairing(&G1Affine::identity(), &b), Gt::identity());
+    let terms = [
+        (&a, &G2Prepared::from(G2Affine::generator())),
+        (
+            &-G1Affine::generator(),
+            &G2Prepared::from(G2Affine::from(G2::generator() * x)),
+        ),
+        (
+            &G1Affine::generator(),
+            &G2Prepared::from(G2Affine::identity()),
+        ),
+    ];
+    assert!(bool::from(
+        Bn256::multi_miller_loop(&terms)
+            .final_exponentiation()
+            .is_identity()
+    ));
+}
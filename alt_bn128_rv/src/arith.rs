@@ -1,14 +1,46 @@
 use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Rem, Shl, Shr};
 use crunchy::unroll;
 
-use byteorder::{BigEndian, ByteOrder};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "bmi2",
+    target_feature = "adx"
+))]
+mod adx;
 
 /// 256-bit, stack allocated biginteger for use in prime field
 /// arithmetic.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub struct U256(pub [u128; 2]);
 
+impl ConditionallySelectable for U256 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        U256([
+            u128::conditional_select(&a.0[0], &b.0[0], choice),
+            u128::conditional_select(&a.0[1], &b.0[1], choice),
+        ])
+    }
+}
+
+impl ConstantTimeEq for U256 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[0].ct_eq(&other.0[0]) & self.0[1].ct_eq(&other.0[1])
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for U256 {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl From<[u64; 4]> for U256 {
     fn from(d: [u64; 4]) -> Self {
         let mut a = [0u128; 2];
@@ -26,7 +58,7 @@ impl From<u64> for U256 {
 
 /// 512-bit, stack allocated biginteger for use in extension
 /// field serialization and scalar interpretation.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub struct U512(pub [u128; 4]);
 
@@ -41,6 +73,12 @@ impl From<[u64; 8]> for U512 {
     }
 }
 
+impl From<U256> for U512 {
+    fn from(d: U256) -> Self {
+        U512([d.0[0], d.0[1], 0, 0])
+    }
+}
+
 impl U512 {
     /// Multiplies c1 by modulo, adds c0.
     pub fn new(c1: &U256, c0: &U256, modulo: &U256) -> U512 {
@@ -81,7 +119,7 @@ impl U512 {
     pub fn from_slice(s: &[u8]) -> Result<U512, Error> {
         if s.len() != 64 {
             return Err(Error::InvalidLength {
-                expected: 32,
+                expected: 64,
                 actual: s.len(),
             });
         }
@@ -94,6 +132,20 @@ impl U512 {
         Ok(U512(n))
     }
 
+    pub fn to_big_endian(&self, s: &mut [u8]) -> Result<(), Error> {
+        if s.len() != 64 {
+            return Err(Error::InvalidLength {
+                expected: 64,
+                actual: s.len(),
+            });
+        }
+        BigEndian::write_u128(&mut s[0..], self.0[3]);
+        BigEndian::write_u128(&mut s[16..], self.0[2]);
+        BigEndian::write_u128(&mut s[32..], self.0[1]);
+        BigEndian::write_u128(&mut s[48..], self.0[0]);
+        Ok(())
+    }
+
     pub fn get_bit(&self, n: usize) -> Option<bool> {
         if n >= 512 {
             None
@@ -131,6 +183,12 @@ impl U512 {
         }
     }
 
+    /// Divides self by a full-width `divisor`, returning quotient and
+    /// remainder, or `None` if the divisor is zero.
+    pub fn divrem_u512(&self, divisor: &U512) -> Option<(U512, U512)> {
+        long_divrem(&self.0, &divisor.0).map(|(q, r)| (U512(q), U512(r)))
+    }
+
     pub fn interpret(buf: &[u8; 64]) -> U512 {
         let mut n = [0; 4];
         n[3] = BigEndian::read_u128(&buf[0..]);
@@ -139,11 +197,38 @@ impl U512 {
         n[0] = BigEndian::read_u128(&buf[48..]);
         U512(n)
     }
+
+    /// The high and low 256 bits.
+    pub fn halves(&self) -> (U256, U256) {
+        (U256([self.0[2], self.0[3]]), U256([self.0[0], self.0[1]]))
+    }
+}
+
+impl Rem for U512 {
+    type Output = U512;
+
+    fn rem(self, divisor: U512) -> U512 {
+        self.divrem_u512(&divisor)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+            .1
+    }
 }
 
 impl Ord for U512 {
     #[inline]
     fn cmp(&self, other: &U512) -> Ordering {
+        if self.0[3] < other.0[3] {
+            return Ordering::Less;
+        }
+        if self.0[3] > other.0[3] {
+            return Ordering::Greater;
+        }
+        if self.0[2] < other.0[2] {
+            return Ordering::Less;
+        }
+        if self.0[2] > other.0[2] {
+            return Ordering::Greater;
+        }
         if self.0[1] < other.0[1] {
             return Ordering::Less;
         }
@@ -187,6 +272,18 @@ pub enum Error {
     InvalidLength { expected: usize, actual: usize },
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidLength { expected, actual } => {
+                write!(f, "expected {} bytes, got {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
 impl U256 {
     /// Initialize U256 from slice of bytes (big endian)
     pub fn from_slice(s: &[u8]) -> Result<U256, Error> {
@@ -214,6 +311,32 @@ impl U256 {
         Ok(())
     }
 
+    /// Initialize U256 from slice of bytes (little endian)
+    pub fn from_le_slice(s: &[u8]) -> Result<U256, Error> {
+        if s.len() != 32 {
+            return Err(Error::InvalidLength {
+                expected: 32,
+                actual: s.len(),
+            });
+        }
+        let mut n = [0; 2];
+        n[0] = LittleEndian::read_u128(&s[0..]);
+        n[1] = LittleEndian::read_u128(&s[16..]);
+        Ok(U256(n))
+    }
+
+    pub fn to_little_endian(&self, s: &mut [u8]) -> Result<(), Error> {
+        if s.len() != 32 {
+            return Err(Error::InvalidLength {
+                expected: 32,
+                actual: s.len(),
+            });
+        }
+        LittleEndian::write_u128(&mut s[0..], self.0[0]);
+        LittleEndian::write_u128(&mut s[16..], self.0[1]);
+        Ok(())
+    }
+
     #[inline]
     pub fn zero() -> U256 {
         U256([0, 0])
@@ -259,39 +382,65 @@ impl U256 {
     /// Add `other` to `self` (mod `modulo`)
     pub fn add(&mut self, other: &U256, modulo: &U256) {
         add_nocarry(&mut self.0, &other.0);
-
-        if *self >= *modulo {
-            sub_noborrow(&mut self.0, &modulo.0);
-        }
+        reduce_once(&mut self.0, &modulo.0);
     }
 
     /// Subtract `other` from `self` (mod `modulo`)
     pub fn sub(&mut self, other: &U256, modulo: &U256) {
-        if *self < *other {
-            add_nocarry(&mut self.0, &modulo.0);
-        }
-
-        sub_noborrow(&mut self.0, &other.0);
+        let borrow = sub_borrow(&mut self.0, &other.0);
+        // add the modulus back if the subtraction wrapped
+        let mask = borrow.wrapping_neg();
+        add_wrapping(&mut self.0, &[modulo.0[0] & mask, modulo.0[1] & mask]);
     }
 
     /// Multiply `self` by `other` (mod `modulo`) via the Montgomery
     /// multiplication method.
     pub fn mul(&mut self, other: &U256, modulo: &U256, inv: u128) {
+        #[cfg(all(
+            target_arch = "x86_64",
+            target_feature = "bmi2",
+            target_feature = "adx"
+        ))]
+        adx::mul_reduce(&mut self.0, &other.0, &modulo.0, inv);
+        #[cfg(all(
+            target_pointer_width = "64",
+            not(target_family = "wasm"),
+            not(all(
+                target_arch = "x86_64",
+                target_feature = "bmi2",
+                target_feature = "adx"
+            ))
+        ))]
+        mul_reduce_cios(&mut self.0, &other.0, &modulo.0, inv);
+        #[cfg(target_family = "wasm")]
+        mul_reduce_cios32(&mut self.0, &other.0, &modulo.0, inv);
+        #[cfg(not(any(target_pointer_width = "64", target_family = "wasm")))]
         mul_reduce(&mut self.0, &other.0, &modulo.0, inv);
+        reduce_once(&mut self.0, &modulo.0);
+    }
 
-        if *self >= *modulo {
-            sub_noborrow(&mut self.0, &modulo.0);
-        }
+    /// Square `self` (mod `modulo`) in Montgomery form; the cross product
+    /// of the limbs is computed once and doubled.
+    pub fn square(&mut self, modulo: &U256, inv: u128) {
+        square_reduce(&mut self.0, &modulo.0, inv);
+        reduce_once(&mut self.0, &modulo.0);
+    }
+
+    /// Double `self` (mod `modulo`). `self` is below the modulus, which is
+    /// below 2^255, so the shift cannot overflow.
+    pub fn double(&mut self, modulo: &U256) {
+        self.0[1] = (self.0[1] << 1) | (self.0[0] >> 127);
+        self.0[0] <<= 1;
+        reduce_once(&mut self.0, &modulo.0);
     }
 
     /// Turn `self` into its additive inverse (mod `modulo`)
     pub fn neg(&mut self, modulo: &U256) {
-        if *self > Self::zero() {
-            let mut tmp = modulo.0;
-            sub_noborrow(&mut tmp, &self.0);
-
-            self.0 = tmp;
-        }
+        let nonzero = ((self.0[0] | self.0[1]) != 0) as u128;
+        let mask = nonzero.wrapping_neg();
+        let mut tmp = modulo.0;
+        sub_noborrow(&mut tmp, &self.0);
+        self.0 = [tmp[0] & mask, tmp[1] & mask];
     }
 
     #[inline]
@@ -348,11 +497,288 @@ impl U256 {
         }
     }
 
+    /// Divides self by `divisor`, returning quotient and remainder, or
+    /// `None` if the divisor is zero.
+    pub fn divrem(&self, divisor: &U256) -> Option<(U256, U256)> {
+        long_divrem(&self.0, &divisor.0).map(|(q, r)| (U256(q), U256(r)))
+    }
+
+    /// Extended Euclidean algorithm over arbitrary (not necessarily odd or
+    /// prime) inputs. See `ExtGcd` for the form of the coefficients.
+    pub fn ext_gcd(&self, other: &U256) -> ExtGcd {
+        // Coefficient magnitudes of consecutive remainders; the signs
+        // alternate, so `r_i = (-1)^i (s_i * self - t_i * other)`.
+        let (mut r0, mut r1) = (*self, *other);
+        let (mut s0, mut s1) = (U256::one(), U256::zero());
+        let (mut t0, mut t1) = (U256::zero(), U256::one());
+        let mut negated = false;
+
+        while !r1.is_zero() {
+            let (q, r) = r0.divrem(&r1).expect("divisor is not zero; qed");
+            let s = s0.wrapping_add(&q.wrapping_mul(&s1));
+            let t = t0.wrapping_add(&q.wrapping_mul(&t1));
+            r0 = r1;
+            r1 = r;
+            s0 = s1;
+            s1 = s;
+            t0 = t1;
+            t1 = t;
+            negated = !negated;
+        }
+
+        ExtGcd {
+            gcd: r0,
+            x: s0,
+            y: t0,
+            negated,
+        }
+    }
+
+    /// The inverse of self modulo an arbitrary `modulus`, or `None` if they
+    /// are not coprime or the modulus is zero.
+    pub fn invert_mod(&self, modulus: &U256) -> Option<U256> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let a = *self % *modulus;
+        let e = a.ext_gcd(modulus);
+        if e.gcd != U256::one() {
+            return None;
+        }
+        let x = e.x % *modulus;
+        if e.negated && !x.is_zero() {
+            let mut inv = *modulus;
+            sub_noborrow(&mut inv.0, &x.0);
+            Some(inv)
+        } else {
+            Some(x)
+        }
+    }
+
     /// Return an Iterator<Item=bool> over all bits from
     /// MSB to LSB.
     pub fn bits(&self) -> BitIterator {
         BitIterator { int: &self, n: 256 }
     }
+
+    /// The full 512-bit product `self * other`.
+    pub fn widening_mul(&self, other: &U256) -> U512 {
+        U512(limbs_mul(&self.0, &other.0))
+    }
+}
+
+/// Integer (not modular) arithmetic, conversions and shifts common to
+/// `U256` and `U512`. `$n` is the number of 128-bit limbs and `$wide`
+/// twice that.
+macro_rules! full_width {
+    ($t:ident, $n:expr, $wide:expr) => {
+        impl $t {
+            /// `self + other` and whether it wrapped around.
+            pub fn overflowing_add(&self, other: &$t) -> ($t, bool) {
+                let mut r = self.0;
+                let mut carry = 0;
+                for (a, b) in r.iter_mut().zip(other.0.iter()) {
+                    *a = adc(*a, *b, &mut carry);
+                }
+                ($t(r), carry != 0)
+            }
+
+            pub fn checked_add(&self, other: &$t) -> Option<$t> {
+                match self.overflowing_add(other) {
+                    (r, false) => Some(r),
+                    _ => None,
+                }
+            }
+
+            pub fn wrapping_add(&self, other: &$t) -> $t {
+                self.overflowing_add(other).0
+            }
+
+            /// `self - other` and whether it wrapped around.
+            pub fn overflowing_sub(&self, other: &$t) -> ($t, bool) {
+                let mut r = self.0;
+                let mut borrow = false;
+                for (a, b) in r.iter_mut().zip(other.0.iter()) {
+                    let (x, o1) = a.overflowing_sub(*b);
+                    let (x, o2) = x.overflowing_sub(borrow as u128);
+                    *a = x;
+                    borrow = o1 | o2;
+                }
+                ($t(r), borrow)
+            }
+
+            pub fn checked_sub(&self, other: &$t) -> Option<$t> {
+                match self.overflowing_sub(other) {
+                    (r, false) => Some(r),
+                    _ => None,
+                }
+            }
+
+            pub fn wrapping_sub(&self, other: &$t) -> $t {
+                self.overflowing_sub(other).0
+            }
+
+            /// The low half of the full product and whether the high half
+            /// is nonzero.
+            pub fn overflowing_mul(&self, other: &$t) -> ($t, bool) {
+                let wide: [u128; $wide] = limbs_mul(&self.0, &other.0);
+                let mut r = [0u128; $n];
+                r.copy_from_slice(&wide[..$n]);
+                ($t(r), wide[$n..].iter().any(|&x| x != 0))
+            }
+
+            pub fn checked_mul(&self, other: &$t) -> Option<$t> {
+                match self.overflowing_mul(other) {
+                    (r, false) => Some(r),
+                    _ => None,
+                }
+            }
+
+            pub fn wrapping_mul(&self, other: &$t) -> $t {
+                self.overflowing_mul(other).0
+            }
+
+            /// `self^exp mod modulus` for any nonzero modulus, by
+            /// square-and-multiply with full-width products. Variable time.
+            pub fn mod_exp(&self, exp: &$t, modulus: &$t) -> Option<$t> {
+                if modulus.0.iter().all(|&x| x == 0) {
+                    return None;
+                }
+                let m: [u128; $wide] = widen(&modulus.0);
+                let mul_mod = |a: &[u128; $n], b: &[u128; $n]| {
+                    let (_, r) = long_divrem(&limbs_mul::<$n, $wide>(a, b), &m)
+                        .expect("modulus is not zero; qed");
+                    let mut out = [0u128; $n];
+                    out.copy_from_slice(&r[..$n]);
+                    out
+                };
+                let (_, base) =
+                    long_divrem(&self.0, &modulus.0).expect("modulus is not zero; qed");
+                let mut one = [0u128; $n];
+                one[0] = 1;
+                let (_, mut acc) =
+                    long_divrem(&one, &modulus.0).expect("modulus is not zero; qed");
+                for i in (0..exp.bit_length()).rev() {
+                    acc = mul_mod(&acc, &acc);
+                    if (exp.0[i / 128] >> (i % 128)) & 1 == 1 {
+                        acc = mul_mod(&acc, &base);
+                    }
+                }
+                Some($t(acc))
+            }
+
+            /// The number of bits needed to represent `self`; zero for zero.
+            pub fn bit_length(&self) -> usize {
+                for i in (0..$n).rev() {
+                    if self.0[i] != 0 {
+                        return 128 * i + 128 - self.0[i].leading_zeros() as usize;
+                    }
+                }
+                0
+            }
+
+            /// Little-endian 64-bit limbs, the inverse of `From<[u64; _]>`.
+            pub fn to_u64_limbs(&self) -> [u64; 2 * $n] {
+                let mut out = [0u64; 2 * $n];
+                for (pair, limb) in out.chunks_mut(2).zip(self.0.iter()) {
+                    pair[0] = *limb as u64;
+                    pair[1] = (*limb >> 64) as u64;
+                }
+                out
+            }
+
+            pub fn to_be_bytes(&self) -> [u8; 16 * $n] {
+                let mut out = [0u8; 16 * $n];
+                for (chunk, limb) in out.chunks_mut(16).rev().zip(self.0.iter()) {
+                    chunk.copy_from_slice(&limb.to_be_bytes());
+                }
+                out
+            }
+
+            pub fn from_be_bytes(bytes: &[u8; 16 * $n]) -> $t {
+                let mut n = [0u128; $n];
+                for (limb, chunk) in n.iter_mut().zip(bytes.chunks(16).rev()) {
+                    *limb = BigEndian::read_u128(chunk);
+                }
+                $t(n)
+            }
+
+            pub fn to_le_bytes(&self) -> [u8; 16 * $n] {
+                let mut out = [0u8; 16 * $n];
+                for (chunk, limb) in out.chunks_mut(16).zip(self.0.iter()) {
+                    chunk.copy_from_slice(&limb.to_le_bytes());
+                }
+                out
+            }
+
+            pub fn from_le_bytes(bytes: &[u8; 16 * $n]) -> $t {
+                let mut n = [0u128; $n];
+                for (limb, chunk) in n.iter_mut().zip(bytes.chunks(16)) {
+                    *limb = LittleEndian::read_u128(chunk);
+                }
+                $t(n)
+            }
+        }
+
+        /// Shifts in zeros; shifting by the width or more gives zero.
+        impl Shl<usize> for $t {
+            type Output = $t;
+
+            fn shl(self, shift: usize) -> $t {
+                let (limbs, bits) = (shift / 128, shift % 128);
+                let mut r = [0u128; $n];
+                for i in limbs..$n {
+                    r[i] = self.0[i - limbs] << bits;
+                    if bits > 0 && i > limbs {
+                        r[i] |= self.0[i - limbs - 1] >> (128 - bits);
+                    }
+                }
+                $t(r)
+            }
+        }
+
+        /// Shifts in zeros; shifting by the width or more gives zero.
+        impl Shr<usize> for $t {
+            type Output = $t;
+
+            fn shr(self, shift: usize) -> $t {
+                let (limbs, bits) = (shift / 128, shift % 128);
+                let mut r = [0u128; $n];
+                for i in 0..($n as usize).saturating_sub(limbs) {
+                    r[i] = self.0[i + limbs] >> bits;
+                    if bits > 0 && i + limbs + 1 < $n {
+                        r[i] |= self.0[i + limbs + 1] << (128 - bits);
+                    }
+                }
+                $t(r)
+            }
+        }
+    };
+}
+
+full_width!(U256, 2, 4);
+full_width!(U512, 4, 8);
+
+impl Rem for U256 {
+    type Output = U256;
+
+    fn rem(self, divisor: U256) -> U256 {
+        self.divrem(&divisor)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+            .1
+    }
+}
+
+/// Result of `U256::ext_gcd(a, b)`: `gcd = a * x - b * y`, or
+/// `gcd = b * y - a * x` if `negated`. The coefficients are the minimal
+/// ones produced by Euclid's algorithm, so they do not exceed `b / gcd`
+/// and `a / gcd`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExtGcd {
+    pub gcd: U256,
+    pub x: U256,
+    pub y: U256,
+    pub negated: bool,
 }
 
 pub struct BitIterator<'a> {
@@ -392,6 +818,76 @@ fn mul2(a: &mut [u128; 2]) {
     a[1] |= tmp;
 }
 
+/// Schoolbook binary long division over little-endian limbs.
+fn long_divrem<const N: usize>(n: &[u128; N], d: &[u128; N]) -> Option<([u128; N], [u128; N])> {
+    if d.iter().all(|&x| x == 0) {
+        return None;
+    }
+
+    let mut q = [0u128; N];
+    let mut r = [0u128; N];
+
+    for i in (0..N * 128).rev() {
+        // the bit shifted out of r means r >= 2^(128 * N) > d
+        let carry = r[N - 1] >> 127 == 1;
+        let mut bit = (n[i / 128] >> (i % 128)) & 1;
+        for limb in r.iter_mut() {
+            let top = *limb >> 127;
+            *limb = (*limb << 1) | bit;
+            bit = top;
+        }
+
+        if carry || !limbs_lt(&r, d) {
+            let mut borrow = false;
+            for (a, b) in r.iter_mut().zip(d.iter()) {
+                let (x, o1) = a.overflowing_sub(*b);
+                let (x, o2) = x.overflowing_sub(borrow as u128);
+                *a = x;
+                borrow = o1 | o2;
+            }
+            q[i / 128] |= 1 << (i % 128);
+        }
+    }
+
+    Some((q, r))
+}
+
+/// The full product of `a` and `b`; `M` must be `2 * N`.
+fn limbs_mul<const N: usize, const M: usize>(a: &[u128; N], b: &[u128; N]) -> [u128; M] {
+    debug_assert_eq!(M, 2 * N);
+    let mut r = [0u128; M];
+    for i in 0..N {
+        let mut carry = 0;
+        for j in 0..N {
+            let (hi, lo) = mul_wide(a[i], b[j]);
+            let (x, o1) = r[i + j].overflowing_add(lo);
+            let (x, o2) = x.overflowing_add(carry);
+            r[i + j] = x;
+            // hi is at most 2^128 - 2, so this cannot overflow
+            carry = hi + o1 as u128 + o2 as u128;
+        }
+        r[i + N] = carry;
+    }
+    r
+}
+
+/// Zero-extends `a` to `M` limbs.
+fn widen<const N: usize, const M: usize>(a: &[u128; N]) -> [u128; M] {
+    let mut r = [0u128; M];
+    r[..N].copy_from_slice(a);
+    r
+}
+
+#[inline]
+fn limbs_lt<const N: usize>(a: &[u128; N], b: &[u128; N]) -> bool {
+    for i in (0..N).rev() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
 #[inline(always)]
 fn split_u128(i: u128) -> (u128, u128) {
     (i >> 64, i & 0xFFFFFFFFFFFFFFFF)
@@ -424,6 +920,36 @@ fn sub_noborrow(a: &mut [u128; 2], b: &[u128; 2]) {
     a[1] = a[1] - b[1] - d as u128;
 }
 
+/// `a -= b` modulo 2^256, returning 1 if it wrapped.
+#[inline]
+fn sub_borrow(a: &mut [u128; 2], b: &[u128; 2]) -> u128 {
+    let (lo, b0) = a[0].overflowing_sub(b[0]);
+    let (hi, b1) = a[1].overflowing_sub(b[1]);
+    let (hi, b2) = hi.overflowing_sub(b0 as u128);
+    a[0] = lo;
+    a[1] = hi;
+    (b1 | b2) as u128
+}
+
+/// `a += b` modulo 2^256.
+#[inline]
+fn add_wrapping(a: &mut [u128; 2], b: &[u128; 2]) {
+    let (lo, carry) = a[0].overflowing_add(b[0]);
+    a[0] = lo;
+    a[1] = a[1].wrapping_add(b[1]).wrapping_add(carry as u128);
+}
+
+/// Subtracts `modulus` from `a` if `a >= modulus`, selecting the result
+/// with a mask rather than a branch so the reduction of field operations
+/// takes the same time for every input.
+#[inline]
+fn reduce_once(a: &mut [u128; 2], modulus: &[u128; 2]) {
+    let mut t = *a;
+    let mask = sub_borrow(&mut t, modulus).wrapping_neg();
+    a[0] = (a[0] & mask) | (t[0] & !mask);
+    a[1] = (a[1] & mask) | (t[1] & !mask);
+}
+
 // TODO: Make `from_index` a const param
 #[inline(always)]
 fn mac_digit(from_index: usize, acc: &mut [u128; 4], b: &[u128; 2], c: u128) {
@@ -472,6 +998,7 @@ fn mac_digit(from_index: usize, acc: &mut [u128; 4], b: &[u128; 2], c: u128) {
     debug_assert!(carry == 0);
 }
 
+#[cfg(any(test, not(any(target_pointer_width = "64", target_family = "wasm"))))]
 #[inline]
 fn mul_reduce(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u128) {
     // The Montgomery reduction here is based on Algorithm 14.32 in
@@ -494,3 +1021,297 @@ fn mul_reduce(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u1
 
     this.copy_from_slice(&res[2..]);
 }
+
+/// The full 256-bit product `a * b` as `(hi, lo)`.
+#[inline(always)]
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    let (a_hi, a_lo) = split_u128(a);
+    let (b_hi, b_lo) = split_u128(b);
+    let (ll_hi, ll_lo) = split_u128(a_lo * b_lo);
+    let (lh_hi, lh_lo) = split_u128(a_lo * b_hi);
+    let (hl_hi, hl_lo) = split_u128(a_hi * b_lo);
+    let (mid_hi, mid_lo) = split_u128(ll_hi + lh_lo + hl_lo);
+
+    (
+        a_hi * b_hi + lh_hi + hl_hi + mid_hi,
+        combine_u128(mid_lo, ll_lo),
+    )
+}
+
+#[inline]
+fn square_reduce(this: &mut [u128; 2], modulus: &[u128; 2], inv: u128) {
+    let (cross_hi, cross_lo) = mul_wide(this[0], this[1]);
+    let (hi0, lo0) = mul_wide(this[0], this[0]);
+    let (hi1, lo1) = mul_wide(this[1], this[1]);
+
+    // a0^2 + 2 a0 a1 2^128 + a1^2 2^256
+    let doubled = [
+        cross_lo << 1,
+        (cross_hi << 1) | (cross_lo >> 127),
+        cross_hi >> 127,
+    ];
+    let mut carry = 0;
+    let mut res = [lo0, 0, 0, 0];
+    res[1] = adc(hi0, doubled[0], &mut carry);
+    res[2] = adc(lo1, doubled[1], &mut carry);
+    res[3] = adc(hi1, doubled[2], &mut carry);
+    debug_assert!(carry == 0);
+
+    unroll! {
+        for i in 0..2 {
+            let k = inv.wrapping_mul(res[i]);
+            mac_digit(i, &mut res, modulus, k);
+        }
+    }
+
+    this.copy_from_slice(&res[2..]);
+}
+
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+#[inline(always)]
+fn to_u64_limbs(a: &[u128; 2]) -> [u64; 4] {
+    [
+        a[0] as u64,
+        (a[0] >> 64) as u64,
+        a[1] as u64,
+        (a[1] >> 64) as u64,
+    ]
+}
+
+/// `a + b * c + carry` as `(low, high)` 64-bit words; it cannot overflow
+/// 128 bits.
+#[cfg_attr(
+    any(
+        all(target_arch = "x86_64", target_feature = "bmi2", target_feature = "adx"),
+        target_family = "wasm"
+    ),
+    allow(dead_code)
+)]
+#[inline(always)]
+fn mac64(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Montgomery multiplication over four 64-bit limbs with widening
+/// `u128` products, interleaving each row of the product with a step of
+/// the reduction (CIOS, Koc et al. 1996). Targets with a native
+/// 64x64->128 multiplier do a quarter of the multiplications of the
+/// 128-bit limb path.
+#[cfg_attr(
+    any(
+        all(target_arch = "x86_64", target_feature = "bmi2", target_feature = "adx"),
+        target_family = "wasm"
+    ),
+    allow(dead_code)
+)]
+#[inline]
+fn mul_reduce_cios(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u128) {
+    let a = to_u64_limbs(this);
+    let b = to_u64_limbs(by);
+    let n = to_u64_limbs(modulus);
+    let inv = inv as u64;
+
+    let mut t = [0u64; 6];
+    unroll! {
+        for i in 0..4 {
+            let mut carry = 0;
+            unroll! {
+                for j in 0..4 {
+                    let (lo, hi) = mac64(t[j], a[j], b[i], carry);
+                    t[j] = lo;
+                    carry = hi;
+                }
+            }
+            let (lo, hi) = mac64(t[4], 1, carry, 0);
+            t[4] = lo;
+            t[5] = hi;
+
+            let m = t[0].wrapping_mul(inv);
+            let (_, mut carry) = mac64(t[0], m, n[0], 0);
+            unroll! {
+                for j in 1..4 {
+                    let (lo, hi) = mac64(t[j], m, n[j], carry);
+                    t[j - 1] = lo;
+                    carry = hi;
+                }
+            }
+            let (lo, hi) = mac64(t[4], 1, carry, 0);
+            t[3] = lo;
+            t[4] = t[5] + hi;
+        }
+    }
+    debug_assert!(t[4] == 0);
+
+    this[0] = (t[1] as u128) << 64 | t[0] as u128;
+    this[1] = (t[3] as u128) << 64 | t[2] as u128;
+}
+
+/// `a + b * c + carry` as `(low, high)` 32-bit words.
+#[cfg(any(test, target_family = "wasm"))]
+#[inline(always)]
+fn mac32(a: u32, b: u32, c: u32, carry: u32) -> (u32, u32) {
+    let t = a as u64 + (b as u64) * (c as u64) + carry as u64;
+    (t as u32, (t >> 32) as u32)
+}
+
+/// `mul_reduce_cios` over eight 32-bit limbs. WebAssembly has a native
+/// 64-bit multiply but no widening one, so every `u128` product costs a
+/// libcall there, while these `u64` products are single instructions on
+/// both wasm32 and wasm64.
+#[cfg(any(test, target_family = "wasm"))]
+#[inline]
+fn mul_reduce_cios32(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u128) {
+    let limbs = |x: &[u128; 2]| {
+        let mut out = [0u32; 8];
+        for (i, limb) in out.iter_mut().enumerate() {
+            *limb = (x[i / 4] >> (32 * (i % 4))) as u32;
+        }
+        out
+    };
+    let a = limbs(this);
+    let b = limbs(by);
+    let n = limbs(modulus);
+    let inv = inv as u32;
+
+    let mut t = [0u32; 10];
+    unroll! {
+        for i in 0..8 {
+            let mut carry = 0;
+            unroll! {
+                for j in 0..8 {
+                    let (lo, hi) = mac32(t[j], a[j], b[i], carry);
+                    t[j] = lo;
+                    carry = hi;
+                }
+            }
+            let (lo, hi) = mac32(t[8], 1, carry, 0);
+            t[8] = lo;
+            t[9] = hi;
+
+            let m = t[0].wrapping_mul(inv);
+            let (_, mut carry) = mac32(t[0], m, n[0], 0);
+            unroll! {
+                for j in 1..8 {
+                    let (lo, hi) = mac32(t[j], m, n[j], carry);
+                    t[j - 1] = lo;
+                    carry = hi;
+                }
+            }
+            let (lo, hi) = mac32(t[8], 1, carry, 0);
+            t[7] = lo;
+            t[8] = t[9] + hi;
+        }
+    }
+    debug_assert!(t[8] == 0);
+
+    for (i, limb) in t[..8].iter().enumerate() {
+        if i % 4 == 0 {
+            this[i / 4] = 0;
+        }
+        this[i / 4] |= (*limb as u128) << (32 * (i % 4));
+    }
+}
+
+#[test]
+fn cios_matches_mul_reduce() {
+    // the Fq modulus and -q^-1 mod 2^128
+    let q = [
+        0x97816a916871ca8d3c208c16d87cfd47,
+        0x30644e72e131a029b85045b68181585d,
+    ];
+    let inv = 0x9ede7d651eca6ac987d20782e4866389;
+    let mut a = [
+        0x1234_5678_9abc_def0_0fed_cba9_8765_4321,
+        0x2000_0000_0000_0000_ffff_ffff_ffff_ffff,
+    ];
+    let mut b = [q[0] - 1, q[1]];
+    for _ in 0..100 {
+        let mut x = a;
+        let mut y = a;
+        mul_reduce(&mut x, &b, &q, inv);
+        mul_reduce_cios(&mut y, &b, &q, inv);
+        assert_eq!(x, y);
+        mul_reduce_cios32(&mut y, &b, &q, inv);
+        mul_reduce(&mut x, &b, &q, inv);
+        assert_eq!(x, y);
+        b = a;
+        a = x;
+        reduce_once(&mut a, &q);
+    }
+}
+
+#[test]
+fn full_width_ops() {
+    let max = U256([u128::MAX, u128::MAX]);
+    assert_eq!(max.overflowing_add(&U256::one()), (U256::zero(), true));
+    assert_eq!(max.checked_add(&U256::one()), None);
+    assert_eq!(U256::zero().wrapping_sub(&U256::one()), max);
+    assert_eq!(U256::one().checked_sub(&max), None);
+    assert_eq!(
+        max.widening_mul(&max),
+        U512([1, 0, u128::MAX - 1, u128::MAX])
+    );
+    assert_eq!(max.checked_mul(&max), None);
+    assert_eq!(max.wrapping_mul(&max), U256::one());
+
+    let bit = U512::from(U256::one()) << 300;
+    assert!(bit.get_bit(300).unwrap());
+    assert_eq!(bit.bit_length(), 301);
+    assert_eq!(bit >> 300, U512::from(U256::one()));
+    assert_eq!(bit >> 512, U512::from(U256::zero()));
+    assert_eq!(max >> 129, U256([u128::MAX >> 1, 0]));
+    assert_eq!(max << 255 >> 255, U256::one());
+
+    // Fermat's little theorem for the Fq modulus, at both widths
+    let q = U256::from([
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+    let q_minus_1 = q.wrapping_sub(&U256::one());
+    let three = U256::from(3);
+    assert_eq!(three.mod_exp(&q_minus_1, &q), Some(U256::one()));
+    let wide = U512::from(three).mod_exp(&U512::from(q_minus_1), &U512::from(q));
+    assert_eq!(wide, Some(U512::from(U256::one())));
+    let m = U256::one() << 200;
+    assert_eq!(
+        U256::from(7).mod_exp(&U256::from(5), &m),
+        Some(U256::from(16807))
+    );
+    assert_eq!(three.mod_exp(&three, &U256::zero()), None);
+
+    let mut be = [0u8; 32];
+    q.to_big_endian(&mut be).unwrap();
+    assert_eq!(q.to_be_bytes(), be);
+    be.reverse();
+    assert_eq!(q.to_le_bytes(), be);
+    assert_eq!(U256::from_le_bytes(&be), q);
+    assert_eq!(U256::from(q.to_u64_limbs()), q);
+    let x = U512::from(q).wrapping_mul(&U512::from(q));
+    assert_eq!(U512::from_be_bytes(&x.to_be_bytes()), x);
+    assert_eq!(U512::from_le_bytes(&x.to_le_bytes()), x);
+    assert_eq!(U512::from(x.to_u64_limbs()), x);
+    assert_eq!(q.widening_mul(&q), x);
+    let (hi, lo) = x.halves();
+    assert_eq!((U512::from(hi) << 256).wrapping_add(&U512::from(lo)), x);
+}
+
+#[test]
+fn u512_ordering() {
+    let high = U512([0, 0, 0, 1]);
+    let low = U512([5, 0, 0, 0]);
+    assert!(high > low);
+    assert!(U512([0, 0, 1, 0]) > U512([u128::MAX, u128::MAX, 0, 0]));
+    assert!(U512([7, 0, 0, 2]) < U512([0, 0, 1, 2]));
+    assert_eq!(high.cmp(&high), Ordering::Equal);
+}
+
+#[test]
+fn u512_from_slice_length() {
+    match U512::from_slice(&[0u8; 32]) {
+        Err(Error::InvalidLength { expected, actual }) => assert_eq!((expected, actual), (64, 32)),
+        Ok(_) => panic!("32 bytes is not a U512"),
+    }
+}
@@ -0,0 +1,297 @@
+//! Baby Jubjub, the twisted Edwards curve `a * x^2 + y^2 = 1 + d * x^2 * y^2`
+//! with `a = 168700` and `d = 168696` over Fr, as specified in EIP-2494
+//! and used by circomlib. Its group has order `8 * ORDER`; keys and
+//! signatures live in the subgroup of prime order `ORDER` generated by
+//! `base8()`.
+//!
+//! `a` is a square and `d` is not, so the addition law is complete: it
+//! holds for every pair of points, doublings and the identity included.
+//! Points are kept in projective coordinates during scalar multiplication
+//! and normalized once at the end.
+
+use crate::arith::U256;
+use crate::fields::const_fr;
+use crate::{poseidon, Fr};
+use core::fmt;
+use core::ops::{Add, Neg, Sub};
+
+const A: Fr = Fr(const_fr([
+    0x95accf61fff261e0,
+    0x24780d659df7d378,
+    0xe0ac11b07e906ae8,
+    0x0f35db2216d3def3,
+]));
+
+const D: Fr = Fr(const_fr([
+    0x2735f484aff261f5,
+    0x70ba1b579a2e0f63,
+    0xff41c9a91e2caa8c,
+    0x07704a8e8fe6025f,
+]));
+
+/// The order of the prime subgroup.
+pub const ORDER: U256 = U256([
+    0xab3eedb83920ee0a677297dc392126f1,
+    0x060c89ce5c263405370a08b6d0302b0b,
+]);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A coordinate pair that is not on the curve.
+    NotOnCurve,
+    /// A signature scalar not below `ORDER`.
+    ScalarRange,
+    InvalidSignature,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::NotOnCurve => "point is not on the curve",
+            Error::ScalarRange => "signature scalar is not below the subgroup order",
+            Error::InvalidSignature => "invalid signature",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// An affine point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Point {
+    x: Fr,
+    y: Fr,
+}
+
+/// `(X : Y : Z)` with `x = X / Z` and `y = Y / Z`.
+#[derive(Copy, Clone)]
+struct Projective {
+    x: Fr,
+    y: Fr,
+    z: Fr,
+}
+
+impl Projective {
+    /// add-2008-bbjlp, complete on this curve.
+    fn add(&self, other: &Projective) -> Projective {
+        let a = self.z * other.z;
+        let b = a * a;
+        let c = self.x * other.x;
+        let d = self.y * other.y;
+        let e = D * c * d;
+        let f = b - e;
+        let g = b + e;
+        Projective {
+            x: a * f * ((self.x + self.y) * (other.x + other.y) - c - d),
+            y: a * g * (d - A * c),
+            z: f * g,
+        }
+    }
+
+    fn to_affine(self) -> Point {
+        let z_inv = self.z.inverse().expect("the addition law is complete; qed");
+        Point {
+            x: self.x * z_inv,
+            y: self.y * z_inv,
+        }
+    }
+}
+
+impl Point {
+    /// Checks that `(x, y)` is on the curve. It need not be in the prime
+    /// subgroup; see `in_subgroup`.
+    pub fn new(x: Fr, y: Fr) -> Result<Point, Error> {
+        let (x2, y2) = (x * x, y * y);
+        if A * x2 + y2 == Fr::one() + D * x2 * y2 {
+            Ok(Point { x, y })
+        } else {
+            Err(Error::NotOnCurve)
+        }
+    }
+
+    pub fn identity() -> Point {
+        Point {
+            x: Fr::zero(),
+            y: Fr::one(),
+        }
+    }
+
+    /// The generator of the full group, of order `8 * ORDER`.
+    pub fn generator() -> Point {
+        Point {
+            x: Fr(const_fr([
+                0x04b1094e6adddecd,
+                0x055ba7d9099f7fa1,
+                0x7bbf2870e518e5de,
+                0x0981eab540ef8b9b,
+            ])),
+            y: Fr(const_fr([
+                0x345a1f442ffffffd,
+                0x8764472692d3ae4c,
+                0xd70f2edc7b7bf6e8,
+                0x2ed314a75c6b1f82,
+            ])),
+        }
+    }
+
+    /// `8 * generator()`, the generator of the prime subgroup.
+    pub fn base8() -> Point {
+        Point {
+            x: Fr(const_fr([
+                0x0a8fc7bc1a89fa86,
+                0xa7d9d786e9e48627,
+                0xee6158b465bea369,
+                0x14a0ff6d2f874519,
+            ])),
+            y: Fr(const_fr([
+                0xb83342d20d0201aa,
+                0x2ffef2f7cdcfeac7,
+                0xbfa79a9425a6e625,
+                0x0dfb859dc3a44b70,
+            ])),
+        }
+    }
+
+    pub fn x(&self) -> Fr {
+        self.x
+    }
+
+    pub fn y(&self) -> Fr {
+        self.y
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Point::identity()
+    }
+
+    fn to_projective(self) -> Projective {
+        Projective {
+            x: self.x,
+            y: self.y,
+            z: Fr::one(),
+        }
+    }
+
+    pub fn double(&self) -> Point {
+        *self + *self
+    }
+
+    /// `self * scalar`, for any integer `scalar`.
+    pub fn mul(&self, scalar: &U256) -> Point {
+        let p = self.to_projective();
+        let mut acc = Point::identity().to_projective();
+        for bit in scalar.bits() {
+            acc = acc.add(&acc);
+            if bit {
+                acc = acc.add(&p);
+            }
+        }
+        acc.to_affine()
+    }
+
+    /// `8 * self`, which maps the whole group into the prime subgroup.
+    pub fn mul_by_cofactor(&self) -> Point {
+        self.double().double().double()
+    }
+
+    /// Whether the point is in the subgroup generated by `base8()`.
+    pub fn in_subgroup(&self) -> bool {
+        self.mul(&ORDER).is_identity()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        self.to_projective().add(&other.to_projective()).to_affine()
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point {
+            x: -self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        self + -other
+    }
+}
+
+/// An EdDSA signature in circomlib's encoding: `s` is the integer below
+/// `ORDER`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub r8: Point,
+    pub s: U256,
+}
+
+/// circomlib's `verifyPoseidon`: checks
+/// `s * base8 == r8 + 8 * h * public_key` for
+/// `h = Poseidon(r8.x, r8.y, public_key.x, public_key.y, message)`.
+pub fn verify(public_key: &Point, message: Fr, signature: &Signature) -> Result<(), Error> {
+    if signature.s >= ORDER {
+        return Err(Error::ScalarRange);
+    }
+    let r8 = signature.r8;
+    let h = poseidon::hash5(&[r8.x, r8.y, public_key.x, public_key.y, message]);
+    let lhs = Point::base8().mul(&signature.s);
+    let rhs = r8 + public_key.mul(&h.into_u256()).mul_by_cofactor();
+    if lhs == rhs {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+#[test]
+fn group_law() {
+    let g = Point::generator();
+    assert_eq!(Point::new(g.x(), g.y()), Ok(g));
+    assert_eq!(Point::new(g.y(), g.x()), Err(Error::NotOnCurve));
+    assert_eq!(g.mul_by_cofactor(), Point::base8());
+    assert!(Point::base8().in_subgroup());
+    assert!(!g.in_subgroup());
+    assert_eq!(g + Point::identity(), g);
+    assert!((g - g).is_identity());
+    let k = U256::from(12345u64);
+    let mut sum = Point::identity();
+    for _ in 0..12345 {
+        sum = sum + g;
+    }
+    assert_eq!(g.mul(&k), sum);
+}
+
+#[test]
+fn eddsa_poseidon() {
+    use crate::arith::U512;
+
+    let mul_mod = |a: U256, b: U256| U512::new(&a, &U256::zero(), &b).divrem(&ORDER).1;
+    // circomlib prunes the secret to a multiple of 8 and publishes
+    // `base8 * (secret / 8)`
+    let k = U256::from(987654321987654321u64);
+    let public_key = Point::base8().mul(&k);
+    let nonce = U256::from(1234567891011121314u64);
+    let r8 = Point::base8().mul(&nonce);
+    let message = Fr::from_str("42").unwrap();
+    let h = poseidon::hash5(&[r8.x(), r8.y(), public_key.x(), public_key.y(), message]);
+    let mut s = mul_mod(h.into_u256(), mul_mod(k, U256::from(8)));
+    s.add(&nonce, &ORDER);
+    let signature = Signature { r8, s };
+    assert_eq!(verify(&public_key, message, &signature), Ok(()));
+    assert_eq!(
+        verify(&public_key, message + Fr::one(), &signature),
+        Err(Error::InvalidSignature)
+    );
+    let high = Signature { r8, s: ORDER };
+    assert_eq!(verify(&public_key, message, &high), Err(Error::ScalarRange));
+}
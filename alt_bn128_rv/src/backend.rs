@@ -0,0 +1,157 @@
+//! The assembly and portable Rust arithmetic kernels, exposed
+//! side by side so downstream CI can check that they agree on real hardware.
+
+use crate::arith::U256;
+
+extern "C" {
+    fn ll_u256_mont_mul(ret: *mut u64, a: *const u64, b: *const u64, n: *const u64, k: u64);
+    fn ll_u256_mont_sqr(ret: *mut u64, a: *const u64, n: *const u64, k: u64);
+    fn ll_u256_mont_reduce(ret: *mut u64, a: *const u64, n: *const u64, k: u64);
+    fn ll_u256_add_mod(ret: *mut u64, a: *const u64, b: *const u64, n: *const u64);
+    fn ll_u256_sub_mod(ret: *mut u64, a: *const u64, b: *const u64, n: *const u64);
+}
+
+/// Outputs of the two kernels for an input on which they disagree.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub asm: U256,
+    pub portable: U256,
+}
+
+/// Montgomery multiplication through `ll_u256_mont_mul`, the kernel used by
+/// `Fq`/`Fr` multiplication in this crate.
+pub fn mont_mul_asm(a: &U256, b: &U256, modulus: &U256, inv: u128) -> U256 {
+    let mut ret = [0u128; 2];
+    unsafe {
+        ll_u256_mont_mul(
+            &mut ret as *mut u128 as *mut u64,
+            &a.0 as *const u128 as *const u64,
+            &b.0 as *const u128 as *const u64,
+            &modulus.0 as *const u128 as *const u64,
+            inv as u64,
+        );
+    }
+    U256(ret)
+}
+
+/// Montgomery multiplication through the portable `U256::mul`.
+pub fn mont_mul_portable(a: &U256, b: &U256, modulus: &U256, inv: u128) -> U256 {
+    let mut ret = *a;
+    ret.mul(b, modulus, inv);
+    ret
+}
+
+/// Runs both kernels on the same Montgomery-form operands and returns the
+/// common result, or both results if they differ.
+pub fn compare_mont_mul(a: &U256, b: &U256, modulus: &U256, inv: u128) -> Result<U256, Mismatch> {
+    let asm = mont_mul_asm(a, b, modulus, inv);
+    let portable = mont_mul_portable(a, b, modulus, inv);
+    if asm == portable {
+        Ok(asm)
+    } else {
+        Err(Mismatch { asm, portable })
+    }
+}
+
+/// `compare_mont_mul` over the `Fq` modulus.
+pub fn compare_fq_mul(a: &crate::Fq, b: &crate::Fq) -> Result<crate::Fq, Mismatch> {
+    let modulus = crate::fields::Fq::modulus();
+    let inv = (a.0).inv();
+    compare_mont_mul((a.0).raw(), (b.0).raw(), &modulus, inv)
+        .map(|raw| crate::Fq(crate::fields::Fq::from_raw(raw)))
+}
+
+/// `compare_mont_mul` over the `Fr` modulus.
+pub fn compare_fr_mul(a: &crate::Fr, b: &crate::Fr) -> Result<crate::Fr, Mismatch> {
+    let modulus = crate::fields::Fr::modulus();
+    let inv = (a.0).inv();
+    compare_mont_mul((a.0).raw(), (b.0).raw(), &modulus, inv)
+        .map(|raw| crate::Fr(crate::fields::Fr::from_raw(raw)))
+}
+
+/// Panics with both outputs if the kernels disagree on `a * b` in `Fq`.
+pub fn assert_fq_mul_eq(a: &crate::Fq, b: &crate::Fq) {
+    if let Err(m) = compare_fq_mul(a, b) {
+        panic!(
+            "Fq mul backends diverge: asm {:?}, portable {:?}",
+            m.asm, m.portable
+        );
+    }
+}
+
+/// Panics with both outputs if the kernels disagree on `a * b` in `Fr`.
+pub fn assert_fr_mul_eq(a: &crate::Fr, b: &crate::Fr) {
+    if let Err(m) = compare_fr_mul(a, b) {
+        panic!(
+            "Fr mul backends diverge: asm {:?}, portable {:?}",
+            m.asm, m.portable
+        );
+    }
+}
+
+fn check(asm: U256, portable: U256) -> Result<(), Mismatch> {
+    if asm == portable {
+        Ok(())
+    } else {
+        Err(Mismatch { asm, portable })
+    }
+}
+
+/// Runs the squaring, reduction, addition and subtraction kernels on
+/// Montgomery-form operands below `modulus` against the portable `U256`
+/// arithmetic, returning the first disagreement.
+pub fn compare_mod_ops(a: &U256, b: &U256, modulus: &U256, inv: u128) -> Result<(), Mismatch> {
+    let p = |x: &U256| &x.0 as *const u128 as *const u64;
+    let mut ret = [U256::zero(); 4];
+    unsafe {
+        ll_u256_mont_sqr(
+            &mut ret[0].0 as *mut u128 as *mut u64,
+            p(a),
+            p(modulus),
+            inv as u64,
+        );
+        ll_u256_mont_reduce(
+            &mut ret[1].0 as *mut u128 as *mut u64,
+            p(a),
+            p(modulus),
+            inv as u64,
+        );
+        ll_u256_add_mod(
+            &mut ret[2].0 as *mut u128 as *mut u64,
+            p(a),
+            p(b),
+            p(modulus),
+        );
+        ll_u256_sub_mod(
+            &mut ret[3].0 as *mut u128 as *mut u64,
+            p(a),
+            p(b),
+            p(modulus),
+        );
+    }
+    let mut square = *a;
+    square.mul(a, modulus, inv);
+    check(ret[0], square)?;
+    let mut reduced = *a;
+    reduced.mul(&U256::one(), modulus, inv);
+    check(ret[1], reduced)?;
+    let mut sum = *a;
+    sum.add(b, modulus);
+    check(ret[2], sum)?;
+    let mut difference = *a;
+    difference.sub(b, modulus);
+    check(ret[3], difference)
+}
+
+/// Panics with both outputs if the kernels disagree on any `Fq` operation
+/// of `a` and `b`.
+pub fn assert_fq_ops_eq(a: &crate::Fq, b: &crate::Fq) {
+    assert_fq_mul_eq(a, b);
+    let modulus = crate::fields::Fq::modulus();
+    if let Err(m) = compare_mod_ops((a.0).raw(), (b.0).raw(), &modulus, (a.0).inv()) {
+        panic!(
+            "Fq backends diverge: asm {:?}, portable {:?}",
+            m.asm, m.portable
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Batch inversion by Montgomery's trick: the running products of the
+//! inputs are inverted once and unwound, for three multiplications per
+//! element. Products are kept on the stack in chunks of `CHUNK`, so a
+//! batch costs one inversion per `CHUNK` elements.
+//!
+//! Batch normalization of Jacobian points inverts their `z` coordinates
+//! this way. With the `parallel` feature the chunks are normalized on the
+//! rayon thread pool.
+
+use crate::{AffineG1, AffineG2, Fq, Fq2, Fr, G1, G2};
+use core::ops::Mul;
+
+const CHUNK: usize = 256;
+
+trait Invert: Copy + Mul<Output = Self> {
+    fn one() -> Self;
+    fn is_zero(&self) -> bool;
+    fn inverse(&self) -> Option<Self>;
+}
+
+macro_rules! invert {
+    ($($t:ident),*) => {
+        $(
+            impl Invert for $t {
+                fn one() -> Self {
+                    $t::one()
+                }
+
+                fn is_zero(&self) -> bool {
+                    $t::is_zero(self)
+                }
+
+                fn inverse(&self) -> Option<Self> {
+                    $t::inverse(self)
+                }
+            }
+        )*
+    };
+}
+
+invert!(Fq, Fq2, Fr);
+
+fn batch_inverse<F: Invert>(xs: &mut [F]) {
+    let mut prefix = [F::one(); CHUNK];
+    for chunk in xs.chunks_mut(CHUNK) {
+        // prefix[i] is the product of the nonzero elements before i
+        let mut acc = F::one();
+        for (p, x) in prefix.iter_mut().zip(chunk.iter()) {
+            *p = acc;
+            if !x.is_zero() {
+                acc = acc * *x;
+            }
+        }
+        let mut inv = acc.inverse().expect("product of nonzero elements; qed");
+        for (p, x) in prefix.iter().zip(chunk.iter_mut()).rev() {
+            if !x.is_zero() {
+                let x_inv = inv * *p;
+                inv = inv * *x;
+                *x = x_inv;
+            }
+        }
+    }
+}
+
+impl Fq {
+    /// Replaces every element by its inverse, with a single inversion for
+    /// up to 256 elements. Zeros are left unchanged.
+    pub fn batch_inverse(xs: &mut [Fq]) {
+        batch_inverse(xs)
+    }
+}
+
+impl Fq2 {
+    /// See `Fq::batch_inverse`.
+    pub fn batch_inverse(xs: &mut [Fq2]) {
+        batch_inverse(xs)
+    }
+}
+
+impl Fr {
+    /// See `Fq::batch_inverse`.
+    pub fn batch_inverse(xs: &mut [Fr]) {
+        batch_inverse(xs)
+    }
+}
+
+macro_rules! batch_normalize {
+    ($g:ident, $affine:ident, $base:ident) => {
+        impl $g {
+            /// `from_jacobian` of every point, sharing one
+            /// inversion per 256 points. `out` must be at least as long as
+            /// `points`.
+            pub fn batch_normalize_into(points: &[$g], out: &mut [Option<$affine>]) {
+                assert!(out.len() >= points.len(), "output is too short");
+                let normalize = |(points, out): (&[$g], &mut [Option<$affine>])| {
+                    let mut zs = [$base::zero(); CHUNK];
+                    for (z, p) in zs.iter_mut().zip(points) {
+                        *z = p.z();
+                    }
+                    $base::batch_inverse(&mut zs[..points.len()]);
+                    for ((o, p), z) in out.iter_mut().zip(points).zip(zs.iter()) {
+                        *o = if z.is_zero() {
+                            None
+                        } else {
+                            Some($affine(p.0.to_affine_with(z.0)))
+                        };
+                    }
+                };
+                // chunks are independent, so they can go to separate threads
+                #[cfg(all(feature = "parallel", not(feature = "consensus")))]
+                {
+                    use rayon::prelude::*;
+                    points
+                        .par_chunks(CHUNK)
+                        .zip(out.par_chunks_mut(CHUNK))
+                        .for_each(normalize);
+                }
+                #[cfg(not(all(feature = "parallel", not(feature = "consensus"))))]
+                points
+                    .chunks(CHUNK)
+                    .zip(out.chunks_mut(CHUNK))
+                    .for_each(normalize);
+            }
+
+            /// Vector form of `batch_normalize_into`, for any number of
+            /// points.
+            #[cfg(feature = "alloc")]
+            pub fn batch_normalize_vec(points: &[$g]) -> alloc::vec::Vec<Option<$affine>> {
+                let mut out = alloc::vec![None; points.len()];
+                Self::batch_normalize_into(points, &mut out);
+                out
+            }
+
+            /// Array form of `batch_normalize_into`.
+            pub fn batch_normalize<const N: usize>(points: &[$g; N]) -> [Option<$affine>; N] {
+                let mut out = [None; N];
+                Self::batch_normalize_into(points, &mut out);
+                out
+            }
+        }
+    };
+}
+
+batch_normalize!(G1, AffineG1, Fq);
+batch_normalize!(G2, AffineG2, Fq2);
+
+#[test]
+fn matches_inverse() {
+    let mut xs = [Fr::zero(); 600];
+    let mut x = Fr::from_str("7").unwrap();
+    for (i, e) in xs.iter_mut().enumerate() {
+        if i % 5 != 3 {
+            *e = x;
+        }
+        x = x * x + Fr::one();
+    }
+    let expected = xs.map(|e| e.inverse().unwrap_or(Fr::zero()));
+    Fr::batch_inverse(&mut xs);
+    assert_eq!(xs, expected);
+    Fq::batch_inverse(&mut []);
+
+    let mut ys = [Fq2::i(), Fq2::zero(), Fq2::one() + Fq2::i()];
+    let expected = ys.map(|e| e.inverse().unwrap_or(Fq2::zero()));
+    Fq2::batch_inverse(&mut ys);
+    assert!(ys == expected);
+}
+
+#[test]
+fn normalize_matches_from_jacobian() {
+    use crate::Group;
+
+    let mut points = [G1::zero(); 300];
+    let mut p = G1::one();
+    for (i, q) in points.iter_mut().enumerate() {
+        if i % 7 != 0 {
+            *q = p;
+        }
+        p = p + p + G1::one();
+    }
+    let affine = G1::batch_normalize(&points);
+    for (a, p) in affine.iter().zip(points.iter()) {
+        assert_eq!(*a, AffineG1::from_jacobian(*p));
+    }
+
+    let q = G2::one() + G2::one();
+    let affine = G2::batch_normalize(&[q, G2::zero()]);
+    assert!(affine[0] == AffineG2::from_jacobian(q));
+    assert!(affine[1].is_none());
+}
@@ -0,0 +1,501 @@
+//! The public API of the upstream `bn` crate, published as `substrate-bn`
+//! 0.6, for EVM implementations switching to this crate: replacing
+//! `use bn::...` with `use alt_bn128::compat::...` is the only source
+//! change needed.
+//!
+//! The types wrap this crate's and expose only the upstream methods, with
+//! the upstream error enums, so that exhaustive matches keep compiling and
+//! every failure maps to the variant upstream returns. Compressed points
+//! already follow the upstream conventions: a 0x02/0x03 prefix for the
+//! parity of y in G1, and 0x0a/0x0b for the lexicographic sign of y in G2,
+//! with x in the `Fq2::from_slice` format. As upstream, `Fr::to_big_endian`
+//! writes the Montgomery form. The wrapped value is the public field `.0`,
+//! and `From` converts both ways.
+
+use crate::entropy::EntropySource;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+pub use crate::arith;
+pub use crate::GroupError;
+
+#[derive(Debug)]
+pub enum FieldError {
+    InvalidSliceLength,
+    InvalidU512Encoding,
+    NotMember,
+}
+
+impl From<crate::FieldError> for FieldError {
+    fn from(e: crate::FieldError) -> Self {
+        match e {
+            crate::FieldError::InputLength { .. } | crate::FieldError::OutputLength { .. } => {
+                FieldError::InvalidSliceLength
+            }
+            crate::FieldError::InvalidU512Encoding => FieldError::InvalidU512Encoding,
+            crate::FieldError::NotMember | crate::FieldError::CoefficientNotMember { .. } => {
+                FieldError::NotMember
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CurveError {
+    InvalidEncoding,
+    NotMember,
+    Field(FieldError),
+    ToAffineConversion,
+}
+
+impl From<FieldError> for CurveError {
+    fn from(fe: FieldError) -> Self {
+        CurveError::Field(fe)
+    }
+}
+
+impl From<crate::CurveError> for CurveError {
+    fn from(e: crate::CurveError) -> Self {
+        match e {
+            crate::CurveError::InvalidEncoding | crate::CurveError::InvalidLength { .. } => {
+                CurveError::InvalidEncoding
+            }
+            crate::CurveError::NotMember => CurveError::NotMember,
+            crate::CurveError::Field(error) | crate::CurveError::Coordinate { error, .. } => {
+                CurveError::Field(error.into())
+            }
+            crate::CurveError::ToAffineConversion => CurveError::ToAffineConversion,
+        }
+    }
+}
+
+/// Declares `$name` wrapping `crate::$name`, with conversions both ways.
+macro_rules! wrapper {
+    ($name:ident, $($derive:ident),*) => {
+        #[derive(Copy, Clone, PartialEq, Eq, $($derive),*)]
+        #[repr(transparent)]
+        pub struct $name(pub crate::$name);
+
+        impl From<crate::$name> for $name {
+            fn from(x: crate::$name) -> Self {
+                $name(x)
+            }
+        }
+
+        impl From<$name> for crate::$name {
+            fn from(x: $name) -> Self {
+                x.0
+            }
+        }
+    };
+}
+
+/// Forwards the binary operators `$tr::$method` from `$t * $rhs` to the
+/// wrapped types.
+macro_rules! forward_ops {
+    ($t:ident, $rhs:ident, $($tr:ident, $method:ident);*) => {
+        $(
+            impl $tr<$rhs> for $t {
+                type Output = $t;
+
+                fn $method(self, other: $rhs) -> $t {
+                    $t($tr::$method(self.0, other.0))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! forward_neg {
+    ($($t:ident),*) => {
+        $(
+            impl Neg for $t {
+                type Output = $t;
+
+                fn neg(self) -> $t {
+                    $t(-self.0)
+                }
+            }
+        )*
+    };
+}
+
+wrapper!(Fr, Debug);
+wrapper!(Fq, Debug);
+wrapper!(Fq2, Debug);
+wrapper!(G1, Debug);
+wrapper!(G2, Debug);
+wrapper!(AffineG1, Debug);
+wrapper!(AffineG2,);
+wrapper!(Gt, Debug);
+
+forward_ops!(Fr, Fr, Add, add; Sub, sub; Mul, mul);
+forward_ops!(Fq, Fq, Add, add; Sub, sub; Mul, mul);
+forward_ops!(Fq2, Fq2, Add, add; Sub, sub; Mul, mul);
+forward_ops!(G1, G1, Add, add; Sub, sub);
+forward_ops!(G1, Fr, Mul, mul);
+forward_ops!(G2, G2, Add, add; Sub, sub);
+forward_ops!(G2, Fr, Mul, mul);
+forward_ops!(Gt, Gt, Mul, mul);
+forward_neg!(Fr, Fq, Fq2, G1, G2);
+
+impl fmt::Debug for AffineG2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AffineG2")
+            .field("x", &self.x())
+            .field("y", &self.y())
+            .finish()
+    }
+}
+
+impl Fr {
+    pub fn zero() -> Self {
+        Fr(crate::Fr::zero())
+    }
+    pub fn one() -> Self {
+        Fr(crate::Fr::one())
+    }
+    pub fn random<R: EntropySource + ?Sized>(rng: &mut R) -> Self {
+        Fr(crate::Fr::random(rng))
+    }
+    pub fn pow(&self, exp: Fr) -> Self {
+        Fr(self.0.pow(exp.0))
+    }
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        crate::Fr::from_str(s).map(Fr)
+    }
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Fr)
+    }
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+    pub fn interpret(buf: &[u8; 64]) -> Fr {
+        Fr(crate::Fr::interpret(buf))
+    }
+    pub fn from_slice(slice: &[u8]) -> Result<Self, FieldError> {
+        Ok(Fr(crate::Fr::from_slice(slice)?))
+    }
+    pub fn to_big_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
+        Ok(self.0.to_big_endian(slice)?)
+    }
+    pub fn new(val: arith::U256) -> Option<Self> {
+        crate::Fr::new(val).map(Fr)
+    }
+    pub fn new_mul_factor(val: arith::U256) -> Self {
+        Fr(crate::Fr::new_mul_factor(val))
+    }
+    pub fn into_u256(self) -> arith::U256 {
+        self.0.into_u256()
+    }
+    pub fn set_bit(&mut self, bit: usize, to: bool) {
+        self.0.set_bit(bit, to);
+    }
+}
+
+impl Fq {
+    pub fn zero() -> Self {
+        Fq(crate::Fq::zero())
+    }
+    pub fn one() -> Self {
+        Fq(crate::Fq::one())
+    }
+    pub fn random<R: EntropySource + ?Sized>(rng: &mut R) -> Self {
+        Fq(crate::Fq::random(rng))
+    }
+    pub fn pow(&self, exp: Fq) -> Self {
+        Fq(self.0.pow(exp.0))
+    }
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        crate::Fq::from_str(s).map(Fq)
+    }
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Fq)
+    }
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+    pub fn interpret(buf: &[u8; 64]) -> Fq {
+        Fq(crate::Fq::interpret(buf))
+    }
+    pub fn from_slice(slice: &[u8]) -> Result<Self, FieldError> {
+        Ok(Fq(crate::Fq::from_slice(slice)?))
+    }
+    pub fn to_big_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
+        Ok(self.0.to_big_endian(slice)?)
+    }
+    pub fn from_u256(u256: arith::U256) -> Result<Self, FieldError> {
+        Ok(Fq(crate::Fq::from_u256(u256)?))
+    }
+    pub fn into_u256(self) -> arith::U256 {
+        self.0.into_u256()
+    }
+    pub fn modulus() -> arith::U256 {
+        crate::Fq::modulus()
+    }
+    pub fn sqrt(&self) -> Option<Self> {
+        self.0.sqrt().map(Fq)
+    }
+}
+
+impl Fq2 {
+    pub fn one() -> Fq2 {
+        Fq2(crate::Fq2::one())
+    }
+    pub fn i() -> Fq2 {
+        Fq2(crate::Fq2::i())
+    }
+    pub fn zero() -> Fq2 {
+        Fq2(crate::Fq2::zero())
+    }
+    /// `a + bi`.
+    pub fn new(a: Fq, b: Fq) -> Fq2 {
+        Fq2(crate::Fq2::new(a.0, b.0))
+    }
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+    pub fn pow(&self, exp: arith::U256) -> Self {
+        Fq2(self.0.pow(exp))
+    }
+    pub fn real(&self) -> Fq {
+        Fq(self.0.real())
+    }
+    pub fn imaginary(&self) -> Fq {
+        Fq(self.0.imaginary())
+    }
+}
+
+pub trait Group:
+    Send
+    + Sync
+    + Copy
+    + Clone
+    + PartialEq
+    + Eq
+    + Sized
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Neg<Output = Self>
+    + Mul<Fr, Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn random<R: EntropySource + ?Sized>(rng: &mut R) -> Self;
+    fn is_zero(&self) -> bool;
+    fn normalize(&mut self);
+}
+
+macro_rules! group {
+    ($t:ident) => {
+        impl Group for $t {
+            fn zero() -> Self {
+                $t(crate::Group::zero())
+            }
+            fn one() -> Self {
+                $t(crate::Group::one())
+            }
+            fn random<R: EntropySource + ?Sized>(rng: &mut R) -> Self {
+                $t(crate::$t::random(rng))
+            }
+            fn is_zero(&self) -> bool {
+                crate::Group::is_zero(&self.0)
+            }
+            fn normalize(&mut self) {
+                crate::Group::normalize(&mut self.0)
+            }
+        }
+    };
+}
+
+group!(G1);
+group!(G2);
+
+impl G1 {
+    pub fn new(x: Fq, y: Fq, z: Fq) -> Self {
+        G1(crate::G1::new(x.0, y.0, z.0))
+    }
+    pub fn x(&self) -> Fq {
+        Fq(self.0.x())
+    }
+    pub fn set_x(&mut self, x: Fq) {
+        self.0.set_x(x.0)
+    }
+    pub fn y(&self) -> Fq {
+        Fq(self.0.y())
+    }
+    pub fn set_y(&mut self, y: Fq) {
+        self.0.set_y(y.0)
+    }
+    pub fn z(&self) -> Fq {
+        Fq(self.0.z())
+    }
+    pub fn set_z(&mut self, z: Fq) {
+        self.0.set_z(z.0)
+    }
+    pub fn b() -> Fq {
+        Fq(crate::G1::b())
+    }
+    pub fn from_compressed(bytes: &[u8]) -> Result<Self, CurveError> {
+        Ok(G1(crate::G1::from_compressed(bytes)?))
+    }
+}
+
+impl AffineG1 {
+    pub fn new(x: Fq, y: Fq) -> Result<Self, GroupError> {
+        crate::AffineG1::new(x.0, y.0).map(AffineG1)
+    }
+    pub fn x(&self) -> Fq {
+        Fq(self.0.x())
+    }
+    pub fn set_x(&mut self, x: Fq) {
+        self.0.set_x(x.0)
+    }
+    pub fn y(&self) -> Fq {
+        Fq(self.0.y())
+    }
+    pub fn set_y(&mut self, y: Fq) {
+        self.0.set_y(y.0)
+    }
+    pub fn from_jacobian(g1: G1) -> Option<Self> {
+        crate::AffineG1::from_jacobian(g1.0).map(AffineG1)
+    }
+}
+
+impl From<AffineG1> for G1 {
+    fn from(affine: AffineG1) -> Self {
+        G1(affine.0.into())
+    }
+}
+
+impl G2 {
+    pub fn new(x: Fq2, y: Fq2, z: Fq2) -> Self {
+        G2(crate::G2::new(x.0, y.0, z.0))
+    }
+    pub fn x(&self) -> Fq2 {
+        Fq2(self.0.x())
+    }
+    pub fn set_x(&mut self, x: Fq2) {
+        self.0.set_x(x.0)
+    }
+    pub fn y(&self) -> Fq2 {
+        Fq2(self.0.y())
+    }
+    pub fn set_y(&mut self, y: Fq2) {
+        self.0.set_y(y.0)
+    }
+    pub fn z(&self) -> Fq2 {
+        Fq2(self.0.z())
+    }
+    pub fn set_z(&mut self, z: Fq2) {
+        self.0.set_z(z.0)
+    }
+    pub fn b() -> Fq2 {
+        Fq2(crate::G2::b())
+    }
+    pub fn from_compressed(bytes: &[u8]) -> Result<Self, CurveError> {
+        Ok(G2(crate::G2::from_compressed(bytes)?))
+    }
+}
+
+impl AffineG2 {
+    pub fn new(x: Fq2, y: Fq2) -> Result<Self, GroupError> {
+        crate::AffineG2::new(x.0, y.0).map(AffineG2)
+    }
+    pub fn x(&self) -> Fq2 {
+        Fq2(self.0.x())
+    }
+    pub fn set_x(&mut self, x: Fq2) {
+        self.0.set_x(x.0)
+    }
+    pub fn y(&self) -> Fq2 {
+        Fq2(self.0.y())
+    }
+    pub fn set_y(&mut self, y: Fq2) {
+        self.0.set_y(y.0)
+    }
+    pub fn from_jacobian(g2: G2) -> Option<Self> {
+        crate::AffineG2::from_jacobian(g2.0).map(AffineG2)
+    }
+}
+
+impl From<AffineG2> for G2 {
+    fn from(affine: AffineG2) -> Self {
+        G2(affine.0.into())
+    }
+}
+
+impl Gt {
+    pub fn one() -> Self {
+        Gt(crate::Gt::one())
+    }
+    pub fn pow(&self, exp: Fr) -> Self {
+        Gt(self.0.pow(exp.0))
+    }
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Gt)
+    }
+    pub fn final_exponentiation(&self) -> Option<Self> {
+        self.0.final_exponentiation().map(Gt)
+    }
+}
+
+pub fn pairing(p: G1, q: G2) -> Gt {
+    Gt(crate::pairing(p.0, q.0))
+}
+
+pub fn pairing_batch(pairs: &[(G1, G2)]) -> Gt {
+    Gt(crate::pairing_batch_iter(
+        pairs.iter().map(|(p, q)| (p.0, q.0)),
+    ))
+}
+
+/// As upstream, fails if any point is at infinity. There is no limit on
+/// the number of pairs.
+pub fn miller_loop_batch(pairs: &[(G2, G1)]) -> Result<Gt, CurveError> {
+    for (q, p) in pairs {
+        if crate::Group::is_zero(&q.0) || crate::Group::is_zero(&p.0) {
+            return Err(CurveError::ToAffineConversion);
+        }
+    }
+    Ok(Gt(crate::miller_loop_batch_iter(
+        pairs.iter().map(|(q, p)| (p.0, q.0)),
+    )))
+}
+
+#[test]
+fn upstream_semantics() {
+    assert!(matches!(
+        Fr::from_slice(&[0u8; 31]),
+        Err(FieldError::InvalidSliceLength)
+    ));
+    let mut q = [0u8; 32];
+    Fq::modulus().to_big_endian(&mut q).unwrap();
+    assert!(matches!(Fq::from_slice(&q), Err(FieldError::NotMember)));
+
+    let g = AffineG1::from_jacobian(G1::one()).unwrap();
+    let mut compressed = g.0.to_compressed();
+    assert_eq!(G1::from_compressed(&compressed).unwrap(), G1::one());
+    assert!(matches!(
+        G1::from_compressed(&compressed[1..]),
+        Err(CurveError::InvalidEncoding)
+    ));
+    compressed[1..].copy_from_slice(&q);
+    assert!(matches!(
+        G1::from_compressed(&compressed),
+        Err(CurveError::Field(FieldError::NotMember))
+    ));
+
+    let s = Fr::from_str("12345").unwrap();
+    let e = pairing(G1::one() * s, G2::one());
+    assert_eq!(e, pairing(G1::one(), G2::one()).pow(s));
+    assert_eq!(
+        pairing_batch(&[(G1::one(), G2::one())]),
+        pairing(G1::one(), G2::one())
+    );
+    let m =
+        miller_loop_batch(&[(G2::one(), G1::one() * s), (G2::one(), -(G1::one() * s))]).unwrap();
+    assert_eq!(m.final_exponentiation(), Some(Gt::one()));
+}
@@ -0,0 +1,77 @@
+//! Constant-time scalar multiplication, `subtle::ConstantTimeEq` for the
+//! public types and `subtle::ConditionallySelectable` for the prime fields,
+//! for code handling secret scalars such as signing keys.
+//!
+//! Field arithmetic reduces with masks rather than branches, so the
+//! operations below take the same time for every secret. Inversion and
+//! square roots are not constant time and are not used here.
+
+use crate::{groups, AffineG1, AffineG2, Fq, Fq2, Fr, G1, G2};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+impl G1 {
+    /// `self * scalar` in constant time with respect to `scalar`; see
+    /// the module documentation.
+    pub fn mul_ct(&self, scalar: Fr) -> G1 {
+        G1(groups::mul_ct(&self.0, &scalar.0))
+    }
+}
+
+impl G2 {
+    /// G2 counterpart of `G1::mul_ct`.
+    pub fn mul_ct(&self, scalar: Fr) -> G2 {
+        G2(groups::mul_ct(&self.0, &scalar.0))
+    }
+}
+
+macro_rules! wrapper_ct_eq {
+    ($($t:ty),*) => {
+        $(
+            impl ConstantTimeEq for $t {
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    self.0.ct_eq(&other.0)
+                }
+            }
+        )*
+    };
+}
+
+wrapper_ct_eq!(Fr, Fq, Fq2, G1, G2, AffineG1, AffineG2);
+
+macro_rules! wrapper_ct_select {
+    ($($t:ident),*) => {
+        $(
+            impl ConditionallySelectable for $t {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    $t(ConditionallySelectable::conditional_select(&a.0, &b.0, choice))
+                }
+            }
+        )*
+    };
+}
+
+wrapper_ct_select!(Fr, Fq);
+
+#[test]
+fn mul_ct_matches_mul() {
+    use crate::Group;
+
+    let p = G1::one() * Fr::from_str("5").unwrap();
+    let q = G2::one() * Fr::from_str("7").unwrap();
+    let scalars = [
+        Fr::zero(),
+        Fr::one(),
+        Fr::from_str("2").unwrap(),
+        Fr::from_str("65535").unwrap(),
+        -Fr::one(),
+        -Fr::from_str("2").unwrap(),
+        Fr::from_str("123456789123456789123456789").unwrap(),
+    ];
+    for s in scalars.iter() {
+        assert_eq!(p.mul_ct(*s), p * *s);
+        assert!(bool::from(p.mul_ct(*s).ct_eq(&(p * *s))));
+        assert!(q.mul_ct(*s) == q * *s);
+    }
+    assert!(!bool::from(p.ct_eq(&G1::zero())));
+    assert!(bool::from(G1::zero().ct_eq(&(p * Fr::zero()))));
+}
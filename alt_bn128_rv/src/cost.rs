@@ -0,0 +1,167 @@
+//! Up-front cost estimates for budgeting cycle or gas limits.
+//!
+//! Native costs are expressed in Fq multiplication equivalents, measured on
+//! this implementation and rounded, then scaled by a per-target cycle cost.
+//! They are meant as budgets and err on the high side; MSM estimates price
+//! the window `G1::msm` picks with `MsmCostModel::default()`.
+
+use crate::ethereum::{add_gas, mul_gas, pairing_gas};
+use crate::groups::MsmCostModel;
+
+/// An operation to estimate.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Op {
+    G1Add,
+    G1Mul,
+    G2Add,
+    G2Mul,
+    /// A G1 multi-scalar multiplication with this many terms.
+    Msm(usize),
+    /// A product of this many pairings with one final exponentiation.
+    Pairing(usize),
+    GtPow,
+    FqInverse,
+    FqSqrt,
+    /// Hashing a message to G1: two field elements mapped to the curve.
+    HashToG1,
+}
+
+/// What the estimate is counted in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Profile {
+    /// Cycles of this crate running in CKB-VM, given the cost of one Fq
+    /// multiplication there.
+    CkbVm { cycles_per_mul: u64 },
+    /// Gas of the EIP-196/197 precompiles as repriced by EIP-1108.
+    EvmPrecompile,
+}
+
+impl Profile {
+    /// CKB-VM with the RISC-V assembly Montgomery multiplication. The
+    /// per-multiplication figure is approximate; use
+    /// `calibrate_cycles_per_mul` with the VM's cycle counter for tight
+    /// budgets.
+    pub fn ckb_vm() -> Self {
+        Profile::CkbVm {
+            cycles_per_mul: 400,
+        }
+    }
+}
+
+/// A cap, in bytes, on the tables and scratch space of the routines that
+/// take one, so that peak memory is known up front. Inputs and outputs
+/// are not counted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryBudget {
+    pub bytes: usize,
+}
+
+impl MemoryBudget {
+    pub fn new(bytes: usize) -> Self {
+        MemoryBudget { bytes }
+    }
+
+    pub fn fits(&self, bytes: usize) -> bool {
+        bytes <= self.bytes
+    }
+}
+
+/// A routine needs `required` bytes, more than its `MemoryBudget`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OverBudget {
+    pub required: usize,
+}
+
+/// G1 addition and doubling in Fq multiplication equivalents, for pricing
+/// an MSM window.
+const MSM_PRICES: MsmCostModel = MsmCostModel { add: 13, double: 6 };
+
+const PAIRING_FINAL_EXP: u64 = 32_500;
+const PAIRING_MILLER_LOOP: u64 = 10_300;
+
+/// Cost of `op` in Fq multiplication equivalents.
+pub fn mul_equivalents(op: Op) -> u64 {
+    match op {
+        Op::G1Add => 13,
+        Op::G1Mul => 3_400,
+        Op::G2Add => 59,
+        Op::G2Mul => 15_200,
+        Op::Msm(0) => 0,
+        Op::Msm(n) => MSM_PRICES.cost(n, MsmCostModel::default().best_window(n)),
+        Op::Pairing(n) => PAIRING_FINAL_EXP + n as u64 * PAIRING_MILLER_LOOP,
+        Op::GtPow => 33_700,
+        Op::FqInverse => 63,
+        Op::FqSqrt => 267,
+        Op::HashToG1 => 2 * (2 * 267 + 63) + 100,
+    }
+}
+
+/// Estimated cost of `op` under `profile`, or `None` if the target cannot
+/// perform it (the EVM has no G2 arithmetic or hashing precompiles).
+pub fn estimate(profile: &Profile, op: Op) -> Option<u64> {
+    match profile {
+        Profile::CkbVm { cycles_per_mul } => Some(mul_equivalents(op) * cycles_per_mul),
+        Profile::EvmPrecompile => match op {
+            Op::G1Add => Some(add_gas(false)),
+            Op::G1Mul => Some(mul_gas(false)),
+            Op::Msm(n) => {
+                Some(n as u64 * mul_gas(false) + (n as u64).saturating_sub(1) * add_gas(false))
+            }
+            Op::Pairing(n) => Some(pairing_gas(n.saturating_mul(192), false)),
+            _ => None,
+        },
+    }
+}
+
+/// Measures the cost of one Fq multiplication with a caller supplied
+/// monotonic counter, for `Profile::CkbVm`.
+/// Not available with the `consensus` feature.
+#[cfg(not(feature = "consensus"))]
+pub fn calibrate_cycles_per_mul<F: FnMut() -> u64>(mut clock: F) -> u64 {
+    use crate::Fq;
+
+    const ROUNDS: u64 = 64;
+
+    let mut a = Fq::one() + Fq::one();
+    let b = a + Fq::one();
+    let start = clock();
+    for _ in 0..ROUNDS {
+        a *= b;
+    }
+    let end = clock();
+    core::hint::black_box(a);
+    ((end - start) / ROUNDS).max(1)
+}
+
+#[test]
+fn evm_estimates_match_precompile_gas() {
+    let evm = Profile::EvmPrecompile;
+    assert_eq!(estimate(&evm, Op::G1Add), Some(150));
+    assert_eq!(estimate(&evm, Op::G1Mul), Some(6_000));
+    assert_eq!(estimate(&evm, Op::G1Add), Some(add_gas(false)));
+    assert_eq!(estimate(&evm, Op::G1Mul), Some(mul_gas(false)));
+    for n in 0..4 {
+        assert_eq!(
+            estimate(&evm, Op::Pairing(n)),
+            Some(pairing_gas(n.saturating_mul(192), false))
+        );
+    }
+    assert_eq!(estimate(&evm, Op::Pairing(2)), Some(45_000 + 2 * 34_000));
+    // an MSM on the EVM is that many ECMULs and one fewer ECADDs
+    assert_eq!(estimate(&evm, Op::Msm(0)), Some(0));
+    assert_eq!(estimate(&evm, Op::Msm(3)), Some(3 * 6_000 + 2 * 150));
+    assert_eq!(estimate(&evm, Op::G2Mul), None);
+    assert_eq!(estimate(&evm, Op::HashToG1), None);
+
+    let vm = Profile::CkbVm { cycles_per_mul: 2 };
+    assert_eq!(
+        estimate(&vm, Op::G1Mul),
+        Some(2 * mul_equivalents(Op::G1Mul))
+    );
+    assert!(mul_equivalents(Op::Msm(64)) < 64 * mul_equivalents(Op::G1Mul));
+    // priced at the window the MSM itself uses
+    for n in [1, 10, 100, 1000] {
+        let window = MsmCostModel::default().best_window(n);
+        assert_eq!(mul_equivalents(Op::Msm(n)), MSM_PRICES.cost(n, window));
+    }
+}
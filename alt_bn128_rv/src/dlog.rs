@@ -0,0 +1,90 @@
+//! Discrete logarithms below a small bound by baby-step giant-step, for
+//! decoding values carried in an exponent such as exponential ElGamal
+//! plaintexts.
+//!
+//! The baby steps are a table of `N` multiples of the base that can be
+//! built once and reused. Solving for a value below `bound` then costs
+//! about `bound / (N + 1)` giant steps, each an addition and an inversion.
+
+use crate::{AffineG1, Fr, Group, G1};
+
+fn key(p: &AffineG1) -> u64 {
+    p.x().into_u256().0[0] as u64
+}
+
+/// The points `m * base` for `m` in `1..=N`, keyed by the low 64 bits of
+/// their x coordinate and sorted for binary search. Holds 16 bytes per
+/// entry; building it costs `N` additions and inversions.
+pub struct BabySteps<const N: usize> {
+    base: G1,
+    entries: [(u64, u32); N],
+}
+
+impl<const N: usize> BabySteps<N> {
+    pub fn new(base: G1) -> Self {
+        assert!(N < u32::MAX as usize, "table index fits in u32");
+        let mut entries = [(0u64, 0u32); N];
+        let mut p = G1::zero();
+        for (m, e) in entries.iter_mut().enumerate() {
+            p += base;
+            let a = AffineG1::from_jacobian(p).expect("base has order r > N; qed");
+            *e = (key(&a), m as u32 + 1);
+        }
+        entries.sort_unstable();
+        BabySteps { base, entries }
+    }
+
+    pub fn base(&self) -> G1 {
+        self.base
+    }
+
+    /// Finds `m` in `0..=N` with `m * base == p`.
+    pub fn lookup(&self, p: G1) -> Option<u64> {
+        let a = match AffineG1::from_jacobian(p) {
+            Some(a) => a,
+            None => return Some(0),
+        };
+        let k = key(&a);
+        let start = self.entries.partition_point(|e| e.0 < k);
+        // distinct points can share a key, so confirm each candidate
+        self.entries[start..]
+            .iter()
+            .take_while(|e| e.0 == k)
+            .map(|e| e.1 as u64)
+            .find(|&m| self.base * Fr::from(m) == p)
+    }
+
+    /// Finds `m` in `0..=bound` with `m * base == target`.
+    pub fn solve(&self, target: G1, bound: u64) -> Option<u64> {
+        let step = N as u64 + 1;
+        let giant = self.base * Fr::from(step);
+        let mut q = target;
+        let mut offset = 0u64;
+        loop {
+            if let Some(m) = self.lookup(q) {
+                return Some(offset + m).filter(|&m| m <= bound);
+            }
+            offset = offset.checked_add(step).filter(|&o| o <= bound)?;
+            q -= giant;
+        }
+    }
+}
+
+/// Finds `m` in `0..=bound` with `m * base == target`, building a table of
+/// 1024 baby steps on the stack. Callers solving repeatedly for the same
+/// base, or with large bounds, should keep a `BabySteps` instead.
+pub fn dlog_small(base: G1, target: G1, bound: u64) -> Option<u64> {
+    BabySteps::<1024>::new(base).solve(target, bound)
+}
+
+#[test]
+fn giant_steps() {
+    let base = G1::one() * Fr::from(7u64);
+    let table = BabySteps::<8>::new(base);
+    for m in [0u64, 5, 8, 9, 100, 1000] {
+        assert_eq!(table.solve(base * Fr::from(m), 1000), Some(m));
+    }
+    assert_eq!(table.solve(base * Fr::from(1001u64), 1000), None);
+    assert_eq!(table.solve(base * Fr::from(12u64), 10), None);
+    assert_eq!(dlog_small(base, base * Fr::from(5000u64), 5000), Some(5000));
+}
@@ -0,0 +1,164 @@
+//! Exponential ElGamal over G1. A value `m` is encrypted to a key `pk` as
+//! `(r * g, m * g + r * pk)`, so ciphertexts add homomorphically and
+//! decryption yields `m * g`, from which `m` is recovered by table lookup
+//! as long as it is small.
+//!
+//! Public keys carry `sk` in both groups. The G2 half lets anyone check a
+//! claimed decryption with one pairing product, without a separate proof.
+
+use crate::dlog::BabySteps;
+use crate::{pairing_check, AffineG1, AffineG2, Fr, Group, G1, G2};
+use core::fmt;
+use core::ops::{Add, Sub};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The two halves of a public key have different discrete logarithms.
+    InconsistentKey,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InconsistentKey => {
+                f.write_str("public key halves have different discrete logarithms")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct SecretKey(Fr);
+
+/// `sk * g1` and `sk * g2` for the standard generators.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    pub g1: AffineG1,
+    pub g2: AffineG2,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext {
+    pub c1: G1,
+    pub c2: G1,
+}
+
+impl SecretKey {
+    /// `None` for zero, which would encrypt in the clear.
+    pub fn new(sk: Fr) -> Option<Self> {
+        if sk.is_zero() {
+            return None;
+        }
+        Some(SecretKey(sk))
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            g1: AffineG1::from_jacobian(G1::one().mul_ct(self.0))
+                .expect("secret key is nonzero; qed"),
+            g2: AffineG2::from_jacobian(G2::one().mul_ct(self.0))
+                .expect("secret key is nonzero; qed"),
+        }
+    }
+
+    /// Returns `m * g` for the value `m` encrypted in `ct`.
+    pub fn decrypt_point(&self, ct: &Ciphertext) -> G1 {
+        ct.c2 - ct.c1.mul_ct(self.0)
+    }
+
+    /// Decrypts a value no larger than `bound` using baby steps of the G1
+    /// generator; `None` if the plaintext is out of range.
+    pub fn decrypt<const N: usize>(
+        &self,
+        ct: &Ciphertext,
+        table: &BabySteps<N>,
+        bound: u64,
+    ) -> Option<u64> {
+        debug_assert!(table.base() == G1::one());
+        table.solve(self.decrypt_point(ct), bound)
+    }
+}
+
+impl PublicKey {
+    /// Checks that both halves share a discrete logarithm, so that
+    /// `verify_decryption` is meaningful for keys received from others.
+    pub fn new(g1: AffineG1, g2: AffineG2) -> Result<Self, Error> {
+        if !pairing_check(&[(g1.into(), -G2::one()), (G1::one(), g2.into())]) {
+            return Err(Error::InconsistentKey);
+        }
+        Ok(PublicKey { g1, g2 })
+    }
+
+    /// Encrypts `m` with the randomness `r`, which must be uniform and never
+    /// reused.
+    pub fn encrypt(&self, m: u64, r: Fr) -> Ciphertext {
+        self.encrypt_fr(Fr::from(m), r)
+    }
+
+    /// Encrypts a full scalar, in constant time in `m` and `r`. Such ciphertexts can still be added and
+    /// checked with `verify_decryption`, but only small sums can be
+    /// decrypted.
+    pub fn encrypt_fr(&self, m: Fr, r: Fr) -> Ciphertext {
+        Ciphertext {
+            c1: G1::one().mul_ct(r),
+            c2: G1::one().mul_ct(m) + G1::from(self.g1).mul_ct(r),
+        }
+    }
+
+    /// Whether `ct` decrypts to `m` under this key, by checking
+    /// `e(c2 - m * g1, g2) == e(c1, sk * g2)`.
+    pub fn verify_decryption(&self, ct: &Ciphertext, m: u64) -> bool {
+        pairing_check(&[
+            (ct.c2 - G1::one() * Fr::from(m), -G2::one()),
+            (ct.c1, self.g2.into()),
+        ])
+    }
+}
+
+impl Add for Ciphertext {
+    type Output = Ciphertext;
+
+    /// Encrypts the sum of the two plaintexts.
+    fn add(self, other: Ciphertext) -> Ciphertext {
+        Ciphertext {
+            c1: self.c1 + other.c1,
+            c2: self.c2 + other.c2,
+        }
+    }
+}
+
+impl Sub for Ciphertext {
+    type Output = Ciphertext;
+
+    fn sub(self, other: Ciphertext) -> Ciphertext {
+        Ciphertext {
+            c1: self.c1 - other.c1,
+            c2: self.c2 - other.c2,
+        }
+    }
+}
+
+#[test]
+fn homomorphic_sum() {
+    let sk = SecretKey::new(Fr::from_str("123456789").unwrap()).unwrap();
+    let pk = sk.public_key();
+    assert!(PublicKey::new(pk.g1, pk.g2).is_ok());
+    assert_eq!(
+        PublicKey::new(AffineG1::generator(), pk.g2).err(),
+        Some(Error::InconsistentKey)
+    );
+
+    let a = pk.encrypt(3, Fr::from_str("11").unwrap());
+    let b = pk.encrypt(5, Fr::from_str("22").unwrap());
+    let r = Fr::from_str("22").unwrap();
+    assert_eq!(b.c1, G1::one() * r);
+    assert_eq!(b.c2, G1::one() * Fr::from(5u64) + G1::from(pk.g1) * r);
+    let table = BabySteps::<4>::new(G1::one());
+    assert_eq!(sk.decrypt(&(a + b), &table, 100), Some(8));
+    assert_eq!(sk.decrypt(&(a - a), &table, 100), Some(0));
+    assert_eq!(sk.decrypt(&pk.encrypt(17, Fr::one()), &table, 16), None);
+    assert!(pk.verify_decryption(&(a + b), 8));
+    assert!(!pk.verify_decryption(&(a + b), 9));
+}
@@ -0,0 +1,211 @@
+//! Radix-2 evaluation domains over Fr. `r - 1` is divisible by `2^28`, so
+//! Fr has subgroups of roots of unity of every power-of-two order up to
+//! `2^28`, over which polynomials convert between coefficients and
+//! evaluations in `O(n log n)` by the number theoretic transform.
+//!
+//! Transforms run in place on slices of exactly the domain size, so they
+//! need no allocation. The coset variants evaluate over `g * H` for the
+//! multiplicative generator `g = 5`, which is disjoint from `H` and is
+//! where quotient polynomials are evaluated without dividing by zero.
+
+use crate::fields::const_fr;
+use crate::Fr;
+
+/// `log2` of the largest domain.
+pub const TWO_ADICITY: u32 = 28;
+
+/// `5^((r - 1) / 2^28)`, a primitive `2^28`-th root of unity.
+const ROOT_OF_UNITY: Fr = Fr(const_fr([
+    0x636e735580d13d9c,
+    0xa22bf3742445ffd6,
+    0x56452ac01eb203d8,
+    0x1860ef942963f9e7,
+]));
+
+/// 5, a generator of the multiplicative group of Fr.
+const GENERATOR: Fr = Fr(const_fr([
+    0x1b0d0ef99fffffe6,
+    0xeaba68a3a32a913f,
+    0x47d8eb76d8dd0689,
+    0x15d0085520f5bbc3,
+]));
+
+/// The subgroup `H = {1, w, ..., w^(n-1)}` of the `n`-th roots of unity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Domain {
+    size: usize,
+    log_size: u32,
+    omega: Fr,
+    omega_inv: Fr,
+    size_inv: Fr,
+}
+
+impl Domain {
+    /// `None` unless `size` is a power of two no larger than `2^28`.
+    pub fn new(size: usize) -> Option<Self> {
+        if !size.is_power_of_two() {
+            return None;
+        }
+        let log_size = size.trailing_zeros();
+        if log_size > TWO_ADICITY {
+            return None;
+        }
+        let mut omega = ROOT_OF_UNITY;
+        for _ in log_size..TWO_ADICITY {
+            omega = omega * omega;
+        }
+        let size_inv = Fr::new((size as u64).into())
+            .and_then(|n| n.inverse())
+            .expect("domain sizes are nonzero and below r; qed");
+        Some(Domain {
+            size,
+            log_size,
+            omega,
+            omega_inv: omega.inverse().expect("roots of unity are nonzero; qed"),
+            size_inv,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// The generator `w` of the domain.
+    pub fn omega(&self) -> Fr {
+        self.omega
+    }
+
+    /// The offset `g` of the coset used by `coset_fft`.
+    pub fn coset_generator(&self) -> Fr {
+        GENERATOR
+    }
+
+    /// The elements `w^i` in order.
+    pub fn elements(&self) -> impl Iterator<Item = Fr> {
+        let omega = self.omega;
+        core::iter::successors(Some(Fr::one()), move |w| Some(*w * omega)).take(self.size)
+    }
+
+    /// `Z_H(z) = z^n - 1`, zero exactly on the domain.
+    pub fn evaluate_vanishing(&self, z: Fr) -> Fr {
+        let mut zn = z;
+        for _ in 0..self.log_size {
+            zn = zn * zn;
+        }
+        zn - Fr::one()
+    }
+
+    /// Replaces the coefficients of a polynomial of degree below `n` by its
+    /// evaluations at `w^0, ..., w^(n-1)`. Panics unless `values` has
+    /// exactly `n` elements.
+    pub fn fft(&self, values: &mut [Fr]) {
+        assert_eq!(values.len(), self.size, "input must match the domain size");
+        transform(values, self.omega, self.log_size);
+    }
+
+    /// Inverse of `fft`: interpolates evaluations over the domain.
+    pub fn ifft(&self, values: &mut [Fr]) {
+        assert_eq!(values.len(), self.size, "input must match the domain size");
+        transform(values, self.omega_inv, self.log_size);
+        for v in values.iter_mut() {
+            *v *= self.size_inv;
+        }
+    }
+
+    /// `fft` over the coset `g * H`.
+    pub fn coset_fft(&self, values: &mut [Fr]) {
+        scale_powers(values, GENERATOR);
+        self.fft(values);
+    }
+
+    /// Inverse of `coset_fft`.
+    pub fn coset_ifft(&self, values: &mut [Fr]) {
+        self.ifft(values);
+        scale_powers(
+            values,
+            GENERATOR.inverse().expect("the generator is nonzero; qed"),
+        );
+    }
+}
+
+/// Multiplies `values[i]` by `g^i`, turning `p(X)` into `p(g * X)`.
+fn scale_powers(values: &mut [Fr], g: Fr) {
+    let mut power = Fr::one();
+    for v in values.iter_mut() {
+        *v *= power;
+        power *= g;
+    }
+}
+
+/// Iterative Cooley-Tukey over the subgroup generated by `omega`, of
+/// order `2^log_n`: bit-reversal permutation, then butterflies of doubling
+/// span.
+fn transform(values: &mut [Fr], omega: Fr, log_n: u32) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut half = 1;
+    for stage in 1..=log_n {
+        // a primitive 2^stage-th root of unity
+        let mut w_m = omega;
+        for _ in stage..log_n {
+            w_m = w_m * w_m;
+        }
+        for chunk in values.chunks_mut(2 * half) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            let mut w = Fr::one();
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = w * *b;
+                *b = *a - t;
+                *a += t;
+                w *= w_m;
+            }
+        }
+        half *= 2;
+    }
+}
+
+#[test]
+fn transforms() {
+    use crate::kzg;
+
+    assert!(Domain::new(12).is_none());
+    assert!(Domain::new(1 << 29).is_none());
+    let big = Domain::new(1 << TWO_ADICITY).unwrap();
+    assert_eq!(big.evaluate_vanishing(big.omega()), Fr::zero());
+    assert!(big.evaluate_vanishing(big.omega() * big.omega()).is_zero());
+
+    let domain = Domain::new(8).unwrap();
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let poly = ["3", "1", "4", "1", "5", "9", "2", "6"].map(fr);
+    let mut values = poly;
+    domain.fft(&mut values);
+    for (v, w) in values.iter().zip(domain.elements()) {
+        assert_eq!(*v, kzg::evaluate(&poly, w));
+        assert!(domain.evaluate_vanishing(w).is_zero());
+    }
+    domain.ifft(&mut values);
+    assert_eq!(values, poly);
+
+    domain.coset_fft(&mut values);
+    for (v, w) in values.iter().zip(domain.elements()) {
+        assert_eq!(*v, kzg::evaluate(&poly, w * domain.coset_generator()));
+    }
+    domain.coset_ifft(&mut values);
+    assert_eq!(values, poly);
+
+    let mut single = [fr("7")];
+    Domain::new(1).unwrap().fft(&mut single);
+    assert_eq!(single, [fr("7")]);
+}
@@ -1,9 +1,113 @@
 use crate::arith::{U256, U512};
 use crate::fields::FieldElement;
-use core::ops::{Add, Mul, Neg, Sub};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
+#[cfg(target_arch = "riscv64")]
 extern "C" {
     fn ll_u256_mont_mul(ret: *mut u64, a: *const u64, b: *const u64, n: *const u64, k: u64);
+    fn ll_u256_mont_sqr(ret: *mut u64, a: *const u64, n: *const u64, k: u64);
+    fn ll_u256_mont_reduce(ret: *mut u64, a: *const u64, n: *const u64, k: u64);
+    fn ll_u256_add_mod(ret: *mut u64, a: *const u64, b: *const u64, n: *const u64);
+    fn ll_u256_sub_mod(ret: *mut u64, a: *const u64, b: *const u64, n: *const u64);
+}
+
+// The kernels in ll_u256_mont-riscv64.S, built only for riscv64. Operands
+// must be below the modulus, which every field element is. Other targets,
+// such as wasm32 or a host running the tests, use the portable `U256`
+// arithmetic instead.
+
+#[cfg(target_arch = "riscv64")]
+#[inline(always)]
+fn ptr(a: &U256) -> *const u64 {
+    &a.0 as *const u128 as *const u64
+}
+
+#[cfg(target_arch = "riscv64")]
+#[inline(always)]
+fn ptr_mut(a: &mut U256) -> *mut u64 {
+    &mut a.0 as *mut u128 as *mut u64
+}
+
+#[cfg(target_arch = "riscv64")]
+#[inline(always)]
+fn mont_mul(a: &U256, b: &U256, n: &U256, k: u128) -> U256 {
+    let mut ret = U256([0; 2]);
+    unsafe { ll_u256_mont_mul(ptr_mut(&mut ret), ptr(a), ptr(b), ptr(n), k as u64) };
+    ret
+}
+
+#[cfg(not(target_arch = "riscv64"))]
+#[inline(always)]
+fn mont_mul(a: &U256, b: &U256, n: &U256, k: u128) -> U256 {
+    let mut ret = *a;
+    ret.mul(b, n, k);
+    ret
+}
+
+#[cfg(target_arch = "riscv64")]
+#[inline(always)]
+fn mont_sqr(a: &U256, n: &U256, k: u128) -> U256 {
+    let mut ret = U256([0; 2]);
+    unsafe { ll_u256_mont_sqr(ptr_mut(&mut ret), ptr(a), ptr(n), k as u64) };
+    ret
+}
+
+#[cfg(not(target_arch = "riscv64"))]
+#[inline(always)]
+fn mont_sqr(a: &U256, n: &U256, k: u128) -> U256 {
+    let mut ret = *a;
+    ret.square(n, k);
+    ret
+}
+
+/// `a * 2^-256 mod n`, i.e. out of Montgomery form.
+#[cfg(target_arch = "riscv64")]
+#[inline(always)]
+fn mont_reduce(a: &U256, n: &U256, k: u128) -> U256 {
+    let mut ret = U256([0; 2]);
+    unsafe { ll_u256_mont_reduce(ptr_mut(&mut ret), ptr(a), ptr(n), k as u64) };
+    ret
+}
+
+#[cfg(not(target_arch = "riscv64"))]
+#[inline(always)]
+fn mont_reduce(a: &U256, n: &U256, k: u128) -> U256 {
+    let mut ret = *a;
+    ret.mul(&U256::one(), n, k);
+    ret
+}
+
+#[cfg(target_arch = "riscv64")]
+#[inline(always)]
+fn add_mod(a: &U256, b: &U256, n: &U256) -> U256 {
+    let mut ret = U256([0; 2]);
+    unsafe { ll_u256_add_mod(ptr_mut(&mut ret), ptr(a), ptr(b), ptr(n)) };
+    ret
+}
+
+#[cfg(not(target_arch = "riscv64"))]
+#[inline(always)]
+fn add_mod(a: &U256, b: &U256, n: &U256) -> U256 {
+    let mut ret = *a;
+    ret.add(b, n);
+    ret
+}
+
+#[cfg(target_arch = "riscv64")]
+#[inline(always)]
+fn sub_mod(a: &U256, b: &U256, n: &U256) -> U256 {
+    let mut ret = U256([0; 2]);
+    unsafe { ll_u256_sub_mod(ptr_mut(&mut ret), ptr(a), ptr(b), ptr(n)) };
+    ret
+}
+
+#[cfg(not(target_arch = "riscv64"))]
+#[inline(always)]
+fn sub_mod(a: &U256, b: &U256, n: &U256) -> U256 {
+    let mut ret = *a;
+    ret.sub(b, n);
+    ret
 }
 
 macro_rules! field_impl {
@@ -12,12 +116,32 @@ macro_rules! field_impl {
         #[repr(C)]
         pub struct $name(U256);
 
+        impl ConditionallySelectable for $name {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                $name(U256::conditional_select(&a.0, &b.0, choice))
+            }
+        }
+
+        /// Compares the Montgomery forms, which are unique since they are
+        /// kept below the modulus.
+        impl ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        /// Wipes the Montgomery-form limbs, leaving zero.
+        #[cfg(feature = "zeroize")]
+        impl zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
         impl From<$name> for U256 {
             #[inline]
-            fn from(mut a: $name) -> Self {
-                a.0.mul(&U256::one(), &U256($modulus), $inv);
-
-                a.0
+            fn from(a: $name) -> Self {
+                mont_reduce(&a.0, &U256($modulus), $inv)
             }
         }
 
@@ -31,8 +155,8 @@ macro_rules! field_impl {
                 for c in s.chars() {
                     match c.to_digit(10) {
                         Some(d) => {
-                            res = res * ints[10];
-                            res = res + ints[d as usize];
+                            res *= ints[10];
+                            res += ints[d as usize];
                         }
                         None => {
                             return None;
@@ -81,9 +205,21 @@ macro_rules! field_impl {
                 &self.0
             }
 
+            #[cfg(all(feature = "backend-compare", target_arch = "riscv64"))]
+            pub(crate) fn from_raw(a: U256) -> Self {
+                $name(a)
+            }
+
             pub fn set_bit(&mut self, bit: usize, to: bool) {
                 self.0.set_bit(bit, to);
             }
+
+            #[inline]
+            pub fn double(&self) -> Self {
+                let mut res = *self;
+                res.0.double(&U256($modulus));
+                res
+            }
         }
 
         impl FieldElement for $name {
@@ -102,6 +238,11 @@ macro_rules! field_impl {
                 self.0.is_zero()
             }
 
+            #[inline]
+            fn squared(&self) -> Self {
+                $name(mont_sqr(&self.0, &U256($modulus), $inv))
+            }
+
             fn inverse(mut self) -> Option<Self> {
                 if self.is_zero() {
                     None
@@ -118,10 +259,8 @@ macro_rules! field_impl {
             type Output = $name;
 
             #[inline]
-            fn add(mut self, other: $name) -> $name {
-                self.0.add(&other.0, &U256($modulus));
-
-                self
+            fn add(self, other: $name) -> $name {
+                $name(add_mod(&self.0, &other.0, &U256($modulus)))
             }
         }
 
@@ -129,10 +268,8 @@ macro_rules! field_impl {
             type Output = $name;
 
             #[inline]
-            fn sub(mut self, other: $name) -> $name {
-                self.0.sub(&other.0, &U256($modulus));
-
-                self
+            fn sub(self, other: $name) -> $name {
+                $name(sub_mod(&self.0, &other.0, &U256($modulus)))
             }
         }
 
@@ -140,21 +277,29 @@ macro_rules! field_impl {
             type Output = $name;
 
             #[inline]
-            fn mul(mut self, other: $name) -> $name {
-                unsafe {
-                    let mut ret = [0u128; 2];
-                    let n: [u128; 2] = $modulus;
-                    let k: u128 = $inv;
-                    ll_u256_mont_mul(
-                        &mut ret as *mut u128 as *mut u64,
-                        &self.0 .0 as *const u128 as *const u64,
-                        &other.0 .0 as *const u128 as *const u64,
-                        &n as *const u128 as *const u64,
-                        k as u64,
-                    );
-                    self.0.0 = ret;
-                    self
-                }
+            fn mul(self, other: $name) -> $name {
+                $name(mont_mul(&self.0, &other.0, &U256($modulus), $inv))
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, other: $name) {
+                self.0 = add_mod(&self.0, &other.0, &U256($modulus));
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, other: $name) {
+                self.0 = sub_mod(&self.0, &other.0, &U256($modulus));
+            }
+        }
+
+        impl MulAssign for $name {
+            #[inline]
+            fn mul_assign(&mut self, other: $name) {
+                self.0 = mont_mul(&self.0, &other.0, &U256($modulus), $inv);
             }
         }
 
@@ -212,43 +357,245 @@ field_impl!(
     0x9ede7d651eca6ac987d20782e4866389
 );
 
-lazy_static::lazy_static! {
+/// A fixed exponent as a sliding-window addition chain over the odd powers
+/// `x, x^3, ..., x^31`: start from odd power `steps[0].1`, then for each
+/// `(n, i)` square `n` times and multiply by odd power `i`, then square
+/// `tail` more times. Generated from the binary expansion with 5-bit
+/// windows.
+struct Chain {
+    steps: &'static [(u8, u8)],
+    tail: u8,
+}
 
-    static ref FQ: U256 = U256([
-        0x97816a916871ca8d3c208c16d87cfd47,
-        0x30644e72e131a029b85045b68181585d
-    ]);
+const P_MINUS_1_DIV_2: Chain = Chain {
+    steps: &[
+        (0, 1),
+        (10, 12),
+        (8, 9),
+        (5, 9),
+        (4, 4),
+        (4, 3),
+        (9, 9),
+        (7, 6),
+        (10, 2),
+        (7, 13),
+        (1, 0),
+        (7, 2),
+        (10, 8),
+        (6, 13),
+        (5, 6),
+        (8, 1),
+        (11, 10),
+        (1, 0),
+        (9, 11),
+        (6, 12),
+        (5, 7),
+        (10, 5),
+        (6, 10),
+        (7, 8),
+        (5, 6),
+        (7, 3),
+        (6, 3),
+        (7, 10),
+        (7, 6),
+        (6, 7),
+        (5, 0),
+        (10, 8),
+        (1, 0),
+        (9, 5),
+        (6, 13),
+        (9, 15),
+        (7, 15),
+        (5, 10),
+        (5, 1),
+    ],
+    tail: 0,
+};
+
+const P_PLUS_1_DIV_4: Chain = Chain {
+    steps: &[
+        (0, 1),
+        (10, 12),
+        (8, 9),
+        (5, 9),
+        (4, 4),
+        (4, 3),
+        (9, 9),
+        (7, 6),
+        (10, 2),
+        (7, 13),
+        (1, 0),
+        (7, 2),
+        (10, 8),
+        (6, 13),
+        (5, 6),
+        (8, 1),
+        (11, 10),
+        (1, 0),
+        (9, 11),
+        (6, 12),
+        (5, 7),
+        (10, 5),
+        (6, 10),
+        (7, 8),
+        (5, 6),
+        (7, 3),
+        (6, 3),
+        (7, 10),
+        (7, 6),
+        (6, 7),
+        (5, 0),
+        (10, 8),
+        (1, 0),
+        (9, 5),
+        (6, 13),
+        (9, 15),
+        (7, 15),
+        (5, 10),
+        (3, 0),
+    ],
+    tail: 1,
+};
 
-    pub static ref FQ_MINUS3_DIV4: Fq =
-        Fq::new(3.into()).expect("3 is a valid field element and static; qed").neg() *
-        Fq::new(4.into()).expect("4 is a valid field element and static; qed").inverse()
-            .expect("4 has inverse in Fq and is static; qed");
+impl Fq {
+    fn pow_chain(&self, chain: &Chain) -> Self {
+        let square = self.squared();
+        let mut odd = [*self; 16];
+        for i in 1..16 {
+            odd[i] = odd[i - 1] * square;
+        }
+
+        let mut acc = odd[chain.steps[0].1 as usize];
+        for &(n, i) in &chain.steps[1..] {
+            for _ in 0..n {
+                acc = acc.squared();
+            }
+            acc *= odd[i as usize];
+        }
+        for _ in 0..chain.tail {
+            acc = acc.squared();
+        }
+        acc
+    }
 
-    static ref FQ_MINUS1_DIV2: Fq =
-        Fq::new(1.into()).expect("1 is a valid field element and static; qed").neg() *
-        Fq::new(2.into()).expect("2 is a valid field element and static; qed").inverse()
-            .expect("2 has inverse in Fq and is static; qed");
+    /// `self^((p - 1) / 2)`: one for non-zero squares, -1 for non-squares
+    /// and zero for zero.
+    pub fn pow_p_minus_1_div_2(&self) -> Self {
+        self.pow_chain(&P_MINUS_1_DIV_2)
+    }
 
-}
+    /// `self^((p + 1) / 4)`, a square root of `self` if there is one, as
+    /// `p = 3 mod 4`.
+    pub fn pow_p_plus_1_div_4(&self) -> Self {
+        self.pow_chain(&P_PLUS_1_DIV_4)
+    }
 
-impl Fq {
     pub fn sqrt(&self) -> Option<Self> {
-        let a1 = self.pow(*FQ_MINUS3_DIV4);
-        let a1a = a1 * *self;
-        let a0 = a1 * (a1a);
+        let y = self.pow_p_plus_1_div_4();
+        if y.squared() == *self {
+            Some(y)
+        } else {
+            None
+        }
+    }
+}
 
-        let mut am1 = *FQ;
-        am1.sub(&1.into(), &*FQ);
+/// `(r - 1) / 2`.
+const R_MINUS_1_DIV_2: U256 = U256([
+    0x9419f4243cdcb848a1f0fac9f8000000,
+    0x183227397098d014dc2822db40c0ac2e,
+]);
+
+/// `r - 1 = 2^S * T` with `T` odd.
+const S: usize = 28;
+
+/// `(T - 1) / 2`.
+const T_MINUS_1_DIV_2: U256 = U256([
+    0x0c0ac2e9419f4243cdcb848a1f0fac9f,
+    0x0000000183227397098d014dc2822db4,
+]);
+
+/// `5^T`, a primitive `2^S`-th root of unity, in Montgomery form; 5 is a
+/// non-square.
+const ROOT_OF_UNITY: Fr = Fr(U256([
+    0xa22bf3742445ffd6636e735580d13d9c,
+    0x1860ef942963f9e756452ac01eb203d8,
+]));
+
+impl Fr {
+    /// `self^((r - 1) / 2)`: one for non-zero squares, -1 for non-squares
+    /// and zero for zero.
+    pub fn pow_r_minus_1_div_2(&self) -> Self {
+        self.pow(R_MINUS_1_DIV_2)
+    }
 
-        if a0 == Fq::new(am1).unwrap() {
-            None
-        } else {
-            Some(a1a)
+    /// Tonelli-Shanks, since `r = 1 mod 2^28`. Variable time.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+        let w = self.pow(T_MINUS_1_DIV_2);
+        // x^2 = self * b throughout, and b has order 2^k < 2^v
+        let mut x = *self * w;
+        let mut b = x * w;
+        let mut z = ROOT_OF_UNITY;
+        let mut v = S;
+        while b != Fr::one() {
+            let mut k = 0;
+            let mut b2k = b;
+            while b2k != Fr::one() {
+                b2k = b2k.squared();
+                k += 1;
+            }
+            if k == v {
+                return None;
+            }
+            let mut w = z;
+            for _ in 0..v - k - 1 {
+                w = w.squared();
+            }
+            z = w.squared();
+            b *= z;
+            x *= w;
+            v = k;
         }
+        Some(x)
     }
 }
 
 #[inline]
-pub fn const_fq(i: [u64; 4]) -> Fq {
-    Fq(U256::from(i))
+pub const fn const_fq(i: [u64; 4]) -> Fq {
+    Fq(U256([
+        (i[1] as u128) << 64 | i[0] as u128,
+        (i[3] as u128) << 64 | i[2] as u128,
+    ]))
+}
+
+/// `const_fq` for Fr: little-endian limbs of the Montgomery form.
+#[inline]
+pub const fn const_fr(i: [u64; 4]) -> Fr {
+    Fr(U256([
+        (i[1] as u128) << 64 | i[0] as u128,
+        (i[3] as u128) << 64 | i[2] as u128,
+    ]))
+}
+
+#[test]
+fn square_double_sqrt() {
+    let mut x = -Fr::one();
+    let mut y = -Fq::one();
+    for _ in 0..100 {
+        assert_eq!(x.squared(), x * x);
+        assert_eq!(x.double(), x + x);
+        assert_eq!(y.squared(), y * y);
+        assert_eq!(y.double(), y + y);
+        assert_eq!(x.squared().sqrt().map(|s| s.squared()), Some(x.squared()));
+        assert_eq!((-x).double(), -(x + x));
+        x = x * x + Fr::one();
+        y = y * y + Fq::one();
+    }
+    assert!(Fr::from_str("5").unwrap().sqrt().is_none());
+    assert_eq!(Fr::zero().sqrt(), Some(Fr::zero()));
+    assert_eq!(-Fr::one(), Fr::from_str("5").unwrap().pow_r_minus_1_div_2());
+    assert_eq!(-Fr::one(), ROOT_OF_UNITY.pow(U256([1 << 27, 0])));
 }
@@ -42,7 +42,99 @@ fn frobenius_coeffs_c1(power: usize) -> Fq2 {
                 2767537931541304486,
             ]),
         ),
-        _ => unimplemented!(),
+        4 => Fq2::new(
+            const_fq([
+                3697675806616062876,
+                9065277094688085689,
+                6918009208039626314,
+                2775033306905974752,
+            ]),
+            Fq::zero(),
+        ),
+        5 => Fq2::new(
+            const_fq([
+                9707350126413123702,
+                4651080606516098580,
+                6043753531608262957,
+                368767491532575399,
+            ]),
+            const_fq([
+                1133953642539445191,
+                7894810023100276260,
+                13759109221827166649,
+                1567514350810742612,
+            ]),
+        ),
+        6 => Fq2::new(
+            const_fq([
+                7548957153968385962,
+                10162512645738643279,
+                5900175412809962033,
+                2475245527108272378,
+            ]),
+            Fq::zero(),
+        ),
+        7 => Fq2::new(
+            const_fq([
+                10125470202929394752,
+                14778084017983066882,
+                12002753090013312981,
+                3274399494041658797,
+            ]),
+            const_fq([
+                11096269095009767381,
+                14371663628800878897,
+                15861768134217273142,
+                2286974686072408791,
+            ]),
+        ),
+        8 => Fq2::new(
+            const_fq([
+                8183898218631979349,
+                12014359695528440611,
+                12263358156045030468,
+                3187210487005268291,
+            ]),
+            Fq::zero(),
+        ),
+        9 => Fq2::new(
+            const_fq([
+                418120076516271050,
+                10127003411466968302,
+                5958999558405050024,
+                2905632002509083398,
+            ]),
+            const_fq([
+                9962315452470322190,
+                6476853605700602637,
+                2102658912390106493,
+                719460335261666179,
+            ]),
+        ),
+        10 => Fq2::new(
+            const_fq([
+                634941064663593387,
+                1851847049789797332,
+                6363182743235068435,
+                711964959896995913,
+            ]),
+            Fq::zero(),
+        ),
+        11 => Fq2::new(
+            const_fq([
+                13072010818576084177,
+                6266043537961784440,
+                7237438419666431792,
+                3118230775270395266,
+            ]),
+            const_fq([
+                3198663228740211072,
+                3022314121377606761,
+                17968826803157079716,
+                1919483915992228052,
+            ]),
+        ),
+        _ => unreachable!(),
     }
 }
 
@@ -58,6 +150,36 @@ impl Fq12 {
         Fq12 { c0: c0, c1: c1 }
     }
 
+    /// The twelve `Fq` coefficients in tower order: c0.c0.real,
+    /// c0.c0.imaginary, c0.c1.real, ..., c1.c2.imaginary.
+    pub fn coeffs(&self) -> [Fq; 12] {
+        let mut out = [Fq::zero(); 12];
+        let fq2s = [
+            self.c0.c0, self.c0.c1, self.c0.c2, self.c1.c0, self.c1.c1, self.c1.c2,
+        ];
+        for (i, c) in fq2s.iter().enumerate() {
+            out[2 * i] = *c.real();
+            out[2 * i + 1] = *c.imaginary();
+        }
+        out
+    }
+
+    /// Inverse of `coeffs`.
+    pub fn from_coeffs(c: [Fq; 12]) -> Self {
+        Fq12 {
+            c0: Fq6::new(
+                Fq2::new(c[0], c[1]),
+                Fq2::new(c[2], c[3]),
+                Fq2::new(c[4], c[5]),
+            ),
+            c1: Fq6::new(
+                Fq2::new(c[6], c[7]),
+                Fq2::new(c[8], c[9]),
+                Fq2::new(c[10], c[11]),
+            ),
+        }
+    }
+
     fn final_exponentiation_first_chunk(&self) -> Option<Fq12> {
         match self.inverse() {
             Some(b) => {
@@ -127,6 +249,58 @@ impl Fq12 {
         Fq12::new(self.c0, -self.c1)
     }
 
+    /// Maps a unitary element `g + h w` (one with `f^(p^6 + 1) = 1`, as
+    /// every final-exponentiated value is) to `(1 + g) / h`, a point of the
+    /// algebraic torus T2. `None` for `h = 0`, i.e. for 1 and -1.
+    pub fn torus_compress(&self) -> Option<Fq6> {
+        self.c1.inverse().map(|h| (Fq6::one() + self.c0) * h)
+    }
+
+    /// Inverse of `torus_compress`: `(c + w) / (c - w)`, which is
+    /// `(c^2 + v + 2 c w) / (c^2 - v)` since `w^2 = v`. The denominator is
+    /// never zero because `v` is not a square in Fq6.
+    pub fn torus_decompress(c: Fq6) -> Fq12 {
+        let v = Fq6::one().mul_by_nonresidue();
+        let c2 = c.squared();
+        let d = (c2 - v).inverse().expect("v is not a square in Fq6; qed");
+        Fq12::new((c2 + v) * d, (c + c) * d)
+    }
+
+    /// The product of two lines `[ell_0, ell_vw, ell_vv]` in the sparse
+    /// form taken by `mul_by_024`, with six `Fq2` multiplications. `c1.c2`
+    /// of the result is zero, so it can be absorbed with `mul_by_01234`.
+    pub fn mul_024_by_024(a: [Fq2; 3], b: [Fq2; 3]) -> Fq12 {
+        let [a0, a4, a2] = a;
+        let [b0, b4, b2] = b;
+        let d0 = a0 * b0;
+        let d2 = a2 * b2;
+        let d4 = a4 * b4;
+
+        Fq12 {
+            c0: Fq6::new(
+                d4.mul_by_nonresidue() + d0,
+                d2.mul_by_nonresidue(),
+                (a0 + a2) * (b0 + b2) - d0 - d2,
+            ),
+            c1: Fq6::new(
+                ((a2 + a4) * (b2 + b4) - d2 - d4).mul_by_nonresidue(),
+                (a0 + a4) * (b0 + b4) - d0 - d4,
+                Fq2::zero(),
+            ),
+        }
+    }
+
+    /// `self * other` for an `other` whose `c1.c2` is zero.
+    pub fn mul_by_01234(&self, other: &Fq12) -> Fq12 {
+        let aa = self.c0 * other.c0;
+        let bb = self.c1.mul_by_01(other.c1.c0, other.c1.c1);
+
+        Fq12 {
+            c0: bb.mul_by_nonresidue() + aa,
+            c1: (self.c0 + self.c1) * (other.c0 + other.c1) - aa - bb,
+        }
+    }
+
     pub fn mul_by_024(&self, ell_0: Fq2, ell_vw: Fq2, ell_vv: Fq2) -> Fq12 {
         let z0 = self.c0.c0;
         let z1 = self.c0.c1;
@@ -195,6 +369,13 @@ impl Fq12 {
         }
     }
 
+    /// Whether `self^(q^4 - q^2 + 1) == 1`, which holds for every output
+    /// of the final exponentiation. `cyclotomic_squared` is only correct
+    /// for such elements.
+    pub fn is_cyclotomic(&self) -> bool {
+        self.frobenius_map(4) * *self == self.frobenius_map(2)
+    }
+
     pub fn cyclotomic_squared(&self) -> Self {
         let z0 = self.c0.c0;
         let z4 = self.c0.c1;
@@ -353,3 +534,63 @@ impl Neg for Fq12 {
         }
     }
 }
+
+#[test]
+fn frobenius_is_q_power() {
+    let mut c = [Fq::zero(); 12];
+    for (i, c) in c.iter_mut().enumerate() {
+        *c = Fq::new(U256::from((i * i + 7) as u64)).unwrap();
+    }
+    let x = Fq12::from_coeffs(c);
+    let q = Fq::modulus();
+    assert_eq!(x.frobenius_map(1), x.pow(q));
+
+    let mut y = x;
+    for power in 0..14 {
+        assert_eq!(x.frobenius_map(power), y);
+        y = y.frobenius_map(1);
+    }
+}
+
+#[test]
+fn cyclotomic_pow_matches_pow() {
+    let mut c = [Fq::zero(); 12];
+    for (i, c) in c.iter_mut().enumerate() {
+        *c = Fq::new(U256::from((3 * i + 1) as u64)).unwrap();
+    }
+    let x = Fq12::from_coeffs(c);
+    assert!(!x.is_cyclotomic());
+    let y = x.final_exponentiation().unwrap();
+    assert!(y.is_cyclotomic());
+    assert_eq!(y.cyclotomic_squared(), y.squared());
+    let e = U256::from(0xdead_beef_1234_5678u64);
+    assert_eq!(y.cyclotomic_pow(e), y.pow(e));
+}
+
+#[test]
+fn sparse_mul_matches_dense() {
+    let fq2 = |i: u64| {
+        Fq2::new(
+            Fq::new(U256::from(i * i + 3)).unwrap(),
+            Fq::new(U256::from(5 * i + 1)).unwrap(),
+        )
+    };
+    let line = |i: u64| [fq2(i), fq2(i + 1), fq2(i + 2)];
+    let dense = |l: [Fq2; 3]| {
+        Fq12::new(
+            Fq6::new(l[0], Fq2::zero(), l[2]),
+            Fq6::new(Fq2::zero(), l[1], Fq2::zero()),
+        )
+    };
+    let mut c = [Fq::zero(); 12];
+    for (i, c) in c.iter_mut().enumerate() {
+        *c = Fq::new(U256::from((7 * i + 2) as u64)).unwrap();
+    }
+    let f = Fq12::from_coeffs(c);
+
+    let (a, b) = (line(1), line(10));
+    assert_eq!(f.mul_by_024(a[0], a[1], a[2]), f * dense(a));
+    let ab = Fq12::mul_024_by_024(a, b);
+    assert_eq!(ab, dense(a) * dense(b));
+    assert_eq!(f.mul_by_01234(&ab), f * ab);
+}
@@ -9,7 +9,7 @@ use core::{
     ops::{Add, Mul, Neg, Sub},
 };
 
-pub use self::fp::{const_fq, Fq, Fr};
+pub use self::fp::{const_fq, const_fr, Fq, Fr};
 pub use self::fq12::Fq12;
 pub use self::fq2::{fq2_nonresidue, Fq2};
 pub use self::fq6::Fq6;
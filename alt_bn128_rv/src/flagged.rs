@@ -0,0 +1,281 @@
+//! Compressed encodings that carry the infinity and y-sign flags in the two
+//! spare top bits of x, as produced by gnark (big-endian) and arkworks
+//! (little-endian) for BN254. Both pick y by `YSign::Lexicographic`.
+//!
+//! - `_be`: x big-endian, flags in the top bits of the first byte: `01`
+//!   for infinity, `10` for the smaller y, `11` for the larger. G2 x is
+//!   written imaginary part first.
+//! - `_le`: x little-endian, flags in the top bits of the last byte: bit 6
+//!   for infinity, bit 7 for the larger y. G2 x is written real part first.
+//!
+//! The point at infinity is its flag with every other bit zero.
+//!
+//! Decoding has a fixed policy, `DecodePolicy::evm()`: x must be below the
+//! modulus, G2 points must be in the prime-order subgroup, and the infinity
+//! flag is accepted. There are no `_with_policy` variants; decode points
+//! that need another policy from their uncompressed form.
+
+use crate::{AffineG1, AffineG2, Component, CurveError, Fq, Fq2, Group, YSign, G1, G2};
+use core::convert::TryInto;
+
+const MASK: u8 = 0b11 << 6;
+const BE_INFINITY: u8 = 0b01 << 6;
+const BE_SMALLEST: u8 = 0b10 << 6;
+const BE_LARGEST: u8 = 0b11 << 6;
+const LE_INFINITY: u8 = 1 << 6;
+const LE_LARGEST: u8 = 1 << 7;
+
+enum Flag {
+    Infinity,
+    Largest(bool),
+}
+
+fn be_flag(b: u8) -> Result<Flag, CurveError> {
+    match b & MASK {
+        BE_INFINITY => Ok(Flag::Infinity),
+        BE_SMALLEST => Ok(Flag::Largest(false)),
+        BE_LARGEST => Ok(Flag::Largest(true)),
+        _ => Err(CurveError::InvalidEncoding),
+    }
+}
+
+fn le_flag(b: u8) -> Result<Flag, CurveError> {
+    match b & MASK {
+        0 => Ok(Flag::Largest(false)),
+        LE_LARGEST => Ok(Flag::Largest(true)),
+        LE_INFINITY => Ok(Flag::Infinity),
+        _ => Err(CurveError::InvalidEncoding),
+    }
+}
+
+fn length<const N: usize>(bytes: &[u8]) -> Result<[u8; N], CurveError> {
+    bytes.try_into().map_err(|_| CurveError::InvalidLength {
+        expected: N,
+        actual: bytes.len(),
+    })
+}
+
+/// The 32-byte big-endian integer at `bytes[offset..]`, reversed from
+/// little-endian if `le`, with the flag bits cleared.
+fn coordinate<const N: usize>(bytes: &[u8; N], offset: usize, le: bool) -> [u8; 32] {
+    let mut c = [0u8; 32];
+    c.copy_from_slice(&bytes[offset..offset + 32]);
+    if le {
+        c.reverse();
+    }
+    c[0] &= !MASK;
+    c
+}
+
+fn write(out: &mut [u8], x: Fq, le: bool) {
+    x.to_big_endian(out).expect("slice is 32 bytes; qed");
+    if le {
+        out.reverse();
+    }
+}
+
+fn g1(x: [u8; 32], flag: Flag) -> Result<G1, CurveError> {
+    let largest = match flag {
+        Flag::Infinity if x == [0; 32] => return Ok(G1::zero()),
+        Flag::Infinity => return Err(CurveError::InvalidEncoding),
+        Flag::Largest(largest) => largest,
+    };
+    let x = Fq::from_slice(&x).map_err(|e| CurveError::fq(Component::X, 0, e))?;
+    AffineG1::new(x, YSign::Lexicographic.g1_y(x, largest)?)
+        .map(Into::into)
+        .map_err(|_| CurveError::NotMember)
+}
+
+/// `real` and `imaginary` are 32-byte big-endian coefficients found at the
+/// given offsets of the input.
+fn g2(real: ([u8; 32], usize), imaginary: ([u8; 32], usize), flag: Flag) -> Result<G2, CurveError> {
+    let largest = match flag {
+        Flag::Infinity if real.0 == [0; 32] && imaginary.0 == [0; 32] => return Ok(G2::zero()),
+        Flag::Infinity => return Err(CurveError::InvalidEncoding),
+        Flag::Largest(largest) => largest,
+    };
+    let c0 = Fq::from_slice(&real.0).map_err(|e| CurveError::fq(Component::XReal, real.1, e))?;
+    let c1 = Fq::from_slice(&imaginary.0)
+        .map_err(|e| CurveError::fq(Component::XImaginary, imaginary.1, e))?;
+    let x = Fq2::new(c0, c1);
+    AffineG2::new(x, YSign::Lexicographic.g2_y(x, largest)?)
+        .map(Into::into)
+        .map_err(|_| CurveError::NotMember)
+}
+
+impl G1 {
+    /// The gnark compressed encoding.
+    pub fn to_bytes_compressed_be(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        match AffineG1::from_jacobian(*self) {
+            None => out[0] = BE_INFINITY,
+            Some(p) => {
+                write(&mut out, p.x(), false);
+                out[0] |= if YSign::Lexicographic.of_fq(p.y()) {
+                    BE_LARGEST
+                } else {
+                    BE_SMALLEST
+                };
+            }
+        }
+        out
+    }
+
+    pub fn from_bytes_compressed_be(bytes: &[u8]) -> Result<G1, CurveError> {
+        let bytes = length::<32>(bytes)?;
+        g1(coordinate(&bytes, 0, false), be_flag(bytes[0])?)
+    }
+
+    /// The arkworks compressed encoding.
+    pub fn to_bytes_compressed_le(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        match AffineG1::from_jacobian(*self) {
+            None => out[31] = LE_INFINITY,
+            Some(p) => {
+                write(&mut out, p.x(), true);
+                if YSign::Lexicographic.of_fq(p.y()) {
+                    out[31] |= LE_LARGEST;
+                }
+            }
+        }
+        out
+    }
+
+    pub fn from_bytes_compressed_le(bytes: &[u8]) -> Result<G1, CurveError> {
+        let bytes = length::<32>(bytes)?;
+        g1(coordinate(&bytes, 0, true), le_flag(bytes[31])?)
+    }
+}
+
+impl G2 {
+    /// The gnark compressed encoding.
+    pub fn to_bytes_compressed_be(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        match AffineG2::from_jacobian(*self) {
+            None => out[0] = BE_INFINITY,
+            Some(p) => {
+                write(&mut out[..32], p.x().imaginary(), false);
+                write(&mut out[32..], p.x().real(), false);
+                out[0] |= if YSign::Lexicographic.of_fq2(p.y()) {
+                    BE_LARGEST
+                } else {
+                    BE_SMALLEST
+                };
+            }
+        }
+        out
+    }
+
+    pub fn from_bytes_compressed_be(bytes: &[u8]) -> Result<G2, CurveError> {
+        let bytes = length::<64>(bytes)?;
+        g2(
+            (coordinate(&bytes, 32, false), 32),
+            (coordinate(&bytes, 0, false), 0),
+            be_flag(bytes[0])?,
+        )
+    }
+
+    /// The arkworks compressed encoding.
+    pub fn to_bytes_compressed_le(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        match AffineG2::from_jacobian(*self) {
+            None => out[63] = LE_INFINITY,
+            Some(p) => {
+                write(&mut out[..32], p.x().real(), true);
+                write(&mut out[32..], p.x().imaginary(), true);
+                if YSign::Lexicographic.of_fq2(p.y()) {
+                    out[63] |= LE_LARGEST;
+                }
+            }
+        }
+        out
+    }
+
+    pub fn from_bytes_compressed_le(bytes: &[u8]) -> Result<G2, CurveError> {
+        let bytes = length::<64>(bytes)?;
+        g2(
+            (coordinate(&bytes, 0, true), 0),
+            (coordinate(&bytes, 32, true), 32),
+            le_flag(bytes[63])?,
+        )
+    }
+}
+
+#[test]
+fn generator_encodings() {
+    let mut be = [0u8; 32];
+    be[0] = BE_SMALLEST;
+    be[31] = 1;
+    let mut le = [0u8; 32];
+    le[0] = 1;
+    assert_eq!(G1::one().to_bytes_compressed_be(), be);
+    assert_eq!(G1::one().to_bytes_compressed_le(), le);
+    assert_eq!(G1::from_bytes_compressed_be(&be), Ok(G1::one()));
+    assert_eq!(G1::from_bytes_compressed_le(&le), Ok(G1::one()));
+    le[31] = LE_LARGEST;
+    assert_eq!(G1::from_bytes_compressed_le(&le), Ok(-G1::one()));
+    le[31] = MASK;
+    assert_eq!(
+        G1::from_bytes_compressed_le(&le),
+        Err(CurveError::InvalidEncoding)
+    );
+    assert_eq!(
+        G1::from_bytes_compressed_be(&G1::zero().to_bytes_compressed_be()),
+        Ok(G1::zero())
+    );
+
+    for q in [G2::one(), -G2::one(), G2::zero()] {
+        assert!(G2::from_bytes_compressed_be(&q.to_bytes_compressed_be()) == Ok(q));
+        assert!(G2::from_bytes_compressed_le(&q.to_bytes_compressed_le()) == Ok(q));
+    }
+}
+
+#[test]
+fn uncompressed_le() {
+    use crate::{FieldError, Fr};
+
+    let g = AffineG1::from_jacobian(G1::one()).unwrap();
+    let bytes = g.to_le_bytes();
+    assert_eq!(bytes[..32], G1::one().to_bytes_compressed_le());
+    assert_eq!(bytes[32], 2);
+    assert_eq!(AffineG1::from_le_slice(&bytes), Ok(g));
+
+    let h = AffineG2::from_jacobian(G2::one()).unwrap();
+    let bytes = h.to_le_bytes();
+    assert!(AffineG2::from_le_slice(&bytes) == Ok(h));
+    let mut x = [0u8; 64];
+    h.x().to_little_endian(&mut x).unwrap();
+    assert_eq!(bytes[..64], x);
+    assert_eq!(Fq2::from_le_slice(&x), Ok(h.x()));
+
+    // q itself as the imaginary part of y
+    let mut bad = bytes;
+    (-Fq::one()).to_little_endian(&mut bad[96..]).unwrap();
+    bad[96] += 1;
+    assert!(
+        AffineG2::from_le_slice(&bad)
+            == Err(CurveError::fq(
+                Component::YImaginary,
+                96,
+                FieldError::NotMember
+            ))
+    );
+    assert!(
+        AffineG2::from_le_slice(&bad[1..])
+            == Err(CurveError::InvalidLength {
+                expected: 128,
+                actual: 127
+            })
+    );
+
+    let r_minus_1 = -Fr::one();
+    let mut le = [0u8; 32];
+    r_minus_1.to_little_endian(&mut le).unwrap();
+    let mut be = [0u8; 32];
+    r_minus_1.into_u256().to_big_endian(&mut be).unwrap();
+    be.reverse();
+    assert_eq!(le, be);
+    assert_eq!(Fr::from_le_slice(&le), Ok(r_minus_1));
+    le[0] += 1;
+    assert_eq!(Fr::from_le_slice(&le), Err(FieldError::NotMember));
+}
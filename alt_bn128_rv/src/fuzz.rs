@@ -0,0 +1,91 @@
+//! `arbitrary::Arbitrary` for the field and group types, so fuzz targets
+//! and property tests can draw valid structured inputs. Field elements are
+//! reduced from 64 bytes, which is close to uniform; points are multiples
+//! of the generator, and the affine types replace the point at infinity,
+//! which they cannot represent, with the generator. `arbitrary` needs std.
+
+use crate::{AffineG1, AffineG2, Fq, Fq2, Fr, Group, G1, G2};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for Fr {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Fr::from_bytes_wide(&u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 64]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Fq {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Fq::interpret(&u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 64]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Fq2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Fq2::new(u.arbitrary()?, u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 128]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for G1 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(G1::one() * Fr::arbitrary(u)?)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Fr::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for G2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(G2::one() * Fr::arbitrary(u)?)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Fr::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for AffineG1 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(AffineG1::from_jacobian(G1::arbitrary(u)?).unwrap_or_else(AffineG1::generator))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Fr::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for AffineG2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(AffineG2::from_jacobian(G2::arbitrary(u)?).unwrap_or_else(AffineG2::generator))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Fr::size_hint(depth)
+    }
+}
+
+#[test]
+fn valid_points() {
+    let bytes = [0x5au8; 128];
+    let mut u = Unstructured::new(&bytes);
+    let p = AffineG1::arbitrary(&mut u).unwrap();
+    assert!(AffineG1::new(p.x(), p.y()).is_ok());
+    let q = G2::arbitrary(&mut u).unwrap();
+    assert!(q.is_in_subgroup());
+    // exhausted input reads as zeros: the scalar is zero
+    assert_eq!(G1::arbitrary(&mut u).unwrap(), G1::zero());
+    assert!(AffineG2::arbitrary(&mut u).unwrap() == AffineG2::generator());
+}
@@ -5,6 +5,23 @@ use core::{
     ops::{Add, Mul, Neg, Sub},
 };
 
+mod comb;
+mod ct;
+mod generator_tables;
+mod glv;
+mod msm;
+
+pub use self::comb::{comb_msm, comb_table, CombTable, COMB_TEETH};
+pub use self::ct::mul_ct;
+pub use self::generator_tables::{G1_GENERATOR, G2_GENERATOR};
+// with the `parallel` feature, every variable-base MSM runs on the rayon
+// thread pool
+#[cfg(not(all(feature = "parallel", not(feature = "consensus"))))]
+pub use self::msm::msm;
+#[cfg(all(feature = "parallel", not(feature = "consensus")))]
+pub use self::msm::msm_parallel as msm;
+pub use self::msm::{bucket_count, sum_small_digits, MsmCostModel, MAX_WINDOW as MSM_MAX_WINDOW};
+
 // This is the NAF version of ate_loop_count. Entries are all mod 4, so 3 = -1
 // n.b. ate_loop_count = 0x19d797039be763ba8
 //                     = 11001110101111001011100000011100110111110011101100011101110101000
@@ -42,6 +59,13 @@ pub trait GroupParams: Sized + fmt::Debug {
     fn check_order() -> bool {
         false
     }
+
+    /// `k * p` for `p` in the prime-order subgroup. Points outside it must
+    /// use `G::mul_u256`, as the endomorphisms in `glv` act differently
+    /// there.
+    fn mul_fr(p: &G<Self>, k: Fr) -> G<Self> {
+        p.mul_u256(&U256::from(k))
+    }
 }
 
 #[repr(C)]
@@ -80,6 +104,26 @@ impl<P: GroupParams> G<P> {
     pub fn z_mut(&mut self) -> &mut P::Base {
         &mut self.z
     }
+
+    /// Double-and-add multiplication by an integer that need not be
+    /// reduced modulo r, e.g. a cofactor.
+    pub fn mul_u256(&self, other: &U256) -> G<P> {
+        let mut res = G::zero();
+        let mut found_one = false;
+
+        for i in other.bits() {
+            if found_one {
+                res = res.double();
+            }
+
+            if i {
+                found_one = true;
+                res = res + *self;
+            }
+        }
+
+        res
+    }
 }
 
 #[derive(Debug, Default)]
@@ -94,21 +138,50 @@ pub enum Error {
     NotInSubgroup,
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::NotOnCurve => "point is not on the curve",
+            Error::NotInSubgroup => "point is not in the prime-order subgroup",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl<P: GroupParams> G<P> {
+    /// Whether `Y^2 = X^3 + b * Z^6`. Any point with `Z = 0` is taken as
+    /// the point at infinity and passes.
+    pub fn is_on_curve(&self) -> bool {
+        if self.z.is_zero() {
+            return true;
+        }
+        let z2 = self.z.squared();
+        let z6 = z2.squared() * z2;
+        self.y.squared() == self.x.squared() * self.x + P::coeff_b() * z6
+    }
+
+    /// Whether `r * self` is the identity. Always true for groups whose
+    /// curve has prime order.
+    pub fn is_in_subgroup(&self) -> bool {
+        !P::check_order() || (self.mul_u256(&U256::from(-Fr::one())) + *self).is_zero()
+    }
+}
+
 impl<P: GroupParams> AffineG<P> {
     pub fn new(x: P::Base, y: P::Base) -> Result<Self, Error> {
-        if y.squared() == (x.squared() * x) + P::coeff_b() {
-            if P::check_order() {
-                let p: G<P> = G {
-                    x: x,
-                    y: y,
-                    z: P::Base::one(),
-                };
-
-                if (p * (-Fr::one())) + p != G::zero() {
-                    return Err(Error::NotInSubgroup);
-                }
-            }
+        let p = Self::new_on_curve(x, y)?;
+        if P::check_order()
+            && p.to_jacobian().mul_u256(&U256::from(-Fr::one())) + p.to_jacobian() != G::zero()
+        {
+            return Err(Error::NotInSubgroup);
+        }
+        Ok(p)
+    }
 
+    /// Like `new`, but skips the subgroup check.
+    pub fn new_on_curve(x: P::Base, y: P::Base) -> Result<Self, Error> {
+        if y.squared() == (x.squared() * x) + P::coeff_b() {
             Ok(AffineG { x: x, y: y })
         } else {
             Err(Error::NotOnCurve)
@@ -208,18 +281,28 @@ impl<P: GroupParams> G<P> {
                 y: self.y,
             })
         } else {
-            let zinv = self.z.inverse().unwrap();
-            let zinv_squared = zinv.squared();
+            Some(self.to_affine_with(self.z.inverse().unwrap()))
+        }
+    }
 
-            Some(AffineG {
-                x: self.x * zinv_squared,
-                y: self.y * (zinv_squared * zinv),
-            })
+    /// `to_affine` of a point not at infinity, given the inverse of `z`,
+    /// e.g. from a batched inversion.
+    pub fn to_affine_with(&self, zinv: P::Base) -> AffineG<P> {
+        let zinv_squared = zinv.squared();
+        AffineG {
+            x: self.x * zinv_squared,
+            y: self.y * (zinv_squared * zinv),
         }
     }
 }
 
 impl<P: GroupParams> AffineG<P> {
+    /// The generator; `P::one()` is given with `z = 1`.
+    pub fn generator() -> Self {
+        let g = P::one();
+        AffineG { x: g.x, y: g.y }
+    }
+
     pub fn to_jacobian(&self) -> G<P> {
         G {
             x: self.x,
@@ -272,21 +355,7 @@ impl<P: GroupParams> Mul<Fr> for G<P> {
     type Output = G<P>;
 
     fn mul(self, other: Fr) -> G<P> {
-        let mut res = G::zero();
-        let mut found_one = false;
-
-        for i in U256::from(other).bits() {
-            if found_one {
-                res = res.double();
-            }
-
-            if i {
-                found_one = true;
-                res = res + self;
-            }
-        }
-
-        res
+        P::mul_fr(&self, other)
     }
 }
 
@@ -332,6 +401,52 @@ impl<P: GroupParams> Add<G<P>> for G<P> {
     }
 }
 
+/// Mixed addition of a point with `z = 1`, saving the multiplications by
+/// its `z`. The affine point must not be the `Default` placeholder, which
+/// is not on the curve.
+impl<P: GroupParams> Add<AffineG<P>> for G<P> {
+    type Output = G<P>;
+
+    fn add(self, other: AffineG<P>) -> G<P> {
+        if self.is_zero() {
+            return other.to_jacobian();
+        }
+
+        let z1_squared = self.z.squared();
+        let u2 = other.x * z1_squared;
+        let s2 = other.y * self.z * z1_squared;
+
+        if self.x == u2 && self.y == s2 {
+            self.double()
+        } else {
+            let h = u2 - self.x;
+            let h_squared = h.squared();
+            let i = h_squared + h_squared;
+            let i = i + i;
+            let j = h * i;
+            let s2_minus_y1 = s2 - self.y;
+            let r = s2_minus_y1 + s2_minus_y1;
+            let v = self.x * i;
+            let y1_j = self.y * j;
+            let x3 = r.squared() - j - (v + v);
+
+            G {
+                x: x3,
+                y: r * (v - x3) - (y1_j + y1_j),
+                z: (self.z + h).squared() - z1_squared - h_squared,
+            }
+        }
+    }
+}
+
+impl<P: GroupParams> Sub<AffineG<P>> for G<P> {
+    type Output = G<P>;
+
+    fn sub(self, other: AffineG<P>) -> G<P> {
+        self + (-other)
+    }
+}
+
 impl<P: GroupParams> Neg for G<P> {
     type Output = G<P>;
 
@@ -398,6 +513,10 @@ impl GroupParams for G1Params {
             0x2a1f6744ce179d8e,
         ])
     }
+
+    fn mul_fr(p: &G<Self>, k: Fr) -> G<Self> {
+        glv::mul_halves(*p, glv::endomorphism_g1(p), glv::decompose_g1(k))
+    }
 }
 
 pub type G1 = G<G1Params>;
@@ -468,6 +587,10 @@ impl GroupParams for G2Params {
     fn check_order() -> bool {
         true
     }
+
+    fn mul_fr(p: &G<Self>, k: Fr) -> G<Self> {
+        glv::mul_halves(*p, glv::endomorphism_g2(p), glv::decompose_g2(k))
+    }
 }
 
 pub type G2 = G<G2Params>;
@@ -578,40 +701,94 @@ impl G2Precomp {
     }
 }
 
+/// Multiplies `f` by the lines at `idx` of every pair. Lines are multiplied
+/// together two at a time, which is cheaper than absorbing each into `f`.
+fn absorb_lines(
+    mut f: Fq12,
+    g2_precomputes: &[G2Precomp],
+    g1_vec: &[AffineG<G1Params>],
+    idx: usize,
+) -> Fq12 {
+    let mut lines = g2_precomputes.iter().zip(g1_vec.iter()).map(|(q, p)| {
+        let c = &q.coeffs[idx];
+        [c.ell_0, c.ell_vw.scale(p.y), c.ell_vv.scale(p.x)]
+    });
+    while let Some(a) = lines.next() {
+        f = match lines.next() {
+            Some(b) => f.mul_by_01234(&Fq12::mul_024_by_024(a, b)),
+            None => f.mul_by_024(a[0], a[1], a[2]),
+        };
+    }
+    f
+}
+
 pub fn miller_loop_batch(g2_precomputes: &[G2Precomp], g1_vec: &[AffineG<G1Params>]) -> Fq12 {
     let mut f = Fq12::one();
 
     let mut idx = 0;
 
     for i in ATE_LOOP_COUNT_NAF.iter() {
-        f = f.squared();
-        for (g2_precompute, g1) in g2_precomputes.iter().zip(g1_vec.iter()) {
-            let c = &g2_precompute.coeffs[idx];
-            f = f.mul_by_024(c.ell_0, c.ell_vw.scale(g1.y), c.ell_vv.scale(g1.x));
-        }
+        f = absorb_lines(f.squared(), g2_precomputes, g1_vec, idx);
         idx += 1;
         if *i != 0 {
-            for (g2_precompute, g1) in g2_precomputes.iter().zip(g1_vec.iter()) {
-                let c = &g2_precompute.coeffs[idx];
-                f = f.mul_by_024(c.ell_0, c.ell_vw.scale(g1.y), c.ell_vv.scale(g1.x));
-            }
+            f = absorb_lines(f, g2_precomputes, g1_vec, idx);
             idx += 1;
         }
     }
 
-    for (g2_precompute, g1) in g2_precomputes.iter().zip(g1_vec.iter()) {
-        let c = &g2_precompute.coeffs[idx];
-        f = f.mul_by_024(c.ell_0, c.ell_vw.scale(g1.y), c.ell_vv.scale(g1.x));
+    f = absorb_lines(f, g2_precomputes, g1_vec, idx);
+    absorb_lines(f, g2_precomputes, g1_vec, idx + 1)
+}
+
+impl G<G2Params> {
+    /// `psi`, the untwist-Frobenius-twist endomorphism, which acts on the
+    /// prime-order subgroup as multiplication by `q`.
+    pub fn frobenius(&self) -> Self {
+        glv::endomorphism_g2(self)
     }
-    idx += 1;
-    for (g2_precompute, g1) in g2_precomputes.iter().zip(g1_vec.iter()) {
-        let c = &g2_precompute.coeffs[idx];
-        f = f.mul_by_024(c.ell_0, c.ell_vw.scale(g1.y), c.ell_vv.scale(g1.x));
+
+    /// Multiplication by the twist cofactor `2q - r`, for any point of the
+    /// twist. `psi` satisfies `psi^2 - t psi + q = 0` on the whole twist,
+    /// with `t = q + 1 - r` the trace, so `(2q - r) P` is
+    /// `t (psi(P) + P) - psi^2(P) - P`: one multiplication by the 127-bit
+    /// `t` instead of one by the 254-bit cofactor.
+    pub fn mul_by_cofactor(&self) -> Self {
+        let psi = self.frobenius();
+        (psi + *self).mul_u256(&U256([TRACE, 0])) - psi.frobenius() - *self
     }
-    f
 }
 
+/// The trace of Frobenius of the curve, `6x^2 + 1`.
+const TRACE: u128 = 0x6f4d8248eeb859fbf83e9682e87cfd47;
+
 impl AffineG<G2Params> {
+    /// Maps the point to `(x w^2, y w^3)` on `y^2 = x^3 + 3` over Fq12,
+    /// where `w^2 = v` and `v^3` is the twist's non-residue.
+    pub fn untwist(&self) -> (Fq12, Fq12) {
+        let mut x = [Fq::zero(); 12];
+        let mut y = [Fq::zero(); 12];
+        x[2] = *self.x.real();
+        x[3] = *self.x.imaginary();
+        y[8] = *self.y.real();
+        y[9] = *self.y.imaginary();
+        (Fq12::from_coeffs(x), Fq12::from_coeffs(y))
+    }
+
+    /// Inverse of `untwist`: `None` unless `x` is a multiple of `w^2` and
+    /// `y` of `w^3`, or if the result is not a valid G2 point.
+    pub fn twist(x: &Fq12, y: &Fq12) -> Option<Self> {
+        let (x, y) = (x.coeffs(), y.coeffs());
+        let only = |c: &[Fq; 12], i: usize| {
+            c.iter()
+                .enumerate()
+                .all(|(j, c)| j == i || j == i + 1 || c.is_zero())
+        };
+        if !only(&x, 2) || !only(&y, 8) {
+            return None;
+        }
+        AffineG::new(Fq2::new(x[2], x[3]), Fq2::new(y[8], y[9])).ok()
+    }
+
     fn mul_by_q(&self) -> Self {
         AffineG {
             x: twist_mul_by_q_x() * self.x.frobenius_map(1),
@@ -672,7 +849,7 @@ impl G2 {
 
         self.x = d * j;
         self.y = e * (i - j) - h * self.y;
-        self.z = self.z * h;
+        self.z *= h;
 
         EllCoeffs {
             ell_0: twist() * (e * base.x - d * base.y),
@@ -717,29 +894,290 @@ pub fn pairing(p: &G1, q: &G2) -> Fq12 {
     }
 }
 
-pub fn pairing_batch(ps: &[G1], qs: &[G2]) -> Fq12 {
-    let mut p_affines = [AffineG::default(); 16];
-    let mut q_precomputes = [G2Precomp::default(); 16];
+/// Collects the pairs without a point at infinity, which contribute one to
+/// the product, into `p_affines` and `q_precomputes`. Returns their count.
+fn collect_pairs(
+    ps: &[G1],
+    qs: &[G2],
+    p_affines: &mut [AffineG<G1Params>],
+    q_precomputes: &mut [G2Precomp],
+) -> usize {
     let mut idx = 0;
-
     for (p, q) in ps.iter().zip(qs.iter()) {
-        let p_affine = p.to_affine();
-        let q_affine = q.to_affine();
-        let exists = match (p_affine, q_affine) {
-            (None, _) | (_, None) => false,
-            (Some(_p_affine), Some(_q_affine)) => true,
-        };
-
-        if exists {
-            p_affines[idx] = p.to_affine().unwrap();
-            q_precomputes[idx] = q.to_affine().unwrap().precompute();
+        if let (Some(p), Some(q)) = (p.to_affine(), q.to_affine()) {
+            p_affines[idx] = p;
+            q_precomputes[idx].q = q;
             idx += 1;
         }
     }
-    if q_precomputes.len() == 0 {
-        return Fq12::one();
+    #[cfg(all(feature = "parallel", not(feature = "consensus")))]
+    {
+        use rayon::prelude::*;
+        q_precomputes[..idx]
+            .par_iter_mut()
+            .for_each(|c| *c = c.q.precompute());
     }
-    miller_loop_batch(&q_precomputes[0..idx], &p_affines[0..idx])
-        .final_exponentiation()
-        .expect("miller loop cannot produce zero")
+    #[cfg(not(all(feature = "parallel", not(feature = "consensus"))))]
+    for c in q_precomputes[..idx].iter_mut() {
+        *c = c.q.precompute();
+    }
+    idx
+}
+
+/// `miller_loop_batch`, with the `parallel` feature split into one batch
+/// per thread of the rayon pool whose results are multiplied together.
+fn miller_loop_pairs(g2_precomputes: &[G2Precomp], g1_vec: &[AffineG<G1Params>]) -> Fq12 {
+    #[cfg(all(feature = "parallel", not(feature = "consensus")))]
+    {
+        use rayon::prelude::*;
+        let chunk = g2_precomputes.len().div_ceil(rayon::current_num_threads());
+        g2_precomputes
+            .par_chunks(chunk)
+            .zip(g1_vec.par_chunks(chunk))
+            .map(|(qs, ps)| miller_loop_batch(qs, ps))
+            .reduce(Fq12::one, |a, b| a * b)
+    }
+    #[cfg(not(all(feature = "parallel", not(feature = "consensus"))))]
+    miller_loop_batch(g2_precomputes, g1_vec)
+}
+
+/// The product of the Miller loops of the pairs without a point at
+/// infinity, using the buffers as scratch space. `None` if there are none.
+fn miller_loop_with(
+    ps: &[G1],
+    qs: &[G2],
+    p_affines: &mut [AffineG<G1Params>],
+    q_precomputes: &mut [G2Precomp],
+) -> Option<Fq12> {
+    let idx = collect_pairs(ps, qs, p_affines, q_precomputes);
+    if idx == 0 {
+        return None;
+    }
+    Some(miller_loop_pairs(
+        &q_precomputes[0..idx],
+        &p_affines[0..idx],
+    ))
+}
+
+/// Whether the final exponentiation of a `miller_loop_with` result is one.
+fn check_miller_loop(f: Option<Fq12>) -> bool {
+    match f {
+        None => true,
+        Some(f) => f.final_exponentiation().is_some_and(|f| f == Fq12::one()),
+    }
+}
+
+/// The product of the Miller loops of the pairs without a point at
+/// infinity, with room for `N` pairs on the stack. One if there are none.
+pub fn miller_loop_batch_n<const N: usize>(ps: &[G1], qs: &[G2]) -> Fq12 {
+    let mut p_affines = [AffineG::default(); N];
+    let mut q_precomputes = [G2Precomp::default(); N];
+    miller_loop_with(ps, qs, &mut p_affines, &mut q_precomputes).unwrap_or(Fq12::one())
+}
+
+/// Whether the product of the pairings of `ps` and `qs` is one, with room
+/// for `N` pairs on the stack, without building a result when every pair
+/// has a point at infinity.
+pub fn pairing_check_n<const N: usize>(ps: &[G1], qs: &[G2]) -> bool {
+    let mut p_affines = [AffineG::default(); N];
+    let mut q_precomputes = [G2Precomp::default(); N];
+    check_miller_loop(miller_loop_with(ps, qs, &mut p_affines, &mut q_precomputes))
+}
+
+/// `miller_loop_batch_n` with the buffers on the heap, for any number of
+/// pairs.
+#[cfg(feature = "alloc")]
+pub fn miller_loop_batch_vec(ps: &[G1], qs: &[G2]) -> Fq12 {
+    let n = core::cmp::min(ps.len(), qs.len());
+    let mut p_affines = alloc::vec![AffineG::default(); n];
+    let mut q_precomputes = alloc::vec![G2Precomp::default(); n];
+    miller_loop_with(ps, qs, &mut p_affines, &mut q_precomputes).unwrap_or(Fq12::one())
+}
+
+/// `pairing_check_n` with the buffers on the heap, for any number of pairs.
+#[cfg(feature = "alloc")]
+pub fn pairing_check_vec(ps: &[G1], qs: &[G2]) -> bool {
+    let n = core::cmp::min(ps.len(), qs.len());
+    let mut p_affines = alloc::vec![AffineG::default(); n];
+    let mut q_precomputes = alloc::vec![G2Precomp::default(); n];
+    check_miller_loop(miller_loop_with(ps, qs, &mut p_affines, &mut q_precomputes))
+}
+
+#[test]
+fn untwist_frobenius() {
+    let p = G2::one() * Fr::new(U256::from(12345u64)).unwrap();
+    let a = p.to_affine().unwrap();
+    let (x, y) = a.untwist();
+    let three = Fq12::from_coeffs({
+        let mut c = [Fq::zero(); 12];
+        c[0] = Fq::new(U256::from(3u64)).unwrap();
+        c
+    });
+    assert_eq!(y.squared(), x.squared() * x + three);
+    assert_eq!(AffineG2::twist(&x, &y), Some(a));
+    assert_eq!(AffineG2::twist(&y, &x), None);
+
+    let (fx, fy) = p.frobenius().to_affine().unwrap().untwist();
+    assert_eq!((fx, fy), (x.frobenius_map(1), y.frobenius_map(1)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn unbounded_batch() {
+    let p = G1::one() * Fr::new(U256::from(77u64)).unwrap();
+    let q = G2::one() * Fr::new(U256::from(99u64)).unwrap();
+    let mut ps = [G1::zero(); 20];
+    let mut qs = [q; 20];
+    for (i, x) in ps.iter_mut().enumerate() {
+        *x = if i % 2 == 0 { p } else { -p };
+    }
+    qs[5] = G2::zero();
+    qs[4] = G2::zero();
+    assert!(pairing_check_vec(&ps, &qs));
+    assert!(!pairing_check_vec(&ps[1..], &qs[1..]));
+    assert_eq!(
+        miller_loop_batch_vec(&ps[..16], &qs[..16]),
+        miller_loop_batch_n::<16>(&ps[..16], &qs[..16])
+    );
+    assert_eq!(miller_loop_batch_vec(&[], &[]), Fq12::one());
+}
+
+#[test]
+fn iterator_batch() {
+    use crate::Group;
+
+    let p = crate::G1::one() * crate::Fr::from_str("77").unwrap();
+    let q = crate::G2::one() * crate::Fr::from_str("99").unwrap();
+    let pairs = (0..18).map(|i| (if i % 2 == 0 { p } else { -p }, q));
+    assert!(crate::pairing_batch_iter(pairs) == crate::Gt::one());
+    let pairs = [(p, q), (p, crate::G2::zero()), (p + p, q)];
+    assert!(
+        crate::miller_loop_batch_iter(pairs.iter().copied())
+            == crate::pairing_batch_unreduced(&pairs)
+    );
+    assert!(crate::pairing_batch_iter(core::iter::empty()) == crate::Gt::one());
+}
+
+#[test]
+fn pairing_check_past_16_pairs() {
+    use crate::{Degenerate, Group};
+
+    let p = crate::G1::one() * crate::Fr::from_str("77").unwrap();
+    let q = crate::G2::one() * crate::Fr::from_str("99").unwrap();
+    let mut pairs = [(p, q); 18];
+    for pair in pairs.iter_mut().skip(1).step_by(2) {
+        pair.0 = -p;
+    }
+    assert!(crate::pairing_check(&pairs));
+    assert!(crate::pairing_check_with(&pairs, Degenerate::Reject));
+    pairs[17].0 = p;
+    assert!(!crate::pairing_check(&pairs));
+}
+
+#[test]
+fn unreduced_past_16_pairs() {
+    use crate::Group;
+
+    let p = crate::G1::one() * crate::Fr::from_str("5").unwrap();
+    let q = crate::G2::one() * crate::Fr::from_str("6").unwrap();
+    let pairs = [(p, q); 20];
+    let f = crate::pairing_batch_unreduced(&pairs);
+    let split = crate::pairing_batch_unreduced(&pairs[..16])
+        * crate::pairing_batch_unreduced(&pairs[16..]);
+    assert!(f == split);
+    let expected = crate::pairing(p, q).pow(crate::Fr::from(20u64));
+    assert!(f.final_exponentiation() == Some(expected));
+}
+
+#[test]
+fn pairing_batch_past_16_pairs() {
+    use crate::Group;
+
+    let p = crate::G1::one() * crate::Fr::from_str("5").unwrap();
+    let q = crate::G2::one() * crate::Fr::from_str("6").unwrap();
+    let mut pairs = [(p, q); 20];
+    pairs[3].0 = crate::G1::zero();
+    let expected = crate::pairing(p, q).pow(crate::Fr::from(19u64));
+    assert!(crate::pairing_batch(&pairs) == expected);
+    let split = crate::pairing_batch(&pairs[..16]) * crate::pairing_batch(&pairs[16..]);
+    assert!(split == expected);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_variants() {
+    use crate::{AffineG1, AffineG2, Fr, Group, G1, G2};
+
+    let fr = |i: u64| Fr::from(i);
+    let mut pairs = heapless::Vec::<_, 20>::new();
+    for i in 1..=10u64 {
+        let p = G1::one() * fr(i);
+        let q = G2::one() * fr(i + 7);
+        pairs.push((p, q)).unwrap();
+        pairs.push((-p, q)).unwrap();
+    }
+    // 20 pairs, more than one batch of 16
+    assert!(crate::pairing_batch_heapless(&pairs) == crate::Gt::one());
+    assert!(crate::pairing_check_heapless(&pairs));
+    pairs[3].0 += G1::one();
+    assert!(!crate::pairing_check_heapless(&pairs));
+    assert!(crate::pairing_batch_heapless(&pairs) != crate::Gt::one());
+
+    // agrees with the slice versions within their limit
+    pairs.truncate(9);
+    assert!(crate::pairing_batch_heapless(&pairs) == crate::pairing_batch(&pairs));
+    assert_eq!(
+        crate::pairing_check_heapless(&pairs),
+        crate::pairing_check(&pairs)
+    );
+    assert!(crate::pairing_check_heapless(&heapless::Vec::<_, 4>::new()));
+
+    let mut g1_terms = heapless::Vec::<_, 40>::new();
+    let mut g2_terms = heapless::Vec::<_, 40>::new();
+    let (mut g1_points, mut g2_points) = ([AffineG1::generator(); 40], [AffineG2::generator(); 40]);
+    let mut scalars = [Fr::zero(); 40];
+    for i in 0..40 {
+        g1_points[i] = AffineG1::from_jacobian(G1::one() * fr(i as u64 + 3)).unwrap();
+        g2_points[i] = AffineG2::from_jacobian(G2::one() * fr(i as u64 + 5)).unwrap();
+        scalars[i] = fr(1000 + 17 * i as u64);
+        assert!(g1_terms.push((g1_points[i], scalars[i])).is_ok());
+        assert!(g2_terms.push((g2_points[i], scalars[i])).is_ok());
+    }
+    assert_eq!(G1::msm_heapless(&g1_terms), G1::msm(&g1_points, &scalars));
+    assert_eq!(G2::msm_heapless(&g2_terms), G2::msm(&g2_points, &scalars));
+    assert_eq!(G1::msm_heapless(&heapless::Vec::<_, 1>::new()), G1::zero());
+}
+
+#[test]
+fn batch_pairing_equations() {
+    use crate::{Fr, Group, G1, G2};
+
+    struct Counter(u8);
+    impl crate::EntropySource for Counter {
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for b in dest.iter_mut() {
+                self.0 = self.0.wrapping_mul(113).wrapping_add(71);
+                *b = self.0;
+            }
+        }
+    }
+
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let (a, b) = (fr("12345"), fr("67890"));
+    let eq1 = [
+        (G1::one() * a, G2::one() * b),
+        (-(G1::one() * (a * b)), G2::one()),
+    ];
+    let eq2 = [(G1::one() * b, G2::one()), (-G1::one(), G2::one() * b)];
+    let bad = [(G1::one() * a, G2::one()), (-G1::one(), G2::one() * b)];
+    let mut rng = Counter(1);
+    assert!(crate::batch_verify_pairing_equations(
+        &[&eq1, &eq2],
+        &mut rng
+    ));
+    assert!(crate::batch_verify_pairing_equations(&[], &mut rng));
+    assert!(!crate::batch_verify_pairing_equations(
+        &[&eq1, &bad, &eq2],
+        &mut rng
+    ));
 }
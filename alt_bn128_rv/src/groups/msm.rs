@@ -0,0 +1,363 @@
+use crate::arith::U256;
+use crate::cost::MemoryBudget;
+use crate::fields::Fr;
+use crate::groups::{AffineG, GroupElement, GroupParams, G};
+
+/// Largest supported bucket window. Signed digits need 2^(w-1) buckets,
+/// which live on the stack, so this bounds the scratch space to 2^8 points.
+pub const MAX_WINDOW: usize = 9;
+
+const SCALAR_BITS: usize = 254;
+
+/// Number of signed-digit windows covering a scalar below 2^254.
+#[inline]
+fn window_count(window: usize) -> usize {
+    SCALAR_BITS / window + 1
+}
+
+/// Relative costs of the group operations used to pick the MSM window.
+///
+/// Only the ratio between the fields matters. The defaults count field
+/// multiplications of the Jacobian formulas; `calibrate` measures them on
+/// the running target instead, since the ratio differs between e.g. CKB-VM
+/// and native x86.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MsmCostModel {
+    pub add: u64,
+    pub double: u64,
+}
+
+impl Default for MsmCostModel {
+    fn default() -> Self {
+        MsmCostModel { add: 16, double: 7 }
+    }
+}
+
+impl MsmCostModel {
+    /// Estimated cost of an `n`-term MSM with the given window.
+    pub fn cost(&self, n: usize, window: usize) -> u64 {
+        let windows = window_count(window) as u64;
+        let per_window = n as u64 + 2 * (1u64 << (window - 1));
+        windows * per_window * self.add + SCALAR_BITS as u64 * self.double
+    }
+
+    /// The window in `1..=MAX_WINDOW` with the lowest estimated cost.
+    pub fn best_window(&self, n: usize) -> usize {
+        (1..=MAX_WINDOW)
+            .min_by_key(|&w| self.cost(n, w))
+            .expect("window range is not empty; qed")
+    }
+
+    /// `best_window` restricted to windows whose buckets, of
+    /// `point_bytes` each, fit in `budget`. `None` if not even window 1
+    /// does.
+    pub fn best_window_within(
+        &self,
+        n: usize,
+        point_bytes: usize,
+        budget: &MemoryBudget,
+    ) -> Option<usize> {
+        (1..=MAX_WINDOW)
+            .filter(|&w| budget.fits(bucket_count(w) * point_bytes))
+            .min_by_key(|&w| self.cost(n, w))
+    }
+
+    /// Measures point addition and doubling with a caller supplied
+    /// monotonic counter (cycle counter, timer, ...).
+    /// Not available with the `consensus` feature.
+    #[cfg(not(feature = "consensus"))]
+    pub fn calibrate<F: FnMut() -> u64>(mut clock: F) -> Self {
+        const ROUNDS: u64 = 32;
+
+        let mut p = G::<crate::groups::G1Params>::one().double();
+        let q = G::<crate::groups::G1Params>::one();
+
+        let start = clock();
+        for _ in 0..ROUNDS {
+            p = p + q;
+        }
+        let added = clock();
+        for _ in 0..ROUNDS {
+            p = p.double();
+        }
+        let doubled = clock();
+        // keep the loops observable
+        core::hint::black_box(p);
+
+        MsmCostModel {
+            add: ((added - start) / ROUNDS).max(1),
+            double: ((doubled - added) / ROUNDS).max(1),
+        }
+    }
+}
+
+/// Signed digits of a `window`-bit window need this many buckets.
+#[inline]
+pub fn bucket_count(window: usize) -> usize {
+    1 << (window - 1)
+}
+
+/// Offset `sum(2^(w-1) << (j * w))` over all but the top window. Adding it
+/// to a scalar before splitting into unsigned windows `u_j` yields signed
+/// digits `u_j - 2^(w-1)` in `[-2^(w-1), 2^(w-1))`; the top window is taken
+/// unsigned and is at most `2^(w-1)`.
+fn signed_offset(window: usize) -> U256 {
+    let mut offset = U256::zero();
+    for j in 0..window_count(window) - 1 {
+        offset.set_bit(j * window + window - 1, true);
+    }
+    offset
+}
+
+#[inline]
+fn add_offset(scalar: &U256, offset: &U256) -> U256 {
+    // scalar < 2^254 and offset < 2^254, so this cannot overflow
+    let (lo, carry) = scalar.0[0].overflowing_add(offset.0[0]);
+    U256([lo, scalar.0[1] + offset.0[1] + carry as u128])
+}
+
+#[inline]
+fn signed_digit(shifted: &U256, index: usize, window: usize, top: bool) -> i32 {
+    let mut digit = 0i32;
+    for bit in (0..window).rev() {
+        digit <<= 1;
+        if shifted.get_bit(index * window + bit).unwrap_or(false) {
+            digit |= 1;
+        }
+    }
+    if top {
+        digit
+    } else {
+        digit - (1 << (window - 1))
+    }
+}
+
+/// Pippenger bucket method with signed digits: a negative digit adds the
+/// negated point, halving the number of buckets. The terms iterator is
+/// cloned and replayed once per window. The buckets are exactly
+/// `bucket_count(window)` points on the stack.
+pub fn msm<P, I>(terms: I, window: usize) -> G<P>
+where
+    P: GroupParams,
+    I: Iterator<Item = (AffineG<P>, Fr)> + Clone,
+{
+    match window {
+        1 => msm_buckets::<P, I, 1>(terms, 1),
+        2 => msm_buckets::<P, I, 2>(terms, 2),
+        3 => msm_buckets::<P, I, 4>(terms, 3),
+        4 => msm_buckets::<P, I, 8>(terms, 4),
+        5 => msm_buckets::<P, I, 16>(terms, 5),
+        6 => msm_buckets::<P, I, 32>(terms, 6),
+        7 => msm_buckets::<P, I, 64>(terms, 7),
+        8 => msm_buckets::<P, I, 128>(terms, 8),
+        9 => msm_buckets::<P, I, 256>(terms, 9),
+        _ => panic!("msm window must be in 1..=MAX_WINDOW"),
+    }
+}
+
+fn msm_buckets<P, I, const B: usize>(terms: I, window: usize) -> G<P>
+where
+    P: GroupParams,
+    I: Iterator<Item = (AffineG<P>, Fr)> + Clone,
+{
+    debug_assert_eq!(B, bucket_count(window));
+    let offset = signed_offset(window);
+    let mut buckets = [G::<P>::zero(); B];
+    let mut acc = G::<P>::zero();
+
+    for index in (0..window_count(window)).rev() {
+        for _ in 0..window {
+            acc = acc.double();
+        }
+        acc = acc + window_sum(terms.clone(), index, window, &offset, &mut buckets);
+    }
+
+    acc
+}
+
+/// `sum(digit * p)` over the signed digits of window `index`, using
+/// `buckets` as scratch space.
+fn window_sum<P, I>(
+    terms: I,
+    index: usize,
+    window: usize,
+    offset: &U256,
+    buckets: &mut [G<P>],
+) -> G<P>
+where
+    P: GroupParams,
+    I: Iterator<Item = (AffineG<P>, Fr)>,
+{
+    let top = index == window_count(window) - 1;
+    for b in buckets.iter_mut() {
+        *b = G::zero();
+    }
+    for (p, s) in terms {
+        let shifted = add_offset(&U256::from(s), offset);
+        let digit = signed_digit(&shifted, index, window, top);
+        if digit > 0 {
+            let b = &mut buckets[digit as usize - 1];
+            *b = *b + p;
+        } else if digit < 0 {
+            let b = &mut buckets[(-digit) as usize - 1];
+            *b = *b - p;
+        }
+    }
+
+    let mut running = G::<P>::zero();
+    let mut sum = G::<P>::zero();
+    for b in buckets.iter().rev() {
+        running = running + *b;
+        sum = sum + running;
+    }
+    sum
+}
+
+/// Below this many terms `msm_parallel` stays on the calling thread.
+#[cfg(all(feature = "parallel", not(feature = "consensus")))]
+const PARALLEL_MIN_TERMS: usize = 64;
+
+/// `msm` with the windows spread across the rayon thread pool. Each
+/// window replays the terms into its own buckets; only the final doublings
+/// are sequential.
+#[cfg(all(feature = "parallel", not(feature = "consensus")))]
+pub fn msm_parallel<P, I>(terms: I, window: usize) -> G<P>
+where
+    P: GroupParams,
+    P::Base: Send + Sync,
+    I: Iterator<Item = (AffineG<P>, Fr)> + Clone + Sync,
+{
+    use rayon::prelude::*;
+
+    assert!(
+        (1..=MAX_WINDOW).contains(&window),
+        "msm window must be in 1..=MAX_WINDOW"
+    );
+    if terms.clone().count() < PARALLEL_MIN_TERMS {
+        return msm(terms, window);
+    }
+    let offset = signed_offset(window);
+    let sums: alloc::vec::Vec<G<P>> = (0..window_count(window))
+        .into_par_iter()
+        .map(|index| {
+            let mut buckets = [G::<P>::zero(); 1 << (MAX_WINDOW - 1)];
+            window_sum(
+                terms.clone(),
+                index,
+                window,
+                &offset,
+                &mut buckets[..bucket_count(window)],
+            )
+        })
+        .collect();
+
+    let mut acc = G::<P>::zero();
+    for sum in sums.iter().rev() {
+        for _ in 0..window {
+            acc = acc.double();
+        }
+        acc = acc + *sum;
+    }
+    acc
+}
+
+/// `sum(d * p)` for small non-negative digits `d <= max_digit` using one
+/// bucket per digit value, i.e. `n + 2 * max_digit` additions.
+pub fn sum_small_digits<P, I>(terms: I, max_digit: u8) -> G<P>
+where
+    P: GroupParams,
+    I: Iterator<Item = (AffineG<P>, u8)>,
+{
+    let mut buckets = [G::<P>::zero(); 255];
+    let buckets = &mut buckets[..max_digit as usize];
+
+    for (p, d) in terms {
+        assert!(d <= max_digit, "digit exceeds max_digit");
+        if d != 0 {
+            let b = &mut buckets[d as usize - 1];
+            *b = *b + p;
+        }
+    }
+
+    let mut running = G::<P>::zero();
+    let mut sum = G::<P>::zero();
+    for b in buckets.iter().rev() {
+        running = running + *b;
+        sum = sum + running;
+    }
+    sum
+}
+
+#[test]
+fn msm_matches_naive() {
+    use crate::fields::FieldElement;
+    use crate::groups::{G1Params, G1};
+
+    let mut points = [AffineG::<G1Params>::default(); 5];
+    let mut scalars = [Fr::zero(); 5];
+    let mut s = Fr::from_str("7").unwrap();
+    for i in 0..5 {
+        let p = G1::one() * Fr::from_str("3").unwrap().pow(U256::from(i as u64 + 1));
+        s = s * s + Fr::from_str("11").unwrap();
+        points[i] = p.to_affine().unwrap();
+        scalars[i] = s;
+    }
+    // zero and -1 leave a window empty and fill every bucket
+    scalars[2] = Fr::zero();
+    scalars[3] = -Fr::one();
+
+    let mut naive = G1::zero();
+    for (p, s) in points.iter().zip(scalars.iter()) {
+        naive = naive + p.to_jacobian() * *s;
+    }
+    for window in 1..=MAX_WINDOW {
+        let terms = points.iter().cloned().zip(scalars.iter().cloned());
+        assert_eq!(msm(terms, window), naive);
+    }
+
+    // the mixed addition's doubling and cancellation cases
+    let p = points[0].to_jacobian() * Fr::from_str("5").unwrap();
+    let q = p.to_affine().unwrap();
+    assert_eq!(p + q, p.double());
+    assert!((p - q).is_zero());
+    assert_eq!(G1::zero() + q, p);
+    assert_eq!(p + points[1], p + points[1].to_jacobian());
+}
+
+#[cfg(all(feature = "parallel", not(feature = "consensus")))]
+#[test]
+fn parallel_matches_sequential() {
+    use crate::fields::FieldElement;
+    use crate::groups::{G1Params, G1};
+
+    let mut points = [AffineG::<G1Params>::default(); 100];
+    let mut scalars = [Fr::zero(); 100];
+    let mut p = G1::one();
+    let mut s = Fr::from_str("5").unwrap();
+    for i in 0..100 {
+        points[i] = p.to_affine().unwrap();
+        scalars[i] = s;
+        p = p.double() + G1::one();
+        s = s * s - Fr::one();
+    }
+    for window in 1..=MAX_WINDOW {
+        let terms = points.iter().cloned().zip(scalars.iter().cloned());
+        assert_eq!(msm_parallel(terms.clone(), window), msm(terms, window));
+    }
+}
+
+#[test]
+fn window_within_budget() {
+    let model = MsmCostModel::default();
+    let unlimited = MemoryBudget::new(usize::MAX);
+    assert_eq!(
+        model.best_window_within(1000, 96, &unlimited),
+        Some(model.best_window(1000))
+    );
+    let four_buckets = MemoryBudget::new(4 * 96);
+    assert_eq!(model.best_window_within(1000, 96, &four_buckets), Some(3));
+    assert_eq!(
+        model.best_window_within(1000, 96, &MemoryBudget::new(95)),
+        None
+    );
+}
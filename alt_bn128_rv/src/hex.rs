@@ -0,0 +1,314 @@
+//! Hexadecimal text encodings, so that test vectors and verification keys
+//! can be embedded as readable constants. Every type is written as its
+//! canonical big-endian bytes: 32 per `Fr` or `Fq`, `Fq2` imaginary part
+//! first as in EIP-197, points as `x || y` with the point at infinity all
+//! zero, and `Gt` as in `Gt::to_bytes`. Parsing accepts an optional `0x`
+//! prefix and either case; `Display` writes lowercase with the prefix.
+
+use crate::{
+    arith::U256, AffineG1, AffineG2, CurveError, FieldError, Fq, Fq2, Fr, Group, Gt, G1, G2,
+};
+use core::{fmt, str::FromStr};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// The string, without its prefix, is not `expected` digits long.
+    Length {
+        expected: usize,
+        actual: usize,
+    },
+    /// The byte at `offset`, counted after the prefix, is not a hex digit.
+    Character {
+        offset: usize,
+    },
+    /// A `FromStr` input without the `0x` prefix is not a decimal integer
+    /// below the modulus.
+    Decimal,
+    Field(FieldError),
+    Curve(CurveError),
+}
+
+impl From<FieldError> for HexError {
+    fn from(e: FieldError) -> Self {
+        HexError::Field(e)
+    }
+}
+
+impl From<CurveError> for HexError {
+    fn from(e: CurveError) -> Self {
+        HexError::Curve(e)
+    }
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HexError::Length { expected, actual } => write!(
+                f,
+                "hex encoding must be {} digits, got {}",
+                expected, actual
+            ),
+            HexError::Character { offset } => write!(f, "invalid hex digit at {}", offset),
+            HexError::Decimal => f.write_str("invalid decimal field element"),
+            HexError::Field(e) => write!(f, "{}", e),
+            HexError::Curve(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for HexError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            HexError::Field(e) => Some(e),
+            HexError::Curve(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+fn strip_prefix(s: &str) -> Option<&str> {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))
+}
+
+fn decode<const N: usize>(s: &str) -> Result<[u8; N], HexError> {
+    let digits = strip_prefix(s).unwrap_or(s).as_bytes();
+    if digits.len() != 2 * N {
+        return Err(HexError::Length {
+            expected: 2 * N,
+            actual: digits.len(),
+        });
+    }
+    let nibble = |offset: usize| {
+        let c = digits[offset];
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(HexError::Character { offset }),
+        }
+    };
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = nibble(2 * i)? << 4 | nibble(2 * i + 1)?;
+    }
+    Ok(out)
+}
+
+fn encode(f: &mut fmt::Formatter, bytes: &[u8]) -> fmt::Result {
+    f.write_str("0x")?;
+    for b in bytes {
+        write!(f, "{:02x}", b)?;
+    }
+    Ok(())
+}
+
+fn fr_bytes(x: &Fr) -> [u8; 32] {
+    // not `Fr::to_big_endian`, which writes the Montgomery form
+    let mut out = [0u8; 32];
+    x.into_u256()
+        .to_big_endian(&mut out)
+        .expect("buffer is 32 bytes; qed");
+    out
+}
+
+fn fr_from_bytes(bytes: &[u8; 32]) -> Result<Fr, HexError> {
+    let x = U256::from_slice(bytes).expect("slice is 32 bytes; qed");
+    Ok(Fr::new(x).ok_or(FieldError::NotMember)?)
+}
+
+fn fq_bytes(x: &Fq) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    x.to_big_endian(&mut out).expect("buffer is 32 bytes; qed");
+    out
+}
+
+fn fq_from_bytes(bytes: &[u8; 32]) -> Result<Fq, HexError> {
+    Ok(Fq::from_slice(bytes)?)
+}
+
+fn fq2_from_bytes(bytes: &[u8; 64]) -> Result<Fq2, HexError> {
+    Ok(Fq2::from_bytes_be_imag_first(bytes)?)
+}
+
+fn affine_g1_bytes(p: &AffineG1) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    out[..32].copy_from_slice(&fq_bytes(&p.x()));
+    out[32..].copy_from_slice(&fq_bytes(&p.y()));
+    out
+}
+
+fn affine_g1_from_bytes(bytes: &[u8; 64]) -> Result<AffineG1, HexError> {
+    let x = Fq::from_slice(&bytes[..32])?;
+    let y = Fq::from_slice(&bytes[32..])?;
+    Ok(AffineG1::new(x, y).map_err(|_| CurveError::NotMember)?)
+}
+
+fn g1_bytes(p: &G1) -> [u8; 64] {
+    AffineG1::from_jacobian(*p).map_or([0u8; 64], |p| affine_g1_bytes(&p))
+}
+
+fn g1_from_bytes(bytes: &[u8; 64]) -> Result<G1, HexError> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(G1::zero());
+    }
+    affine_g1_from_bytes(bytes).map(G1::from)
+}
+
+fn affine_g2_bytes(p: &AffineG2) -> [u8; 128] {
+    let mut out = [0u8; 128];
+    out[..64].copy_from_slice(&p.x().to_bytes_be_imag_first());
+    out[64..].copy_from_slice(&p.y().to_bytes_be_imag_first());
+    out
+}
+
+fn affine_g2_from_bytes(bytes: &[u8; 128]) -> Result<AffineG2, HexError> {
+    let x = Fq2::from_bytes_be_imag_first(&bytes[..64])?;
+    let y = Fq2::from_bytes_be_imag_first(&bytes[64..])?;
+    Ok(AffineG2::new(x, y).map_err(|_| CurveError::NotMember)?)
+}
+
+fn g2_bytes(p: &G2) -> [u8; 128] {
+    AffineG2::from_jacobian(*p).map_or([0u8; 128], |p| affine_g2_bytes(&p))
+}
+
+fn g2_from_bytes(bytes: &[u8; 128]) -> Result<G2, HexError> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(G2::zero());
+    }
+    affine_g2_from_bytes(bytes).map(G2::from)
+}
+
+fn gt_bytes(x: &Gt) -> [u8; 384] {
+    let mut out = [0u8; 384];
+    x.to_bytes(&mut out);
+    out
+}
+
+fn gt_from_bytes(bytes: &[u8; 384]) -> Result<Gt, HexError> {
+    Ok(Gt::from_slice(bytes)?)
+}
+
+/// Implements `from_hex_str`, `to_hex` and `Display` for `$t` through a
+/// `[u8; $n]` encoding, `$to` producing it and `$from` validating it.
+macro_rules! hex_codec {
+    ($t:ty, $n:expr, $to:expr, $from:expr) => {
+        impl $t {
+            /// Parses the canonical big-endian encoding as hex, with or
+            /// without a `0x` prefix.
+            pub fn from_hex_str(s: &str) -> Result<Self, HexError> {
+                $from(&decode::<$n>(s)?)
+            }
+
+            /// The `Display` form: `0x` followed by lowercase hex.
+            #[cfg(feature = "alloc")]
+            pub fn to_hex(&self) -> alloc::string::String {
+                alloc::format!("{}", self)
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                encode(f, &$to(self))
+            }
+        }
+    };
+}
+
+/// Adds a `FromStr` that accepts only hex.
+macro_rules! hex_from_str {
+    ($t:ty) => {
+        impl FromStr for $t {
+            type Err = HexError;
+
+            fn from_str(s: &str) -> Result<Self, HexError> {
+                <$t>::from_hex_str(s)
+            }
+        }
+    };
+}
+
+hex_codec!(Fr, 32, fr_bytes, fr_from_bytes);
+hex_codec!(Fq, 32, fq_bytes, fq_from_bytes);
+hex_codec!(Fq2, 64, Fq2::to_bytes_be_imag_first, fq2_from_bytes);
+hex_codec!(G1, 64, g1_bytes, g1_from_bytes);
+hex_codec!(AffineG1, 64, affine_g1_bytes, affine_g1_from_bytes);
+hex_codec!(G2, 128, g2_bytes, g2_from_bytes);
+hex_codec!(AffineG2, 128, affine_g2_bytes, affine_g2_from_bytes);
+hex_codec!(Gt, 384, gt_bytes, gt_from_bytes);
+
+hex_from_str!(Fq2);
+hex_from_str!(G1);
+hex_from_str!(AffineG1);
+hex_from_str!(G2);
+hex_from_str!(AffineG2);
+hex_from_str!(Gt);
+
+/// Hex with the `0x` prefix, as written by `Display`, otherwise decimal as
+/// in the inherent `Fr::from_str`.
+impl FromStr for Fr {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, HexError> {
+        match strip_prefix(s) {
+            Some(_) => Fr::from_hex_str(s),
+            None => Fr::from_str(s).ok_or(HexError::Decimal),
+        }
+    }
+}
+
+/// Hex with the `0x` prefix, as written by `Display`, otherwise decimal as
+/// in the inherent `Fq::from_str`.
+impl FromStr for Fq {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, HexError> {
+        match strip_prefix(s) {
+            Some(_) => Fq::from_hex_str(s),
+            None => Fq::from_str(s).ok_or(HexError::Decimal),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn round_trip() {
+    let g = G1::one() * Fr::from_str("7").expect("small decimal; qed");
+    let s = g.to_hex();
+    assert_eq!(s.len(), 2 + 128);
+    assert_eq!(G1::from_hex_str(&s), Ok(g));
+    assert_eq!(s.parse::<G1>(), Ok(g));
+    assert_eq!(G1::from_hex_str(&s[2..].to_uppercase()), Ok(g));
+    assert_eq!(G1::zero().to_hex(), alloc::format!("0x{:0128}", 0));
+    assert_eq!(G1::from_hex_str(&G1::zero().to_hex()), Ok(G1::zero()));
+
+    let h = G2::one() * Fr::from_str("11").expect("small decimal; qed");
+    assert_eq!(h.to_hex().parse::<G2>(), Ok(h));
+    let e = crate::pairing(g, h);
+    assert_eq!(Gt::from_hex_str(&e.to_hex()), Ok(e));
+
+    // the G1 generator is (1, 2)
+    assert_eq!(G1::one().to_hex(), alloc::format!("0x{:064x}{:064x}", 1, 2));
+    assert_eq!(
+        "0x0a".parse::<Fr>(),
+        Err(HexError::Length {
+            expected: 64,
+            actual: 2
+        })
+    );
+    let ten = Fr::from_str("10").expect("small decimal; qed");
+    assert_eq!(ten.to_hex(), alloc::format!("0x{:064x}", 10));
+    assert_eq!(ten.to_hex().parse::<Fr>(), Ok(ten));
+    assert_eq!(
+        "10".parse::<Fr>(),
+        Fr::from_hex_str(&alloc::format!("{:064x}", 10))
+    );
+    assert_eq!(
+        Fq::from_hex_str(&alloc::format!("0x{:063x}g", 0)),
+        Err(HexError::Character { offset: 63 })
+    );
+    let q = "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+    assert_eq!(
+        Fq::from_hex_str(q),
+        Err(HexError::Field(FieldError::NotMember))
+    );
+}
@@ -0,0 +1,308 @@
+//! KZG polynomial commitments. A commitment to `p` is `p(tau) * g1` for
+//! the secret `tau` of a trusted setup, and the proof of `p(z) = y` is the
+//! commitment to the quotient `(p(X) - y) / (X - z)`, checked with
+//!
+//! `e(C - y * g1 + z * proof, g2) == e(proof, tau * g2)`.
+//!
+//! Polynomials are coefficient slices, constant term first. The quotient
+//! is never materialized: its coefficients are generated by synthetic
+//! division as the MSM consumes them, so opening needs no scratch space.
+//!
+//! Any number of openings, of different polynomials at different points,
+//! verify together as one two-pair check on a random linear combination
+//! of their equations.
+
+use crate::groups::{self, MsmCostModel};
+use crate::{
+    miller_loop, pairing_check, AffineG1, AffineG2, EntropySource, Fr, G1PrecomputedTable,
+    G2Prepared, Group, Gt, G1, G2,
+};
+use core::fmt;
+
+/// Openings per MSM in `verify_batch_with`, bounding its stack buffers.
+const BATCH_CHUNK: usize = 32;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The polynomial has more coefficients than the SRS has G1 powers.
+    Degree,
+    InvalidProof,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::Degree => "polynomial degree exceeds the SRS",
+            Error::InvalidProof => "invalid opening proof",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// A structured reference string: `g1_powers[i] = tau^i * g1`, which
+/// bounds the degree of committed polynomials, and `tau * g2`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Srs<'a> {
+    pub g1_powers: &'a [AffineG1],
+    pub tau_g2: AffineG2,
+}
+
+/// `p(z)` by Horner's rule.
+pub fn evaluate(poly: &[Fr], z: Fr) -> Fr {
+    poly.iter().rev().fold(Fr::zero(), |acc, c| acc * z + *c)
+}
+
+impl<'a> Srs<'a> {
+    fn powers(&self, len: usize) -> Result<&'a [AffineG1], Error> {
+        self.g1_powers.get(..len).ok_or(Error::Degree)
+    }
+
+    /// `p(tau) * g1`.
+    pub fn commit(&self, poly: &[Fr]) -> Result<G1, Error> {
+        Ok(G1::msm(self.powers(poly.len())?, poly))
+    }
+
+    /// `p(z)` and the proof of it.
+    pub fn open(&self, poly: &[Fr], z: Fr) -> Result<(Fr, G1), Error> {
+        let powers = self.powers(poly.len())?;
+        if poly.len() < 2 {
+            return Ok((evaluate(poly, z), G1::zero()));
+        }
+        let powers = &powers[..poly.len() - 1];
+        // q[i - 1] = p[i] + z * q[i], from the top coefficient down
+        let quotient = poly[1..].iter().rev().scan(Fr::zero(), move |q, c| {
+            *q = *c + z * *q;
+            Some(q.0)
+        });
+        let terms = powers.iter().rev().map(|p| p.0).zip(quotient);
+        let window = MsmCostModel::default().best_window(powers.len());
+        Ok((evaluate(poly, z), G1(groups::msm(terms, window))))
+    }
+}
+
+/// Checks that `proof` opens `commitment` to `eval` at `point`.
+pub fn verify(commitment: G1, point: Fr, eval: Fr, proof: G1, srs: &Srs) -> Result<(), Error> {
+    let lhs = opening_lhs(commitment, point, eval, proof);
+    if pairing_check(&[(lhs, G2::one()), (-proof, srs.tau_g2.into())]) {
+        Ok(())
+    } else {
+        Err(Error::InvalidProof)
+    }
+}
+
+/// `C - y * g1 + z * proof`.
+fn opening_lhs(commitment: G1, point: Fr, eval: Fr, proof: G1) -> G1 {
+    commitment - G1PrecomputedTable::generator().mul(eval) + proof * point
+}
+
+/// The G2 side of an SRS with its Miller loop lines precomputed, for
+/// verifiers checking many openings against one setup.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct PreparedSrs {
+    g2: G2Prepared,
+    tau_g2: G2Prepared,
+}
+
+impl PreparedSrs {
+    pub fn new(srs: &Srs) -> Self {
+        let prepare = |q: G2| q.prepare().expect("affine points are finite; qed");
+        PreparedSrs {
+            g2: prepare(G2::one()),
+            tau_g2: prepare(srs.tau_g2.into()),
+        }
+    }
+
+    /// `e(p, g2) * e(q, tau * g2)` before the final exponentiation.
+    fn miller_loop(&self, p: G1, q: G1) -> Gt {
+        let mut f = Gt::one();
+        if let Some(p) = AffineG1::from_jacobian(p) {
+            f *= miller_loop(&p, &self.g2);
+        }
+        if let Some(q) = AffineG1::from_jacobian(q) {
+            f *= miller_loop(&q, &self.tau_g2);
+        }
+        f
+    }
+}
+
+/// `verify` with the G2 points of the SRS prepared.
+pub fn verify_prepared(
+    commitment: G1,
+    point: Fr,
+    eval: Fr,
+    proof: G1,
+    srs: &PreparedSrs,
+) -> Result<(), Error> {
+    let lhs = opening_lhs(commitment, point, eval, proof);
+    if srs.miller_loop(lhs, -proof).final_exponentiation() == Some(Gt::one()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidProof)
+    }
+}
+
+/// A claim that `proof` opens `commitment` to `eval` at `point`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Opening {
+    pub commitment: G1,
+    pub point: Fr,
+    pub eval: Fr,
+    pub proof: G1,
+}
+
+/// `verify_prepared` for every opening at the cost of one, with random
+/// coefficients drawn from `rng`.
+pub fn verify_batch<E: EntropySource + ?Sized>(
+    openings: &[Opening],
+    srs: &PreparedSrs,
+    rng: &mut E,
+) -> Result<(), Error> {
+    verify_batch_with(openings, srs, core::iter::repeat_with(|| Fr::random(rng)))
+}
+
+/// Checks the sum of the opening equations weighted by `coefficients`,
+/// which must yield one scalar per opening. A false opening passes with
+/// probability about `1/r` if the coefficients are unpredictable to
+/// whoever produced the openings, e.g. drawn from an `EntropySource` or
+/// from an `FrSequence` seeded with a transcript that binds every opening.
+pub fn verify_batch_with(
+    openings: &[Opening],
+    srs: &PreparedSrs,
+    mut coefficients: impl Iterator<Item = Fr>,
+) -> Result<(), Error> {
+    // sum(r * (C + z * proof)) - sum(r * y) * g1 and sum(r * proof)
+    let mut lhs = G1::zero();
+    let mut proofs = G1::zero();
+    let mut evals = Fr::zero();
+    for chunk in openings.chunks(BATCH_CHUNK) {
+        let mut points = [G1::zero(); 2 * BATCH_CHUNK];
+        let mut scalars = [Fr::zero(); 2 * BATCH_CHUNK];
+        for (i, o) in chunk.iter().enumerate() {
+            let r = coefficients.next().expect("one coefficient per opening");
+            points[2 * i] = o.commitment;
+            points[2 * i + 1] = o.proof;
+            scalars[2 * i] = r;
+            scalars[2 * i + 1] = r * o.point;
+            evals += r * o.eval;
+        }
+        let n = 2 * chunk.len();
+        let mut affine = [None; 2 * BATCH_CHUNK];
+        G1::batch_normalize_into(&points[..n], &mut affine[..n]);
+
+        let window = MsmCostModel::default().best_window(n);
+        let terms = affine[..n]
+            .iter()
+            .zip(scalars.iter())
+            .filter_map(|(p, s)| p.map(|p| (p.0, s.0)));
+        lhs += G1(groups::msm(terms, window));
+        let terms = affine[1..n]
+            .iter()
+            .step_by(2)
+            .zip(scalars.iter().step_by(2))
+            .filter_map(|(p, s)| p.map(|p| (p.0, s.0)));
+        proofs += G1(groups::msm(terms, window));
+    }
+    lhs -= G1PrecomputedTable::generator().mul(evals);
+    if srs.miller_loop(lhs, -proofs).final_exponentiation() == Some(Gt::one()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidProof)
+    }
+}
+
+#[test]
+fn open_and_verify() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let tau = fr("123456789");
+    let mut powers = [AffineG1::generator(); 6];
+    let mut t = Fr::one();
+    for p in powers.iter_mut() {
+        *p = AffineG1::from_jacobian(G1::one() * t).unwrap();
+        t *= tau;
+    }
+    let srs = Srs {
+        g1_powers: &powers,
+        tau_g2: AffineG2::from_jacobian(G2::one() * tau).unwrap(),
+    };
+    let prepared = PreparedSrs::new(&srs);
+
+    let poly = [fr("3"), -fr("1"), fr("4"), fr("1"), -fr("5"), fr("9")];
+    let commitment = srs.commit(&poly).unwrap();
+    assert_eq!(commitment, G1::one() * evaluate(&poly, tau));
+
+    let z = fr("1000");
+    let (y, proof) = srs.open(&poly, z).unwrap();
+    assert_eq!(y, evaluate(&poly, z));
+    assert_eq!(verify(commitment, z, y, proof, &srs), Ok(()));
+    assert_eq!(verify_prepared(commitment, z, y, proof, &prepared), Ok(()));
+    assert_eq!(
+        verify(commitment, z, y + Fr::one(), proof, &srs),
+        Err(Error::InvalidProof)
+    );
+    assert_eq!(
+        verify_prepared(commitment, -z, y, proof, &prepared),
+        Err(Error::InvalidProof)
+    );
+
+    // constants open with the identity as proof
+    let (y, proof) = srs.open(&poly[..1], z).unwrap();
+    assert_eq!(
+        verify(srs.commit(&poly[..1]).unwrap(), z, y, proof, &srs),
+        Ok(())
+    );
+    assert_eq!(srs.commit(&[Fr::one(); 7]), Err(Error::Degree));
+    assert_eq!(srs.open(&[Fr::one(); 7], z), Err(Error::Degree));
+}
+
+#[test]
+fn batch_verify() {
+    use crate::transcript::FrSequence;
+
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let tau = fr("987654321");
+    let mut powers = [AffineG1::generator(); 4];
+    let mut t = Fr::one();
+    for p in powers.iter_mut() {
+        *p = AffineG1::from_jacobian(G1::one() * t).unwrap();
+        t *= tau;
+    }
+    let srs = Srs {
+        g1_powers: &powers,
+        tau_g2: AffineG2::from_jacobian(G2::one() * tau).unwrap(),
+    };
+    let prepared = PreparedSrs::new(&srs);
+
+    let polys: [&[Fr]; 3] = [
+        &[fr("1"), fr("2"), fr("3"), fr("4")],
+        &[-fr("7"), fr("11")],
+        &[fr("5")],
+    ];
+    let mut openings = [Opening {
+        commitment: G1::zero(),
+        point: Fr::zero(),
+        eval: Fr::zero(),
+        proof: G1::zero(),
+    }; 5];
+    let mut point = fr("31");
+    for (i, o) in openings.iter_mut().enumerate() {
+        let poly = polys[i % 3];
+        point += fr("17");
+        let (eval, proof) = srs.open(poly, point).unwrap();
+        *o = Opening {
+            commitment: srs.commit(poly).unwrap(),
+            point,
+            eval,
+            proof,
+        };
+    }
+    let seq = || FrSequence::new([7u8; 32]);
+    assert_eq!(verify_batch_with(&openings, &prepared, seq()), Ok(()));
+    assert_eq!(verify_batch_with(&[], &prepared, seq()), Ok(()));
+
+    openings[3].eval += Fr::one();
+    assert_eq!(
+        verify_batch_with(&openings, &prepared, seq()),
+        Err(Error::InvalidProof)
+    );
+}
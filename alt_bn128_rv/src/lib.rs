@@ -1,19 +1,90 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "heapless")]
+pub use heapless;
+
 pub mod arith;
+#[cfg(feature = "ark")]
+mod ark;
+pub mod babyjubjub;
+mod batch;
+#[cfg(feature = "bench-api")]
+pub mod bench;
+pub mod cache;
+pub mod committee;
+pub mod compat;
+mod constant_time;
+pub mod cost;
+#[cfg(feature = "differential")]
+pub mod differential;
+pub mod dleq;
+pub mod dlog;
+pub mod elgamal;
+pub mod entropy;
 pub mod ethereum;
+pub mod fft;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod fields;
+mod fixed_base;
+mod flagged;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+pub mod groth16;
 mod groups;
+pub mod hash_to_curve;
+pub mod hasher;
+mod hex;
+pub mod ipa;
+pub mod kzg;
+#[cfg(feature = "defmt")]
+mod log;
+pub mod mimc;
+mod ops;
+pub mod pedersen;
+pub mod poe;
+#[cfg(feature = "alloc")]
+pub mod poly;
+pub mod poseidon;
+mod random;
+pub mod raw;
+#[cfg(feature = "verify-twice")]
+mod redundant;
+mod selftest;
+#[cfg(feature = "serde")]
+mod serialization;
+pub mod signatures;
+mod snarkjs;
+pub mod transcript;
+mod validate;
+pub mod vrf;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+#[cfg(feature = "zeroize")]
+mod wipe;
+pub mod wnaf;
+#[cfg(feature = "zkcrypto")]
+pub mod zkcrypto;
 
 use crate::fields::FieldElement;
 use crate::groups::{G1Params, G2Params, GroupElement, GroupParams};
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops::{Add, Mul, Neg, Sub};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 #[repr(C)]
 pub struct Fr(fields::Fr);
 
 impl Fr {
+    /// Builds an element from the little-endian limbs of its Montgomery
+    /// form, `x * 2^256 mod r`, without reduction; for constants in statics
+    /// and const tables. The limbs must encode a value below r.
+    pub const fn from_raw(limbs: [u64; 4]) -> Self {
+        Fr(fields::const_fr(limbs))
+    }
     pub fn zero() -> Self {
         Fr(fields::Fr::zero())
     }
@@ -32,19 +103,50 @@ impl Fr {
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
     }
+    /// Reduces the 512-bit big-endian integer `buf` modulo r. Same as
+    /// `from_bytes_wide`.
     pub fn interpret(buf: &[u8; 64]) -> Fr {
         Fr(fields::Fr::interpret(buf))
     }
+    /// Reduces the 512-bit big-endian integer `bytes` modulo r. For uniform
+    /// input the result is within a statistical distance of 2^-258 of
+    /// uniform, so it is suitable for hash outputs.
+    pub fn from_bytes_wide(bytes: &[u8; 64]) -> Fr {
+        Fr(fields::Fr::interpret(bytes))
+    }
+    /// Fills `out` with scalars derived from `msg` under the domain
+    /// separation tag `dst`; see `hash_to_curve::hash_to_field_fr`.
+    pub fn hash_to_field(
+        msg: &[u8],
+        dst: &[u8],
+        out: &mut [Fr],
+    ) -> Result<(), hash_to_curve::Error> {
+        hash_to_curve::hash_to_field_fr(msg, dst, out)
+    }
     pub fn from_slice(slice: &[u8]) -> Result<Self, FieldError> {
         arith::U256::from_slice(slice)
-            .map_err(|_| FieldError::InvalidSliceLength) // todo: maybe more sensful error handling
+            .map_err(|_| FieldError::input(Field::Fr, 32, slice.len()))
             .map(|x| Fr::new_mul_factor(x))
     }
     pub fn to_big_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
         self.0
             .raw()
             .to_big_endian(slice)
-            .map_err(|_| FieldError::InvalidSliceLength)
+            .map_err(|_| FieldError::output(Field::Fr, 32, slice.len()))
+    }
+    /// Reads a canonical little-endian integer. Unlike `from_slice`,
+    /// values not below r are rejected rather than reduced.
+    pub fn from_le_slice(slice: &[u8]) -> Result<Self, FieldError> {
+        let x = arith::U256::from_le_slice(slice)
+            .map_err(|_| FieldError::input(Field::Fr, 32, slice.len()))?;
+        Fr::new(x).ok_or(FieldError::NotMember)
+    }
+    /// Writes the canonical integer little-endian; unlike `to_big_endian`,
+    /// not the Montgomery form, so it round-trips with `from_le_slice`.
+    pub fn to_little_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
+        self.into_u256()
+            .to_little_endian(slice)
+            .map_err(|_| FieldError::output(Field::Fr, 32, slice.len()))
     }
     pub fn new(val: arith::U256) -> Option<Self> {
         fields::Fr::new(val).map(|x| Fr(x))
@@ -58,6 +160,62 @@ impl Fr {
     pub fn set_bit(&mut self, bit: usize, to: bool) {
         self.0.set_bit(bit, to);
     }
+
+    /// `self * self`, cheaper than the general multiplication.
+    pub fn square(&self) -> Self {
+        Fr(self.0.squared())
+    }
+    /// `self + self` by a shift.
+    pub fn double(&self) -> Self {
+        Fr(self.0.double())
+    }
+    /// A square root of `self`, if there is one. Variable time.
+    pub fn sqrt(&self) -> Option<Self> {
+        self.0.sqrt().map(Fr)
+    }
+    /// The Legendre symbol: 1 for non-zero squares, -1 for non-squares and
+    /// 0 for zero.
+    pub fn legendre(&self) -> i8 {
+        let s = self.0.pow_r_minus_1_div_2();
+        if s.is_zero() {
+            0
+        } else if s == fields::Fr::one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Converts an `Fq` element to `Fr`, failing if its canonical value is
+    /// not below the scalar field modulus r.
+    pub fn from_fq(fq: Fq) -> Option<Self> {
+        Fr::new(fq.into_u256())
+    }
+
+    /// Reduces the canonical value of an `Fq` element modulo r. Since
+    /// r < p < 2r, values in [r, p) wrap around, so this is not injective.
+    pub fn from_fq_reduced(fq: Fq) -> Self {
+        Fr::new_mul_factor(fq.into_u256())
+    }
+
+    /// The canonical value as four little-endian 64-bit limbs.
+    pub fn to_limbs_le(&self) -> [u64; 4] {
+        let a = self.into_u256();
+        [
+            a.0[0] as u64,
+            (a.0[0] >> 64) as u64,
+            a.0[1] as u64,
+            (a.0[1] >> 64) as u64,
+        ]
+    }
+
+    /// Inverse of `to_limbs_le`; `None` if the value is not below r.
+    pub fn from_limbs_le(limbs: &[u64; 4]) -> Option<Self> {
+        Fr::new(arith::U256([
+            limbs[0] as u128 | (limbs[1] as u128) << 64,
+            limbs[2] as u128 | (limbs[3] as u128) << 64,
+        ]))
+    }
 }
 
 impl Add<Fr> for Fr {
@@ -92,19 +250,214 @@ impl Mul for Fr {
     }
 }
 
-#[derive(Debug)]
+/// Numeric order of the canonical integers in `[0, r)`, not of the
+/// Montgomery form.
+impl Ord for Fr {
+    fn cmp(&self, other: &Fr) -> core::cmp::Ordering {
+        self.into_u256().cmp(&other.into_u256())
+    }
+}
+
+impl PartialOrd for Fr {
+    fn partial_cmp(&self, other: &Fr) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for Fr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_u256().hash(state);
+    }
+}
+
+impl From<u64> for Fr {
+    fn from(x: u64) -> Fr {
+        Fr::new(arith::U256::from(x)).expect("u64 is below the modulus; qed")
+    }
+}
+
+impl From<u128> for Fr {
+    fn from(x: u128) -> Fr {
+        Fr::new(arith::U256([x, 0])).expect("u128 is below the modulus; qed")
+    }
+}
+
+/// The field whose encoding an error refers to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Field {
+    Fr,
+    Fq,
+    Fq2,
+    Gt,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Field::Fr => "Fr",
+            Field::Fq => "Fq",
+            Field::Fq2 => "Fq2",
+            Field::Gt => "Gt",
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FieldError {
-    InvalidSliceLength,
+    /// An encoding to decode is not `expected` bytes long.
+    InputLength {
+        field: Field,
+        expected: usize,
+        actual: usize,
+    },
+    /// A buffer to encode into is not `expected` bytes long.
+    OutputLength {
+        field: Field,
+        expected: usize,
+        actual: usize,
+    },
     InvalidU512Encoding,
     NotMember,
+    /// A coefficient of an `Fq2` encoding is not below the modulus.
+    /// `offset` is where the encoding of the coefficient starts; for the
+    /// 512-bit integer format of `Fq2::from_slice` it is the whole input.
+    CoefficientNotMember {
+        imaginary: bool,
+        offset: usize,
+    },
 }
 
-#[derive(Debug)]
+impl FieldError {
+    fn input(field: Field, expected: usize, actual: usize) -> Self {
+        FieldError::InputLength {
+            field,
+            expected,
+            actual,
+        }
+    }
+
+    fn output(field: Field, expected: usize, actual: usize) -> Self {
+        FieldError::OutputLength {
+            field,
+            expected,
+            actual,
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FieldError::InputLength {
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} encoding must be {} bytes, got {}",
+                field, expected, actual
+            ),
+            FieldError::OutputLength {
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} output buffer must be {} bytes, got {}",
+                field, expected, actual
+            ),
+            FieldError::InvalidU512Encoding => f.write_str("invalid 512-bit integer encoding"),
+            FieldError::NotMember => f.write_str("value is not below the field modulus"),
+            FieldError::CoefficientNotMember { imaginary, offset } => write!(
+                f,
+                "{} coefficient at byte {} is not below the field modulus",
+                if *imaginary { "imaginary" } else { "real" },
+                offset
+            ),
+        }
+    }
+}
+
+impl core::error::Error for FieldError {}
+
+/// The part of a point encoding an error refers to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Component {
+    X,
+    Y,
+    XReal,
+    XImaginary,
+    YReal,
+    YImaginary,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Component::X => "x",
+            Component::Y => "y",
+            Component::XReal => "real part of x",
+            Component::XImaginary => "imaginary part of x",
+            Component::YReal => "real part of y",
+            Component::YImaginary => "imaginary part of y",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub enum CurveError {
     InvalidEncoding,
     NotMember,
     Field(FieldError),
     ToAffineConversion,
+    /// The input is not `expected` bytes long.
+    InvalidLength {
+        expected: usize,
+        actual: usize,
+    },
+    /// A coordinate starting at byte `offset` of the input failed to
+    /// decode.
+    Coordinate {
+        component: Component,
+        offset: usize,
+        error: FieldError,
+    },
+}
+
+impl CurveError {
+    /// Attributes a failure to decode an `Fq` coordinate at `offset`.
+    pub(crate) fn fq(component: Component, offset: usize, error: FieldError) -> Self {
+        CurveError::Coordinate {
+            component,
+            offset,
+            error,
+        }
+    }
+
+    /// Attributes a failure to decode the `Fq2` coordinate `x` (or `y`
+    /// with `y` set) at `offset`, naming the failing coefficient if known.
+    pub(crate) fn fq2(y: bool, offset: usize, error: FieldError) -> Self {
+        let (component, offset, error) = match error {
+            FieldError::CoefficientNotMember {
+                imaginary,
+                offset: inner,
+            } => {
+                let component = match (y, imaginary) {
+                    (false, false) => Component::XReal,
+                    (false, true) => Component::XImaginary,
+                    (true, false) => Component::YReal,
+                    (true, true) => Component::YImaginary,
+                };
+                (component, offset + inner, FieldError::NotMember)
+            }
+            error => (if y { Component::Y } else { Component::X }, offset, error),
+        };
+        CurveError::Coordinate {
+            component,
+            offset,
+            error,
+        }
+    }
 }
 
 impl From<FieldError> for CurveError {
@@ -113,13 +466,183 @@ impl From<FieldError> for CurveError {
     }
 }
 
-pub use crate::groups::Error as GroupError;
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CurveError::InvalidEncoding => f.write_str("invalid point encoding"),
+            CurveError::NotMember => f.write_str("point is not in the group"),
+            CurveError::Field(e) => write!(f, "invalid coordinate: {}", e),
+            CurveError::ToAffineConversion => {
+                f.write_str("point at infinity has no affine coordinates")
+            }
+            CurveError::InvalidLength { expected, actual } => write!(
+                f,
+                "point encoding must be {} bytes, got {}",
+                expected, actual
+            ),
+            CurveError::Coordinate {
+                component,
+                offset,
+                error,
+            } => write!(f, "invalid {} at byte {}: {}", component, offset, error),
+        }
+    }
+}
+
+impl core::error::Error for CurveError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            CurveError::Field(e) | CurveError::Coordinate { error: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
 
+/// Acceptance rules for decoding points from bytes. The default is the
+/// strictest setting and is what the functions without a policy argument
+/// use.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DecodePolicy {
+    /// Reject coordinates that are not below the field modulus instead of
+    /// reducing them.
+    pub require_canonical: bool,
+    /// Check that G2 points are in the prime-order subgroup. G1 has
+    /// cofactor one, so every curve point qualifies.
+    pub require_subgroup: bool,
+    /// Decode the all-zero encoding as the point at infinity.
+    pub allow_infinity: bool,
+}
+
+impl Default for DecodePolicy {
+    fn default() -> Self {
+        DecodePolicy {
+            require_canonical: true,
+            require_subgroup: true,
+            allow_infinity: false,
+        }
+    }
+}
+
+impl DecodePolicy {
+    /// The EIP-196/197 precompile rules: canonical coordinates, subgroup
+    /// checks, and zero for the point at infinity.
+    pub fn evm() -> Self {
+        DecodePolicy {
+            allow_infinity: true,
+            ..Default::default()
+        }
+    }
+
+    /// Accepts anything that decodes to a curve point.
+    pub fn permissive() -> Self {
+        DecodePolicy {
+            require_canonical: false,
+            require_subgroup: false,
+            allow_infinity: true,
+        }
+    }
+
+    /// Reads a 32-byte big-endian coordinate.
+    pub(crate) fn read_fq(&self, bytes: &[u8]) -> Result<Fq, FieldError> {
+        if self.require_canonical {
+            return Fq::from_slice(bytes);
+        }
+        let x = arith::U256::from_slice(bytes)
+            .map_err(|_| FieldError::input(Field::Fq, 32, bytes.len()))?;
+        Fq::from_u256(x % Fq::modulus())
+    }
+
+    /// Reads 64 bytes in the `Fq2::from_slice` format.
+    pub(crate) fn read_fq2(&self, bytes: &[u8]) -> Result<Fq2, FieldError> {
+        if self.require_canonical {
+            return Fq2::from_slice(bytes);
+        }
+        let u512 = arith::U512::from_slice(bytes)
+            .map_err(|_| FieldError::input(Field::Fq2, 64, bytes.len()))?;
+        let q = Fq::modulus();
+        let (quotient, remainder) = u512
+            .divrem_u512(&arith::U512([q.0[0], q.0[1], 0, 0]))
+            .expect("modulus is not zero; qed");
+        let imaginary = quotient % arith::U512([q.0[0], q.0[1], 0, 0]);
+        Ok(Fq2::new(
+            Fq::from_u256(arith::U256([remainder.0[0], remainder.0[1]]))?,
+            Fq::from_u256(arith::U256([imaginary.0[0], imaginary.0[1]]))?,
+        ))
+    }
+
+    /// Builds an affine G2 point, checking the subgroup if required.
+    pub(crate) fn g2(&self, x: Fq2, y: Fq2) -> Result<AffineG2, GroupError> {
+        if self.require_subgroup {
+            AffineG2::new(x, y)
+        } else {
+            groups::AffineG2::new_on_curve(x.0, y.0).map(AffineG2)
+        }
+    }
+}
+
+/// Which of the two square roots the flag of a compressed point selects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum YSign {
+    /// The flag is set when the canonical y is odd; for `Fq2`, the parity
+    /// of the real part, or of the imaginary part if the real part is zero
+    /// (`sgn0` of RFC 9380). The default for G1.
+    Parity,
+    /// The flag is set when y is the larger of `y` and `-y`, comparing
+    /// `Fq2` elements as the integer `imaginary * q + real`. The default
+    /// for G2.
+    Lexicographic,
+}
+
+impl YSign {
+    fn of_fq(&self, y: Fq) -> bool {
+        match self {
+            YSign::Parity => y.into_u256().0[0] & 1 == 1,
+            YSign::Lexicographic => y.into_u256() > (-y).into_u256(),
+        }
+    }
+
+    fn of_fq2(&self, y: Fq2) -> bool {
+        match self {
+            YSign::Parity => {
+                let odd = |c: Fq| c.into_u256().0[0] & 1 == 1;
+                odd(y.real()) || (y.real().is_zero() && odd(y.imaginary()))
+            }
+            YSign::Lexicographic => y.0.to_u512() > (-y).0.to_u512(),
+        }
+    }
+
+    /// The y of the G1 curve point with abscissa `x` whose sign is `flag`.
+    /// Every decoder of a compressed G1 point goes through this.
+    fn g1_y(&self, x: Fq, flag: bool) -> Result<Fq, CurveError> {
+        let y = (x * x * x + G1::b()).sqrt().ok_or(CurveError::NotMember)?;
+        Ok(if self.of_fq(y) == flag { y } else { -y })
+    }
+
+    /// `g1_y` on the twist.
+    fn g2_y(&self, x: Fq2, flag: bool) -> Result<Fq2, CurveError> {
+        let y = (x * x * x + G2::b()).sqrt().ok_or(CurveError::NotMember)?;
+        Ok(if self.of_fq2(y) == flag { y } else { -y })
+    }
+}
+
+pub use crate::cost::{MemoryBudget, OverBudget};
+pub use crate::entropy::EntropySource;
+pub use crate::fixed_base::{G1PrecomputedTable, G2PrecomputedTable};
+pub use crate::groups::Error as GroupError;
+pub use crate::groups::{MsmCostModel, MSM_MAX_WINDOW};
+pub use crate::hex::HexError;
+pub use crate::selftest::{self_test, SelfTestError};
+pub use crate::validate::ValidationError;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 #[repr(C)]
 pub struct Fq(fields::Fq);
 
 impl Fq {
+    /// `Fr::from_raw` for Fq: the limbs are `x * 2^256 mod q`.
+    pub const fn from_raw(limbs: [u64; 4]) -> Self {
+        Fq(fields::const_fq(limbs))
+    }
     pub fn zero() -> Self {
         Fq(fields::Fq::zero())
     }
@@ -143,7 +666,7 @@ impl Fq {
     }
     pub fn from_slice(slice: &[u8]) -> Result<Self, FieldError> {
         arith::U256::from_slice(slice)
-            .map_err(|_| FieldError::InvalidSliceLength) // todo: maybe more sensful error handling
+            .map_err(|_| FieldError::input(Field::Fq, 32, slice.len()))
             .and_then(|x| fields::Fq::new(x).ok_or(FieldError::NotMember))
             .map(|x| Fq(x))
     }
@@ -156,7 +679,19 @@ impl Fq {
             self.0.inv(),
         );
         a.to_big_endian(slice)
-            .map_err(|_| FieldError::InvalidSliceLength)
+            .map_err(|_| FieldError::output(Field::Fq, 32, slice.len()))
+    }
+    /// Little-endian counterpart of `from_slice`.
+    pub fn from_le_slice(slice: &[u8]) -> Result<Self, FieldError> {
+        let x = arith::U256::from_le_slice(slice)
+            .map_err(|_| FieldError::input(Field::Fq, 32, slice.len()))?;
+        Fq::from_u256(x)
+    }
+    /// Little-endian counterpart of `to_big_endian`.
+    pub fn to_little_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
+        self.into_u256()
+            .to_little_endian(slice)
+            .map_err(|_| FieldError::output(Field::Fq, 32, slice.len()))
     }
     pub fn from_u256(u256: arith::U256) -> Result<Self, FieldError> {
         Ok(Fq(fields::Fq::new(u256).ok_or(FieldError::NotMember)?))
@@ -168,9 +703,32 @@ impl Fq {
         fields::Fq::modulus()
     }
 
+    /// `self * self`, cheaper than the general multiplication.
+    pub fn square(&self) -> Self {
+        Fq(self.0.squared())
+    }
+    /// `self + self` by a shift.
+    pub fn double(&self) -> Self {
+        Fq(self.0.double())
+    }
     pub fn sqrt(&self) -> Option<Self> {
         self.0.sqrt().map(Fq)
     }
+    /// `self^((p - 1) / 2)` through a fixed addition chain: one for
+    /// non-zero squares, -1 for non-squares and zero for zero.
+    pub fn pow_p_minus_1_div_2(&self) -> Self {
+        Fq(self.0.pow_p_minus_1_div_2())
+    }
+    /// `self^((p + 1) / 4)` through a fixed addition chain; the square root
+    /// returned by `sqrt` when there is one.
+    pub fn pow_p_plus_1_div_4(&self) -> Self {
+        Fq(self.0.pow_p_plus_1_div_4())
+    }
+
+    /// Embeds an `Fr` element into `Fq`. This never fails since r < p.
+    pub fn from_fr(fr: Fr) -> Self {
+        Fq(fields::Fq::new(fr.into_u256()).expect("r is less than p; qed"))
+    }
 }
 
 impl Add<Fq> for Fq {
@@ -205,6 +763,38 @@ impl Mul for Fq {
     }
 }
 
+/// Numeric order of the canonical integers in `[0, q)`, not of the
+/// Montgomery form.
+impl Ord for Fq {
+    fn cmp(&self, other: &Fq) -> core::cmp::Ordering {
+        self.into_u256().cmp(&other.into_u256())
+    }
+}
+
+impl PartialOrd for Fq {
+    fn partial_cmp(&self, other: &Fq) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for Fq {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_u256().hash(state);
+    }
+}
+
+impl From<u64> for Fq {
+    fn from(x: u64) -> Fq {
+        Fq::from_u256(arith::U256::from(x)).expect("u64 is below the modulus; qed")
+    }
+}
+
+impl From<u128> for Fq {
+    fn from(x: u128) -> Fq {
+        Fq::from_u256(arith::U256([x, 0])).expect("u128 is below the modulus; qed")
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(C)]
 pub struct Fq2(fields::Fq2);
@@ -231,6 +821,10 @@ impl Fq2 {
         self.0.is_zero()
     }
 
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Fq2)
+    }
+
     pub fn pow(&self, exp: arith::U256) -> Self {
         Fq2(self.0.pow(exp))
     }
@@ -247,14 +841,123 @@ impl Fq2 {
         self.0.sqrt().map(Fq2)
     }
 
+    /// `real - imaginary * i`.
+    pub fn conjugate(&self) -> Self {
+        Fq2(self.0.conjugate())
+    }
+
+    /// `self * self.conjugate()`, i.e. `real^2 + imaginary^2`.
+    pub fn norm(&self) -> Fq {
+        Fq(self.0.norm())
+    }
+
+    /// `self^(q^power)`: the identity for even powers, `conjugate` for odd.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Fq2(self.0.frobenius_map(power))
+    }
+
+    /// Whether `self` has a square root; zero does.
+    pub fn is_quadratic_residue(&self) -> bool {
+        self.0.is_quadratic_residue()
+    }
+
+    /// Parses 64 big-endian bytes as the integer `imaginary * q + real`.
+    /// See `from_bytes_be_real_first`/`from_bytes_be_imag_first` for the
+    /// coefficient-wise encodings.
     pub fn from_slice(bytes: &[u8]) -> Result<Self, FieldError> {
-        let u512 = arith::U512::from_slice(bytes).map_err(|_| FieldError::InvalidU512Encoding)?;
+        let u512 = arith::U512::from_slice(bytes)
+            .map_err(|_| FieldError::input(Field::Fq2, 64, bytes.len()))?;
         let (res, c0) = u512.divrem(&Fq::modulus());
+        // the remainder is always below q; only the quotient can overflow
+        let imaginary = FieldError::CoefficientNotMember {
+            imaginary: true,
+            offset: 0,
+        };
         Ok(Fq2::new(
-            Fq::from_u256(c0).map_err(|_| FieldError::NotMember)?,
-            Fq::from_u256(res.ok_or(FieldError::NotMember)?).map_err(|_| FieldError::NotMember)?,
+            Fq::from_u256(c0)?,
+            res.ok_or(imaginary)
+                .and_then(|c1| Fq::from_u256(c1).map_err(|_| imaginary))?,
         ))
     }
+
+    /// Inverse of `from_slice`.
+    pub fn to_slice(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        self.0
+            .to_u512()
+            .to_big_endian(&mut bytes)
+            .expect("buffer is 64 bytes; qed");
+        bytes
+    }
+
+    fn to_bytes_be(first: Fq, second: Fq) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        first
+            .to_big_endian(&mut bytes[..32])
+            .expect("slice is 32 bytes; qed");
+        second
+            .to_big_endian(&mut bytes[32..])
+            .expect("slice is 32 bytes; qed");
+        bytes
+    }
+
+    /// Reads two 32-byte coefficients, the first being the imaginary part
+    /// if `imaginary_first`.
+    fn from_bytes_be(bytes: &[u8], imaginary_first: bool) -> Result<(Fq, Fq), FieldError> {
+        if bytes.len() != 64 {
+            return Err(FieldError::input(Field::Fq2, 64, bytes.len()));
+        }
+        let read = |offset: usize, imaginary: bool| {
+            Fq::from_slice(&bytes[offset..offset + 32])
+                .map_err(|_| FieldError::CoefficientNotMember { imaginary, offset })
+        };
+        Ok((read(0, imaginary_first)?, read(32, !imaginary_first)?))
+    }
+
+    /// `real || imaginary`, each a 32-byte big-endian canonical integer.
+    pub fn to_bytes_be_real_first(&self) -> [u8; 64] {
+        Self::to_bytes_be(self.real(), self.imaginary())
+    }
+
+    /// `imaginary || real`, each a 32-byte big-endian canonical integer, as
+    /// in the EIP-197 encoding of G2 coordinates.
+    pub fn to_bytes_be_imag_first(&self) -> [u8; 64] {
+        Self::to_bytes_be(self.imaginary(), self.real())
+    }
+
+    /// Inverse of `to_bytes_be_real_first`; both coefficients must be below
+    /// q, as under `DecodePolicy::default()`. There is no relaxed variant.
+    pub fn from_bytes_be_real_first(bytes: &[u8]) -> Result<Self, FieldError> {
+        Self::from_bytes_be(bytes, false).map(|(real, imaginary)| Fq2::new(real, imaginary))
+    }
+
+    /// Inverse of `to_bytes_be_imag_first`, with the same policy as
+    /// `from_bytes_be_real_first`.
+    pub fn from_bytes_be_imag_first(bytes: &[u8]) -> Result<Self, FieldError> {
+        Self::from_bytes_be(bytes, true).map(|(imaginary, real)| Fq2::new(real, imaginary))
+    }
+
+    /// `real || imaginary`, each a 32-byte little-endian canonical integer,
+    /// as arkworks writes `Fq2`.
+    pub fn to_little_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
+        if slice.len() != 64 {
+            return Err(FieldError::output(Field::Fq2, 64, slice.len()));
+        }
+        self.real().to_little_endian(&mut slice[..32])?;
+        self.imaginary().to_little_endian(&mut slice[32..])
+    }
+
+    /// Inverse of `to_little_endian`; both coefficients must be below q.
+    pub fn from_le_slice(slice: &[u8]) -> Result<Self, FieldError> {
+        if slice.len() != 64 {
+            return Err(FieldError::input(Field::Fq2, 64, slice.len()));
+        }
+        let read = |offset: usize, imaginary: bool| {
+            Fq::from_le_slice(&slice[offset..offset + 32])
+                .map_err(|_| FieldError::CoefficientNotMember { imaginary, offset })
+        };
+        Ok(Fq2::new(read(0, false)?, read(32, true)?))
+    }
 }
 
 impl Add<Fq2> for Fq2 {
@@ -289,6 +992,13 @@ impl Mul for Fq2 {
     }
 }
 
+impl Hash for Fq2 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.real().hash(state);
+        self.imaginary().hash(state);
+    }
+}
+
 pub trait Group:
     Send
     + Sync
@@ -341,35 +1051,166 @@ impl G1 {
         *self.0.z_mut() = z.0
     }
 
+    /// `self + self` by the dedicated doubling formulas, with fewer field
+    /// multiplications than the general addition.
+    pub fn double(&self) -> Self {
+        G1(self.0.double())
+    }
+
     pub fn b() -> Fq {
         Fq(G1Params::coeff_b())
     }
 
+    /// Parses `0x02 || x` or `0x03 || x`, where the prefix selects y by
+    /// `YSign::Parity`.
     pub fn from_compressed(bytes: &[u8]) -> Result<Self, CurveError> {
+        Self::from_compressed_with_policy(bytes, &DecodePolicy::default())
+    }
+
+    pub fn from_compressed_with_policy(
+        bytes: &[u8],
+        policy: &DecodePolicy,
+    ) -> Result<Self, CurveError> {
+        Self::from_compressed_with_sign(bytes, YSign::Parity, policy)
+    }
+
+    /// `from_compressed_with_policy` with the prefix read under `sign`.
+    pub fn from_compressed_with_sign(
+        bytes: &[u8],
+        sign: YSign,
+        policy: &DecodePolicy,
+    ) -> Result<Self, CurveError> {
         if bytes.len() != 33 {
-            return Err(CurveError::InvalidEncoding);
+            return Err(CurveError::InvalidLength {
+                expected: 33,
+                actual: bytes.len(),
+            });
+        }
+        if policy.allow_infinity && bytes.iter().all(|&b| b == 0) {
+            return Ok(G1::zero());
         }
 
-        let sign = bytes[0];
-        let fq = Fq::from_slice(&bytes[1..])?;
-        let x = fq;
-        let y_squared = (fq * fq * fq) + Self::b();
-
-        let mut y = y_squared.sqrt().ok_or(CurveError::NotMember)?;
+        let x = policy
+            .read_fq(&bytes[1..])
+            .map_err(|e| CurveError::fq(Component::X, 1, e))?;
 
-        if sign == 2 && y.into_u256().get_bit(0).expect("bit 0 always exist; qed") {
-            y = y.neg();
-        } else if sign == 3 && !y.into_u256().get_bit(0).expect("bit 0 always exist; qed") {
-            y = y.neg();
-        } else if sign != 3 && sign != 2 {
-            return Err(CurveError::InvalidEncoding);
-        }
-        AffineG1::new(x, y)
-            .map_err(|_| CurveError::NotMember)
+        let flag = match bytes[0] {
+            2 => false,
+            3 => true,
+            _ => return Err(CurveError::InvalidEncoding),
+        };
+        AffineG1::new(x, sign.g1_y(x, flag)?)
             .map(Into::into)
+            .map_err(|_| CurveError::NotMember)
+    }
+
+    /// Hashes `msg` to G1 with the RFC 9380 suite
+    /// `BN254G1_XMD:SHA-256_SVDW_RO_` under the domain separation tag `dst`.
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> G1 {
+        hash_to_curve::hash_to_curve_g1(msg, dst)
+    }
+
+    /// Multi-scalar multiplication `sum(scalars[i] * points[i])`. Pairs
+    /// beyond the shorter slice are ignored. The bucket window is chosen
+    /// from the input length by the default `MsmCostModel`.
+    pub fn msm(points: &[AffineG1], scalars: &[Fr]) -> G1 {
+        Self::msm_with_model(points, scalars, &MsmCostModel::default())
+    }
+
+    /// `msm` with the window chosen by a caller supplied (e.g. calibrated)
+    /// cost model.
+    pub fn msm_with_model(points: &[AffineG1], scalars: &[Fr], model: &MsmCostModel) -> G1 {
+        let n = core::cmp::min(points.len(), scalars.len());
+        Self::msm_with_window(points, scalars, model.best_window(n))
+    }
+
+    /// `sum(a_i * g[i] + b_i * h[i])` for `terms[i] = (a_i, b_i)`, the shape
+    /// of vector Pedersen commitments with per-element blinding. Both
+    /// generator sets go through one bucket pass over `2n` terms, sharing
+    /// the doublings and bucket sums of two separate `msm` calls. Terms
+    /// beyond the shortest slice are ignored.
+    pub fn msm_masked(g: &[AffineG1], h: &[AffineG1], terms: &[(Fr, Fr)]) -> G1 {
+        let n = core::cmp::min(core::cmp::min(g.len(), h.len()), terms.len());
+        let window = MsmCostModel::default().best_window(2 * n);
+        let terms = g
+            .iter()
+            .zip(h.iter())
+            .zip(terms.iter())
+            .flat_map(|((g, h), (a, b))| [(g.0, a.0), (h.0, b.0)]);
+        G1(groups::msm(terms, window))
+    }
+
+    /// `msm_with_model` using only windows whose buckets fit in `budget`.
+    /// Returns the result with the bytes of bucket space actually used.
+    pub fn msm_with_budget(
+        points: &[AffineG1],
+        scalars: &[Fr],
+        model: &MsmCostModel,
+        budget: &MemoryBudget,
+    ) -> Result<(G1, usize), OverBudget> {
+        let n = core::cmp::min(points.len(), scalars.len());
+        let size = core::mem::size_of::<G1>();
+        let window = model
+            .best_window_within(n, size, budget)
+            .ok_or(OverBudget { required: size })?;
+        Ok((
+            Self::msm_with_window(points, scalars, window),
+            groups::bucket_count(window) * size,
+        ))
+    }
+
+    /// `msm` with a fixed bucket window in `1..=MSM_MAX_WINDOW`.
+    pub fn msm_with_window(points: &[AffineG1], scalars: &[Fr], window: usize) -> G1 {
+        let terms = points.iter().map(|p| p.0).zip(scalars.iter().map(|s| s.0));
+        G1(groups::msm(terms, window))
+    }
+
+    /// `msm` over `(point, scalar)` terms gathered in a `heapless::Vec`,
+    /// e.g. by a decoder that cannot allocate.
+    #[cfg(feature = "heapless")]
+    pub fn msm_heapless<const N: usize>(terms: &heapless::Vec<(AffineG1, Fr), N>) -> G1 {
+        let window = MsmCostModel::default().best_window(terms.len());
+        G1(groups::msm(terms.iter().map(|(p, s)| (p.0, s.0)), window))
+    }
+
+    /// Sums the points selected by a bitmap, where point `i` is selected by
+    /// bit `i % 8` (least significant first) of `bitmask[i / 8]`, as in SSZ
+    /// bitfields. Points without a corresponding bit are not selected.
+    pub fn sum_selected(points: &[AffineG1], bitmask: &[u8]) -> G1 {
+        Self::sum_weighted_packed(points, bitmask, 1)
+    }
+
+    /// `sum(d_i * points[i])` for unsigned `bits`-wide digits packed least
+    /// significant first into `digits` (`bits` = 1, 2, 4 or 8). Meant for
+    /// small weights such as participation counts, where a bucket per digit
+    /// value beats a general MSM.
+    pub fn sum_weighted_packed(points: &[AffineG1], digits: &[u8], bits: u32) -> G1 {
+        let mask = packed_digit_mask(bits);
+        let terms = points
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| packed_digit(digits, i, bits).map(|d| (p.0, d)));
+        G1(groups::sum_small_digits(terms, mask))
     }
 }
 
+fn packed_digit_mask(bits: u32) -> u8 {
+    assert!(
+        bits == 1 || bits == 2 || bits == 4 || bits == 8,
+        "digit width must be 1, 2, 4 or 8 bits"
+    );
+    ((1u16 << bits) - 1) as u8
+}
+
+/// Digit `i` of `bits`-wide digits packed least significant first.
+#[inline]
+fn packed_digit(digits: &[u8], i: usize, bits: u32) -> Option<u8> {
+    let per_byte = 8 / bits as usize;
+    digits
+        .get(i / per_byte)
+        .map(|byte| (byte >> ((i % per_byte) as u32 * bits)) & packed_digit_mask(bits))
+}
+
 impl Group for G1 {
     fn zero() -> Self {
         G1(groups::G1::zero())
@@ -406,6 +1247,24 @@ impl Sub<G1> for G1 {
     }
 }
 
+/// Mixed addition, cheaper than converting `other` to Jacobian coordinates
+/// first.
+impl Add<AffineG1> for G1 {
+    type Output = G1;
+
+    fn add(self, other: AffineG1) -> G1 {
+        G1(self.0 + other.0)
+    }
+}
+
+impl Sub<AffineG1> for G1 {
+    type Output = G1;
+
+    fn sub(self, other: AffineG1) -> G1 {
+        G1(self.0 - other.0)
+    }
+}
+
 impl Neg for G1 {
     type Output = G1;
 
@@ -431,6 +1290,11 @@ impl AffineG1 {
         Ok(AffineG1(groups::AffineG1::new(x.0, y.0)?))
     }
 
+    /// The generator in affine coordinates, equal to `G1::one()`.
+    pub fn generator() -> Self {
+        AffineG1(groups::AffineG1::generator())
+    }
+
     pub fn x(&self) -> Fq {
         Fq(self.0.x().clone())
     }
@@ -450,6 +1314,68 @@ impl AffineG1 {
     pub fn from_jacobian(g1: G1) -> Option<Self> {
         g1.0.to_affine().map(|x| AffineG1(x))
     }
+
+    /// Inverse of `G1::from_compressed`: parity of y in the prefix.
+    pub fn to_compressed(&self) -> [u8; 33] {
+        self.to_compressed_with(YSign::Parity)
+    }
+
+    /// `prefix || x` with `x` as 32 big-endian bytes and the prefix 0x02 or
+    /// 0x03 carrying the sign of y under `sign`.
+    pub fn to_compressed_with(&self, sign: YSign) -> [u8; 33] {
+        let mut bytes = [0u8; 33];
+        bytes[0] = if sign.of_fq(self.y()) { 3 } else { 2 };
+        self.x()
+            .to_big_endian(&mut bytes[1..])
+            .expect("slice is 32 bytes; qed");
+        bytes
+    }
+
+    /// Returns the x coordinate together with the parity of y, `true`
+    /// meaning the canonical (non-Montgomery) y is odd. This is the same
+    /// convention as the 0x02/0x03 prefix of `G1::from_compressed`.
+    pub fn x_and_parity(&self) -> (Fq, bool) {
+        (self.x(), YSign::Parity.of_fq(self.y()))
+    }
+
+    /// Inverse of `x_and_parity`: recovers the point whose y has the
+    /// requested parity.
+    pub fn from_x_and_parity(x: Fq, odd: bool) -> Result<Self, CurveError> {
+        AffineG1::new(x, YSign::Parity.g1_y(x, odd)?).map_err(|_| CurveError::NotMember)
+    }
+
+    /// `x || y`, each in the `Fq::to_little_endian` format: the arkworks
+    /// uncompressed encoding of a finite point.
+    pub fn to_le_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        self.x()
+            .to_little_endian(&mut bytes[..32])
+            .expect("slice is 32 bytes; qed");
+        self.y()
+            .to_little_endian(&mut bytes[32..])
+            .expect("slice is 32 bytes; qed");
+        bytes
+    }
+
+    /// Inverse of `to_le_bytes`, with the checks of `AffineG1::new`.
+    pub fn from_le_slice(bytes: &[u8]) -> Result<Self, CurveError> {
+        if bytes.len() != 64 {
+            return Err(CurveError::InvalidLength {
+                expected: 64,
+                actual: bytes.len(),
+            });
+        }
+        let x = Fq::from_le_slice(&bytes[..32]).map_err(|e| CurveError::fq(Component::X, 0, e))?;
+        let y = Fq::from_le_slice(&bytes[32..]).map_err(|e| CurveError::fq(Component::Y, 32, e))?;
+        AffineG1::new(x, y).map_err(|_| CurveError::NotMember)
+    }
+}
+
+impl Hash for AffineG1 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x().hash(state);
+        self.y().hash(state);
+    }
 }
 
 impl From<AffineG1> for G1 {
@@ -467,6 +1393,12 @@ impl G2 {
         G2(groups::G2::new(x.0, y.0, z.0))
     }
 
+    /// The endomorphism `psi` (untwist, Frobenius, twist), which acts on G2
+    /// as multiplication by `q`. It costs a few `Fq2` multiplications.
+    pub fn frobenius(&self) -> G2 {
+        G2(self.0.frobenius())
+    }
+
     pub fn x(&self) -> Fq2 {
         Fq2(self.0.x().clone())
     }
@@ -491,44 +1423,150 @@ impl G2 {
         *self.0.z_mut() = z.0
     }
 
+    /// `self + self` by the dedicated doubling formulas, with fewer field
+    /// multiplications than the general addition.
+    pub fn double(&self) -> Self {
+        G2(self.0.double())
+    }
+
+    /// Multiplication by the twist cofactor `2q - r`, which maps any point
+    /// of the twist curve, e.g. from `hash_to_curve::map_to_curve_g2`, into
+    /// G2. It goes through `frobenius` and costs about half a scalar
+    /// multiplication.
+    pub fn mul_by_cofactor(&self) -> Self {
+        G2(self.0.mul_by_cofactor())
+    }
+
     pub fn b() -> Fq2 {
         Fq2(G2Params::coeff_b())
     }
 
+    /// Parses `0x0a || x` or `0x0b || x` with `x` in the `Fq2::from_slice`
+    /// format, where the prefix selects y by `YSign::Lexicographic`.
     pub fn from_compressed(bytes: &[u8]) -> Result<Self, CurveError> {
-        if bytes.len() != 65 {
-            return Err(CurveError::InvalidEncoding);
-        }
+        Self::from_compressed_with_policy(bytes, &DecodePolicy::default())
+    }
 
-        let sign = bytes[0];
-        let x = Fq2::from_slice(&bytes[1..])?;
+    pub fn from_compressed_with_policy(
+        bytes: &[u8],
+        policy: &DecodePolicy,
+    ) -> Result<Self, CurveError> {
+        Self::from_compressed_with_sign(bytes, YSign::Lexicographic, policy)
+    }
 
-        let y_squared = (x * x * x) + G2::b();
-        let y = y_squared.sqrt().ok_or(CurveError::NotMember)?;
-        let y_neg = -y;
+    /// `from_compressed_with_policy` with the prefix read under `sign`.
+    pub fn from_compressed_with_sign(
+        bytes: &[u8],
+        sign: YSign,
+        policy: &DecodePolicy,
+    ) -> Result<Self, CurveError> {
+        if bytes.len() != 65 {
+            return Err(CurveError::InvalidLength {
+                expected: 65,
+                actual: bytes.len(),
+            });
+        }
+        if policy.allow_infinity && bytes.iter().all(|&b| b == 0) {
+            return Ok(G2::zero());
+        }
 
-        let y_gt = y.0.to_u512() > y_neg.0.to_u512();
+        let x = policy
+            .read_fq2(&bytes[1..])
+            .map_err(|e| CurveError::fq2(false, 1, e))?;
 
-        let e_y = if sign == 10 {
-            if y_gt {
-                y_neg
-            } else {
-                y
-            }
-        } else if sign == 11 {
-            if y_gt {
-                y
-            } else {
-                y_neg
-            }
-        } else {
-            return Err(CurveError::InvalidEncoding);
+        let flag = match bytes[0] {
+            10 => false,
+            11 => true,
+            _ => return Err(CurveError::InvalidEncoding),
         };
-
-        AffineG2::new(x, e_y)
+        policy
+            .g2(x, sign.g2_y(x, flag)?)
             .map_err(|_| CurveError::NotMember)
             .map(Into::into)
     }
+
+    /// Hashes `msg` into G2 under the domain separation tag `dst`; see
+    /// `hash_to_curve::hash_to_curve_g2`.
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> G2 {
+        hash_to_curve::hash_to_curve_g2(msg, dst)
+    }
+
+    /// Precomputes the Miller loop line coefficients for this point, or
+    /// `None` for the point at infinity.
+    pub fn prepare(&self) -> Option<G2Prepared> {
+        self.0.to_affine().map(|q| G2Prepared(q.precompute()))
+    }
+
+    /// `prepare`, failing if the `G2Prepared::TABLE_BYTES` of line
+    /// coefficients exceed `budget`. The table size is fixed by the
+    /// Miller loop, so this only checks it up front.
+    pub fn prepare_within(&self, budget: &MemoryBudget) -> Result<Option<G2Prepared>, OverBudget> {
+        if !budget.fits(G2Prepared::TABLE_BYTES) {
+            return Err(OverBudget {
+                required: G2Prepared::TABLE_BYTES,
+            });
+        }
+        Ok(self.prepare())
+    }
+
+    /// G2 counterpart of `G1::msm`. Additions and doublings in G2 cost
+    /// roughly the same multiple of each other as in G1, so the default
+    /// `MsmCostModel` picks a suitable window for both.
+    pub fn msm(points: &[AffineG2], scalars: &[Fr]) -> G2 {
+        Self::msm_with_model(points, scalars, &MsmCostModel::default())
+    }
+
+    /// G2 counterpart of `G1::msm_with_model`.
+    pub fn msm_with_model(points: &[AffineG2], scalars: &[Fr], model: &MsmCostModel) -> G2 {
+        let n = core::cmp::min(points.len(), scalars.len());
+        Self::msm_with_window(points, scalars, model.best_window(n))
+    }
+
+    /// G2 counterpart of `G1::msm_with_budget`.
+    pub fn msm_with_budget(
+        points: &[AffineG2],
+        scalars: &[Fr],
+        model: &MsmCostModel,
+        budget: &MemoryBudget,
+    ) -> Result<(G2, usize), OverBudget> {
+        let n = core::cmp::min(points.len(), scalars.len());
+        let size = core::mem::size_of::<G2>();
+        let window = model
+            .best_window_within(n, size, budget)
+            .ok_or(OverBudget { required: size })?;
+        Ok((
+            Self::msm_with_window(points, scalars, window),
+            groups::bucket_count(window) * size,
+        ))
+    }
+
+    /// G2 counterpart of `G1::msm_with_window`.
+    pub fn msm_with_window(points: &[AffineG2], scalars: &[Fr], window: usize) -> G2 {
+        let terms = points.iter().map(|p| p.0).zip(scalars.iter().map(|s| s.0));
+        G2(groups::msm(terms, window))
+    }
+
+    /// G2 counterpart of `G1::msm_heapless`.
+    #[cfg(feature = "heapless")]
+    pub fn msm_heapless<const N: usize>(terms: &heapless::Vec<(AffineG2, Fr), N>) -> G2 {
+        let window = MsmCostModel::default().best_window(terms.len());
+        G2(groups::msm(terms.iter().map(|(p, s)| (p.0, s.0)), window))
+    }
+
+    /// G2 counterpart of `G1::sum_selected`.
+    pub fn sum_selected(points: &[AffineG2], bitmask: &[u8]) -> G2 {
+        Self::sum_weighted_packed(points, bitmask, 1)
+    }
+
+    /// G2 counterpart of `G1::sum_weighted_packed`.
+    pub fn sum_weighted_packed(points: &[AffineG2], digits: &[u8], bits: u32) -> G2 {
+        let mask = packed_digit_mask(bits);
+        let terms = points
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| packed_digit(digits, i, bits).map(|d| (p.0, d)));
+        G2(groups::sum_small_digits(terms, mask))
+    }
 }
 
 impl Group for G2 {
@@ -567,6 +1605,24 @@ impl Sub<G2> for G2 {
     }
 }
 
+/// Mixed addition, cheaper than converting `other` to Jacobian coordinates
+/// first.
+impl Add<AffineG2> for G2 {
+    type Output = G2;
+
+    fn add(self, other: AffineG2) -> G2 {
+        G2(self.0 + other.0)
+    }
+}
+
+impl Sub<AffineG2> for G2 {
+    type Output = G2;
+
+    fn sub(self, other: AffineG2) -> G2 {
+        G2(self.0 - other.0)
+    }
+}
+
 impl Neg for G2 {
     type Output = G2;
 
@@ -583,6 +1639,16 @@ impl Mul<Fr> for G2 {
     }
 }
 
+/// An element of the target group, a subgroup of Fq12.
+///
+/// Values returned by `pairing` and `pairing_batch` are final-exponentiated
+/// and therefore have a unique representation. Every `Fq` coefficient is
+/// kept fully reduced, so equality of the representations is equality of
+/// the canonical values, and ordering compares those values; neither
+/// depends on how an element was computed. `miller_loop_batch`
+/// instead returns a raw Miller loop value, which is only defined up to an
+/// r-th power; two such values must each go through `final_exponentiation`
+/// before they can be meaningfully compared.
 #[derive(Copy, Clone, PartialEq, Eq)]
 #[repr(C)]
 pub struct Gt(fields::Fq12);
@@ -591,8 +1657,31 @@ impl Gt {
     pub fn one() -> Self {
         Gt(fields::Fq12::one())
     }
+
+    /// The canonical (non-Montgomery) values of the coefficients, in the
+    /// tower order c0.c0.real, c0.c0.imaginary, c0.c1.real, ...
+    fn canonical_coeffs(&self) -> [arith::U256; 12] {
+        let mut out = [arith::U256::zero(); 12];
+        for (o, c) in out.iter_mut().zip(self.0.coeffs().iter()) {
+            *o = (*c).into();
+        }
+        out
+    }
+    /// Uses cyclotomic squarings when `self` is in the cyclotomic subgroup,
+    /// as every pairing output is, and generic squarings otherwise, e.g.
+    /// for raw Miller loop values.
     pub fn pow(&self, exp: Fr) -> Self {
-        Gt(self.0.pow(exp.0))
+        if self.0.is_cyclotomic() {
+            Gt(self.0.cyclotomic_pow(exp.0))
+        } else {
+            Gt(self.0.pow(exp.0))
+        }
+    }
+    /// Granger-Scott squaring, considerably cheaper than `self * self`.
+    /// Only correct in the cyclotomic subgroup, which contains every
+    /// pairing output and every result of `final_exponentiation`.
+    pub fn cyclotomic_square(&self) -> Self {
+        Gt(self.0.cyclotomic_squared())
     }
     pub fn inverse(&self) -> Option<Self> {
         self.0.inverse().map(Gt)
@@ -600,6 +1689,126 @@ impl Gt {
     pub fn final_exponentiation(&self) -> Option<Self> {
         self.0.final_exponentiation().map(Gt)
     }
+
+    /// `self^(q^power)`, a cheap automorphism of Fq12. On `Gt` it is
+    /// exponentiation by `q^power mod r`.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Gt(self.0.frobenius_map(power))
+    }
+
+    /// Whether this is the identity. The pairing is non-degenerate, so for
+    /// points of the prime-order groups `e(p, q)` is the identity exactly
+    /// when `p` or `q` is the point at infinity: a degenerate value carries
+    /// no constraint in a product check.
+    pub fn is_degenerate(&self) -> bool {
+        *self == Gt::one()
+    }
+
+    /// Writes the twelve canonical coefficients as 32-byte big-endian
+    /// integers, in the tower order c0.c0.real, c0.c0.imaginary,
+    /// c0.c1.real, ..., c1.c2.imaginary.
+    pub fn to_bytes(&self, out: &mut [u8; 384]) {
+        for (chunk, c) in out.chunks_mut(32).zip(self.canonical_coeffs().iter()) {
+            c.to_big_endian(chunk).expect("chunk is 32 bytes; qed");
+        }
+    }
+
+    /// Inverse of `to_bytes`. Fails if a coefficient is not below the
+    /// modulus or the element is not of order r, so the result can be
+    /// trusted as a pairing output.
+    pub fn from_slice(slice: &[u8]) -> Result<Gt, FieldError> {
+        if slice.len() != 384 {
+            return Err(FieldError::input(Field::Gt, 384, slice.len()));
+        }
+        let mut coeffs = [fields::Fq::zero(); 12];
+        for (c, chunk) in coeffs.iter_mut().zip(slice.chunks(32)) {
+            *c = Fq::from_slice(chunk)?.0;
+        }
+        let gt = Gt(fields::Fq12::from_coeffs(coeffs));
+        if !gt.is_valid() {
+            return Err(FieldError::NotMember);
+        }
+        Ok(gt)
+    }
+
+    /// Compresses to the 192-byte encoding of a single `Fq6` element, half
+    /// the size of `to_bytes`. Final exponentiation leaves every pairing
+    /// value unitary, `g^2 - v h^2 = 1` for `g + h w`, so it is determined
+    /// by the torus coordinate `(1 + g) / h`; the identity, with `h = 0`,
+    /// is encoded as zero. Every element has exactly one encoding, so
+    /// stored values, e.g. a cached `e(alpha, beta)`, compare bytewise.
+    pub fn compress_canonical(&self) -> [u8; 192] {
+        let c = self.0.torus_compress().unwrap_or_else(fields::Fq6::zero);
+        let mut out = [0u8; 192];
+        let fq2s = [c.c0, c.c1, c.c2];
+        let fqs = fq2s.iter().flat_map(|c| [*c.real(), *c.imaginary()]);
+        for (chunk, fq) in out.chunks_mut(32).zip(fqs) {
+            Fq(fq).to_big_endian(chunk).expect("chunk is 32 bytes; qed");
+        }
+        out
+    }
+
+    /// Inverse of `compress_canonical`. Every `Fq6` element decompresses
+    /// to a unitary value, so the result is additionally checked with
+    /// `is_valid`.
+    pub fn decompress_canonical(bytes: &[u8]) -> Result<Gt, FieldError> {
+        if bytes.len() != 192 {
+            return Err(FieldError::input(Field::Gt, 192, bytes.len()));
+        }
+        let mut fqs = [fields::Fq::zero(); 6];
+        for (c, chunk) in fqs.iter_mut().zip(bytes.chunks(32)) {
+            *c = Fq::from_slice(chunk)?.0;
+        }
+        let c = fields::Fq6::new(
+            fields::Fq2::new(fqs[0], fqs[1]),
+            fields::Fq2::new(fqs[2], fqs[3]),
+            fields::Fq2::new(fqs[4], fqs[5]),
+        );
+        if c.is_zero() {
+            return Ok(Gt::one());
+        }
+        let gt = Gt(fields::Fq12::torus_decompress(c));
+        if !gt.is_valid() {
+            return Err(FieldError::NotMember);
+        }
+        Ok(gt)
+    }
+
+    /// Whether this is an r-th root of unity, i.e. an element of the
+    /// order-r subgroup of the cyclotomic subgroup that pairings map into.
+    /// Values from outside the crate should be checked before they are
+    /// multiplied into a pairing product.
+    pub fn is_valid(&self) -> bool {
+        self.0.pow(fields::Fr::modulus()) == fields::Fq12::one()
+    }
+}
+
+/// Prints the canonical coefficients in tower order as hexadecimal.
+impl core::fmt::Debug for Gt {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("Gt(")?;
+        for (i, c) in self.canonical_coeffs().iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "0x{:032x}{:032x}", c.0[1], c.0[0])?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Lexicographic over the canonical coefficients in tower order. The order
+/// carries no algebraic meaning but is stable across versions and backends.
+impl Ord for Gt {
+    fn cmp(&self, other: &Gt) -> core::cmp::Ordering {
+        self.canonical_coeffs().cmp(&other.canonical_coeffs())
+    }
+}
+
+impl PartialOrd for Gt {
+    fn partial_cmp(&self, other: &Gt) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl Mul<Gt> for Gt {
@@ -614,19 +1823,156 @@ pub fn pairing(p: G1, q: G2) -> Gt {
     Gt(groups::pairing(&p.0, &q.0))
 }
 
+/// The product of the pairings of `pairs`, for any number of pairs, with
+/// one final exponentiation. Pairs are folded 16 at a time through stack
+/// buffers by `pairing_batch_iter`.
 pub fn pairing_batch(pairs: &[(G1, G2)]) -> Gt {
+    pairing_batch_iter(pairs.iter().copied())
+}
+
+/// `pairing_batch` without the final exponentiation: the product of the
+/// Miller loops of `pairs`, skipping those with a point at infinity, for
+/// any number of pairs.
+///
+/// Lets a verifier fuse independent product checks under one final
+/// exponentiation: raise each accumulator to a random weight, multiply,
+/// and compare `final_exponentiation` of the product against one. Like
+/// `miller_loop_batch`, the result is not a canonical target group
+/// element; see `Gt`.
+pub fn pairing_batch_unreduced(pairs: &[(G1, G2)]) -> Gt {
+    miller_loop_batch_iter(pairs.iter().copied())
+}
+
+/// Whether `pairing_check_with` accepts pairs with a point at infinity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Degenerate {
+    Allow,
+    /// Fail the check if any pair contributes the identity, including when
+    /// there are no pairs at all.
+    Reject,
+}
+
+/// Whether the product of the pairings of `pairs` is the identity. Up to
+/// 16 pairs go through one stack-allocated batch; longer inputs are
+/// folded 16 at a time by `miller_loop_batch_iter`. Cheaper than comparing
+/// the result of `pairing_batch`: pairs with a point at infinity are dropped
+/// before any line evaluation, and the final exponentiation is compared
+/// against one in Montgomery form without canonicalizing a `Gt`.
+pub fn pairing_check(pairs: &[(G1, G2)]) -> bool {
+    pairing_check_with(pairs, Degenerate::Allow)
+}
+
+/// `pairing_check` with explicit handling of degenerate pairs. With
+/// `Degenerate::Reject`, inputs at infinity cannot satisfy the check
+/// vacuously, which a verifier relying on every term would otherwise miss.
+pub fn pairing_check_with(pairs: &[(G1, G2)], degenerate: Degenerate) -> bool {
+    if degenerate == Degenerate::Reject
+        && (pairs.is_empty() || pairs.iter().any(|(p, q)| p.is_zero() || q.is_zero()))
+    {
+        return false;
+    }
+    if pairs.len() > 16 {
+        return miller_loop_batch_iter(pairs.iter().copied()).final_exponentiation()
+            == Some(Gt::one());
+    }
     let mut ps = [groups::G1::default(); 16];
     let mut qs = [groups::G2::default(); 16];
     for (i, (p, q)) in pairs.iter().enumerate() {
         ps[i] = p.0;
         qs[i] = q.0;
     }
-    Gt(groups::pairing_batch(
-        &ps[0..pairs.len()],
-        &qs[0..pairs.len()],
-    ))
+    groups::pairing_check_n::<16>(&ps[0..pairs.len()], &qs[0..pairs.len()])
+}
+
+/// `pairing_batch` over a `heapless::Vec`. Like `pairing_batch`, the
+/// pairs are folded 16 at a time, so the stack use does not grow with `N`.
+#[cfg(feature = "heapless")]
+pub fn pairing_batch_heapless<const N: usize>(pairs: &heapless::Vec<(G1, G2), N>) -> Gt {
+    pairing_batch_iter(pairs.iter().copied())
+}
+
+/// `pairing_check` over a `heapless::Vec`, folding the pairs 16 at a
+/// time.
+#[cfg(feature = "heapless")]
+pub fn pairing_check_heapless<const N: usize>(pairs: &heapless::Vec<(G1, G2), N>) -> bool {
+    miller_loop_batch_iter(pairs.iter().copied()).final_exponentiation() == Some(Gt::one())
+}
+
+/// `pairing_batch` over pairs produced lazily, e.g. by a decoder, without
+/// buffering them first. Pairs are taken 16 at a time into stack buffers,
+/// so there is no limit on their number.
+pub fn pairing_batch_iter(pairs: impl Iterator<Item = (G1, G2)>) -> Gt {
+    miller_loop_batch_iter(pairs)
+        .final_exponentiation()
+        .expect("miller loop cannot produce zero")
+}
+
+/// `pairing_batch_unreduced` counterpart of `pairing_batch_iter`: the
+/// product of the Miller loops, skipping pairs with a point at infinity.
+pub fn miller_loop_batch_iter(mut pairs: impl Iterator<Item = (G1, G2)>) -> Gt {
+    let mut f = Gt::one();
+    loop {
+        let mut ps = [groups::G1::default(); 16];
+        let mut qs = [groups::G2::default(); 16];
+        let mut n = 0;
+        for (p, q) in pairs.by_ref().take(16) {
+            ps[n] = p.0;
+            qs[n] = q.0;
+            n += 1;
+        }
+        if n == 0 {
+            return f;
+        }
+        f *= Gt(groups::miller_loop_batch_n::<16>(&ps[..n], &qs[..n]));
+    }
+}
+
+/// Checks several independent pairing equations, each a list of pairs
+/// whose pairings should multiply to one, with a single combined Miller
+/// loop and final exponentiation. Equation `i` is raised to a random
+/// `r_i` from `rng` by scaling its G1 points, so a false equation passes
+/// with probability about `1/r`. Verifying N Groth16 proofs this way costs
+/// one final exponentiation instead of N.
+pub fn batch_verify_pairing_equations<E: EntropySource + ?Sized>(
+    equations: &[&[(G1, G2)]],
+    rng: &mut E,
+) -> bool {
+    let pairs = equations.iter().flat_map(|eq| {
+        let r = Fr::random(rng);
+        eq.iter().map(move |(p, q)| (*p * r, *q))
+    });
+    miller_loop_batch_iter(pairs).final_exponentiation() == Some(Gt::one())
+}
+
+/// `pairing_batch` with the scratch space on the heap, so all pairs go
+/// through one batch.
+#[cfg(feature = "alloc")]
+pub fn pairing_batch_vec(pairs: &[(G1, G2)]) -> Gt {
+    let (ps, qs): (alloc::vec::Vec<_>, alloc::vec::Vec<_>) =
+        pairs.iter().map(|(p, q)| (p.0, q.0)).unzip();
+    Gt(groups::miller_loop_batch_vec(&ps, &qs)
+        .final_exponentiation()
+        .expect("miller loop cannot produce zero"))
+}
+
+/// `pairing_check` for any number of pairs, with the scratch space on the
+/// heap.
+#[cfg(feature = "alloc")]
+pub fn pairing_check_vec(pairs: &[(G1, G2)]) -> bool {
+    let (ps, qs): (alloc::vec::Vec<_>, alloc::vec::Vec<_>) =
+        pairs.iter().map(|(p, q)| (p.0, q.0)).unzip();
+    groups::pairing_check_vec(&ps, &qs)
 }
 
+/// The Miller loop of a single pair with a prepared G2 point, without the
+/// final exponentiation. Multiply such values and pass the product to
+/// `Gt::final_exponentiation` to evaluate a product of pairings.
+pub fn miller_loop(p: &AffineG1, q: &G2Prepared) -> Gt {
+    Gt(q.0.miller_loop(&p.0))
+}
+
+/// Runs the Miller loop over all pairs without the final exponentiation.
+/// The result is not a canonical target group element; see `Gt`.
 pub fn miller_loop_batch(pairs: &[(G2, G1)]) -> Result<Gt, CurveError> {
     let mut ps = [groups::G2Precomp::default(); 16];
     let mut qs = [groups::AffineG::<groups::G1Params>::default(); 16];
@@ -649,6 +1995,23 @@ impl AffineG2 {
         Ok(AffineG2(groups::AffineG2::new(x.0, y.0)?))
     }
 
+    /// The generator in affine coordinates, equal to `G2::one()`.
+    pub fn generator() -> Self {
+        AffineG2(groups::AffineG2::generator())
+    }
+
+    /// The point on `y^2 = x^3 + 3` over Fq12 that this point of the
+    /// sextic twist corresponds to.
+    pub fn untwist(&self) -> (raw::Fq12, raw::Fq12) {
+        self.0.untwist()
+    }
+
+    /// Inverse of `untwist`; `None` if `(x, y)` is not the image of a G2
+    /// point.
+    pub fn twist(x: &raw::Fq12, y: &raw::Fq12) -> Option<Self> {
+        groups::AffineG2::twist(x, y).map(AffineG2)
+    }
+
     pub fn x(&self) -> Fq2 {
         Fq2(self.0.x().clone())
     }
@@ -668,6 +2031,65 @@ impl AffineG2 {
     pub fn from_jacobian(g2: G2) -> Option<Self> {
         g2.0.to_affine().map(|x| AffineG2(x))
     }
+
+    /// Inverse of `G2::from_compressed`: lexicographic sign of y in the
+    /// prefix.
+    pub fn to_compressed(&self) -> [u8; 65] {
+        self.to_compressed_with(YSign::Lexicographic)
+    }
+
+    /// `prefix || x` with `x` in the `Fq2::to_slice` format and the prefix
+    /// 0x0a or 0x0b carrying the sign of y under `sign`.
+    pub fn to_compressed_with(&self, sign: YSign) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[0] = if sign.of_fq2(self.y()) { 11 } else { 10 };
+        bytes[1..].copy_from_slice(&self.x().to_slice());
+        bytes
+    }
+
+    /// `x || y`, each in the `Fq2::to_little_endian` format: the arkworks
+    /// uncompressed encoding of a finite point.
+    pub fn to_le_bytes(&self) -> [u8; 128] {
+        let mut bytes = [0u8; 128];
+        self.x()
+            .to_little_endian(&mut bytes[..64])
+            .expect("slice is 64 bytes; qed");
+        self.y()
+            .to_little_endian(&mut bytes[64..])
+            .expect("slice is 64 bytes; qed");
+        bytes
+    }
+
+    /// Inverse of `to_le_bytes`, with the checks of `AffineG2::new`.
+    pub fn from_le_slice(bytes: &[u8]) -> Result<Self, CurveError> {
+        if bytes.len() != 128 {
+            return Err(CurveError::InvalidLength {
+                expected: 128,
+                actual: bytes.len(),
+            });
+        }
+        let x = Fq2::from_le_slice(&bytes[..64]).map_err(|e| CurveError::fq2(false, 0, e))?;
+        let y = Fq2::from_le_slice(&bytes[64..]).map_err(|e| CurveError::fq2(true, 64, e))?;
+        AffineG2::new(x, y).map_err(|_| CurveError::NotMember)
+    }
+}
+
+/// A G2 point with its Miller loop line coefficients precomputed, for
+/// fixed points (verifying keys, committee keys) paired many times.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct G2Prepared(groups::G2Precomp);
+
+impl G2Prepared {
+    /// Memory taken by a prepared point.
+    pub const TABLE_BYTES: usize = core::mem::size_of::<groups::G2Precomp>();
+}
+
+impl Hash for AffineG2 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x().hash(state);
+        self.y().hash(state);
+    }
 }
 
 impl From<AffineG2> for G2 {
@@ -675,3 +2097,6 @@ impl From<AffineG2> for G2 {
         G2(affine.0.to_jacobian())
     }
 }
+
+#[cfg(all(feature = "backend-compare", target_arch = "riscv64"))]
+pub mod backend;
@@ -0,0 +1,223 @@
+//! Compound assignment operators, operators taking the right-hand side by
+//! reference, and iterator folds of the public types. The field types
+//! update their limbs in place; the group and target group types fall back
+//! to the binary operator.
+
+use crate::{AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+macro_rules! in_place {
+    ($t:ident, $rhs:ident, $($tr:ident, $method:ident, $op:tt);*) => {
+        $(
+            impl $tr<$rhs> for $t {
+                #[inline]
+                fn $method(&mut self, other: $rhs) {
+                    self.0 $op other.0;
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! by_value {
+    ($t:ident, $rhs:ident, $($tr:ident, $method:ident, $op:tt);*) => {
+        $(
+            impl $tr<$rhs> for $t {
+                #[inline]
+                fn $method(&mut self, other: $rhs) {
+                    *self = *self $op other;
+                }
+            }
+        )*
+    };
+}
+
+in_place!(Fr, Fr, AddAssign, add_assign, +=; SubAssign, sub_assign, -=; MulAssign, mul_assign, *=);
+in_place!(Fq, Fq, AddAssign, add_assign, +=; SubAssign, sub_assign, -=; MulAssign, mul_assign, *=);
+in_place!(Fq2, Fq2, AddAssign, add_assign, +=; SubAssign, sub_assign, -=; MulAssign, mul_assign, *=);
+
+by_value!(G1, G1, AddAssign, add_assign, +; SubAssign, sub_assign, -);
+by_value!(G1, AffineG1, AddAssign, add_assign, +; SubAssign, sub_assign, -);
+by_value!(G1, Fr, MulAssign, mul_assign, *);
+by_value!(G2, G2, AddAssign, add_assign, +; SubAssign, sub_assign, -);
+by_value!(G2, AffineG2, AddAssign, add_assign, +; SubAssign, sub_assign, -);
+by_value!(G2, Fr, MulAssign, mul_assign, *);
+by_value!(Gt, Gt, MulAssign, mul_assign, *);
+
+/// `$tr<&$rhs>` and `$assign<&$rhs>` for `$t`, copying the operand.
+macro_rules! by_ref {
+    ($t:ident, $rhs:ident, $($tr:ident, $method:ident, $assign:ident, $assign_method:ident);*) => {
+        $(
+            impl<'a> $tr<&'a $rhs> for $t {
+                type Output = $t;
+
+                #[inline]
+                fn $method(self, other: &'a $rhs) -> $t {
+                    $tr::$method(self, *other)
+                }
+            }
+
+            impl<'a> $assign<&'a $rhs> for $t {
+                #[inline]
+                fn $assign_method(&mut self, other: &'a $rhs) {
+                    $assign::$assign_method(self, *other);
+                }
+            }
+        )*
+    };
+}
+
+by_ref!(Fr, Fr, Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+by_ref!(Fq, Fq, Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+by_ref!(Fq2, Fq2, Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign; Mul, mul, MulAssign, mul_assign);
+
+by_ref!(G1, G1, Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign);
+by_ref!(G1, AffineG1, Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign);
+by_ref!(G1, Fr, Mul, mul, MulAssign, mul_assign);
+by_ref!(G2, G2, Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign);
+by_ref!(G2, AffineG2, Add, add, AddAssign, add_assign; Sub, sub, SubAssign, sub_assign);
+by_ref!(G2, Fr, Mul, mul, MulAssign, mul_assign);
+by_ref!(Gt, Gt, Mul, mul, MulAssign, mul_assign);
+
+macro_rules! fold {
+    ($t:ident, $tr:ident, $method:ident, $init:expr, $op:tt) => {
+        impl $tr for $t {
+            fn $method<I: Iterator<Item = $t>>(iter: I) -> $t {
+                iter.fold($init, |acc, x| acc $op x)
+            }
+        }
+
+        impl<'a> $tr<&'a $t> for $t {
+            fn $method<I: Iterator<Item = &'a $t>>(iter: I) -> $t {
+                iter.fold($init, |acc, x| acc $op *x)
+            }
+        }
+    };
+}
+
+fold!(Fr, Sum, sum, Fr::zero(), +);
+fold!(Fq, Sum, sum, Fq::zero(), +);
+fold!(G1, Sum, sum, G1::zero(), +);
+fold!(G2, Sum, sum, G2::zero(), +);
+fold!(Fr, Product, product, Fr::one(), *);
+fold!(Fq, Product, product, Fq::one(), *);
+fold!(Gt, Product, product, Gt::one(), *);
+
+#[test]
+fn assign_matches_binary() {
+    let a = Fr::from_str("1234567").unwrap();
+    let b = -Fr::from_str("89").unwrap();
+    let mut x = a;
+    x += b;
+    x *= a;
+    x -= b;
+    assert_eq!(x, (a + b) * a - b);
+
+    let (c, d) = (Fq2::i() + Fq2::one(), Fq2::i());
+    let mut y = c;
+    y *= d;
+    y -= c;
+    y += d;
+    assert!(y == c * d - c + d);
+
+    let mut p = G1::one();
+    p += G1::one();
+    p *= a;
+    p -= AffineG1::generator();
+    assert_eq!(p, (G1::one() + G1::one()) * a - G1::one());
+
+    let mut q = G2::one();
+    q -= G2::one() * b;
+    q += AffineG2::generator();
+    assert!(q == G2::one() - G2::one() * b + G2::one());
+
+    let g = crate::pairing(G1::one(), G2::one());
+    let mut h = g;
+    h *= g;
+    assert_eq!(h, g.pow(Fr::from_str("2").unwrap()));
+}
+
+#[test]
+fn folds() {
+    let xs = ["2", "3", "5", "7"].map(|i| Fr::from_str(i).unwrap());
+    assert_eq!(xs.iter().sum::<Fr>(), Fr::from_str("17").unwrap());
+    assert_eq!(
+        xs.iter().copied().product::<Fr>(),
+        Fr::from_str("210").unwrap()
+    );
+    assert_eq!(core::iter::empty::<Fq>().product::<Fq>(), Fq::one());
+
+    let points = xs.map(|x| G1::one() * x);
+    assert_eq!(
+        points.iter().copied().sum::<G1>(),
+        G1::one() * xs.iter().sum::<Fr>()
+    );
+    assert!(
+        xs.iter().map(|x| G2::one() * *x).sum::<G2>() == G2::one() * Fr::from_str("17").unwrap()
+    );
+
+    let g = crate::pairing(G1::one(), G2::one());
+    let gts = xs.map(|x| g.pow(x));
+    assert_eq!(
+        gts.iter().product::<Gt>(),
+        g.pow(Fr::from_str("17").unwrap())
+    );
+}
+
+#[test]
+fn small_integers() {
+    const TWO: Fr = Fr::from_raw([
+        0x592c68389ffffff6,
+        0x6df8ed2b3ec19a53,
+        0xccdd46def0f28c5c,
+        0x1c14ef83340fbe5e,
+    ]);
+    assert_eq!(TWO, Fr::one() + Fr::one());
+    assert_eq!(Fr::from(2u64), TWO);
+    assert_eq!(Fr::from(2u128), TWO);
+    assert_eq!(Fq::from(34u64), Fq::from_str("34").unwrap());
+
+    let max = Fr::from(u64::MAX);
+    assert_eq!(Fr::from(u128::MAX), max * (max + Fr::one()) + max);
+}
+
+#[test]
+fn ordering_and_hashing() {
+    use core::hash::{Hash, Hasher};
+
+    struct Fnv(u64);
+    impl Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+        fn write(&mut self, bytes: &[u8]) {
+            for b in bytes {
+                self.0 = (self.0 ^ *b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+    fn hash<T: Hash>(x: &T) -> u64 {
+        let mut h = Fnv(0xcbf29ce484222325);
+        x.hash(&mut h);
+        h.finish()
+    }
+
+    // the Montgomery forms of these are not in numeric order
+    let mut xs = [-Fr::one(), Fr::from(3u64), Fr::zero(), Fr::from(2u64)];
+    xs.sort_unstable();
+    assert_eq!(xs, [Fr::zero(), Fr::from(2u64), Fr::from(3u64), -Fr::one()]);
+    assert!(-Fq::one() > Fq::from(1u64 << 63));
+
+    assert_eq!(hash(&Fr::from(5u64)), hash(&Fr::from_str("5").unwrap()));
+    assert_ne!(hash(&Fq::from(5u64)), hash(&Fq::from(6u64)));
+    let p = AffineG1::from_jacobian(G1::one() * Fr::from(9u64)).unwrap();
+    let q = AffineG1::from_jacobian(G1::one() * Fr::from(3u64) * Fr::from(3u64)).unwrap();
+    assert_eq!(hash(&p), hash(&q));
+    let g2 = AffineG2::from_jacobian(G2::one()).unwrap();
+    assert_eq!(
+        hash(&g2),
+        hash(&AffineG2::from_jacobian(G2::one()).unwrap())
+    );
+    assert_ne!(hash(&g2.x()), hash(&g2.y()));
+}
@@ -0,0 +1,4035 @@
+//! The Poseidon hash of circomlib, over Fr: the permutation with the
+//! x^5 S-box, 8 full rounds and 57 or 60 partial rounds for widths 3 and
+//! 6, with the round constants and Cauchy MDS matrices of the reference
+//! Grain LFSR generator. The state starts as `[0, inputs..]` and the hash
+//! is its first element after one permutation.
+//!
+//! Only the two widths that circom protocols lean on are provided:
+//! `hash2` for Merkle trees and `hash5` for EdDSA message hashing.
+
+use crate::arith::U256;
+use crate::fields::const_fr;
+use crate::hasher::FieldHasher;
+use crate::Fr;
+
+const FULL_ROUNDS: usize = 8;
+
+const C3: [Fr; 195] = [
+    Fr(const_fr([
+        0x83bbbac36534a858,
+        0x0971619601250a07,
+        0x27a219050e23910c,
+        0x1638bf18c8507442,
+    ])),
+    Fr(const_fr([
+        0x5e2bb0e6bb25631a,
+        0x56b042b8def5875e,
+        0x01bc3e10a8e9b451,
+        0x17bfe16092ef1d7e,
+    ])),
+    Fr(const_fr([
+        0xa4fef9b954508d89,
+        0xafeb6b7d4ee4c69e,
+        0xd8ebe8a7fc301c39,
+        0x1840976e07ad780c,
+    ])),
+    Fr(const_fr([
+        0x18b8e553a04b72cb,
+        0x4d654fb8ec90df10,
+        0x3f8dafc39d1b9658,
+        0x1a72c6a50e4e6a8a,
+    ])),
+    Fr(const_fr([
+        0x44f18fdd12c7177a,
+        0x43d28a9b520bd0ee,
+        0x440b83130192f008,
+        0x13c53f3b277b2fde,
+    ])),
+    Fr(const_fr([
+        0xfc116b6c9e33b756,
+        0x933e67d042eb5320,
+        0xabc0d9f2c9d9bdcf,
+        0x23b0bd1fabcb64b0,
+    ])),
+    Fr(const_fr([
+        0xde8ebdf8535243af,
+        0x1e851377b3d81387,
+        0xdfa4b107ef127fb9,
+        0x1e12da839a042314,
+    ])),
+    Fr(const_fr([
+        0xe25ff0a9dd264f6b,
+        0x0215e509d49d08b2,
+        0xc2cf093542425093,
+        0x0baaf53d85cb02e0,
+    ])),
+    Fr(const_fr([
+        0x6eaa462f90cf8115,
+        0xaf07e5d221dd2e13,
+        0x9a0b65abec88aa63,
+        0x1fcea175624b262f,
+    ])),
+    Fr(const_fr([
+        0x99aa70f04fe89c1e,
+        0xdc11129d80826695,
+        0x1a68006fcc353aee,
+        0x299ea7eabe5d4996,
+    ])),
+    Fr(const_fr([
+        0x413df2c8c5541138,
+        0xeb469618c9de6bb3,
+        0x1a668e52b76fe275,
+        0x0fed7116657d8da5,
+    ])),
+    Fr(const_fr([
+        0x4c5cd4085911f6df,
+        0xa69c3b9c95ae98e6,
+        0xf308d88c93d19608,
+        0x079c79c9cffd5ad4,
+    ])),
+    Fr(const_fr([
+        0xdd5d50a6115b6ae6,
+        0x3e2079ce31994482,
+        0x0beac0a93142d7a6,
+        0x29838a07888323b4,
+    ])),
+    Fr(const_fr([
+        0x3031a7c21bf4f9f8,
+        0xf093c1cc653a991a,
+        0x092a0cd128aac7c2,
+        0x1e230e4aa7c4c266,
+    ])),
+    Fr(const_fr([
+        0x2a72b06d29407105,
+        0x281dbf7148ac7771,
+        0x175c5083bc22a44d,
+        0x08c912947cf53b92,
+    ])),
+    Fr(const_fr([
+        0x7eb180a889ee3c2d,
+        0xd38343a986d44c8c,
+        0x286bdbe0472b1d0e,
+        0x24f6be203117cc53,
+    ])),
+    Fr(const_fr([
+        0xe221229550b8eb66,
+        0x9ffe52fabfc796eb,
+        0x1b9eef2ed361ed14,
+        0x0c575104fa7034f4,
+    ])),
+    Fr(const_fr([
+        0xf9b28f842a2e4643,
+        0x4d5c080bd2ca1317,
+        0xcd76ae812c271eb9,
+        0x0ea4878f3b96e943,
+    ])),
+    Fr(const_fr([
+        0x1b12a0ead7ee00cd,
+        0x794461ec0d8acd32,
+        0x3abb3e24c75e20a7,
+        0x27cb85854f2c16db,
+    ])),
+    Fr(const_fr([
+        0xc34a0ce29e5c844a,
+        0xbae3900198f23f61,
+        0xf582e09985e6d0c4,
+        0x055c6d26a35cf2de,
+    ])),
+    Fr(const_fr([
+        0x33e1751f74f74c21,
+        0xdef83f5279f2ec1a,
+        0x3ce7d0bd374490ba,
+        0x0eecc29342805119,
+    ])),
+    Fr(const_fr([
+        0xe45772c6259d3e58,
+        0x4c683f93ebeab683,
+        0xfb8c13b607a4642e,
+        0x2e3e8ef03eeaed41,
+    ])),
+    Fr(const_fr([
+        0xd197c44c630c5eb4,
+        0x053857ab70186b79,
+        0x18ee44c7bdb0d26a,
+        0x18b94e2583a0557b,
+    ])),
+    Fr(const_fr([
+        0xd27736b000acdc77,
+        0x258b4b227cbcb7f5,
+        0x40df2e84269de616,
+        0x209aa4bbc966b500,
+    ])),
+    Fr(const_fr([
+        0x5aafe5dc0720254f,
+        0x0e91a580f18d1016,
+        0x11a00abff154d5b7,
+        0x28083140319b0d09,
+    ])),
+    Fr(const_fr([
+        0x624ed13efd7f2bca,
+        0xcc9e53bcf2c28b13,
+        0x7ca39c04d885ccfa,
+        0x032c7e9c1ed0d643,
+    ])),
+    Fr(const_fr([
+        0xbee575ac208c3258,
+        0x8976d09923790363,
+        0xeac3c4d39b58eaaf,
+        0x28585586ade6e3d5,
+    ])),
+    Fr(const_fr([
+        0x275e64927c987248,
+        0x1023453306381ce9,
+        0xd90511a7cb2159f6,
+        0x2997881a59a4151b,
+    ])),
+    Fr(const_fr([
+        0x96ea7b645b7ee005,
+        0x12e49ee4759a80d5,
+        0x3ff0710c0c7c209c,
+        0x1207d4f65a29a973,
+    ])),
+    Fr(const_fr([
+        0x225570d6a943095f,
+        0xa11ab5832d54aa69,
+        0x5ed544b9dc72feac,
+        0x16ba83b29fa0dfde,
+    ])),
+    Fr(const_fr([
+        0xd35fe784498f8347,
+        0x448e2c74dc5c310b,
+        0x8bc35d4158df81d3,
+        0x11a01d27b058a3b8,
+    ])),
+    Fr(const_fr([
+        0xcf4dac5594af2d83,
+        0xc6f6531b8cc21243,
+        0x6e65adc157b9548f,
+        0x082a1067f00d6dfb,
+    ])),
+    Fr(const_fr([
+        0x173fc30b103a6dcf,
+        0xc91b63e498d301c3,
+        0xb8f7ea4064eea1f2,
+        0x06548827d7df35e4,
+    ])),
+    Fr(const_fr([
+        0xf1dcdd1866e91665,
+        0xaf44edb31bfcc76e,
+        0x69a2f851bc5fdeb1,
+        0x16a3add7fd6847e5,
+    ])),
+    Fr(const_fr([
+        0x277fa19569af0ab3,
+        0x28e4a94421550cd3,
+        0x8b1893dc1510def2,
+        0x152b3eebf2e3b9af,
+    ])),
+    Fr(const_fr([
+        0x82a43eaade461743,
+        0x4bd307501a719bfd,
+        0x94e914f0f35fb5ea,
+        0x2d45e6c07093f3ec,
+    ])),
+    Fr(const_fr([
+        0x1307c7dfcdec9d68,
+        0x569ed7de9138f494,
+        0x3345109c6374a0b6,
+        0x21b5a1904b9d9fa1,
+    ])),
+    Fr(const_fr([
+        0xeb775b5909a98dc7,
+        0x54a57335128a23bf,
+        0x5b11e52888015162,
+        0x212a6cfdd608fde8,
+    ])),
+    Fr(const_fr([
+        0x4c04265f18d146f1,
+        0x10d10bc4c7853d4e,
+        0xa8e2a51db14f2f2c,
+        0x0f5fd95645106055,
+    ])),
+    Fr(const_fr([
+        0x68dd43d7b51c37dd,
+        0x1e46476089e1d073,
+        0x58e04cf38f805754,
+        0x0b0bbf5b8688b08b,
+    ])),
+    Fr(const_fr([
+        0x1b14f57c4122a309,
+        0xe02913572e132548,
+        0x8d3c17048b7c32db,
+        0x22434cd0616dbf37,
+    ])),
+    Fr(const_fr([
+        0xa0a5d482a6154db8,
+        0xd1c8cb01ba422f45,
+        0xb8a57919e05a3d45,
+        0x285a24a238af96cb,
+    ])),
+    Fr(const_fr([
+        0x1aaa1405fafbd364,
+        0xb8d8b685230034b7,
+        0x0b7aef992fcc0854,
+        0x28d85f4b9f36af66,
+    ])),
+    Fr(const_fr([
+        0x21fc148d2efde136,
+        0xc0a3008d29eeb0a8,
+        0xf3914ca16745a3c9,
+        0x0856523236dd2341,
+    ])),
+    Fr(const_fr([
+        0xa14a336112fbf9f3,
+        0xb1ff289ba2eae970,
+        0x32a2feccaf57f78d,
+        0x003726ecc0267f6c,
+    ])),
+    Fr(const_fr([
+        0x65e4051e623a8231,
+        0x3de6d47d48c406d3,
+        0xa9bbd406bdb8ead2,
+        0x0d600d159a165a0c,
+    ])),
+    Fr(const_fr([
+        0x50648feb424d5e04,
+        0x78694de6ef267969,
+        0x5aa8b3580718f950,
+        0x1b044d4e05de9b70,
+    ])),
+    Fr(const_fr([
+        0x2a796e5ea8f4e119,
+        0x946bbb44d739483a,
+        0xe21604d22b7ff1bb,
+        0x0c4327027869e663,
+    ])),
+    Fr(const_fr([
+        0xe85e117615b09542,
+        0x96ce582e170967b3,
+        0x065b39840af36d54,
+        0x11b11f0e477f05b0,
+    ])),
+    Fr(const_fr([
+        0x0d74f0994cb254bf,
+        0x4f4e4871fc63bd6d,
+        0x89d7b727ebdad559,
+        0x2cf20283390eb822,
+    ])),
+    Fr(const_fr([
+        0xa30b526823bdb058,
+        0x886bb7b6f8eccd0d,
+        0x1f437e6dbc295914,
+        0x29ad2879e6833325,
+    ])),
+    Fr(const_fr([
+        0xec3b39410fe43a5d,
+        0x3fb9d90ddcfa176a,
+        0x5fcaa229a47556d0,
+        0x07a583162e51b6d8,
+    ])),
+    Fr(const_fr([
+        0x5b3fe2630608396e,
+        0x1e4496112e954403,
+        0x855a9daaa37c42a9,
+        0x10f643f4b4fa5128,
+    ])),
+    Fr(const_fr([
+        0x28590d0004f98e35,
+        0xe13a159fc2cc8cf6,
+        0x8d22be85be68317a,
+        0x085a637b8618db96,
+    ])),
+    Fr(const_fr([
+        0x5588c6954188d4d5,
+        0x28bdcaed360f0373,
+        0x67cc7a411974cf44,
+        0x27ab756e2cc849ac,
+    ])),
+    Fr(const_fr([
+        0x32a0c18f088e99ad,
+        0x22795c50dd9cc1f3,
+        0x39c0855c6dc26a51,
+        0x12388feb2e428a82,
+    ])),
+    Fr(const_fr([
+        0xaddbd56d19cb859c,
+        0x2f7ceda49032fb62,
+        0xebdf3719874c459e,
+        0x036d7aa8bc44149d,
+    ])),
+    Fr(const_fr([
+        0x2caa41d785b941c8,
+        0x971e4e4c154f2c6b,
+        0x7874a8d468e0e6c8,
+        0x07b4735d54f5d234,
+    ])),
+    Fr(const_fr([
+        0x01f51a3aa044c8f2,
+        0x7a636b4bfcab2c29,
+        0xb31eb377a96a00ce,
+        0x0f0c53f00bb104ac,
+    ])),
+    Fr(const_fr([
+        0xd79328003e9566e7,
+        0xed5f36c07d3074ba,
+        0x8a2da6c60a9e4cb8,
+        0x28bd60c78f4c27c2,
+    ])),
+    Fr(const_fr([
+        0xfffebd8720631bbc,
+        0x8efe2a4219d8aba5,
+        0x6bdf4ded7ef1b62d,
+        0x1ff6c9e06d6cf68e,
+    ])),
+    Fr(const_fr([
+        0x22a696d457b8106d,
+        0x8b98ffed5d4905f8,
+        0x5edf9329bbef0232,
+        0x1392824c46ea4c39,
+    ])),
+    Fr(const_fr([
+        0xab107cfef787c8a0,
+        0x278e7c4207fb3230,
+        0x99b9ea6ce46d8256,
+        0x0d682fd0fb31a00e,
+    ])),
+    Fr(const_fr([
+        0x4ecef90fb11c850c,
+        0x84e45781c9eec3a9,
+        0x8ec25128e2f1f4f8,
+        0x2a2c1a8412a70cce,
+    ])),
+    Fr(const_fr([
+        0x8f369a4d332e2cc7,
+        0xb22c513234251590,
+        0x706ac3aeb811231f,
+        0x19740e5e8ade8979,
+    ])),
+    Fr(const_fr([
+        0x221080f029f28b75,
+        0xa437450974cb1e94,
+        0x87926f33c69f3da2,
+        0x029cf616ee2ad166,
+    ])),
+    Fr(const_fr([
+        0x02442c4fee11e91d,
+        0x5dc410e07fdcbe17,
+        0x1a867232b63d9457,
+        0x1a1c9a07b2782748,
+    ])),
+    Fr(const_fr([
+        0x1edb82dae474f114,
+        0xd3bc7df0735a0a00,
+        0x03b3817d280da899,
+        0x0701ed4f517f4c79,
+    ])),
+    Fr(const_fr([
+        0xe14d649a6bf08497,
+        0xa3ea3ec2fba171b1,
+        0x8f61083e65c736bd,
+        0x1ac1a9fdf38c7ebb,
+    ])),
+    Fr(const_fr([
+        0xcf49198274ef25d7,
+        0x562c05e01036644e,
+        0x3f41976e276e258c,
+        0x1f5be530194ef994,
+    ])),
+    Fr(const_fr([
+        0x82c0529d3b0c6560,
+        0xce8537e7cb3d788c,
+        0x92494f9d3bd8e8bc,
+        0x132c81f43fd33e53,
+    ])),
+    Fr(const_fr([
+        0x3f40cc1629cd3111,
+        0xb9f9665b8d257da8,
+        0x185f61f8e8e16198,
+        0x021adba7bd33ab21,
+    ])),
+    Fr(const_fr([
+        0x342f2a113fd36130,
+        0x9ceccbd5349c530a,
+        0x617bd57533444841,
+        0x1648cdf733eee1d3,
+    ])),
+    Fr(const_fr([
+        0x9efbd552827fcf73,
+        0xfaebeb1c6ab69375,
+        0x464172e7be8e88a8,
+        0x0847931e0d042c0f,
+    ])),
+    Fr(const_fr([
+        0x120193648a28dc6c,
+        0x988a6302e7370526,
+        0xa78edb0d97474b0c,
+        0x19959641a1b547fa,
+    ])),
+    Fr(const_fr([
+        0x8e6dfb112427c2d2,
+        0x20c25819e588e384,
+        0x1d88377d30897cb6,
+        0x2a508af373b9729a,
+    ])),
+    Fr(const_fr([
+        0x1d14c873ddc51680,
+        0x0e0da86908009656,
+        0xbf4ec9ba1340d6a4,
+        0x0098bdf65a0afb9c,
+    ])),
+    Fr(const_fr([
+        0x50d73614f3243e60,
+        0x45209e69e50c537e,
+        0xbf44d99fc759be13,
+        0x279f34df0724a583,
+    ])),
+    Fr(const_fr([
+        0x219cffe63c3aa682,
+        0xa6dd59c7425acf48,
+        0x444cb706acccec8f,
+        0x2e8c17deff5b9dd4,
+    ])),
+    Fr(const_fr([
+        0x9997c91436bf5d0b,
+        0x1629fb48677678be,
+        0x49c4d97bdb9eb29a,
+        0x2035dd09daa0d61e,
+    ])),
+    Fr(const_fr([
+        0x10168e7c8e51546b,
+        0x507ec1516ab22d0c,
+        0x1a194fcaa4bf09c0,
+        0x0c637dc86849f4fd,
+    ])),
+    Fr(const_fr([
+        0x57fe04691ea6f531,
+        0x2190682e353d037b,
+        0x72e47ef9e904d3b9,
+        0x211f475dfb3c75ed,
+    ])),
+    Fr(const_fr([
+        0x7e838b86503617d0,
+        0xe6bad1948ae1955e,
+        0x707e58fad5b30483,
+        0x0a0972982b764b31,
+    ])),
+    Fr(const_fr([
+        0x03bb8a08fc34185c,
+        0x7697ad2d4d810bfd,
+        0xfd12136fc816f501,
+        0x120d076c88279b56,
+    ])),
+    Fr(const_fr([
+        0xee24105eeb22800c,
+        0xb7cbe7af82283d5e,
+        0x6191e1e38cd71bb5,
+        0x1aeaf2e491efd9a9,
+    ])),
+    Fr(const_fr([
+        0xa04e07018cc9237a,
+        0x5361733b3151e0b6,
+        0x40e61ee38ddf97c3,
+        0x227af916f8b30fd4,
+    ])),
+    Fr(const_fr([
+        0x3a89c2121dbc8729,
+        0xbc8fbdb3d8db1a87,
+        0xe86e48af8d840b86,
+        0x2ce7a4cb0acf31c8,
+    ])),
+    Fr(const_fr([
+        0x5034a090dc3af464,
+        0x6ed46f82617008aa,
+        0x358e71ffd819f7e3,
+        0x26c8080ae116e13b,
+    ])),
+    Fr(const_fr([
+        0x788952cfb8b52e37,
+        0xefc72a5be7fb7fbc,
+        0xc77bac4f9459447a,
+        0x001040af8a6d261e,
+    ])),
+    Fr(const_fr([
+        0x16e6d600d2ff7426,
+        0xca5f5816acfc9401,
+        0xe38beefe9a0e0072,
+        0x21938b191a7bc331,
+    ])),
+    Fr(const_fr([
+        0xd5b44d21a21f9297,
+        0x86167973aa7d0743,
+        0x79bd6f4550d4e54f,
+        0x03cfdb40ac6940ca,
+    ])),
+    Fr(const_fr([
+        0x9c929ba1d69d55fc,
+        0x87d64dfc151271ae,
+        0x2d27e3179fac9536,
+        0x1a90b4df9101e879,
+    ])),
+    Fr(const_fr([
+        0x7499664fc0ebe7ec,
+        0x09716f8e84931303,
+        0x4cc0dccb94634b45,
+        0x1a32f5ff8519e33c,
+    ])),
+    Fr(const_fr([
+        0x870877b73b9bcaa9,
+        0x1881870f75379e4f,
+        0x9f3f06e451a7e672,
+        0x1151fe3bb41dcb29,
+    ])),
+    Fr(const_fr([
+        0x0b7cb1a81cac0430,
+        0x271f481c441f88f9,
+        0xeda16949984a6294,
+        0x073cc8fe043bb91c,
+    ])),
+    Fr(const_fr([
+        0x5d4c4bfe348cbfe4,
+        0xcfd654c4476c9489,
+        0xdd48f53cf0b1efcd,
+        0x06c975ea69621011,
+    ])),
+    Fr(const_fr([
+        0x39fb6c74184c9024,
+        0x80e79b39a143fe21,
+        0xb1253d509d48cbfc,
+        0x1a4900be8c52124d,
+    ])),
+    Fr(const_fr([
+        0x190a59d14d51b5c0,
+        0xa444e21ad8bdb73a,
+        0xb2259807fe061798,
+        0x271a2e9bbfcf2f6a,
+    ])),
+    Fr(const_fr([
+        0xc0af13dc96417133,
+        0x1cb1c8979c88420d,
+        0x353f8e5673b9f841,
+        0x0ad2874818ce1183,
+    ])),
+    Fr(const_fr([
+        0x5eae10d5fd0f7d29,
+        0x7da0cc2beaa853e4,
+        0xb844b04ad2a3b6e2,
+        0x0b184f0b40c038ee,
+    ])),
+    Fr(const_fr([
+        0xe5e995b4b1d54591,
+        0x19548f28b12e5099,
+        0xe0da5976f40f1c72,
+        0x1c55359d2d014456,
+    ])),
+    Fr(const_fr([
+        0x68c411465f6f87d8,
+        0xa3a18882c980c957,
+        0x5c79518c4fabcf20,
+        0x26edc29497bc5cb3,
+    ])),
+    Fr(const_fr([
+        0xb50f63c3948b6732,
+        0x9c160bd71ae1dbb4,
+        0x24cfd9385abff66a,
+        0x02063a7ceced9ea6,
+    ])),
+    Fr(const_fr([
+        0xdb0cbc35d22245b0,
+        0xfe86319a55232e6d,
+        0x6105fbfdf3add538,
+        0x2c2dc27c38d94255,
+    ])),
+    Fr(const_fr([
+        0xf1895d988a8c8995,
+        0xdedefbad0c1b5a82,
+        0x5f3fb8bc0bd416d8,
+        0x0e2a4c7d21d78dc2,
+    ])),
+    Fr(const_fr([
+        0x72f59f40b87cd518,
+        0x619b20e02d8b3ee3,
+        0xd6ebb896f7d9329f,
+        0x211c1fdf6141dd3a,
+    ])),
+    Fr(const_fr([
+        0xd6e372c64f54c992,
+        0x99827b2bf785abe0,
+        0xb67492d94fbeae14,
+        0x0288174a915f1cb7,
+    ])),
+    Fr(const_fr([
+        0x0cee89601a4ad1c5,
+        0x66dee7acd570e8b4,
+        0x237331b25471fc2c,
+        0x0f79a3dc068020d0,
+    ])),
+    Fr(const_fr([
+        0x898fee0b8f31bc12,
+        0x5c8954aa2da1961c,
+        0x77326678c3da12af,
+        0x144313217a7f389f,
+    ])),
+    Fr(const_fr([
+        0x05f342cad3b4864a,
+        0xe2c7d39d0656a010,
+        0x61b8bef5da62a23f,
+        0x195806ad7b6696ef,
+    ])),
+    Fr(const_fr([
+        0x76c6fbf50cbdef02,
+        0xf535ea975a6b40f4,
+        0x0c5fe2383fe86bce,
+        0x117abeb34b8688b9,
+    ])),
+    Fr(const_fr([
+        0x722fcd9132e35924,
+        0x8d991c0f2fa40665,
+        0xab060365a33bc385,
+        0x0b61468d5f2060aa,
+    ])),
+    Fr(const_fr([
+        0xf37de77a8f1be22a,
+        0x427159a97577781e,
+        0x14e99813266148d9,
+        0x138e8d0f0e5be0c4,
+    ])),
+    Fr(const_fr([
+        0xe1543733c9bb6fc7,
+        0xd8c9704535e21c8c,
+        0xd433edc282de438c,
+        0x11466ae0f81b3763,
+    ])),
+    Fr(const_fr([
+        0x2cbd8cdb39524874,
+        0xf9855b644afce00e,
+        0xb290cb62abfbd66c,
+        0x25acd478b1084494,
+    ])),
+    Fr(const_fr([
+        0x8ebc6672b41dcee8,
+        0x606f0745849196a1,
+        0xe55ae090bd88d82a,
+        0x009470554167249d,
+    ])),
+    Fr(const_fr([
+        0x062094bcc7a6418b,
+        0x2803495b8583c637,
+        0x53eb48b97437c010,
+        0x0c7a1222e9138165,
+    ])),
+    Fr(const_fr([
+        0x27eab79c36018a4e,
+        0xbd1864c1d0c4068d,
+        0xf902113a25a237e3,
+        0x128d87abeb1e9c1f,
+    ])),
+    Fr(const_fr([
+        0xdb7fc417f7652d14,
+        0x58bd4f3aaac09e67,
+        0x728f58664d60e85c,
+        0x0f85a634b96fdda0,
+    ])),
+    Fr(const_fr([
+        0xe32fe000e302eadc,
+        0x7d23c3dd3c7df083,
+        0x5bd45107b443a012,
+        0x2acca27428d1a73c,
+    ])),
+    Fr(const_fr([
+        0xbf727ded10944264,
+        0x4ebf249fe7df1f42,
+        0x9ae7e1ea5448317c,
+        0x098478d1386f06d1,
+    ])),
+    Fr(const_fr([
+        0x41702faeb62c6d90,
+        0x5dea60565d31eda6,
+        0x99144c98f362ec45,
+        0x0666d88648f73f04,
+    ])),
+    Fr(const_fr([
+        0x83b3ca7e199d699c,
+        0x32ebc3b0c43d5f81,
+        0x14a61edd1a1d5f6b,
+        0x2cb5c8fea1103978,
+    ])),
+    Fr(const_fr([
+        0x5d05e3ae54a68bc4,
+        0x4faa9d463460a6f2,
+        0x7f3ed5cd074f3aaf,
+        0x2d280f20dae67de3,
+    ])),
+    Fr(const_fr([
+        0xd2efe4ca6dada240,
+        0x85c3424c91e6e450,
+        0x67ded928e819912f,
+        0x2a47110115ad187e,
+    ])),
+    Fr(const_fr([
+        0xfd689c88325914ba,
+        0x217ccba0c4f678a8,
+        0x775c3aa0caac332b,
+        0x0237eeffadb3dc0b,
+    ])),
+    Fr(const_fr([
+        0x951cba230d48ab5a,
+        0xac60fef56f7dcd80,
+        0xee0e118c667a107a,
+        0x286963de12abebeb,
+    ])),
+    Fr(const_fr([
+        0xd5bf474c37545102,
+        0x8df0210724a7bbf7,
+        0x2869e00fd06cee5c,
+        0x10a5a9a9e9679a80,
+    ])),
+    Fr(const_fr([
+        0xb756d548a7c1fc31,
+        0x91a8ff87ec937a72,
+        0xc052832f337e9900,
+        0x0529844a975226c0,
+    ])),
+    Fr(const_fr([
+        0xd6bd6219ea723ea7,
+        0xb15ef75a47a73dbc,
+        0xce0d24f3bcdba423,
+        0x00f2c9c00b75da95,
+    ])),
+    Fr(const_fr([
+        0x8839b8e43934561b,
+        0xeed5c479997614af,
+        0x8e13af4ce37ec975,
+        0x1c11b1381a0da7d1,
+    ])),
+    Fr(const_fr([
+        0xf38903221b52a70d,
+        0x57941bad5616f0b4,
+        0x79e0e60ac24d695a,
+        0x28196a6e4dc51202,
+    ])),
+    Fr(const_fr([
+        0x064ebe0c3dd24eb6,
+        0x3802fd84feb04f9d,
+        0x86b2d2b0907fa843,
+        0x2c574ff686c617aa,
+    ])),
+    Fr(const_fr([
+        0x4350bfc9d26b3c37,
+        0x21c07f7c582555a7,
+        0xc26f0a1a39e61d98,
+        0x23e04b5b20773885,
+    ])),
+    Fr(const_fr([
+        0x9ecdf0801c4960f1,
+        0x90455ea0aa5c29bd,
+        0x7a4f7128a78bbda6,
+        0x08a72f2200068a14,
+    ])),
+    Fr(const_fr([
+        0x8e78423055561239,
+        0xc68657f8b6a9b0c3,
+        0x3c1f30023e9a9859,
+        0x020a5307957c386b,
+    ])),
+    Fr(const_fr([
+        0xc2b2ec89df413870,
+        0x698417646a3cbfa5,
+        0x85b88d3108e46241,
+        0x1153cf64cd3c1731,
+    ])),
+    Fr(const_fr([
+        0xd3c742f41b1facfd,
+        0x48cc11f7ce5600d8,
+        0xd924a20425ad06ca,
+        0x2b92d896b64a36f0,
+    ])),
+    Fr(const_fr([
+        0x47e3641e16232e80,
+        0xb5b83d38f4118ce6,
+        0x9c757d818e9fe957,
+        0x0f040021849b01ec,
+    ])),
+    Fr(const_fr([
+        0xbf3cd76e39709602,
+        0xe2d93e6cf717615f,
+        0x4e5f8607aa4aa483,
+        0x1fcceb2264ae4e31,
+    ])),
+    Fr(const_fr([
+        0xcad7b25f437a38d9,
+        0xf66dcd4743bd617a,
+        0x75627910ca985f41,
+        0x1c2d962253a27827,
+    ])),
+    Fr(const_fr([
+        0x3eca0791994cce65,
+        0x059234b17e8476d9,
+        0x8403f0cd92adc262,
+        0x2116c5244c9f73b9,
+    ])),
+    Fr(const_fr([
+        0x7ebcbcd0ece065e3,
+        0xc1a06905a0139ff9,
+        0xf0e0a214eafcce74,
+        0x102221a24bb3f1ad,
+    ])),
+    Fr(const_fr([
+        0xc73946d2fd0793c6,
+        0xd684e968a7714ca9,
+        0x4516892656a0badd,
+        0x2bb1a1d769bcf7e8,
+    ])),
+    Fr(const_fr([
+        0x6b45148d7b52e3c9,
+        0x030bfb6edf8c0734,
+        0x78374b448edd38c4,
+        0x05ba2d1f46a5b689,
+    ])),
+    Fr(const_fr([
+        0x96a850062bca34d6,
+        0x1af76c923852014e,
+        0xad93ce5cfda5d027,
+        0x1c592caf1ab3d348,
+    ])),
+    Fr(const_fr([
+        0xafbb1743f0bb4932,
+        0x6a7696e10bda8c0c,
+        0x07a07831609bf58e,
+        0x2d35e80598271115,
+    ])),
+    Fr(const_fr([
+        0xdc24c33ffdafcabc,
+        0xf4dd4a38331852d4,
+        0x5ed1b79ac97e8d89,
+        0x07fcc7dca8d2fa83,
+    ])),
+    Fr(const_fr([
+        0xe41041004d1b83f0,
+        0x09933bdb3497d06a,
+        0xa3a268100bbdd441,
+        0x2dacd4f6ccf638f4,
+    ])),
+    Fr(const_fr([
+        0x41e818cce281e4e9,
+        0xdacb05da4c9f0b3c,
+        0x9c98e80797123b9e,
+        0x05c2785171cf9893,
+    ])),
+    Fr(const_fr([
+        0x8bb70713661dd3ef,
+        0xa3219b41e1e866e4,
+        0x4841aeae94dbafcb,
+        0x1f04ab075b343c2e,
+    ])),
+    Fr(const_fr([
+        0x09877b99da1ed4d8,
+        0x3e1b13bebb562ab7,
+        0xfd0a453abc796707,
+        0x1b08caf18fe542b6,
+    ])),
+    Fr(const_fr([
+        0x8772776c35ed4ddb,
+        0xd1e483c46dca8f20,
+        0x3d6ef5b0ece3cb77,
+        0x04350d60ac3cdeda,
+    ])),
+    Fr(const_fr([
+        0xd59789d52dfd828d,
+        0xd0d4f5c7c7c66b69,
+        0x4184bf91b5309734,
+        0x0e09dedd81137365,
+    ])),
+    Fr(const_fr([
+        0x3c46a5ad6730c5ce,
+        0x5c7bd37bda525007,
+        0xd57d1e170a707411,
+        0x13e189b521197bdd,
+    ])),
+    Fr(const_fr([
+        0xa6343ee1f304a26f,
+        0xca33c793d2ba7228,
+        0x25dad12500d4d448,
+        0x09cf827625852c46,
+    ])),
+    Fr(const_fr([
+        0x797a8602c63a54d9,
+        0x1ac2bc433aa0e827,
+        0x7c3fbe7f580b7789,
+        0x03174635b882d894,
+    ])),
+    Fr(const_fr([
+        0x4f16dab5318908d8,
+        0x618709ae9bb33e9b,
+        0x79f6ce9d1fcccdb9,
+        0x2a42c258663e5559,
+    ])),
+    Fr(const_fr([
+        0x9352c66f8db85bed,
+        0xf76a6cfc91216ae2,
+        0xecadbbeeab6ddeba,
+        0x0501860d019acbef,
+    ])),
+    Fr(const_fr([
+        0x33bbf7559b633bea,
+        0x59bc392c36c8c252,
+        0x13a0563e80b1aebc,
+        0x15fa97680663b761,
+    ])),
+    Fr(const_fr([
+        0xd34b76cc7ce56662,
+        0x4637562052f0c797,
+        0x121bc99292d6f09e,
+        0x0a694fce552ddc2d,
+    ])),
+    Fr(const_fr([
+        0x2c91b8df8e961db5,
+        0xe0847837f14b5eb8,
+        0xdfa9bfc0600b988a,
+        0x0e88c05fa2dd61ec,
+    ])),
+    Fr(const_fr([
+        0x062b0126d1594ed2,
+        0xf6b8ef949360c8f3,
+        0x88685c33681f9b19,
+        0x057ca2cedc6147f2,
+    ])),
+    Fr(const_fr([
+        0xbc4cb8237d1d6988,
+        0x40a88f0ac02628ed,
+        0x38d89898d686d929,
+        0x2333cd11b0515c31,
+    ])),
+    Fr(const_fr([
+        0xb242031f673208b5,
+        0x144ef3dff309ec58,
+        0x74d56678e4dc599f,
+        0x2ca99301804984ef,
+    ])),
+    Fr(const_fr([
+        0x5131dba233a4bed1,
+        0x8a8e1b36d8400bac,
+        0x21e59e8865901cd2,
+        0x0e5c8ec6f3fab0a5,
+    ])),
+    Fr(const_fr([
+        0x2a4bb5fd88edc047,
+        0x5b86f3ebc68d0c2a,
+        0x54882f2a5688b04f,
+        0x05a94e75507cbe33,
+    ])),
+    Fr(const_fr([
+        0x2b1caac2f8ecdb92,
+        0x1fc9ccb8dee0c00d,
+        0x132b98f93676a1fc,
+        0x26683390479088d2,
+    ])),
+    Fr(const_fr([
+        0x35366d726512589d,
+        0x1a3e6b999132d200,
+        0xde087c496782aa42,
+        0x19bd0fcb4d53dd00,
+    ])),
+    Fr(const_fr([
+        0x047cb750ec6c0762,
+        0x24d6a46e5dabb47b,
+        0xc919256c1ba14cab,
+        0x273396a78c4b62c4,
+    ])),
+    Fr(const_fr([
+        0xf0b98c2b57687d8e,
+        0x80147c912063365e,
+        0x59b461ea91ba1607,
+        0x28c3fc150c32ff50,
+    ])),
+    Fr(const_fr([
+        0xf5eccf1c3d434232,
+        0x473330d9dd3d1536,
+        0xade583c6c46d86b2,
+        0x2c598c961ec58e70,
+    ])),
+    Fr(const_fr([
+        0x03b800160def6797,
+        0xc4cf4ab9a943177e,
+        0xcde7b62ac1588f3c,
+        0x06eecbd051cf7c34,
+    ])),
+    Fr(const_fr([
+        0x0369b2583c6bd1fd,
+        0x0dbdc1a076d43177,
+        0xcb0dd179adcedd3d,
+        0x0bbe0370470abd29,
+    ])),
+    Fr(const_fr([
+        0x7cef46f570a500c7,
+        0xd5c89d2b7ae0671e,
+        0x32a472d2e25b5d5d,
+        0x1819574a6430cd10,
+    ])),
+    Fr(const_fr([
+        0x6f7de7d7f2113dc2,
+        0x9bad7676e26494cc,
+        0x59b77b7b4f7cb546,
+        0x2624ebdd096c1e37,
+    ])),
+    Fr(const_fr([
+        0x11a5cdddfca66b49,
+        0x768bcc294debebe3,
+        0x852080c87fbfda18,
+        0x1c7d91a619b633ee,
+    ])),
+    Fr(const_fr([
+        0xaae5e35d8145efad,
+        0xce1d50cae0bbe5f3,
+        0x36970cefb2134e9f,
+        0x1280d61543c46cfe,
+    ])),
+    Fr(const_fr([
+        0xbaaef2d36b5468c6,
+        0x498f7feb859e85a0,
+        0xfbbe4f4fef131eac,
+        0x0b321fd2bfd3b824,
+    ])),
+    Fr(const_fr([
+        0x73cf2dd7fb1b7a9a,
+        0xfa91136de4c46704,
+        0x7477293dd0a4ef08,
+        0x1b2e2f421e0c4760,
+    ])),
+    Fr(const_fr([
+        0xb4af5805db788bf2,
+        0x4c06654190ce9257,
+        0x3c0942be862b0413,
+        0x19358aa7ecb1ffdc,
+    ])),
+    Fr(const_fr([
+        0x1a5f2289be6e42d5,
+        0xb8558e89c40d8a7a,
+        0x6db403e0d417290a,
+        0x0df8c12d84359369,
+    ])),
+    Fr(const_fr([
+        0x2c1623378fa50e7b,
+        0x334709d38676a013,
+        0x424966dfeb1a23e8,
+        0x13f728b84aee75c8,
+    ])),
+    Fr(const_fr([
+        0x58cf088ebda25f34,
+        0x7a5d6a37c20a05fc,
+        0xc3e67e0e8ef2478f,
+        0x28cf15d813041154,
+    ])),
+    Fr(const_fr([
+        0xcd9b371660e26854,
+        0xa798e006bde7c2a5,
+        0xa589cbbf8f0cde4e,
+        0x0cc83461e6654fc6,
+    ])),
+    Fr(const_fr([
+        0x5aa5df881cf08b7a,
+        0x854536b686b10afa,
+        0x54fbdc7acbaf9ae0,
+        0x2267e5792b131883,
+    ])),
+    Fr(const_fr([
+        0x6800c90673d40313,
+        0x370cc2be065355bb,
+        0x48b361af98b894c4,
+        0x1574397ef2d5da4d,
+    ])),
+    Fr(const_fr([
+        0x980463e7193ce68c,
+        0xae87dc9d1a4abf45,
+        0x038a72f6f99cdb5e,
+        0x169da4f52a8f168c,
+    ])),
+    Fr(const_fr([
+        0xc837d64534683bb9,
+        0xa1c5ad4757898b12,
+        0xc4eb6597a3d9aa3f,
+        0x10551f64e930629a,
+    ])),
+    Fr(const_fr([
+        0xc276f1f23fa3dfda,
+        0x07be179d0f4e390f,
+        0xef2cc368f6a7932d,
+        0x169888fd256ea6c8,
+    ])),
+    Fr(const_fr([
+        0x0933f9e69fdb4920,
+        0x757798d2cb552ac2,
+        0x44617531aff8a6e8,
+        0x1c88dc66ae439dee,
+    ])),
+    Fr(const_fr([
+        0x84d6faa1219d011a,
+        0x6c621fc73f57d413,
+        0x06661776bd78cbf4,
+        0x0d40569eac57478d,
+    ])),
+    Fr(const_fr([
+        0x65a764cb33746490,
+        0xd857618b6d3d133b,
+        0x597d95e1bcb82d7c,
+        0x1999b22a5b250c0d,
+    ])),
+    Fr(const_fr([
+        0x09913f64ed0f55eb,
+        0x71f4b894b1d49b95,
+        0xe64f2d869782046b,
+        0x2ac51c6d3b362aaa,
+    ])),
+    Fr(const_fr([
+        0x4e1f1fb6f3864524,
+        0x183aa6e52734a295,
+        0x7a585dc7414a772d,
+        0x0e2e7f46b2e96900,
+    ])),
+];
+
+const M3: [[Fr; 3]; 3] = [
+    [
+        Fr(const_fr([
+            0xf2e8909a56fcf3d7,
+            0x8019ce3145ed8c1d,
+            0xdda896a228616418,
+            0x0e5ed723ffc885e1,
+        ])),
+        Fr(const_fr([
+            0x3158f311d66c0469,
+            0x9511d96f69f040a0,
+            0xbc6996e5b22127bf,
+            0x07e69e17a7c9122a,
+        ])),
+        Fr(const_fr([
+            0x28f45876169969b0,
+            0x3d6ded69e30a7649,
+            0x79aed6124c9b23dd,
+            0x03cf3048ffadf517,
+        ])),
+    ],
+    [
+        Fr(const_fr([
+            0x670d8bd946474dd5,
+            0x56daed800bf07bae,
+            0x5c98d51ecca20e6d,
+            0x1a3491eda18b0028,
+        ])),
+        Fr(const_fr([
+            0xf0193e572ba79c47,
+            0x5fb2e46a6ee2dac5,
+            0x6892f0d5b6ffb984,
+            0x0df1dabd49661413,
+        ])),
+        Fr(const_fr([
+            0x3293bffccaab272d,
+            0x85cbae38b11c4e1f,
+            0x67208956c8757b3c,
+            0x17ca537ab6c9d981,
+        ])),
+    ],
+    [
+        Fr(const_fr([
+            0xcc226561d2802757,
+            0xfcfbd22f5bb9f4ed,
+            0xc8ef58acce2b8678,
+            0x05984bb41bae9c88,
+        ])),
+        Fr(const_fr([
+            0x17561a5176bfeefd,
+            0x1cd5d7be100061af,
+            0x714cefb2dce7646c,
+            0x0043bf61f2173fe9,
+        ])),
+        Fr(const_fr([
+            0x4c72e3c51c729128,
+            0xd35b9fd9170d616c,
+            0x4d095dc74ab700a6,
+            0x1282bdf76dc5d39b,
+        ])),
+    ],
+];
+
+const C6: [Fr; 408] = [
+    Fr(const_fr([
+        0x3966746eb3b06f10,
+        0x67c6466fc0f39fd5,
+        0x3a03de41c85d5250,
+        0x0d857a86544d1217,
+    ])),
+    Fr(const_fr([
+        0xbc41a286da885fac,
+        0x7a38ffb857aba248,
+        0xb512f7291d795ad9,
+        0x10fb7fec7c13d076,
+    ])),
+    Fr(const_fr([
+        0x6d1d4964dea84ce0,
+        0x8d4a87dec2f648e3,
+        0xa40f4401414bfd32,
+        0x2dc186390ef4dbe9,
+    ])),
+    Fr(const_fr([
+        0x18a8327c512ca337,
+        0xb086d4a532ea851f,
+        0x9ccdfb6b34619d2f,
+        0x2e597226b701f891,
+    ])),
+    Fr(const_fr([
+        0x08bc1e357682efd7,
+        0x32233461245550ba,
+        0xaafed5b7566b525c,
+        0x1df63ae07aad0a7c,
+    ])),
+    Fr(const_fr([
+        0x7a670fa6bdd0b678,
+        0x47d788f24768646c,
+        0xd9afdd5fad8e8097,
+        0x16a41755958b58b4,
+    ])),
+    Fr(const_fr([
+        0xfa09f8fd4fec24b2,
+        0x7c86bf4afea6877e,
+        0xced393878bc5e9d5,
+        0x13b6ef9e4cf5c5eb,
+    ])),
+    Fr(const_fr([
+        0x14fc85d26b0c98d8,
+        0x9dfb5edb70d6ce88,
+        0xdfe52d5b1a8fde5e,
+        0x061561e143f89d53,
+    ])),
+    Fr(const_fr([
+        0xeeef2d6c9f6dd59a,
+        0x4d13164bc058d0d3,
+        0xe6d2d0921cb2d1f4,
+        0x04e0e31791feaeb1,
+    ])),
+    Fr(const_fr([
+        0x59e30550bffe6a58,
+        0xd550a71c9628c9b4,
+        0x3b965dd504b2002f,
+        0x143c1bf1ecd0981d,
+    ])),
+    Fr(const_fr([
+        0xc2470349d0ad2648,
+        0x7be94ea485ca5e3d,
+        0xcf87727fb96160dc,
+        0x21044b0fbfb309a0,
+    ])),
+    Fr(const_fr([
+        0x898b515a5242c73e,
+        0xbc2b51061fcf8bc3,
+        0x973294b5cbe8bf0f,
+        0x276d0e6ece729a62,
+    ])),
+    Fr(const_fr([
+        0x2b54a5be69a29b1a,
+        0x28d2518158ea41fc,
+        0x34108a13f040c523,
+        0x21b88876e30bfdeb,
+    ])),
+    Fr(const_fr([
+        0x49e60f91c94e64d2,
+        0x2f5eb5f89547ba60,
+        0xe3b288a6c1316752,
+        0x11aa0660f8898ae6,
+    ])),
+    Fr(const_fr([
+        0xd10525422c953f27,
+        0x93a1222500746945,
+        0xacf92f6e40837dba,
+        0x2844326b34953a5a,
+    ])),
+    Fr(const_fr([
+        0xd41c0d968d1e18ab,
+        0x5193652f94293a4f,
+        0xcc7349c778ecc8ca,
+        0x07b27037105e8d5d,
+    ])),
+    Fr(const_fr([
+        0x15efffbb876ef744,
+        0xe7133aee8786a855,
+        0xe38d4fdc47127677,
+        0x0dcf3d9e7533e8ac,
+    ])),
+    Fr(const_fr([
+        0x04e4386a4a540c8b,
+        0x1520a4e61f8bff70,
+        0x1a2638fda40e74d4,
+        0x015f720959224d2b,
+    ])),
+    Fr(const_fr([
+        0xc88889fae9f82abe,
+        0x58daef106ed2ee81,
+        0x9005c3a61ecaa234,
+        0x155b25beaf58fcb2,
+    ])),
+    Fr(const_fr([
+        0x15c0a6ac9601d55a,
+        0x1000105f46049eb5,
+        0x9a48a748b3a5c2c1,
+        0x168dadbabff828f5,
+    ])),
+    Fr(const_fr([
+        0xc116b063fe2801cc,
+        0x0226b88a33ac22e8,
+        0xe88900ba454329dc,
+        0x0c308695fc71f971,
+    ])),
+    Fr(const_fr([
+        0x5e13fe575cebe35e,
+        0x167c9d035a466f56,
+        0xcee78ae549d9005c,
+        0x08bb37b90fc51b05,
+    ])),
+    Fr(const_fr([
+        0x10cdc00f55d77bb8,
+        0xe7f4fb9c27abb969,
+        0x05ace3cdec49500e,
+        0x0fda420f14b51566,
+    ])),
+    Fr(const_fr([
+        0x311a3344ae2dda59,
+        0xb15e2fd088035ff1,
+        0x0b6d75ea3c3244a7,
+        0x1f56ab0efc7a0a92,
+    ])),
+    Fr(const_fr([
+        0xc33efa2df19f808e,
+        0x260ceca7d573366e,
+        0x3954875915c159b1,
+        0x12069dd43d34df85,
+    ])),
+    Fr(const_fr([
+        0xd05f2e16e3caf7f8,
+        0x68b3c85f3c5af7c5,
+        0xe9df071c065cece0,
+        0x197d30d853620048,
+    ])),
+    Fr(const_fr([
+        0xb7e2125258a3db90,
+        0x0274e5391e04740e,
+        0xd2567fbbc6bf0e3a,
+        0x24e7a6e6a6d223dc,
+    ])),
+    Fr(const_fr([
+        0xfa160bc705598c62,
+        0x9fc1698c64e6db47,
+        0xe8bb75f84ab7161b,
+        0x1439260b0147bff8,
+    ])),
+    Fr(const_fr([
+        0x934fd1cdc2005a39,
+        0x130bc16a8ad1135d,
+        0x9c091934871bfd55,
+        0x089c021f59a5a392,
+    ])),
+    Fr(const_fr([
+        0x55da838c29e8312e,
+        0x16ced810d5b89262,
+        0xb5d75190b60eb107,
+        0x110d368299b3b668,
+    ])),
+    Fr(const_fr([
+        0xe525e62416d160b5,
+        0x47f58bb9af3e9361,
+        0x02bdd699744d6cf9,
+        0x06a535fccff51935,
+    ])),
+    Fr(const_fr([
+        0xeca04c8077ca63a3,
+        0xa110f854aea7bd4d,
+        0xcccdaa160187aa8d,
+        0x11b1126725ef8b0d,
+    ])),
+    Fr(const_fr([
+        0x135c6f285cd41183,
+        0xc8d21fafcf602f08,
+        0xe992102b5f1ea435,
+        0x1c50c6da5f0d77b7,
+    ])),
+    Fr(const_fr([
+        0xa78dbecac0380d59,
+        0x004e7c6ab74aa092,
+        0x5e8832ad44acfe90,
+        0x2e5e5a0fa00b2b30,
+    ])),
+    Fr(const_fr([
+        0x22c38a2cf9408578,
+        0x6ebb7d7b201056a8,
+        0x275495c75f47121b,
+        0x22a544ea6f04e856,
+    ])),
+    Fr(const_fr([
+        0xa360744dd7be81ee,
+        0x6edd12f5e4c3becd,
+        0x0202ff666f560304,
+        0x0b93b94c18430b1c,
+    ])),
+    Fr(const_fr([
+        0xc8afbc39fab52714,
+        0x48037109eba47d93,
+        0xdd99f961e632b3de,
+        0x1b20501edc0c9595,
+    ])),
+    Fr(const_fr([
+        0xb3993f2ce4dfcae2,
+        0xce9ca4f4914dde52,
+        0x2f14f00b416fa8d4,
+        0x1f49a13e308d6016,
+    ])),
+    Fr(const_fr([
+        0xbc89f0fb7e9ec6a8,
+        0x40218cb2f0a67c4b,
+        0x563de2dfe1bca64f,
+        0x0ec938ef826afad0,
+    ])),
+    Fr(const_fr([
+        0x645913ac7122d4ae,
+        0xe93f6711d07d9772,
+        0x4e47357277715f73,
+        0x07b2f0e22b967957,
+    ])),
+    Fr(const_fr([
+        0x16303f114dbc348c,
+        0xd9117cf9ebba297f,
+        0xcf8002ade4b3d67f,
+        0x0bf55bb81aa5862a,
+    ])),
+    Fr(const_fr([
+        0xfc9926771bf31c67,
+        0xd165d0339741778a,
+        0xa9bb96d5d7f314ee,
+        0x039a2848412d3e51,
+    ])),
+    Fr(const_fr([
+        0xb622d21971efcada,
+        0xbfa59e318f07f4cb,
+        0xf551a400ced54121,
+        0x2f225ebe738fd44a,
+    ])),
+    Fr(const_fr([
+        0x846dba88e639a470,
+        0xce9a420fcddf7d86,
+        0x4a6c37d9e65c6d70,
+        0x165dcd650c31760e,
+    ])),
+    Fr(const_fr([
+        0x8692b2b556ac1197,
+        0xb6b2b5d5b409e047,
+        0xc2d1048de1d2b24f,
+        0x21212652245277f2,
+    ])),
+    Fr(const_fr([
+        0x5e8b6e88576a8e7c,
+        0x74ba1d3e0ea3f242,
+        0xd00f55ed6a0f60b6,
+        0x2dd77fa60c6bcc6b,
+    ])),
+    Fr(const_fr([
+        0xa3e0d70675f5a756,
+        0xbe726d72570628a2,
+        0xff17cdb00bf654cf,
+        0x16aa7bd9b08ab8be,
+    ])),
+    Fr(const_fr([
+        0x81b1b2495fba7724,
+        0xfadc75a483313058,
+        0xe407cd27413a3a01,
+        0x128bb8cf48c07d37,
+    ])),
+    Fr(const_fr([
+        0xf0c88645a6613c7c,
+        0x004962e560f47b81,
+        0x663d9cbd92bbed7f,
+        0x09d5748c6e8ce64a,
+    ])),
+    Fr(const_fr([
+        0x4014740cd582e07b,
+        0x96ac206fd5b04f3c,
+        0x11169550e852fb13,
+        0x25526ff83d1c2b22,
+    ])),
+    Fr(const_fr([
+        0x0740a89f0a4b6fc0,
+        0xa9ab92c87b3d0a58,
+        0xd9ccf64e80a941c6,
+        0x222bccc922ebae2b,
+    ])),
+    Fr(const_fr([
+        0x4f1dd43452ae2ded,
+        0x59f7f44924cac5e0,
+        0xe6bc8e95ddb8748b,
+        0x0771d802cd7a858c,
+    ])),
+    Fr(const_fr([
+        0x7673d0af9b80ed2a,
+        0x14308d25e693f4e0,
+        0x096c5fbbfd0e7f8c,
+        0x0c197d1b4bc854c6,
+    ])),
+    Fr(const_fr([
+        0x3af887a77d1f2d63,
+        0xefa7b30889215160,
+        0x999a270da24e1ca1,
+        0x0e0e5452bffbdb09,
+    ])),
+    Fr(const_fr([
+        0xe3de08d3a3d70d65,
+        0x2a29d443c40ab416,
+        0x0a0900ec06c1c92b,
+        0x1290aa4e1aa4850c,
+    ])),
+    Fr(const_fr([
+        0x07bb16d83f7cc02c,
+        0x0fd983cbefcacb99,
+        0x619c65c5ba4b1cd5,
+        0x0bd1502578be54c7,
+    ])),
+    Fr(const_fr([
+        0xfc7d550228d7946a,
+        0x1584ae094fe97876,
+        0x333cdc2c12a3bb9c,
+        0x2eac9c20d1c5c982,
+    ])),
+    Fr(const_fr([
+        0xe8a3fa35fce80ca6,
+        0x920d1d45b11e017d,
+        0x9a588bcacb1c290f,
+        0x1b1002c8d6dbb210,
+    ])),
+    Fr(const_fr([
+        0xededf131dc7606dc,
+        0x50187d69e3fc1702,
+        0x3fc5d363b4cc47ce,
+        0x0a2b5c8dd12d578b,
+    ])),
+    Fr(const_fr([
+        0x128635fafb44da34,
+        0x1c9cffa122d8791e,
+        0xae6fdb98cdcd84e0,
+        0x1d51eafae880249f,
+    ])),
+    Fr(const_fr([
+        0x103af6dc560068a5,
+        0x0064895fee71537d,
+        0x8e741f4dca8be343,
+        0x07d77b34c89986dd,
+    ])),
+    Fr(const_fr([
+        0x79dda1ac698a3070,
+        0xf872642863bd2acd,
+        0x09f13ab20f30fb37,
+        0x303890c8762bc959,
+    ])),
+    Fr(const_fr([
+        0xa15aa91e352a82ee,
+        0x55d23e7414e90c0b,
+        0xaa48e3b487d62f66,
+        0x2f1ca54a9ef35245,
+    ])),
+    Fr(const_fr([
+        0xf0b2ab0be33f3f82,
+        0x40a4dd1f110af669,
+        0x1002994e0aa6878b,
+        0x1fda2a496cf27c4a,
+    ])),
+    Fr(const_fr([
+        0xe3b699733f2df159,
+        0x27560485d5f20207,
+        0x27aef4a0aa7e0782,
+        0x26c60630a267a257,
+    ])),
+    Fr(const_fr([
+        0x5ff2f174b89f34c2,
+        0x88e58153f83a3a6a,
+        0x086667e3e6df1f38,
+        0x214abff5ac11f473,
+    ])),
+    Fr(const_fr([
+        0x7aa04291eb6ad5af,
+        0x30b564783d2dd3ed,
+        0x423444641ab124ac,
+        0x25c10e5850fb5be6,
+    ])),
+    Fr(const_fr([
+        0x171b7f8d84ee2cd5,
+        0x45e0d2e9fb3ab93d,
+        0xa9e7f4c1be01eec6,
+        0x165d9103bf7393f0,
+    ])),
+    Fr(const_fr([
+        0xb96b856dca8b4e66,
+        0x01a84a73d4c7974a,
+        0xce7dbaaa84c22b2b,
+        0x009731117bf58661,
+    ])),
+    Fr(const_fr([
+        0xe08a3405f5d07170,
+        0xb50acad59a75d210,
+        0x1de6bc641f1e850c,
+        0x2dd9b85f969e8d19,
+    ])),
+    Fr(const_fr([
+        0x7f2d5fd41a1a22f2,
+        0x449124b656df4aa1,
+        0x779a78704ef0caaf,
+        0x2cf012e850ffbc65,
+    ])),
+    Fr(const_fr([
+        0x06ec1014f1ab363f,
+        0x77a433eec391778a,
+        0xf5f64719c7dc30d7,
+        0x0cf1ddd3ecefb1da,
+    ])),
+    Fr(const_fr([
+        0x4ac03a56ea9d802e,
+        0xa37e87dd4a540d4d,
+        0xb87309ac3c722585,
+        0x22d8548fa17b290a,
+    ])),
+    Fr(const_fr([
+        0x5ad539a20ad343af,
+        0xcfa75f4615b8df03,
+        0x77529563d3fbb74e,
+        0x11eb63cb243754c2,
+    ])),
+    Fr(const_fr([
+        0x2ded37be1fee523a,
+        0x40acc26c28af1f98,
+        0x3ef0fbe137b2e459,
+        0x252b318d26248256,
+    ])),
+    Fr(const_fr([
+        0xd5e754451f1138d9,
+        0x1ab7237b2c6b35f8,
+        0x439620a6336add76,
+        0x06e67392922cc115,
+    ])),
+    Fr(const_fr([
+        0xa6849c8285cf1f81,
+        0xda0b177af3c39933,
+        0x0f1969b505527f55,
+        0x1568e7e9fbe529de,
+    ])),
+    Fr(const_fr([
+        0xb775df8e922d1bf5,
+        0x1810284a65475d50,
+        0xef78dcaa4df0874d,
+        0x10ca3e02218b4689,
+    ])),
+    Fr(const_fr([
+        0xacd8a65b68fa76a0,
+        0x049ba43f0563e87e,
+        0xb4ed07860bb34b69,
+        0x2d1ed7a62e9d9f2f,
+    ])),
+    Fr(const_fr([
+        0x078a59c511bd7c13,
+        0x857612f5dfc32b7e,
+        0x258870fb4a19bd99,
+        0x278f0ecf51bc570d,
+    ])),
+    Fr(const_fr([
+        0xbb3df6901c04bee7,
+        0x8bb2de5aa2311a58,
+        0x8a7a73c1eff6a7f1,
+        0x2cc296c338ab9c9c,
+    ])),
+    Fr(const_fr([
+        0x3a4974bb25c8bfd9,
+        0x53043bd3d0280f41,
+        0xc24c49006bf1ec58,
+        0x0e4062bcd577e68f,
+    ])),
+    Fr(const_fr([
+        0x0ed0e35fafb8d6ef,
+        0xbbd8299e771dcf5f,
+        0x9c1ee7b04e79aa51,
+        0x28a16aa9e7c86209,
+    ])),
+    Fr(const_fr([
+        0x043a40ecd3fc8cc0,
+        0xa46aceb76e868700,
+        0x81146a83f2c92d5a,
+        0x0cc69fc35bc4a50d,
+    ])),
+    Fr(const_fr([
+        0x3fd640b81c4b9d42,
+        0x94fefa09eb516a30,
+        0x1782209400fcedea,
+        0x0df7245c0f072bd9,
+    ])),
+    Fr(const_fr([
+        0x000663e99a7a4061,
+        0x68a260fb7991a637,
+        0x381d8d88db78cb58,
+        0x28e7ca5a3e136d15,
+    ])),
+    Fr(const_fr([
+        0xed4b6f38eeeffae6,
+        0xd8cd0a7b3521ba4d,
+        0x76a38303088871bb,
+        0x1ae7292e736931dc,
+    ])),
+    Fr(const_fr([
+        0x311f234d23667c9a,
+        0xb48a324000232ab4,
+        0xeb00aa901bb09c9e,
+        0x13870f90a791b184,
+    ])),
+    Fr(const_fr([
+        0x9f992b482a28d8b1,
+        0x4fe93a70af49537d,
+        0xe11ce322e8e44cfe,
+        0x117ab2671237e974,
+    ])),
+    Fr(const_fr([
+        0x21593d6b9aaa2c83,
+        0xd8bb02a756c3363d,
+        0x1ec88e15ab1b62c8,
+        0x01d3b94afe96d8a6,
+    ])),
+    Fr(const_fr([
+        0x31e552c5650b81db,
+        0x1a056dbe240b3027,
+        0xf4bc5246ee613608,
+        0x22b572966309a1cc,
+    ])),
+    Fr(const_fr([
+        0x00585bcab74468bc,
+        0xede6d007da17de0b,
+        0xc1a736609cbb28ff,
+        0x013e80e4bde075cf,
+    ])),
+    Fr(const_fr([
+        0x5a7d05b43e4c6c02,
+        0x5c04baf06c8222a2,
+        0xc935e0b7b3981b58,
+        0x149a451b1606939c,
+    ])),
+    Fr(const_fr([
+        0x9503faf9da57357f,
+        0xa0118e10bcc74d8d,
+        0xe71cde657e5e75a1,
+        0x1a97d2bc3ba8b0bf,
+    ])),
+    Fr(const_fr([
+        0x447b39ad23a5b1a1,
+        0xa3742bd3b2e305d2,
+        0x816f5cd595b8198a,
+        0x00ccfdda024879b8,
+    ])),
+    Fr(const_fr([
+        0xc4c0d371e3e9a866,
+        0xd91c43e598b626ee,
+        0x09a5ae619e3931d2,
+        0x209919e39e5ad5a1,
+    ])),
+    Fr(const_fr([
+        0x7b0b3cc2ac0dddba,
+        0xf5d7c0062ddcf2c9,
+        0xfceaf8318718ff5d,
+        0x25ec66821efc529b,
+    ])),
+    Fr(const_fr([
+        0x569eca1e93174241,
+        0x5371e8227e462c07,
+        0x674cade026b19be8,
+        0x177f9eddfb269187,
+    ])),
+    Fr(const_fr([
+        0x485622c51c21a075,
+        0x8b17a8b193df6029,
+        0x4ce7bb04739acbd3,
+        0x09fdb275ca2aab34,
+    ])),
+    Fr(const_fr([
+        0x4f90752c7c593ad5,
+        0xbc4cc28bc36af37a,
+        0xc8acd005f3e38dd4,
+        0x2f1d89d693f47a86,
+    ])),
+    Fr(const_fr([
+        0x5002ea9dae0d395f,
+        0x0a4e12a9d94d5450,
+        0x027e473d3417a16f,
+        0x1fefdf8b1b8cdf94,
+    ])),
+    Fr(const_fr([
+        0x005063fae14e22ea,
+        0x0fe32b4f80a4c1a6,
+        0x39e04bed3508c93e,
+        0x2d3e49d2d5414138,
+    ])),
+    Fr(const_fr([
+        0xe8c6d98ea0fde024,
+        0x413dd1391fc8965e,
+        0x20c71a5152638000,
+        0x02c90e3952ae8e2b,
+    ])),
+    Fr(const_fr([
+        0xe8fec068162a98c7,
+        0x794b71225c49f12c,
+        0x323329aac80c38fc,
+        0x2ec39c53c50af2d0,
+    ])),
+    Fr(const_fr([
+        0x21d0ac2d9d5228d1,
+        0xe3831744112996e9,
+        0x453af13ae52b5d34,
+        0x2dc81d1dd6168171,
+    ])),
+    Fr(const_fr([
+        0x2f9603f7c54066b4,
+        0xf1b97d69fecfe2b4,
+        0x248286b67ea24602,
+        0x0a8164d74812e45f,
+    ])),
+    Fr(const_fr([
+        0x0f0120093056314d,
+        0x162df9ee1105e29c,
+        0xdf1d96dafbe3b286,
+        0x2e0dfa81d5bec5d4,
+    ])),
+    Fr(const_fr([
+        0xfb1931f1d37a89b3,
+        0xc7d858a5908932ad,
+        0x2540f663e36d0dcb,
+        0x18620335ec4e2f3a,
+    ])),
+    Fr(const_fr([
+        0xea6169dc42fa2252,
+        0xf6a08e15a9742ae3,
+        0x355b0bfc085ba5af,
+        0x0d118bcd1d1fbbcb,
+    ])),
+    Fr(const_fr([
+        0xcc7471981c237251,
+        0x9aa2a38624a1a0d8,
+        0x29af15f45749de3e,
+        0x0d44ba94c2596da1,
+    ])),
+    Fr(const_fr([
+        0x8a28bcef9c32ffdc,
+        0xbb13b698aa6af22b,
+        0x041be70979cdca2c,
+        0x1607b1f4f008b006,
+    ])),
+    Fr(const_fr([
+        0xd0bc062cab73d3f1,
+        0x2d37834ee314886f,
+        0xedfb9d06715fd3a1,
+        0x1ea64cdc9234dc3e,
+    ])),
+    Fr(const_fr([
+        0x3c9dc62f2ba0c429,
+        0x093e063a3d5c66bc,
+        0x2e507be2fb29b5c6,
+        0x155520e31638d4a8,
+    ])),
+    Fr(const_fr([
+        0xf85a648269d9ec25,
+        0x3a15e2a2b7b3bc05,
+        0xc30ab76eb1ea7f20,
+        0x033ffbd4f27850e8,
+    ])),
+    Fr(const_fr([
+        0x94884acc5f51e3bc,
+        0xc8334b1d0502a106,
+        0x8684b172fd56fd47,
+        0x14ddaf43b15eeb6b,
+    ])),
+    Fr(const_fr([
+        0xdaeb76bfe0ec414d,
+        0x267b221ea3d606a2,
+        0xa8f3161dd88d001d,
+        0x18c4179c402a4d0f,
+    ])),
+    Fr(const_fr([
+        0xbca149bfdb0b4fc1,
+        0x0350bb9eaca90928,
+        0x29753db0edf9185d,
+        0x0a7d93572ae38fda,
+    ])),
+    Fr(const_fr([
+        0xb88cc8b12a989021,
+        0xafd15b21f6e4fc12,
+        0x5b713752c9e2501c,
+        0x28bd4b96bbf8ee62,
+    ])),
+    Fr(const_fr([
+        0x5639ad50ae38f1b9,
+        0x8fe74e35043432fc,
+        0xfb32ea7edfed4155,
+        0x059061b1222d0977,
+    ])),
+    Fr(const_fr([
+        0xd76396eae397f0ce,
+        0x19ae313a5d4cff43,
+        0xf8616bf3eff23b3c,
+        0x1ea6840b4b5b0cc4,
+    ])),
+    Fr(const_fr([
+        0xf5cc5b3c68bf51a2,
+        0x5d9493c20ee1286a,
+        0xb22e83d111586bf4,
+        0x0f0ecb1b6a45c7e8,
+    ])),
+    Fr(const_fr([
+        0xc2b6f32f643fd997,
+        0x43df2c7a28fb88f6,
+        0x9b5fb4a7473ad881,
+        0x0d1e85e45f65c460,
+    ])),
+    Fr(const_fr([
+        0x751cf2df28774d30,
+        0x1663a72415a159a4,
+        0xf222485a091f2d1b,
+        0x065959a163643798,
+    ])),
+    Fr(const_fr([
+        0xde06d5a623c39460,
+        0xc869d1c300c93a1c,
+        0x174f3c282bacc26e,
+        0x2536ebb273367a8a,
+    ])),
+    Fr(const_fr([
+        0xeb8312268e02b892,
+        0xca5b54aa33c6315d,
+        0x1336bea3f04d819a,
+        0x0c49e3174bd8179e,
+    ])),
+    Fr(const_fr([
+        0xe20f9114d872df14,
+        0x3c76671f7e1d4efd,
+        0xd46ea9183fa5c87b,
+        0x2031de796c011155,
+    ])),
+    Fr(const_fr([
+        0xf5438b617cbfd46e,
+        0xdc33bad0196ca937,
+        0xb3d87b57193ed66d,
+        0x028ef92edb9d026e,
+    ])),
+    Fr(const_fr([
+        0xd212a881adf15cd6,
+        0xd76e3af001a65700,
+        0x3f8eca48013ec96b,
+        0x18237ab4b68d441a,
+    ])),
+    Fr(const_fr([
+        0x36747eeb7b28be68,
+        0x255da0d7f5ef2aac,
+        0x87e8aea54c2eeef9,
+        0x0e691ff74ce6b9c4,
+    ])),
+    Fr(const_fr([
+        0x793d917f67de45eb,
+        0xbecd27f3d87a8ada,
+        0x2c4b3905231b8f54,
+        0x08837185fbd76794,
+    ])),
+    Fr(const_fr([
+        0xfbaa7bdcf81b55c4,
+        0x05322904845d45c0,
+        0x9a2eeb3450aad710,
+        0x19e5db3588aaee91,
+    ])),
+    Fr(const_fr([
+        0x2f8bcc3bf5fd22d8,
+        0x8a767f1c297092a2,
+        0x6522ab7178fd0cff,
+        0x22c378d5436a817d,
+    ])),
+    Fr(const_fr([
+        0x9603c51dd5657619,
+        0xea6bad6851461e39,
+        0x05afcb55b2340e89,
+        0x1c3a81e122d86060,
+    ])),
+    Fr(const_fr([
+        0x4ea46765d995e710,
+        0x0cb463dc6ba8096c,
+        0xdd4ad8320d827cd4,
+        0x1b62db516fc95fc3,
+    ])),
+    Fr(const_fr([
+        0x5406f042683f5ce3,
+        0x4c2dbb3659d62586,
+        0x6c1732cebb56b305,
+        0x18edd1f7885e52e2,
+    ])),
+    Fr(const_fr([
+        0x26b623daa7268b10,
+        0x5954901d56c2a2cb,
+        0x31d9555d3fa3f378,
+        0x12241f2aad5c071e,
+    ])),
+    Fr(const_fr([
+        0x5d6b307668493638,
+        0x33cbd08562342c04,
+        0xa545fa08f04b11f5,
+        0x0ab6d59676fad3cd,
+    ])),
+    Fr(const_fr([
+        0x623ce369ff716218,
+        0x7081a93e5f93126f,
+        0xd73c531e8a187c81,
+        0x08c0aa36803e40fa,
+    ])),
+    Fr(const_fr([
+        0xfadecd5055ff408d,
+        0x0fda49dfd9aa3780,
+        0xb92d92ecfe364065,
+        0x2bc5bd58aaad02d2,
+    ])),
+    Fr(const_fr([
+        0x83c1b9c3687da909,
+        0x04def1d47161b156,
+        0x6ebb0f6026dd862b,
+        0x1d5681899f8c2f15,
+    ])),
+    Fr(const_fr([
+        0xa1b4c1a5ef03988a,
+        0x41b7a9512e1d13b8,
+        0x58abe6205ee341dd,
+        0x15fc432514efdbc9,
+    ])),
+    Fr(const_fr([
+        0x6635bc4453a463af,
+        0x889f1c023b2e83b4,
+        0x4d7209a0e3cc8bb6,
+        0x018e40bfc4ab0718,
+    ])),
+    Fr(const_fr([
+        0x043e33c17b254273,
+        0x06236b59a230e3f1,
+        0xea0532faca717c37,
+        0x0fc4512f90e14356,
+    ])),
+    Fr(const_fr([
+        0x587fcd22b336d8fd,
+        0xd59f994db4866d6f,
+        0xbb8ad77b22a8ffe1,
+        0x095800dc370e6b6d,
+    ])),
+    Fr(const_fr([
+        0x8bc943e9da425ff8,
+        0xee30aafdfb30c6ff,
+        0xb0c0bd7ea60d9224,
+        0x0574b0495bf14c88,
+    ])),
+    Fr(const_fr([
+        0x2f562b391a4e8620,
+        0xea99b60cb39d9ceb,
+        0x9b6267325608902e,
+        0x09bf75f6474b766c,
+    ])),
+    Fr(const_fr([
+        0xacd0e5dfcbbfd861,
+        0x02a20bfe37873ffd,
+        0x79688efc24afa84d,
+        0x2152eb08ba93b454,
+    ])),
+    Fr(const_fr([
+        0x845b68d3c5308647,
+        0xb489e0da7d466476,
+        0x3d00aca8db9176f3,
+        0x1187901a8f9e1070,
+    ])),
+    Fr(const_fr([
+        0x9eb6fdc7a5b31fa5,
+        0xfe4bd582353e4859,
+        0x4e635c28762dd1ea,
+        0x18a658472f10f4ce,
+    ])),
+    Fr(const_fr([
+        0x1f37e2d59202fef0,
+        0xd9f1343541a0e348,
+        0x30ec8730f40b442c,
+        0x02e1e8ae280d00e3,
+    ])),
+    Fr(const_fr([
+        0xaa8b89d8e18421d9,
+        0xfd4452557bb273cf,
+        0xb5c59007133cb739,
+        0x0253b6ca330e52b8,
+    ])),
+    Fr(const_fr([
+        0x655152bb88cfc480,
+        0x922b0d225e1f6536,
+        0xad01ac27b5f13274,
+        0x04db7d3761d14f5a,
+    ])),
+    Fr(const_fr([
+        0xf2d88cee5661fdbc,
+        0x652c4a29707ebc98,
+        0x3832ae5a1c8f8842,
+        0x27fe1af5b0910c01,
+    ])),
+    Fr(const_fr([
+        0x8a9a1f85cff51415,
+        0x285f3dad5060d402,
+        0xddbbe8b8429cc0f4,
+        0x21f4d0e5d111dccf,
+    ])),
+    Fr(const_fr([
+        0xb6dcaae05e30a2ea,
+        0x1245fc6d28bd9e8f,
+        0xb7930bd6abb83fc5,
+        0x00cf4c2a7b20abfc,
+    ])),
+    Fr(const_fr([
+        0xb736a9564295d887,
+        0xcbcb99668f8c9cda,
+        0x4f6c339a2dfffa57,
+        0x0799cddcef0ae80b,
+    ])),
+    Fr(const_fr([
+        0x88c61688d89253ee,
+        0x4994f8bd0327ffee,
+        0x29cd0591153cfd01,
+        0x14f21e1b4ffa14cf,
+    ])),
+    Fr(const_fr([
+        0xc0da92678ab2d475,
+        0x93eff9eeba268a32,
+        0xce5b0a25090ac979,
+        0x07dca652ea22bb5b,
+    ])),
+    Fr(const_fr([
+        0x23f32fcb654d8529,
+        0x902e538d0964af94,
+        0x62533d456c89dd56,
+        0x28d6002bed0108e4,
+    ])),
+    Fr(const_fr([
+        0x796547571e5becbf,
+        0xee605a58eea861d2,
+        0xb75686c1dc232c74,
+        0x20b9a7db4f79be94,
+    ])),
+    Fr(const_fr([
+        0x6c5d574d3241cce1,
+        0xf4ff57be69788500,
+        0x90bc551b1f18c624,
+        0x282e05d73e225ee5,
+    ])),
+    Fr(const_fr([
+        0xfc1804b7f4b5d4f7,
+        0xadea519384ce2dfd,
+        0xfe7a918ce15a7667,
+        0x100969de44fb5abf,
+    ])),
+    Fr(const_fr([
+        0x6b8d3a8008d48042,
+        0x0732bd0543531b6a,
+        0x0a2616c79aad93e6,
+        0x07b7fbddc3039b9e,
+    ])),
+    Fr(const_fr([
+        0xefa37f23e42a0ca6,
+        0xa739b49099fd489b,
+        0xa2de7c3ac5497253,
+        0x244b89d7c4237683,
+    ])),
+    Fr(const_fr([
+        0x5f860c45a335725d,
+        0x792a35118a0d037e,
+        0xb8f6ea4d61b03928,
+        0x2c1fa4d1af130125,
+    ])),
+    Fr(const_fr([
+        0xb38791e061e5e710,
+        0x18248e0b010652af,
+        0xd1ee9d08592f580f,
+        0x13dae509da34d54d,
+    ])),
+    Fr(const_fr([
+        0x5f56153898f5b41f,
+        0xa63b4c7578dce489,
+        0x2599c336f77d1afa,
+        0x26856b51e73a1b9b,
+    ])),
+    Fr(const_fr([
+        0x0af0e32511a4555b,
+        0x06a59a2d97b00cb3,
+        0x822223b2cd719989,
+        0x2850e6a483a749f2,
+    ])),
+    Fr(const_fr([
+        0xfee51a1d8bbd7a27,
+        0xc4ae7c249f78bbf5,
+        0x754c8afa1592c11f,
+        0x0edf0b8f3007723a,
+    ])),
+    Fr(const_fr([
+        0x9877b8408aa5d57e,
+        0xa98e8d40c51a277f,
+        0x61f3f325dc813421,
+        0x2eec759e0227174e,
+    ])),
+    Fr(const_fr([
+        0x46c9a5541b6e4d07,
+        0x2d329e0a7a42944f,
+        0x48cf54c90c47c2a0,
+        0x0877650f6eb357a8,
+    ])),
+    Fr(const_fr([
+        0xfe429045b83367e8,
+        0xf6fefcc17eab6edd,
+        0x359268bcadd27231,
+        0x276a1a9bceaa80b1,
+    ])),
+    Fr(const_fr([
+        0xa6a3788246538294,
+        0x7877f8b3e5b0675c,
+        0xfc7e1d24a815ef6c,
+        0x0ec67f0198c3fc72,
+    ])),
+    Fr(const_fr([
+        0x40e7d553ead9a892,
+        0x2f425edd9f9eaa6b,
+        0xa9db997b948814ab,
+        0x0cd222910ef8a3e0,
+    ])),
+    Fr(const_fr([
+        0x3496a8eaa4b0209b,
+        0x55ea2cd29f752d2e,
+        0x642715c9ec0fad73,
+        0x15fc2006e9966ae3,
+    ])),
+    Fr(const_fr([
+        0x2fe46d888fd96595,
+        0x98d289cb6fc6ccd4,
+        0x72baf5738fa1cf04,
+        0x233c75efe91709c1,
+    ])),
+    Fr(const_fr([
+        0x987898e54fced68b,
+        0xb0b8a66fc39fe934,
+        0x986b6901d674b79e,
+        0x0efa47aa3347cd60,
+    ])),
+    Fr(const_fr([
+        0x5b314b9ee9fd9384,
+        0xae69ea85323b2d5e,
+        0xb4cbef7d253ca975,
+        0x1c7f850ef640821b,
+    ])),
+    Fr(const_fr([
+        0x4524aff4f32e96f6,
+        0x3a8704be820a3df4,
+        0x23e4394a931cf0cd,
+        0x19ae72243295ace4,
+    ])),
+    Fr(const_fr([
+        0x58c0db4a6447de0b,
+        0xf2d8a25341226514,
+        0x25ab3fadbc376c44,
+        0x25e9fbd8fc8d03fb,
+    ])),
+    Fr(const_fr([
+        0x1ef1b3bb660fd8cc,
+        0xebb655ae310d04d0,
+        0xe7c986e3577a61c8,
+        0x1565c50b05b7382a,
+    ])),
+    Fr(const_fr([
+        0xd6c3b1defee1264e,
+        0xf115030d47a1362f,
+        0x3a9d8e8044c1da66,
+        0x27ba367ff3000edb,
+    ])),
+    Fr(const_fr([
+        0x48ccd05a40d38206,
+        0xca849cfdd9b44398,
+        0xccc00163ba8f1580,
+        0x1390b89a14157c8c,
+    ])),
+    Fr(const_fr([
+        0x3663bc85ab9b4c4d,
+        0x140b590532236cd1,
+        0x82d0cbfeb4fe36f5,
+        0x2e5496e7e4dcc5de,
+    ])),
+    Fr(const_fr([
+        0x725749955e9d2e7c,
+        0x3b221ce0a47d97fb,
+        0x7b849f2dfd348192,
+        0x05384965967126e4,
+    ])),
+    Fr(const_fr([
+        0xe4914e96e8be9ed2,
+        0x53b40a9a3d79c4da,
+        0xa7b3ec9d305214ec,
+        0x15fb54d5a79e3117,
+    ])),
+    Fr(const_fr([
+        0x0b6f6f224da003c8,
+        0x132fa489ed324234,
+        0xc258d11a99f9cd94,
+        0x29b8500bcb392b00,
+    ])),
+    Fr(const_fr([
+        0x6cd8f26fc5a85480,
+        0xdb72bb7c4599e994,
+        0xfa3d92e1fcb23761,
+        0x2b4ec4f247017253,
+    ])),
+    Fr(const_fr([
+        0xe65cdf8d28670e8d,
+        0xc5484c7dcf7b56e7,
+        0x5f1217b4d82f398b,
+        0x251b77876eb9431d,
+    ])),
+    Fr(const_fr([
+        0x586fdc6122984605,
+        0xe88fc85ee664c429,
+        0xd1816ecb64314398,
+        0x1153fd1cf4d1aef5,
+    ])),
+    Fr(const_fr([
+        0x5427a9c1cc491ac8,
+        0xa48834582b733a48,
+        0x22cbb38a95bfd4b7,
+        0x1ac69e518f01f46d,
+    ])),
+    Fr(const_fr([
+        0x80faa88769b79ca2,
+        0x1306a6c070fdbcf9,
+        0xdf94f666d172330c,
+        0x1f6b8aeb614ead33,
+    ])),
+    Fr(const_fr([
+        0x460a434ffbc9aa16,
+        0xe677da6bb0d4fbd7,
+        0xb6c25225f4ef87e9,
+        0x0c347c07e50165b1,
+    ])),
+    Fr(const_fr([
+        0xf7b2bdfdb4de222f,
+        0xf288f23ecde20887,
+        0x36fefe95af0e0918,
+        0x245b0adcb916344f,
+    ])),
+    Fr(const_fr([
+        0x2ed9915e05d4ab11,
+        0x8337b76855f62c0a,
+        0xfc0be59e3e7b3d2a,
+        0x0bf0b7b416ee9d6a,
+    ])),
+    Fr(const_fr([
+        0x9d64a3eccf938eec,
+        0xcfcd18e6796717e5,
+        0x8ac639b9cfc676a3,
+        0x29d18cb8140cbb91,
+    ])),
+    Fr(const_fr([
+        0xe564d910b579b46b,
+        0x66fe274af53b0873,
+        0x95ee328ffda36e99,
+        0x2e01d29489dfdbc1,
+    ])),
+    Fr(const_fr([
+        0xe90d21029f65c915,
+        0x341ad4b6a58c195f,
+        0xb099c1fa0d5bdfa3,
+        0x2005120bd5943b6e,
+    ])),
+    Fr(const_fr([
+        0xbdad7283e39e68b6,
+        0xd84474f5d3ca343a,
+        0xd76fb9fc208c7985,
+        0x08803685bdd89ab4,
+    ])),
+    Fr(const_fr([
+        0x8fedc904b4b1fba3,
+        0xeae9f56cc03c3c63,
+        0x618df9f29d76482a,
+        0x212fcc65f9fb7d13,
+    ])),
+    Fr(const_fr([
+        0xa21b090723b60a06,
+        0xa536074a573543e0,
+        0x2eb45a325730b5dc,
+        0x085f4df3f5afd5e0,
+    ])),
+    Fr(const_fr([
+        0x1450611adc1974c2,
+        0xd7a5280e1a07fae3,
+        0xfa72070b63423687,
+        0x26df9c4de35bc282,
+    ])),
+    Fr(const_fr([
+        0x8ea3e37a7ad111b1,
+        0xcf443bda9ac33adc,
+        0xe08fa99fc24fbd0c,
+        0x1ed666d74b58fa9d,
+    ])),
+    Fr(const_fr([
+        0x5344909ab978e027,
+        0xed9dcb7c511dd446,
+        0xec6bb7c7143492e5,
+        0x2fca0c0ee6d3d8f4,
+    ])),
+    Fr(const_fr([
+        0xd6364288704590cc,
+        0xae5fd971db1d01f4,
+        0x2fb261a079e33901,
+        0x1f5ed2a118c02e97,
+    ])),
+    Fr(const_fr([
+        0xdc837e4b50cac7b5,
+        0x3e918e089707e16e,
+        0x2b199a82db4221e4,
+        0x05360ce495bd1f9a,
+    ])),
+    Fr(const_fr([
+        0xb28151afa7b3886b,
+        0x7a90972656c2db00,
+        0x548f4a2fd32b3c2b,
+        0x186e8fbe14d692ee,
+    ])),
+    Fr(const_fr([
+        0x4f75c5e33b2ece56,
+        0xce2da160bc20a13d,
+        0xa36ee657bf2f6485,
+        0x19be32bba21db8ae,
+    ])),
+    Fr(const_fr([
+        0x6bb8c796ed46b044,
+        0x8e3fd8532b68f8fb,
+        0xabc746376478d8b5,
+        0x0a7aba9980321a9a,
+    ])),
+    Fr(const_fr([
+        0x76d8475b1ce07a1d,
+        0xb45ae7ea3143a9ce,
+        0x7f48d8b16cb80147,
+        0x116cd2932195fe0b,
+    ])),
+    Fr(const_fr([
+        0xa01fd9644b02de21,
+        0xb37648764848bf69,
+        0x4fcb80c966acf45f,
+        0x19423a591149cc30,
+    ])),
+    Fr(const_fr([
+        0xbd562fe2638916ba,
+        0xe2e30fdbc036ca21,
+        0x851a179db15f166c,
+        0x20e2adf2d5fbbec5,
+    ])),
+    Fr(const_fr([
+        0xd03c806746a29886,
+        0x97261915cd4d06c7,
+        0xff064edceab1225d,
+        0x281695a5ef68896c,
+    ])),
+    Fr(const_fr([
+        0x448111fe25300fb6,
+        0xd7f62b472a7c5ffa,
+        0x104905e404522c40,
+        0x0f993e7fa0b98ec4,
+    ])),
+    Fr(const_fr([
+        0xc70ead753d7c51d6,
+        0xea163ac21f1e8758,
+        0xc5c861252eea7e06,
+        0x10a0ad4330a89a0b,
+    ])),
+    Fr(const_fr([
+        0xc13fad2daf6ae876,
+        0xb0adc061491fd288,
+        0x91dc2ba7a40be968,
+        0x2e13ca97520d5cac,
+    ])),
+    Fr(const_fr([
+        0x19ef42003440116d,
+        0xa7c0985342f5943c,
+        0x6d6139582f04dba0,
+        0x2f3b2543f6a9dc99,
+    ])),
+    Fr(const_fr([
+        0xbc968863a757c4cc,
+        0x28d9d5f03a0cc9cc,
+        0x4bc890c257d096cc,
+        0x0d237f97e2d0bf8f,
+    ])),
+    Fr(const_fr([
+        0x521067c3306e3bbb,
+        0x879086a1f0c17f1a,
+        0x68d91f66a3c2b626,
+        0x0b854c13c61840f4,
+    ])),
+    Fr(const_fr([
+        0x0585d8e1a9f15e62,
+        0xbabd5ef7a6321651,
+        0x103fca6c8e490b61,
+        0x04c734c0e6809bdf,
+    ])),
+    Fr(const_fr([
+        0xdc74817c7cede367,
+        0xcd5b769998c01bc1,
+        0xdc25ca21b524231e,
+        0x09c90e27cf8e1279,
+    ])),
+    Fr(const_fr([
+        0x450c7b10972615bd,
+        0x9a1a345c53dd5749,
+        0x5675730c47ebef0f,
+        0x2d20bf895cd8e4d2,
+    ])),
+    Fr(const_fr([
+        0x197411c2a1e62d88,
+        0x86a3eee28809b7e5,
+        0x1252adcf83895d54,
+        0x278431b497aff011,
+    ])),
+    Fr(const_fr([
+        0xe454e891d27be324,
+        0x672d88b9d56d09df,
+        0x62caccd9198ab2c6,
+        0x133a42a48e1e0bee,
+    ])),
+    Fr(const_fr([
+        0x998d56d9010bcb6f,
+        0x2351cfd902fdb3c5,
+        0x5f822112825e6699,
+        0x138617bd07997da6,
+    ])),
+    Fr(const_fr([
+        0x31d476a1a03905c3,
+        0x1247470c5b756cd1,
+        0x5053416c9f1553cc,
+        0x1195cc2173ba5600,
+    ])),
+    Fr(const_fr([
+        0x9baf5662338cf8a4,
+        0xc5267eebc6ff0702,
+        0x8d5a2bc16868dff4,
+        0x267b20dcdb83b73b,
+    ])),
+    Fr(const_fr([
+        0x43497cac40843601,
+        0x587f4faa00ab367e,
+        0x9c8323f06b4460b8,
+        0x2df10a456153a045,
+    ])),
+    Fr(const_fr([
+        0x40f114797b351909,
+        0x15e6634977498319,
+        0x96e809dab866e83a,
+        0x0bdb61b36fe2e015,
+    ])),
+    Fr(const_fr([
+        0x447e8b1e7f90d72b,
+        0xf0712b3a1766e702,
+        0xfc110742a04d1c00,
+        0x0fdde8ba44883516,
+    ])),
+    Fr(const_fr([
+        0x2d6f74a5015a0618,
+        0xf699d533d45f48c3,
+        0x632cb67b0089573d,
+        0x2aa4103ce054cfe6,
+    ])),
+    Fr(const_fr([
+        0x1e176950b2771747,
+        0x7d435cf829a04684,
+        0xa79e80bce8dc5228,
+        0x2471066f893afcf3,
+    ])),
+    Fr(const_fr([
+        0x7d2ce6f8c413b3a1,
+        0xb867568c0f6dbca5,
+        0xfb92afc369795c96,
+        0x1da371c6c52ba6df,
+    ])),
+    Fr(const_fr([
+        0xd267f7598786ff8f,
+        0x3b8144e4561f5ab1,
+        0xd515ba08bf00a528,
+        0x00cb385a1dfa5af3,
+    ])),
+    Fr(const_fr([
+        0x747c94ec5c073ee0,
+        0x9e71bc955be76145,
+        0x53fc807b72692a06,
+        0x12deb4fcc299db88,
+    ])),
+    Fr(const_fr([
+        0x3cd1a18318b19608,
+        0xc85cc63842f71729,
+        0x841253519d4bce26,
+        0x23c2006753c3522e,
+    ])),
+    Fr(const_fr([
+        0xfd3d31d41fc44aa2,
+        0xe55f7e1b2f3175a6,
+        0x723b35ac9dd3baa2,
+        0x015c8e07d7d9ec65,
+    ])),
+    Fr(const_fr([
+        0xeb55eb877c9e9494,
+        0xaf0ba7e88bf418a4,
+        0x972a0eab1f8811d3,
+        0x0aafe708612f6acb,
+    ])),
+    Fr(const_fr([
+        0x7371e8f90c09caca,
+        0xf3c1a9cc388bc381,
+        0x88158dfefa0263b5,
+        0x1893cb4d66a7a1f4,
+    ])),
+    Fr(const_fr([
+        0x28b31da745a35176,
+        0xd337f7bf2b4ccfd4,
+        0xec930d7cc69e1ebb,
+        0x1e6772c7f7c4682e,
+    ])),
+    Fr(const_fr([
+        0x733101758d6fe482,
+        0xe67ca1a3e9d681e1,
+        0x585bdbbcdfffaec4,
+        0x2f4cb6155ac1ae99,
+    ])),
+    Fr(const_fr([
+        0x86cc8136eed5f9ec,
+        0x78dc7dbd42698ab5,
+        0xf699e90c96b60884,
+        0x2df00414e02c3281,
+    ])),
+    Fr(const_fr([
+        0x44df753561441b99,
+        0x1656250a9eb51917,
+        0x3fe27f95a54ab25c,
+        0x0b7d94db461be1ae,
+    ])),
+    Fr(const_fr([
+        0x421afc442f55cc8e,
+        0x0b8b55877ad059c6,
+        0x532920fbf6ecded3,
+        0x17619f692d9bf7b8,
+    ])),
+    Fr(const_fr([
+        0xdddceede29cd4e13,
+        0x008166fe2bef3241,
+        0x09bfd5e10ec2395e,
+        0x12066d57f44cde33,
+    ])),
+    Fr(const_fr([
+        0xcaf34dece7718112,
+        0x124eb824135d0f7f,
+        0xc5dc4ad0ba348a55,
+        0x27d23b5967fadcea,
+    ])),
+    Fr(const_fr([
+        0xcdc224b4080ec1a3,
+        0x74007ffeb41aeb57,
+        0x37dd1f1c31e76769,
+        0x05519adb4b1efa63,
+    ])),
+    Fr(const_fr([
+        0x96b1974fbf27374b,
+        0x00382b385fe73ce4,
+        0xfb09d60c60c39ae7,
+        0x129798025c50daff,
+    ])),
+    Fr(const_fr([
+        0x00375bc0ca01832c,
+        0x9ee9a30d8a22ab02,
+        0xf2b15eefb95a6a52,
+        0x2e3e5c2562a0215a,
+    ])),
+    Fr(const_fr([
+        0xab34dc2e59427c34,
+        0x77b5fb339f1b317a,
+        0x22b89b5a264d6e88,
+        0x2d32ec13301b03b9,
+    ])),
+    Fr(const_fr([
+        0xdd6c809de4f5134f,
+        0xebbd2f7168fdc865,
+        0x18683e0e792cb507,
+        0x01c0a46cd9ecf1e4,
+    ])),
+    Fr(const_fr([
+        0x4f7cac0810308920,
+        0x713fb9e573dd7c25,
+        0x9c545623fd4e804e,
+        0x09cc8b305cc005ab,
+    ])),
+    Fr(const_fr([
+        0xbe41412e7667caec,
+        0xf25ed5fbbfa74be8,
+        0x66c97a592ef92f4a,
+        0x0ffc0a5f921fe450,
+    ])),
+    Fr(const_fr([
+        0x0c396f71ca044dff,
+        0x9c1d7c29246409e6,
+        0x2f88684cfb44abd3,
+        0x0cd63247ad734aa9,
+    ])),
+    Fr(const_fr([
+        0x39abcb2637268893,
+        0x51b103c1d3f94555,
+        0x770b61e08ea8067a,
+        0x2040930292c5dbce,
+    ])),
+    Fr(const_fr([
+        0x6d6a09d6bda1707f,
+        0x76ffe534128d982b,
+        0x30f916de5655cda7,
+        0x0b96c7c574e857b1,
+    ])),
+    Fr(const_fr([
+        0x697b0c9ac92f815c,
+        0x0ccb878aa75e5f8d,
+        0x151dc08fbed63fe3,
+        0x03283a75cd201076,
+    ])),
+    Fr(const_fr([
+        0xd026313cf3a3576a,
+        0xa2500c61ca939170,
+        0xfb837c32ffea86d0,
+        0x1f4d663148622ce5,
+    ])),
+    Fr(const_fr([
+        0x1f857ef511b36649,
+        0xa55884125a6f3aad,
+        0xdbd7c2ef430e9c67,
+        0x3025d145937d8fd5,
+    ])),
+    Fr(const_fr([
+        0x5f7de48d18bde89a,
+        0xa411c91833922a47,
+        0x50720caa362d1336,
+        0x0fa5a0c4f5bb9e9e,
+    ])),
+    Fr(const_fr([
+        0x27144293c446187a,
+        0xc0e8fe9974c1fa47,
+        0xcbd59ff69052b6a1,
+        0x1961abf1cab6d63e,
+    ])),
+    Fr(const_fr([
+        0x892851e8e105102b,
+        0xc23b3bb3ac083e1b,
+        0x24a174a0f0ba4e66,
+        0x301a6dc5621f9847,
+    ])),
+    Fr(const_fr([
+        0x74355968a978e100,
+        0xefedc2cf2b3cd6f3,
+        0xccdc0a073d300621,
+        0x2352b265b773316f,
+    ])),
+    Fr(const_fr([
+        0x29599148d7402883,
+        0x7c3fa5abc592ebe1,
+        0x18c78bd7448e7c9e,
+        0x143e96662d173334,
+    ])),
+    Fr(const_fr([
+        0x23606ffe7eb2c021,
+        0x57eb594e95085272,
+        0x44a8da6a6a370a07,
+        0x0ec58a569ffd218f,
+    ])),
+    Fr(const_fr([
+        0x6b7a9bd4a26c4eb5,
+        0xcabae5b6710d825b,
+        0xb03e5e9c19f01777,
+        0x1ba959a0795b6ef4,
+    ])),
+    Fr(const_fr([
+        0xbb65a55f2038b264,
+        0xcb3922a7e1bce13a,
+        0x3da07ec89642e972,
+        0x1bd6d03037a2eb41,
+    ])),
+    Fr(const_fr([
+        0x538d58144db12393,
+        0x8940329780b4304b,
+        0x2c24eb747163d9a9,
+        0x1d144e12a5ac7aa8,
+    ])),
+    Fr(const_fr([
+        0xe5344e5b551772b4,
+        0x358f4066cb2b7ff4,
+        0xa357e72863f5de11,
+        0x2c422b91a9237e41,
+    ])),
+    Fr(const_fr([
+        0xb1046be34fab2001,
+        0x886cb52e52969304,
+        0x0df5e7ddb4cf1e30,
+        0x1d01a4c6fea44a20,
+    ])),
+    Fr(const_fr([
+        0x38b5e893f3423477,
+        0x879b1cf7c2bc878e,
+        0xfb866b9a4a8396a8,
+        0x09b132082db862f7,
+    ])),
+    Fr(const_fr([
+        0x38acfa9e4b65bb45,
+        0xd25193b099cd47d9,
+        0xfb4330a87e56e315,
+        0x08b5dc5cbd680d08,
+    ])),
+    Fr(const_fr([
+        0x6fc616bae35caf1c,
+        0xeed5a1b7f2c24b67,
+        0xce2b8c4e6a4d20c9,
+        0x1e28fbb3987fb962,
+    ])),
+    Fr(const_fr([
+        0x0242f4434b65f5d9,
+        0x568d062468bc057e,
+        0x1444b33c5d821093,
+        0x06635a74a9ac19ae,
+    ])),
+    Fr(const_fr([
+        0x5c2782adc5ffe514,
+        0xe9cc1d3dd1a3b97a,
+        0xc8cc3de3efdf75be,
+        0x1b79448193a92a13,
+    ])),
+    Fr(const_fr([
+        0x6c55fbc33966fd47,
+        0x29ba1e48cb20d27e,
+        0x8703480d65fcd090,
+        0x112d888a6a770e2e,
+    ])),
+    Fr(const_fr([
+        0x931bd9aa9c0098fb,
+        0xdd156512a0ab32d1,
+        0xc4c71fc3766e2b09,
+        0x2ff6e2ea5e3af3b9,
+    ])),
+    Fr(const_fr([
+        0x71f0cc60034c509f,
+        0x7a089dc3ae89705c,
+        0x045008c612886021,
+        0x1a012cc1b8feacc5,
+    ])),
+    Fr(const_fr([
+        0x2e310ad82f587f2b,
+        0x943001f97dc14be3,
+        0xbde1ad461ea70a6a,
+        0x131fc09f12c8c500,
+    ])),
+    Fr(const_fr([
+        0x585f0f619155ee8f,
+        0xd36dc7b596c58f66,
+        0x639baa5e28d399a4,
+        0x043a105e4f24adc0,
+    ])),
+    Fr(const_fr([
+        0xc3019148a6e5e3cb,
+        0xfb93b698dd5345cb,
+        0x94fda8f591f678bf,
+        0x089ad8aef6becfa9,
+    ])),
+    Fr(const_fr([
+        0x7dfb4db5a655b87e,
+        0x7e03dd499c00ceba,
+        0x92574f0d1bed2ef1,
+        0x2583f2a34bce96a9,
+    ])),
+    Fr(const_fr([
+        0xcb588d38325da9f8,
+        0x7207085f83bf4b34,
+        0x14a0c291229318a2,
+        0x21055998e3dc428e,
+    ])),
+    Fr(const_fr([
+        0x71b665f0d5af6000,
+        0x29e734ea5f863816,
+        0x7e5c4133f215d544,
+        0x00d633b3b130dd74,
+    ])),
+    Fr(const_fr([
+        0x389e615650c6c1c6,
+        0x660ca96c848cb8c2,
+        0x77d96268105590a0,
+        0x0c2988963f12f88a,
+    ])),
+    Fr(const_fr([
+        0xd38299c30e33cb5c,
+        0xd586caf92984a870,
+        0x7ac8d69b9ec9d292,
+        0x18d506067eca5866,
+    ])),
+    Fr(const_fr([
+        0xe2e8ed63ce02ce4a,
+        0x699132f7701c32d2,
+        0xf1b4536a0c34aa8f,
+        0x2b8d2db8e0a21924,
+    ])),
+    Fr(const_fr([
+        0x64a6c6c46c26e840,
+        0x896036312b02ee04,
+        0xc3e4aae9603b73bf,
+        0x01b5662205215684,
+    ])),
+    Fr(const_fr([
+        0xe67b7e1a5bcc7276,
+        0xe66746d2396387f3,
+        0x0be54132e072b99f,
+        0x098f56882aa14d76,
+    ])),
+    Fr(const_fr([
+        0xae6051ffa205e902,
+        0xb163719122b02f29,
+        0x7aff545bc0bc3efe,
+        0x0105790223dcbea7,
+    ])),
+    Fr(const_fr([
+        0xab4849e40e2707b8,
+        0xc74f8043ba9388ac,
+        0x04a48f74d989ccdd,
+        0x24c002765f91c2b9,
+    ])),
+    Fr(const_fr([
+        0x4f8addf8c07512f2,
+        0x03e4edcaf48f05b8,
+        0xc6fe4d80b1b3fa22,
+        0x0bc803d5f34b2094,
+    ])),
+    Fr(const_fr([
+        0x91fe03fe0f16e84f,
+        0x534bb039020b396e,
+        0x275b023e8c6b4d8c,
+        0x2aef572f6e9f52ac,
+    ])),
+    Fr(const_fr([
+        0xa8a8c3f92c60d332,
+        0x8782563bae659842,
+        0xb2f2496c404d6d3d,
+        0x1ead98dd014ed52f,
+    ])),
+    Fr(const_fr([
+        0x97f1fe5082a59cce,
+        0x9e85e09d8fe59f6f,
+        0x010f15ead1f81604,
+        0x2d734a87deadc4e3,
+    ])),
+    Fr(const_fr([
+        0x01f110b41ff0852d,
+        0x8bcff8c0ca8b9b59,
+        0x6081edf131f6aac5,
+        0x10d983306873f09e,
+    ])),
+    Fr(const_fr([
+        0xac740990d163043d,
+        0xd7a1d9c6c6159e5a,
+        0x3a6167122a4b2aa0,
+        0x003a81ab177b66d7,
+    ])),
+    Fr(const_fr([
+        0x25f51e0459350ebd,
+        0x1e15ebe3692f4ea1,
+        0x5fb7890ca65ff0ec,
+        0x25a235bcd2893db3,
+    ])),
+    Fr(const_fr([
+        0xb27703276a9a20a3,
+        0x65318cc4deecef89,
+        0x1713110a1b8b5c6f,
+        0x23938b64008d0f7e,
+    ])),
+    Fr(const_fr([
+        0xb452a032ec770000,
+        0x8764316fb85e8ec7,
+        0x2b8d34ddb37f8147,
+        0x1aa41fb83ce9599f,
+    ])),
+    Fr(const_fr([
+        0xe5d410df91206d9e,
+        0x660c37de88ec9fc3,
+        0x09d066a17131230d,
+        0x2f565bf7fe34c162,
+    ])),
+    Fr(const_fr([
+        0x69a7b2327a49f23f,
+        0x0508a408a3b5d212,
+        0x0f0664567840c0cd,
+        0x24b26111c77c2767,
+    ])),
+    Fr(const_fr([
+        0xec75825204e5b805,
+        0x41e08d7d92ecf1c9,
+        0xbf1221b6bb322815,
+        0x0b3ef3dd2825aef9,
+    ])),
+    Fr(const_fr([
+        0xfb9cd9b6387427b3,
+        0xe8eb6ebf6542d73f,
+        0x110529ef84eecaba,
+        0x23a014d0e2cd0f81,
+    ])),
+    Fr(const_fr([
+        0x384c5177dea9534b,
+        0xb6c826fcfd564b1b,
+        0xb840c2e3f0cd52a0,
+        0x0765cd74ddf4b99d,
+    ])),
+    Fr(const_fr([
+        0x859655112ef14d7b,
+        0x1b235e6e98b3ca5c,
+        0x4f7c0fb4c749f93d,
+        0x1b538074a90fbe66,
+    ])),
+    Fr(const_fr([
+        0xff781546cce0f1f8,
+        0x7a2e9e559c2dc114,
+        0xe800577829d7862d,
+        0x28661ffb7463dbc4,
+    ])),
+    Fr(const_fr([
+        0xe8f4e76335e8b20d,
+        0xc9677fa3be8f9a84,
+        0xf2621d2c8b7a3504,
+        0x1ce22df8157e5c5d,
+    ])),
+    Fr(const_fr([
+        0xb6c9361d32f688bd,
+        0x5cd1fe9838635fb0,
+        0x8b8d1fb88c896996,
+        0x00bafa7ada989cce,
+    ])),
+    Fr(const_fr([
+        0xd47b6ea53c2a1411,
+        0x065161c10156d83a,
+        0x2d3758e5ce5cfe69,
+        0x2ef3cac22066abef,
+    ])),
+    Fr(const_fr([
+        0x3323290f3a4ecd40,
+        0xd4ee5f0b04ed813b,
+        0xc3fb3dee96754fe6,
+        0x0a02a3302f84047d,
+    ])),
+    Fr(const_fr([
+        0xa2344ed406f0f2df,
+        0xaee1eacacb8cdd67,
+        0x3b27a6fb24cb6327,
+        0x18d2a18d0dbe2e17,
+    ])),
+    Fr(const_fr([
+        0xe5bb9f7de072ee02,
+        0x831b4bb884d96b7a,
+        0x52d25973a11b09b1,
+        0x1ac75ff004313c22,
+    ])),
+    Fr(const_fr([
+        0xb124bd0128949c62,
+        0x77df97586648848a,
+        0x6441ad78732c77c1,
+        0x1014667ee18397a7,
+    ])),
+    Fr(const_fr([
+        0xc30253dc32c8f9f3,
+        0x96dc79b3a925a9ae,
+        0x05805de2e6bdcb57,
+        0x076f91318710d271,
+    ])),
+    Fr(const_fr([
+        0x8d0959c2ace9d186,
+        0xce67a30bc08fe7a6,
+        0x900a8b23bbbb1c26,
+        0x1ec8e208c698de61,
+    ])),
+    Fr(const_fr([
+        0xd91cb687fd9894d2,
+        0x920758470c24c797,
+        0x86c4c84aa0ad391c,
+        0x181cf3c6f4beefbc,
+    ])),
+    Fr(const_fr([
+        0x3b4a0bada44770bc,
+        0x7f49f7c9578e4bbf,
+        0x46453a8b677a4ecb,
+        0x080b712ff24a06a1,
+    ])),
+    Fr(const_fr([
+        0x52f6996e33cc75e7,
+        0xadcc700feb93de19,
+        0x94d2fe33dca04622,
+        0x1dc8f4c304fff9fd,
+    ])),
+    Fr(const_fr([
+        0x3641c37aadab43bb,
+        0x8e9a81c16602bc3a,
+        0xb344f0447b96aacf,
+        0x09b985231a81d354,
+    ])),
+    Fr(const_fr([
+        0x99f387c7626539a2,
+        0x266e031d822672ff,
+        0xa0a35cc5c6e51e08,
+        0x198b93469f9dad8c,
+    ])),
+    Fr(const_fr([
+        0x6134b83c0881ba9f,
+        0x97e516beda856b13,
+        0x50534d1e47ed15cd,
+        0x1ea754de37af8089,
+    ])),
+    Fr(const_fr([
+        0xd4d2f9a7767e5dd4,
+        0xbabf9c0492071603,
+        0x639c784c913bdbf0,
+        0x0e8372582350b839,
+    ])),
+    Fr(const_fr([
+        0xa7d048470c50c3c3,
+        0xe754e25a0981c3c9,
+        0x96ee33e469873b5a,
+        0x1cf7d39943513200,
+    ])),
+    Fr(const_fr([
+        0xa6c1b713d80c7dc8,
+        0x80a9aecbc3aeaccd,
+        0x9f8b8313b90e50f4,
+        0x2e16acdc5b0ddf0c,
+    ])),
+    Fr(const_fr([
+        0x5199dd55aabd80e7,
+        0xc718c1156b04a6fc,
+        0x5f573b7978e52dc4,
+        0x0c06967711d0b672,
+    ])),
+    Fr(const_fr([
+        0x80528bff714e57f4,
+        0xe81605f06c3608c8,
+        0x860ce1a3c7eb1ff5,
+        0x0fafbc3f1f9218ab,
+    ])),
+    Fr(const_fr([
+        0xc06f1a50d154b975,
+        0x4bdc0ff70723cdac,
+        0x31864400d5225892,
+        0x22d28f5dbe476151,
+    ])),
+    Fr(const_fr([
+        0xfb25aef74ea7b256,
+        0x7ecc896bbbbb85e4,
+        0x8f10228b665349a8,
+        0x21032e30b5f4c45f,
+    ])),
+    Fr(const_fr([
+        0xd0ce13a49facd0a9,
+        0x5af7360195aabc51,
+        0xb7a3624acf4bebed,
+        0x2287b3f9eb3a60f1,
+    ])),
+    Fr(const_fr([
+        0x65a640764bfb9032,
+        0x46dc336c07b67260,
+        0xae61b895c93129b6,
+        0x2ad2f0383d148322,
+    ])),
+    Fr(const_fr([
+        0x7a74bf994a0e1dcb,
+        0x130e344986834bf1,
+        0xa1be5d4b74c924a8,
+        0x186204831ea15c0d,
+    ])),
+    Fr(const_fr([
+        0xb9036b670435db8b,
+        0x321fa12a078a61c6,
+        0x92c3acd43ee986a0,
+        0x1b6f89423946297b,
+    ])),
+    Fr(const_fr([
+        0x22d9ce273f55258c,
+        0x761cf9092ebd2e0b,
+        0xc87e41539e7c510a,
+        0x06e71048561c25c4,
+    ])),
+    Fr(const_fr([
+        0x9526afca8916c0a3,
+        0xaf969425db3e9c4c,
+        0x52f3bc8a3911e8c9,
+        0x207af7add37f3cb5,
+    ])),
+    Fr(const_fr([
+        0x23562b52762eb52f,
+        0x2fda2396fc4bc26d,
+        0x55b1a763f937f9a2,
+        0x1b60e42303be55f4,
+    ])),
+    Fr(const_fr([
+        0x42eba1509986ecc1,
+        0xf192e0de3e026d00,
+        0xd90448ffe86e9781,
+        0x1679fe5fee22684b,
+    ])),
+    Fr(const_fr([
+        0x8b0bee1b501e0e92,
+        0xa3e0ebf995e1800c,
+        0xd0581b3b750dd2b1,
+        0x02898df5bed7214d,
+    ])),
+    Fr(const_fr([
+        0x1a8872b995000ded,
+        0x81519e18174d6369,
+        0xcc27dfb3d23d9d4f,
+        0x2d518e8706e826b8,
+    ])),
+    Fr(const_fr([
+        0x3bda469ce5c4ba35,
+        0xd3a9ce5870ffbe42,
+        0x4427be0cd6dd3cb1,
+        0x15f9660fb47fb9d9,
+    ])),
+    Fr(const_fr([
+        0x4141977bdd1c42a6,
+        0x840fa2d7ab931d3a,
+        0xad746a0228d6ad99,
+        0x0508d81e955229fa,
+    ])),
+    Fr(const_fr([
+        0xeab101f899006229,
+        0x057b8e0143a82393,
+        0xf5b817832722d61b,
+        0x262b11f6832812d6,
+    ])),
+    Fr(const_fr([
+        0x509444c7d7feed5a,
+        0x560471adc59a459d,
+        0xccc36d8d2cf3657b,
+        0x0d464d0de231aebd,
+    ])),
+    Fr(const_fr([
+        0xf76443bc87b0e68d,
+        0x0d21ee141b9fb227,
+        0x12beb933865d8229,
+        0x2c8832dcd9c3c69f,
+    ])),
+    Fr(const_fr([
+        0x02ec85492bdbdc41,
+        0x14e21dcfbefaf1e7,
+        0x533208efa2521165,
+        0x04bc74bdf7752bea,
+    ])),
+    Fr(const_fr([
+        0x0fcc34bfb31c1af6,
+        0x27e4d756e6a12e63,
+        0x5b106d6999c69f05,
+        0x015f5b409e305fa0,
+    ])),
+    Fr(const_fr([
+        0x39a0541d86d12606,
+        0x559dbd8eedcb2ddd,
+        0xa51daea2111afe82,
+        0x0b58bd7092e95f3f,
+    ])),
+    Fr(const_fr([
+        0x52eb0cfdcf39514a,
+        0x02c4a8cd8e0853ee,
+        0xd3355fbfeb1941b9,
+        0x2223545968ae6faf,
+    ])),
+    Fr(const_fr([
+        0xa2c42a206cd930cc,
+        0x02f71d25f347a588,
+        0xfa27b0c5849d515b,
+        0x0b0934a71d640bad,
+    ])),
+    Fr(const_fr([
+        0x7754fae19b4d268c,
+        0x85e0c733c3b43aa7,
+        0x909c5c73503731c5,
+        0x0699e39c4d17e382,
+    ])),
+    Fr(const_fr([
+        0x482886c329dc3a85,
+        0xc9f34b0881d8204f,
+        0x6eac13babe30369b,
+        0x1fae7f5108d97457,
+    ])),
+    Fr(const_fr([
+        0x0c3ee338ebb1a999,
+        0xbe53bd8d5a98be0f,
+        0x15a839bf45a16da7,
+        0x1f843fc0c54e92df,
+    ])),
+    Fr(const_fr([
+        0xab108c266bebba84,
+        0x17d1d97ea3599303,
+        0x98d4cb2ef1d28dc0,
+        0x174aa3388a07dc8e,
+    ])),
+    Fr(const_fr([
+        0x64bc4d3eee170412,
+        0xfc4089d52169456a,
+        0x35e3a2e412f2609c,
+        0x1a661de5ca996df2,
+    ])),
+    Fr(const_fr([
+        0x88c4bc3adb94b051,
+        0x0014685790875b74,
+        0x7ee7380b2d38d125,
+        0x106b2a04360e95b0,
+    ])),
+    Fr(const_fr([
+        0x5e172d2e9bc21424,
+        0x961bc47ddbf63cfe,
+        0xe5f4b325d13934cf,
+        0x2060d9dcc507d337,
+    ])),
+    Fr(const_fr([
+        0x9f830eaadf1721cc,
+        0xd85c10b1cd0062db,
+        0x2f7b4db7bdba5497,
+        0x1e773a2d20ecf2c6,
+    ])),
+    Fr(const_fr([
+        0xb9aad08332527d0d,
+        0x481c1022bd091b96,
+        0x30a07619973963c6,
+        0x2bf4634e42098020,
+    ])),
+    Fr(const_fr([
+        0x93b80bc25123d1f0,
+        0xb329dd0dff3a881a,
+        0x75949f1a12ebf163,
+        0x14b04652e6b28b0a,
+    ])),
+    Fr(const_fr([
+        0xdf6a4314cc09a2b7,
+        0xdd0bc50605fe857e,
+        0xcd11610cec968746,
+        0x04f8cc5e65683650,
+    ])),
+    Fr(const_fr([
+        0x0998d1a1f53da9aa,
+        0x943cbe30aed6400f,
+        0x1cd583d37dcfe043,
+        0x25fd3dcb3ef5943e,
+    ])),
+    Fr(const_fr([
+        0x8ffcf0408283cd55,
+        0x0232fd66108651bd,
+        0xf4d9ac98fbbefe15,
+        0x04fd68f5ce69fb01,
+    ])),
+    Fr(const_fr([
+        0x39d4636a7f27c8cc,
+        0xd629d8c3aac1e87a,
+        0x63165d4d2c4fab25,
+        0x0946f7f8f97eaf1c,
+    ])),
+    Fr(const_fr([
+        0x1ded528d586f576e,
+        0x8d52a0da181ba1fa,
+        0xd3f9696e8bf23812,
+        0x0c20039e23c62aec,
+    ])),
+    Fr(const_fr([
+        0x8cd8bbd38dd18c34,
+        0x9feef2505caa2b3c,
+        0xdfebf366f3854930,
+        0x0951b84dc6515c48,
+    ])),
+    Fr(const_fr([
+        0xabefc6527ab4b183,
+        0x7a5f392240450498,
+        0xda76cb37e75a389a,
+        0x28c2605a3f6af8fa,
+    ])),
+    Fr(const_fr([
+        0x98bdcb1849e56b97,
+        0x15911f5272c3000e,
+        0x671e56db7de2d931,
+        0x05f1bc64f3988cc7,
+    ])),
+    Fr(const_fr([
+        0xe6de313fe72075c7,
+        0x5337d45adfbbcaa8,
+        0x8823a51e1f3bfd1a,
+        0x1710e7bbea323dba,
+    ])),
+    Fr(const_fr([
+        0x2554df2b46b663e8,
+        0xf52bd3f8c3c704e4,
+        0xcc3a326ccf0094d1,
+        0x275a28f215f64445,
+    ])),
+    Fr(const_fr([
+        0xaa652a8613b5d399,
+        0x0cafbb5338ea9350,
+        0x707ab5b71559183e,
+        0x2070ea8458b46aa6,
+    ])),
+    Fr(const_fr([
+        0xefc764d1d4926fd3,
+        0xfaaab25c0db29fda,
+        0xdbe5f8d22020e0fc,
+        0x0510db94042a86c8,
+    ])),
+    Fr(const_fr([
+        0x2ee7252bf087dc52,
+        0xf4b108d6c5035df5,
+        0x0592c52b980830c1,
+        0x12912603784bb10c,
+    ])),
+    Fr(const_fr([
+        0xf821a7c4fbeb3c21,
+        0xc33f823767370bff,
+        0x19f64e202f795e5b,
+        0x2bd01679bb4c9427,
+    ])),
+    Fr(const_fr([
+        0x712eb4c6969874b2,
+        0xd768be74f9a8f732,
+        0x4e380535ad0b79a3,
+        0x26426d821674334e,
+    ])),
+    Fr(const_fr([
+        0x2b343d12e3ade136,
+        0xe4f6cbd3c5a2b0bb,
+        0xe084cae32ee43ed4,
+        0x2e20a21f38842ee7,
+    ])),
+    Fr(const_fr([
+        0x1b585fa173089d07,
+        0x7c3b684b2bb5fb3d,
+        0xb79d1dbda2580f7f,
+        0x26e8826b621bc598,
+    ])),
+    Fr(const_fr([
+        0xeb37337f238c176d,
+        0xa832847a7fe45b73,
+        0x0f813943bf04388a,
+        0x019bae10ee498300,
+    ])),
+    Fr(const_fr([
+        0x56f375855d5837d3,
+        0x4d87ec97d702c3f5,
+        0xf0290173e3f36a16,
+        0x19857aec37979ce1,
+    ])),
+    Fr(const_fr([
+        0x12a1f7314ef51b4f,
+        0xe544863a3a282340,
+        0x4a123618f3a96d3a,
+        0x04577f18098b5bd4,
+    ])),
+    Fr(const_fr([
+        0x040d83887e4bb45e,
+        0x1f27534f48c303c6,
+        0x503a66a62f243cb6,
+        0x27db4df0db09e8fa,
+    ])),
+    Fr(const_fr([
+        0xcf74fb0b23974201,
+        0xf9b0fc1a56c5dd36,
+        0x128ca17144399425,
+        0x04753de3b98a65a2,
+    ])),
+    Fr(const_fr([
+        0xfe89953b916bfb18,
+        0x47e4c7dc74969f3d,
+        0x5c9e6edd5018a926,
+        0x152578162ab89b4e,
+    ])),
+    Fr(const_fr([
+        0x6c95d6e96237444e,
+        0x546bd19ea0b99b6d,
+        0xd8bdae4ad92f64da,
+        0x11cb2b6e82fd4ec6,
+    ])),
+    Fr(const_fr([
+        0x3306fbaa1c459577,
+        0x5b06c5e21d3928f2,
+        0x25c00be7d47ff946,
+        0x1f37bb413e406962,
+    ])),
+    Fr(const_fr([
+        0x20e7ae245f19d2c4,
+        0x2ede297f89f46951,
+        0xabdca1e9b3bc9c61,
+        0x28a9e0bcea7f7678,
+    ])),
+    Fr(const_fr([
+        0x02e195748efe07bc,
+        0x60bc163852649c3c,
+        0x877d486fc21bec22,
+        0x1c92461898d28a01,
+    ])),
+    Fr(const_fr([
+        0x63bbb6e28bf9f1d2,
+        0x28753682f2bec891,
+        0x08217e69b49cd687,
+        0x0ed64e17bf6071c2,
+    ])),
+    Fr(const_fr([
+        0x9fafa221a700169f,
+        0x7f1396fde419b7ad,
+        0x3958f65ea92b1ce6,
+        0x1115ba5f4791a045,
+    ])),
+    Fr(const_fr([
+        0x0da9b75af0a88a17,
+        0xaf573d3a5a67549f,
+        0x938662c35b1bb23b,
+        0x2d0428a3e151f825,
+    ])),
+    Fr(const_fr([
+        0xd9837d8963ffddb7,
+        0x17e06e1f16e2be23,
+        0x7c6945cd7bb4ff2f,
+        0x01e76a07cff3c1b8,
+    ])),
+    Fr(const_fr([
+        0x6d052af31e2edc54,
+        0xd16b2efac96367e7,
+        0x54dbec02d9eaabcc,
+        0x160e0fbd33961ef4,
+    ])),
+    Fr(const_fr([
+        0x87ce46b585287c4b,
+        0xaf150f93c98777a6,
+        0x6c087437ccbe3b04,
+        0x12ccd9fe8358f334,
+    ])),
+    Fr(const_fr([
+        0x3437e193e3cb68e3,
+        0xf31d50fb2d58d632,
+        0xad68ce26273442ad,
+        0x027b379369fe3fad,
+    ])),
+    Fr(const_fr([
+        0x0fbb826668641c61,
+        0x8385a41e29dc75d6,
+        0xf0e7d990a6d7105a,
+        0x28aa63a7ef77b16d,
+    ])),
+    Fr(const_fr([
+        0x071b114d9e112dd8,
+        0x7bfd13038bd7f177,
+        0x42cef71362999ffd,
+        0x19045de0392eb44d,
+    ])),
+    Fr(const_fr([
+        0x85683af0ae39592b,
+        0x175b065f6449741d,
+        0xd95184c28da0118e,
+        0x26420ee7e2ff2ee0,
+    ])),
+    Fr(const_fr([
+        0xaf153d21ba45e3e4,
+        0x6b254950bf233609,
+        0x6cdbdeeb76b9d87e,
+        0x1a6bd422a1be216a,
+    ])),
+    Fr(const_fr([
+        0xb1cf5d65cfb745ea,
+        0x602434efe1be0bc1,
+        0x94d76a25e99db79b,
+        0x275575186a1d6930,
+    ])),
+    Fr(const_fr([
+        0xb83d4334b8f1c05e,
+        0x98a63904eed4652b,
+        0x5b06906ef710948d,
+        0x0652787fe8dd54e9,
+    ])),
+    Fr(const_fr([
+        0xb38e03ac9eb995c8,
+        0x185c59328bec13cd,
+        0x8f44fe4e79c43b0b,
+        0x2d4338193b4d0987,
+    ])),
+    Fr(const_fr([
+        0xb48c5de756167e94,
+        0x8c02beb83d368e2e,
+        0x794079a0a616b4b2,
+        0x2b78270e9bcba012,
+    ])),
+    Fr(const_fr([
+        0x4f2bcd036ebe7949,
+        0x6cf49eda303bcca0,
+        0x5e62623352a49fbe,
+        0x1201ace602d51745,
+    ])),
+    Fr(const_fr([
+        0x0a79e62dee9fc020,
+        0x4549ae4c7e03c6a2,
+        0x790ab2232bf28ef4,
+        0x1d7f6938b25d1767,
+    ])),
+    Fr(const_fr([
+        0x5b60ca52507e16a4,
+        0x219b0c660200947a,
+        0x18fd4602efb2d432,
+        0x154e5ca99e04b000,
+    ])),
+    Fr(const_fr([
+        0x96e791341473213a,
+        0x0d403b9c189b5253,
+        0xaccba20ab997fad8,
+        0x2703c98f474f78ec,
+    ])),
+    Fr(const_fr([
+        0x83492ecd864966af,
+        0xa78f2dfeb2f82efa,
+        0x203f716afbc6d8fd,
+        0x3006bbcc28dead23,
+    ])),
+    Fr(const_fr([
+        0xe53d67e45d98330f,
+        0x827f5ddbcd5832b6,
+        0xf629392ead1776cf,
+        0x1733955bce2c6dcc,
+    ])),
+    Fr(const_fr([
+        0xd974536469fcbb13,
+        0xeb9a8abe10a292c5,
+        0x26afa8d6bf9df9d0,
+        0x06589029a37a193f,
+    ])),
+];
+
+const M6: [[Fr; 6]; 6] = [
+    [
+        Fr(const_fr([
+            0x40608a783ee62bad,
+            0xb04481cea961436e,
+            0x97a709db9ed8c008,
+            0x281f30c1bf2d293a,
+        ])),
+        Fr(const_fr([
+            0x1eb9eb4e60ba3373,
+            0xf00765566cd757d0,
+            0x268dc55adb55846f,
+            0x269cfc86a2aa48eb,
+        ])),
+        Fr(const_fr([
+            0xfbd0fae3e707c83d,
+            0x0747923dbcdfee7a,
+            0xe557147ed47c883f,
+            0x17e8bde04d18fe5f,
+        ])),
+        Fr(const_fr([
+            0x7bc95074a957f067,
+            0x92742eda793e04d2,
+            0x8a21a997bcea54eb,
+            0x1940a532751ec116,
+        ])),
+        Fr(const_fr([
+            0x452d49d15618e722,
+            0xf190e2a158c159cf,
+            0xf3872d51c2664285,
+            0x0178a647be9003c9,
+        ])),
+        Fr(const_fr([
+            0xc7ffe564d3eddaf9,
+            0x35a45ad2b23d1285,
+            0xb93fe800101207a9,
+            0x0e899bc033f34aad,
+        ])),
+    ],
+    [
+        Fr(const_fr([
+            0xefda51bad9992600,
+            0xb16fd81c76521488,
+            0x2cb484bec9ea82ab,
+            0x23da01c08fa91fdf,
+        ])),
+        Fr(const_fr([
+            0x8ebbefa3fccd5f05,
+            0x5426dfcd19dde090,
+            0x20be5e39a7e6d97e,
+            0x0f7c06a615f5e4b4,
+        ])),
+        Fr(const_fr([
+            0x23d1b77b033d60f4,
+            0x3a318f06f771ca28,
+            0x274637939c2a62cf,
+            0x11a6537465cf9e63,
+        ])),
+        Fr(const_fr([
+            0x484cc8e7d5f9e814,
+            0xe648b1359b98a57b,
+            0x3ff7f647ef353142,
+            0x27b31ee9c20ff9ff,
+        ])),
+        Fr(const_fr([
+            0xb3bd0f39349ed952,
+            0x7312b9eb8a75b33d,
+            0xa4cb7e2f22f8707b,
+            0x211e5f99af40c21d,
+        ])),
+        Fr(const_fr([
+            0xe689b0b0b960b0e9,
+            0xa59654462b376397,
+            0xc1b82be00456d0b5,
+            0x1e160fcaeb5e06f3,
+        ])),
+    ],
+    [
+        Fr(const_fr([
+            0x3e66e1111b61380e,
+            0xb3bb4d0a482fed80,
+            0xf1fc52e48b3c87c3,
+            0x29c2b69d270a7a26,
+        ])),
+        Fr(const_fr([
+            0x3cc59fe2ec6e0ee3,
+            0x37485e8bb490e1ac,
+            0x70f730f0cc0c998e,
+            0x0c104d3d523b237d,
+        ])),
+        Fr(const_fr([
+            0x8766ea049b81b64d,
+            0xd7eb0b588f7ee7ff,
+            0xa680d52360b40d40,
+            0x2ca41a0d59a7c287,
+        ])),
+        Fr(const_fr([
+            0xf082905d919792ca,
+            0x356f1731e730d31b,
+            0xb875ae04a1ab56d5,
+            0x19ec9b0bad23b140,
+        ])),
+        Fr(const_fr([
+            0xcb79be0ca8ffd3d7,
+            0xa44293e88721cb2d,
+            0x3b18a7d5973a5e2e,
+            0x02e98dd0e637f10d,
+        ])),
+        Fr(const_fr([
+            0x3f4a96b43a524eac,
+            0x0eae4179c556059c,
+            0xe5f44612842f2f44,
+            0x1de7db929e3a2ebf,
+        ])),
+    ],
+    [
+        Fr(const_fr([
+            0xa891ce4cf72c8118,
+            0x05ea1137dca7b6dc,
+            0x5d29edc5b17f099b,
+            0x0d2055ef4c751416,
+        ])),
+        Fr(const_fr([
+            0xfe40bc12d589914c,
+            0xc80b5c9641012cab,
+            0x06141dd49a0f2a09,
+            0x1801513dd0fcf525,
+        ])),
+        Fr(const_fr([
+            0x5a828f0a35fcd279,
+            0x964872d3376aa04c,
+            0x1b96474d51f0077d,
+            0x1865ba3db468d563,
+        ])),
+        Fr(const_fr([
+            0x6d095f01a26e38d7,
+            0xb058478241d1ee84,
+            0x20fdad0cbee0343e,
+            0x15e1aa2a804448fe,
+        ])),
+        Fr(const_fr([
+            0xf42b9ce424fb5920,
+            0x04ce20816a5c0bbb,
+            0xbb768122771c499e,
+            0x029c56eac72c04b4,
+        ])),
+        Fr(const_fr([
+            0x053ee9a8b40dea2f,
+            0x8c0f06c76d015743,
+            0x9fa29e578da2d6b7,
+            0x23b88cab9f8127f6,
+        ])),
+    ],
+    [
+        Fr(const_fr([
+            0xd5084b983d059f09,
+            0xb26e4cc70ef857ed,
+            0x4b26508a71981283,
+            0x2b1a7b81185ba003,
+        ])),
+        Fr(const_fr([
+            0x63cc2e19b2f5e970,
+            0x627de97f5dadf3e2,
+            0xbea9a303fa8e4cfe,
+            0x04673cdd12ef3efb,
+        ])),
+        Fr(const_fr([
+            0x1867682fa6c0667c,
+            0x85c52d48f9ad0b1f,
+            0x0ecad0b8d863a0b4,
+            0x2a8b3bd310a0fb6c,
+        ])),
+        Fr(const_fr([
+            0xd0109ef67b446a43,
+            0x8f1c5c34cb2cf7bd,
+            0x2b9433541d70e88b,
+            0x2f0a210228cfe694,
+        ])),
+        Fr(const_fr([
+            0x415264bb2f851f60,
+            0x654b6ae05b764f1b,
+            0x95ec4302bf86cc97,
+            0x2ff1febb53aa2132,
+        ])),
+        Fr(const_fr([
+            0x77ace1660180df7a,
+            0x81e9f4808991cad5,
+            0x70948c9cd60fe288,
+            0x155f834700ff1a52,
+        ])),
+    ],
+    [
+        Fr(const_fr([
+            0xa582444f5961d9e4,
+            0xff79361827fd1e0a,
+            0x017b68f5275f16e3,
+            0x2cdb7c001a0f5f1f,
+        ])),
+        Fr(const_fr([
+            0x3f97d29cf63a99b9,
+            0x585ce6f59f5d0179,
+            0xb5eb9fe498bef091,
+            0x1def28255939535b,
+        ])),
+        Fr(const_fr([
+            0xbf051a5712364a91,
+            0x944cc3120036058f,
+            0x009bc4bbd9ba986a,
+            0x2f67f5aae7583230,
+        ])),
+        Fr(const_fr([
+            0x93e0d9d279578ecd,
+            0x3f5900a10336c640,
+            0x3c07e3830ae2c921,
+            0x26fb3f9e2ad30a18,
+        ])),
+        Fr(const_fr([
+            0xb4e790ea44be8ff5,
+            0xa263d62f64bee9d3,
+            0xfc321bdc1b5d3f9d,
+            0x0acba80b0018c1d9,
+        ])),
+        Fr(const_fr([
+            0x1cf3860522396dee,
+            0x6e8ac845cc78648a,
+            0x8befbc792c684b44,
+            0x27d25218ce3dace9,
+        ])),
+    ],
+];
+
+fn permute<const T: usize>(state: &mut [Fr; T], constants: &[Fr], mds: &[[Fr; T]; T]) {
+    let rounds = constants.len() / T;
+    let partial = FULL_ROUNDS / 2..rounds - FULL_ROUNDS / 2;
+    for (r, c) in constants.chunks(T).enumerate() {
+        for (s, c) in state.iter_mut().zip(c) {
+            *s += *c;
+        }
+        let sbox = |x: Fr| {
+            let x2 = x * x;
+            x2 * x2 * x
+        };
+        if partial.contains(&r) {
+            state[0] = sbox(state[0]);
+        } else {
+            for s in state.iter_mut() {
+                *s = sbox(*s);
+            }
+        }
+        let mut mixed = [Fr::zero(); T];
+        for (m, row) in mixed.iter_mut().zip(mds.iter()) {
+            for (a, s) in row.iter().zip(state.iter()) {
+                *m += *a * *s;
+            }
+        }
+        *state = mixed;
+    }
+}
+
+/// circomlib's `Poseidon(2)`.
+pub fn hash2(left: Fr, right: Fr) -> Fr {
+    let mut state = [Fr::zero(), left, right];
+    permute(&mut state, &C3, &M3);
+    state[0]
+}
+
+/// circomlib's `Poseidon(5)`.
+pub fn hash5(inputs: &[Fr; 5]) -> Fr {
+    let mut state = [Fr::zero(); 6];
+    state[1..].copy_from_slice(inputs);
+    permute(&mut state, &C6, &M6);
+    state[0]
+}
+
+/// Poseidon as a `FieldHasher`. `hash_pair` is `hash2`; `hash_bytes`
+/// absorbs the data as 31-byte big-endian chunks followed by its length
+/// in bytes, each folded in as `acc = hash2(acc, x)` from zero.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Poseidon;
+
+impl FieldHasher for Poseidon {
+    fn hash_bytes(&self, data: &[u8]) -> Fr {
+        let mut acc = Fr::zero();
+        for chunk in data.chunks(31) {
+            let mut word = [0u8; 32];
+            word[32 - chunk.len()..].copy_from_slice(chunk);
+            let x = U256::from_slice(&word).expect("word is 32 bytes; qed");
+            acc = hash2(acc, Fr::new(x).expect("31 bytes are below r; qed"));
+        }
+        let len = U256::from(data.len() as u64);
+        hash2(acc, Fr::new(len).expect("lengths are below r; qed"))
+    }
+
+    fn hash_pair(&self, left: Fr, right: Fr) -> Fr {
+        hash2(left, right)
+    }
+}
+
+#[test]
+fn circomlib_vectors() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    assert_eq!(
+        hash2(fr("1"), fr("2")),
+        fr("7853200120776062878684798364095072458815029376092732009249414926327459813530")
+    );
+    assert_eq!(
+        hash5(&[fr("1"), fr("2"), fr("3"), fr("4"), fr("5")]),
+        fr("6183221330272524995739186171720101788151706631170188140075976616310159254464")
+    );
+
+    // circomlib's Poseidon(2) of zeros, the empty leaf of Poseidon Merkle
+    // trees, is the hash of no bytes: only the zero length is absorbed
+    let zeros = fr("14744269619966411208579211824598458697587494354926760081771325075741142829156");
+    assert_eq!(hash2(Fr::zero(), Fr::zero()), zeros);
+    assert_eq!(Poseidon.hash_bytes(b""), zeros);
+    assert_eq!(
+        Poseidon.hash_pair(fr("1"), fr("2")),
+        hash2(fr("1"), fr("2"))
+    );
+
+    // two chunks, then the length
+    let data = [0xabu8; 40];
+    let mut word = [0u8; 32];
+    word[1..].copy_from_slice(&data[..31]);
+    let first = Fr::new(U256::from_slice(&word).unwrap()).unwrap();
+    let mut word = [0u8; 32];
+    word[23..].copy_from_slice(&data[31..]);
+    let second = Fr::new(U256::from_slice(&word).unwrap()).unwrap();
+    let expected = hash2(hash2(hash2(Fr::zero(), first), second), fr("40"));
+    assert_eq!(Poseidon.hash_bytes(&data), expected);
+    assert_ne!(Poseidon.hash_bytes(b""), Poseidon.hash_bytes(&[0]));
+}
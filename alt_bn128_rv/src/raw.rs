@@ -0,0 +1,68 @@
+//! The internal representations behind the public wrapper types, for code
+//! that chains many group or field operations and cannot afford the
+//! conversions the wrappers perform at their boundaries.
+//!
+//! Invariants, which the types here do not check:
+//!
+//! - Field elements are in Montgomery form and reduced below the modulus.
+//!   All arithmetic preserves this, so it holds for any value built through
+//!   this module.
+//! - `G<P>` holds Jacobian coordinates `(X, Y, Z)` for the affine point
+//!   `(X / Z^2, Y / Z^3)`; any `Z == 0` is the point at infinity. A point
+//!   is not normalized after an operation: compare with `==`, which
+//!   cross-multiplies, rather than coordinate by coordinate.
+//! - `AffineG<P>::new` checks that the point is on the curve and, for G2,
+//!   in the prime-order subgroup; `new_on_curve` and the `_mut` accessors
+//!   do not.
+//!
+//! Every wrapper converts to and from its representation with `From`, at no
+//! cost. The wrappers keep the invariants above and nothing more, so a
+//! point that was valid before conversion is valid after.
+
+pub use crate::fields::{FieldElement, Fq, Fq12, Fq2, Fq6, Fr};
+pub use crate::groups::{
+    AffineG, AffineG1, AffineG2, G1Params, G2Params, GroupElement, GroupParams, G, G1, G2,
+};
+
+macro_rules! raw_conversions {
+    ($($wrapper:ident => $raw:ty),*) => {
+        $(
+            impl From<$raw> for crate::$wrapper {
+                fn from(raw: $raw) -> Self {
+                    crate::$wrapper(raw)
+                }
+            }
+
+            impl From<crate::$wrapper> for $raw {
+                fn from(wrapper: crate::$wrapper) -> Self {
+                    wrapper.0
+                }
+            }
+        )*
+    };
+}
+
+raw_conversions!(
+    Fr => Fr,
+    Fq => Fq,
+    Fq2 => Fq2,
+    G1 => G1,
+    G2 => G2,
+    AffineG1 => AffineG1,
+    AffineG2 => AffineG2,
+    Gt => Fq12
+);
+
+#[test]
+fn round_trip() {
+    use crate::Group;
+
+    let p = crate::G1::one() * crate::Fr::from_str("3").unwrap();
+    let raw: G1 = p.into();
+    assert_eq!(
+        crate::G1::from(raw.double() + raw),
+        p * crate::Fr::from_str("3").unwrap()
+    );
+    let q: G2 = crate::G2::one().into();
+    assert!(crate::G2::from(q.double()) == crate::G2::one() + crate::G2::one());
+}
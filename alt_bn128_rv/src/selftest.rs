@@ -0,0 +1,276 @@
+use crate::ethereum::{alt_bn128_pairing, read_pt};
+use crate::fields::{FieldElement, Fq12, Fq2, Fq6};
+use crate::groth16::{self, Mode, VerifyingKey};
+use crate::{fields, pairing, AffineG1, AffineG2, DecodePolicy, Fq, Fr, Group, Gt, G1, G2};
+use core::convert::TryInto;
+use core::fmt;
+
+/// Which known-answer check failed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SelfTestError {
+    FqArithmetic,
+    FrArithmetic,
+    G1Arithmetic,
+    G2Arithmetic,
+    GeneratorPairing,
+    Bilinearity,
+    PairingEquation,
+    Groth16,
+}
+
+impl fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SelfTestError::FqArithmetic => "Fq arithmetic known answer failed",
+            SelfTestError::FrArithmetic => "Fr arithmetic known answer failed",
+            SelfTestError::G1Arithmetic => "G1 arithmetic known answer failed",
+            SelfTestError::G2Arithmetic => "G2 arithmetic known answer failed",
+            SelfTestError::GeneratorPairing => "generator pairing known answer failed",
+            SelfTestError::Bilinearity => "pairing bilinearity check failed",
+            SelfTestError::PairingEquation => "pairing equation known answer failed",
+            SelfTestError::Groth16 => "Groth16 known answer failed",
+        })
+    }
+}
+
+impl core::error::Error for SelfTestError {}
+
+/// Decodes lowercase hex of exactly `2 * N` digits, at compile time for
+/// the vectors below.
+const fn hex<const N: usize>(s: &str) -> [u8; N] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+    let s = s.as_bytes();
+    assert!(s.len() == 2 * N, "wrong hex length");
+    let mut out = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = nibble(s[2 * i]) << 4 | nibble(s[2 * i + 1]);
+        i += 1;
+    }
+    out
+}
+
+// a = 12345678901234567890123456789012345678901234567890
+// b = 98765432109876543210987654321098765432109876543210
+const A: &str = "12345678901234567890123456789012345678901234567890";
+const B: &str = "98765432109876543210987654321098765432109876543210";
+const FQ_A_MUL_B: &str =
+    "1476025239850857531232078297827420122179349266213190746718804435654982072856";
+const FQ_A_INV: &str =
+    "8815414642653501070591600531690181003618523074229144734873928631237914622388";
+const FR_A_MUL_B: &str =
+    "1476025239850865772888313753328132807173985418210471644103231973181912135344";
+const FR_A_INV: &str =
+    "10646065283695455229323530529943769294509271489156732030190125980904671122754";
+
+// 2 * G1
+const G1_DOUBLE: [&str; 2] = [
+    "1368015179489954701390400359078579693043519447331113978918064868415326638035",
+    "9918110051302171585080402603319702774565515993150576347155970296011118125764",
+];
+
+// 2 * G2, as (x real, x imaginary, y real, y imaginary)
+const G2_DOUBLE: [&str; 4] = [
+    "18029695676650738226693292988307914797657423701064905010927197838374790804409",
+    "14583779054894525174450323658765874724019480979794335525732096752006891875705",
+    "2140229616977736810657479771656733941598412651537078903776637920509952744750",
+    "11474861747383700316476719153975578001603231366361248090558603872215261634898",
+];
+
+// e(G1, G2), Fq12 coefficients in tower order c0.c0.real, c0.c0.imaginary, ...
+const GENERATOR_PAIRING: [&str; 12] = [
+    "17264119758069723980713015158403419364912226240334615592005620718956030922389",
+    "1300711225518851207585954685848229181392358478699795190245709208408267917898",
+    "8894217292938489450175280157304813535227569267786222825147475294561798790624",
+    "1829859855596098509359522796979920150769875799037311140071969971193843357227",
+    "4968700049505451466697923764727215585075098085662966862137174841375779106779",
+    "12814315002058128940449527172080950701976819591738376253772993495204862218736",
+    "4233474252585134102088637248223601499779641130562251948384759786370563844606",
+    "9420544134055737381096389798327244442442230840902787283326002357297404128074",
+    "13457906610892676317612909831857663099224588803620954529514857102808143524905",
+    "5122435115068592725432309312491733755581898052459744089947319066829791570839",
+    "8891987925005301465158626530377582234132838601606565363865129986128301774627",
+    "440796048150724096437130979851431985500142692666486515369083499585648077975",
+];
+
+// A fixed two-pair pairing-product equation from the go-ethereum precompile
+// vectors (bn256Pairing.json, "jeff1"); the product must be one.
+const PAIRING_EQUATION: [u8; 384] = hex("1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f593034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf704bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a416782bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa");
+
+// A Groth16 verifying key with two public inputs, in the EVM encodings:
+// alpha_g1 || beta_g2 || gamma_g2 || delta_g2 || ic[0] || ic[1] || ic[2].
+// Key and proof were built from known discrete logarithms, which exercises
+// the verifier exactly like a key from a trusted setup.
+const GROTH16_VK: [u8; 640] = hex("1148454ceb3135d7f5e9bdc8fbba52d64e42a404a1b8eea375a2a1d121157ba603aa3734577dfd216373118d11f7fabd0e3894e448b078f962b6a4e490efd0b40fc68ffb6130c3e9c3a52507ce2c3299569c892261805b9c8d0ce0159539f58d2f07d672a5e4310c0d1348bb3cef24bdd754ccbeb929e8b25621671f4757fdbb24d8a0424f15d585885fc80d2ae3209edb40c506bbbda9703b32d0d370133bec16bdb31e82ae04159c355174031c0525cbd442b0912c002ec203731d3768b9472e413bd95d15a7d1e9af5250d81acf06b5df9ff69c949b560af4de5de15cf9ec1175995ab6ae8b8b431a2aff724b166cf78e9403d6afac37d79b56e9ebd475770edc925472d847a026e978e61cfe54b9be7f1deac23328dafe96f31929cbc14211d6ace25ee9a6c8e74956f694cb7ce18c602be6f0cfbe4873f99a944094c9b21e6f598e5252d2eadb8570ff2abfec41f8a2ee40f1ac602e8ba6d3fbe998104c03738c08c6e557b73c9abd25f3935741aa695339c7eb4b419d48637fa2fb959f10a2f0bf7910cdd2ad64e9f67e6977aa0b581649340237c262298d5c3a43cd8909207459f0e77abf83890d828d9226dc69ff8a4bfe6cc59426e29bedadf0bd892303b3046b54910485b30f94c3803d1f03c4fa9caa7e24eed8a194935bf5dce40a4507bc71356dc525b2de44ae9a1a050a969f792b3e5430cf96095ba73423551be92237c0ada516428ed761f876580257ff73688f5580104ee05a5f5fe33a89275258b354866a0aa516a79ad919df002e4853c5e1f95131c910a8a2bd1f3aad19ff663dbdf5921a193c77159174e369526541be5d14a1959e3bbeb0d9f9c46321864535370681397b2decee283bd5b685630c0c1e9b398aacee196599ad70ea");
+
+// A proof for GROTH16_INPUTS under GROTH16_VK, in the layout of
+// `groth16::Proof::from_bytes`.
+const GROTH16_PROOF: [u8; 256] = hex("14757bf57af8cc27f8e21eb62d7957dc910d3c30260a4568a3c31ed931743367131183561fa26efbfd917ea8484bd9683f9db83057d1bddda02ae10a9f29e73c017a7352902a9cfa7633909827b89d10712ce187fef7ca50f4e56fdfa434b3ae00e893959a45a5606d1c68515f16732a955ef4d80028b89c8ff9dd536a89fa6d1adbfc217a3359bc3303907b4c0508584f36c1fe042868de95c703723be4dfa20cbc1e8878138a63a936fef76e006a3654e0f2f567f0b3fd8ae56a22f4eb259c27ae20716c64bcc67ffeb876aaf5defe415c55f1730ab71c274587494b199cfd0d2c0ac49a57a30a8c0eb68aa62191305b3ff6f861459917a0e26d0e03e1519b");
+
+const GROTH16_INPUTS: [&str; 2] = ["12345678901234567890", "98765432109876543210"];
+
+fn fq(s: &str) -> Fq {
+    Fq::from_str(s).expect("embedded constant is a valid Fq; qed")
+}
+
+fn fr(s: &str) -> Fr {
+    Fr::from_str(s).expect("embedded constant is a valid Fr; qed")
+}
+
+fn check(ok: bool, err: SelfTestError) -> Result<(), SelfTestError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(err)
+    }
+}
+
+fn check_fq() -> Result<(), SelfTestError> {
+    let (a, b) = (fq(A), fq(B));
+    check(a * b == fq(FQ_A_MUL_B), SelfTestError::FqArithmetic)?;
+    check(
+        a.inverse() == Some(fq(FQ_A_INV)),
+        SelfTestError::FqArithmetic,
+    )?;
+    check(a + b - b == a, SelfTestError::FqArithmetic)?;
+    let c = a * a;
+    let root = c.sqrt().ok_or(SelfTestError::FqArithmetic)?;
+    check(root == a || root == -a, SelfTestError::FqArithmetic)
+}
+
+fn check_fr() -> Result<(), SelfTestError> {
+    let (a, b) = (fr(A), fr(B));
+    check(a * b == fr(FR_A_MUL_B), SelfTestError::FrArithmetic)?;
+    check(
+        a.inverse() == Some(fr(FR_A_INV)),
+        SelfTestError::FrArithmetic,
+    )?;
+    check(a + b - b == a, SelfTestError::FrArithmetic)
+}
+
+fn check_g1() -> Result<(), SelfTestError> {
+    let two = G1::one() * fr("2");
+    let expected = AffineG1::new(fq(G1_DOUBLE[0]), fq(G1_DOUBLE[1]))
+        .map_err(|_| SelfTestError::G1Arithmetic)?;
+    check(two == G1::from(expected), SelfTestError::G1Arithmetic)?;
+    check(two - G1::one() == G1::one(), SelfTestError::G1Arithmetic)?;
+    check(
+        (G1::one() * -Fr::one() + G1::one()).is_zero(),
+        SelfTestError::G1Arithmetic,
+    )
+}
+
+fn check_g2() -> Result<(), SelfTestError> {
+    let two = G2::one() * fr("2");
+    let expected = AffineG2::new(
+        crate::Fq2::new(fq(G2_DOUBLE[0]), fq(G2_DOUBLE[1])),
+        crate::Fq2::new(fq(G2_DOUBLE[2]), fq(G2_DOUBLE[3])),
+    )
+    .map_err(|_| SelfTestError::G2Arithmetic)?;
+    check(two == G2::from(expected), SelfTestError::G2Arithmetic)?;
+    check(two - G2::one() == G2::one(), SelfTestError::G2Arithmetic)?;
+    check(
+        (G2::one() * -Fr::one() + G2::one()).is_zero(),
+        SelfTestError::G2Arithmetic,
+    )
+}
+
+fn check_pairing() -> Result<(), SelfTestError> {
+    let c: [fields::Fq; 12] = {
+        let mut c = [fields::Fq::zero(); 12];
+        for (c, s) in c.iter_mut().zip(GENERATOR_PAIRING.iter()) {
+            *c = fq(s).0;
+        }
+        c
+    };
+    let expected = Gt(Fq12::new(
+        Fq6::new(
+            Fq2::new(c[0], c[1]),
+            Fq2::new(c[2], c[3]),
+            Fq2::new(c[4], c[5]),
+        ),
+        Fq6::new(
+            Fq2::new(c[6], c[7]),
+            Fq2::new(c[8], c[9]),
+            Fq2::new(c[10], c[11]),
+        ),
+    ));
+    let e = pairing(G1::one(), G2::one());
+    check(e == expected, SelfTestError::GeneratorPairing)?;
+
+    let (a, b) = (fr("6"), fr("35"));
+    let lhs = pairing(G1::one() * a, G2::one() * b);
+    check(lhs == e.pow(a * b), SelfTestError::Bilinearity)?;
+    check(
+        lhs == pairing(G1::one() * (a * b), G2::one()),
+        SelfTestError::Bilinearity,
+    )
+}
+
+fn check_pairing_equation() -> Result<(), SelfTestError> {
+    check(
+        alt_bn128_pairing(&PAIRING_EQUATION) == Ok(true),
+        SelfTestError::PairingEquation,
+    )
+}
+
+fn check_groth16() -> Result<(), SelfTestError> {
+    let vk = GROTH16_VK;
+    let g1 = |offset: usize| {
+        read_pt(&vk, offset)
+            .ok()
+            .and_then(AffineG1::from_jacobian)
+            .ok_or(SelfTestError::Groth16)
+    };
+    let g2 = |offset: usize| {
+        let bytes = vk[offset..offset + 128]
+            .try_into()
+            .expect("slice is 128 bytes; qed");
+        AffineG2::from_evm_slice(bytes).map_err(|_| SelfTestError::Groth16)
+    };
+    let ic = [g1(448)?, g1(512)?, g1(576)?];
+    let vk = VerifyingKey {
+        alpha_g1: g1(0)?,
+        beta_g2: g2(64)?,
+        gamma_g2: g2(192)?,
+        delta_g2: g2(320)?,
+        ic: &ic,
+    };
+    let proof = groth16::Proof::from_bytes(&GROTH16_PROOF, &DecodePolicy::default())
+        .map_err(|_| SelfTestError::Groth16)?;
+    let inputs = [fr(GROTH16_INPUTS[0]), fr(GROTH16_INPUTS[1])];
+    check(
+        groth16::verify(&vk, &proof, &inputs, Mode::Strict).is_ok(),
+        SelfTestError::Groth16,
+    )?;
+    check(
+        groth16::verify(&vk, &proof, &[inputs[1], inputs[0]], Mode::Strict)
+            == Err(groth16::Error::InvalidProof),
+        SelfTestError::Groth16,
+    )
+}
+
+/// Runs a handful of embedded known-answer checks over the field, group and
+/// pairing arithmetic, ending with a Groth16 verification. It is cheap
+/// enough (a few pairings) to run once at boot to catch a miscompiled or
+/// mis-selected arithmetic backend.
+pub fn self_test() -> Result<(), SelfTestError> {
+    check_fq()?;
+    check_fr()?;
+    check_g1()?;
+    check_g2()?;
+    check_pairing()?;
+    check_pairing_equation()?;
+    check_groth16()
+}
+
+#[test]
+fn self_test_passes() {
+    assert_eq!(self_test(), Ok(()));
+}
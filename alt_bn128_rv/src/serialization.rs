@@ -0,0 +1,301 @@
+//! `serde` support using the EIP-196/197 byte encodings: 32-byte
+//! big-endian field elements, `Fq2` as `imaginary || real`, points as
+//! `x || y` with all zeros for the point at infinity, and `Gt` in the
+//! format of `Gt::to_bytes`. Deserializing validates like the matching
+//! parsers: values must be canonical and points in the prime-order
+//! subgroup. `AffineG1` and `AffineG2` reject the point at infinity.
+//! That is `DecodePolicy::default()` for the affine types and
+//! `DecodePolicy::evm()` for `G1` and `G2`; serde has no way to pass
+//! another policy.
+//!
+//! Encodings are written with `serialize_bytes`; formats without a byte
+//! type, such as JSON, get an array of numbers.
+
+use crate::arith::U256;
+use crate::{AffineG1, AffineG2, CurveError, FieldError, Fq, Fq2, Fr, Group, Gt, G1, G2};
+use core::convert::TryInto;
+use core::fmt;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+struct Bytes<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for Bytes<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes", N)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<[u8; N], E> {
+        v.try_into().map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u8; N], A::Error> {
+        let mut out = [0u8; N];
+        for (i, b) in out.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+        Ok(out)
+    }
+}
+
+fn fq_bytes(x: Fq, out: &mut [u8]) {
+    x.to_big_endian(out).expect("slice is 32 bytes; qed");
+}
+
+fn g1_bytes(p: &AffineG1) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    fq_bytes(p.x(), &mut out[..32]);
+    fq_bytes(p.y(), &mut out[32..]);
+    out
+}
+
+fn g1_from_bytes(bytes: &[u8; 64]) -> Result<AffineG1, CurveError> {
+    let x = Fq::from_slice(&bytes[..32])?;
+    let y = Fq::from_slice(&bytes[32..])?;
+    AffineG1::new(x, y).map_err(|_| CurveError::NotMember)
+}
+
+fn g2_bytes(p: &AffineG2) -> [u8; 128] {
+    let mut out = [0u8; 128];
+    out[..64].copy_from_slice(&p.x().to_bytes_be_imag_first());
+    out[64..].copy_from_slice(&p.y().to_bytes_be_imag_first());
+    out
+}
+
+fn g2_from_bytes(bytes: &[u8; 128]) -> Result<AffineG2, CurveError> {
+    let x = Fq2::from_bytes_be_imag_first(&bytes[..64])?;
+    let y = Fq2::from_bytes_be_imag_first(&bytes[64..])?;
+    AffineG2::new(x, y).map_err(|_| CurveError::NotMember)
+}
+
+/// Implements both traits for `$t` through a `[u8; $n]` encoding, `$to`
+/// producing it and `$from` validating it.
+macro_rules! bytes_serde {
+    ($t:ty, $n:expr, $to:expr, $from:expr) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                let bytes: [u8; $n] = $to(self);
+                s.serialize_bytes(&bytes)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                let bytes = d.deserialize_bytes(Bytes::<$n>)?;
+                $from(&bytes).map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+bytes_serde!(
+    Fr,
+    32,
+    |x: &Fr| {
+        // not `Fr::to_big_endian`, which writes the Montgomery form
+        let mut out = [0u8; 32];
+        x.into_u256()
+            .to_big_endian(&mut out)
+            .expect("buffer is 32 bytes; qed");
+        out
+    },
+    |b: &[u8; 32]| U256::from_slice(b)
+        .ok()
+        .and_then(Fr::new)
+        .ok_or(FieldError::NotMember)
+);
+
+bytes_serde!(
+    Fq,
+    32,
+    |x: &Fq| {
+        let mut out = [0u8; 32];
+        fq_bytes(*x, &mut out);
+        out
+    },
+    |b: &[u8; 32]| Fq::from_slice(b)
+);
+
+bytes_serde!(
+    Fq2,
+    64,
+    |x: &Fq2| x.to_bytes_be_imag_first(),
+    |b: &[u8; 64]| Fq2::from_bytes_be_imag_first(b)
+);
+
+bytes_serde!(AffineG1, 64, g1_bytes, g1_from_bytes);
+
+bytes_serde!(AffineG2, 128, g2_bytes, g2_from_bytes);
+
+bytes_serde!(
+    G1,
+    64,
+    |p: &G1| AffineG1::from_jacobian(*p).map_or([0u8; 64], |p| g1_bytes(&p)),
+    |b: &[u8; 64]| if *b == [0u8; 64] {
+        Ok(G1::zero())
+    } else {
+        g1_from_bytes(b).map(G1::from)
+    }
+);
+
+bytes_serde!(
+    G2,
+    128,
+    |p: &G2| AffineG2::from_jacobian(*p).map_or([0u8; 128], |p| g2_bytes(&p)),
+    |b: &[u8; 128]| if *b == [0u8; 128] {
+        Ok(G2::zero())
+    } else {
+        g2_from_bytes(b).map(G2::from)
+    }
+);
+
+bytes_serde!(
+    Gt,
+    384,
+    |x: &Gt| {
+        let mut out = [0u8; 384];
+        x.to_bytes(&mut out);
+        out
+    },
+    |b: &[u8; 384]| Gt::from_slice(b)
+);
+
+#[cfg(test)]
+fn round_trip<T>(x: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq,
+{
+    let bytes = bincode::serialize(x).unwrap();
+    assert!(bincode::deserialize::<T>(&bytes).unwrap() == *x);
+    let json = serde_json::to_string(x).unwrap();
+    assert!(serde_json::from_str::<T>(&json).unwrap() == *x);
+}
+
+/// Deserializes `bytes` as `T` through both a byte format and JSON.
+#[cfg(test)]
+fn decode<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> [Option<T>; 2] {
+    [
+        bincode::deserialize::<T>(&bincode::serialize(bytes).unwrap()).ok(),
+        serde_json::from_str::<T>(&serde_json::to_string(bytes).unwrap()).ok(),
+    ]
+}
+
+#[test]
+fn serde_round_trips() {
+    let fr = |s: &str| Fr::from_str(s).unwrap();
+    let fq = |s: &str| Fq::from_str(s).unwrap();
+    round_trip(&fr("12345678901234567890"));
+    round_trip(&-Fr::one());
+    round_trip(&fq("98765432109876543210"));
+    round_trip(&-Fq::one());
+    round_trip(&Fq2::new(fq("3"), -fq("5")));
+    round_trip(&AffineG1::from_jacobian(G1::one() * fr("7")).unwrap());
+    round_trip(&AffineG2::from_jacobian(G2::one() * fr("11")).unwrap());
+    round_trip(&(G1::one() * fr("13")));
+    round_trip(&G1::zero());
+    round_trip(&(G2::one() * fr("17")));
+    round_trip(&G2::zero());
+    round_trip(&crate::pairing(G1::one(), G2::one() * fr("19")));
+    round_trip(&Gt::one());
+
+    // the bytes are the EVM encodings
+    let p = G1::one() * fr("13");
+    let bytes = bincode::serialize(&p).unwrap();
+    assert_eq!(bytes[8..], crate::ethereum::encode_g1(p));
+    let q = AffineG2::from_jacobian(G2::one() * fr("11")).unwrap();
+    let bytes = bincode::serialize(&q).unwrap();
+    assert_eq!(bytes[8..], q.to_evm_bytes());
+}
+
+#[test]
+fn serde_rejects_invalid_values() {
+    let mut p = [0u8; 32];
+    Fq::modulus().to_big_endian(&mut p).unwrap();
+    let mut r = [0u8; 32];
+    crate::fields::Fr::modulus().to_big_endian(&mut r).unwrap();
+
+    // non-canonical field elements
+    assert!(decode::<Fq>(&p) == [None, None]);
+    assert!(decode::<Fr>(&r) == [None, None]);
+    let mut fq2 = [0u8; 64];
+    fq2[32..].copy_from_slice(&p);
+    assert!(decode::<Fq2>(&fq2) == [None, None]);
+
+    // a non-canonical coordinate of an otherwise valid point: x + p
+    let g = crate::ethereum::encode_g1(G1::one());
+    let mut shifted = g;
+    U256::from_slice(&g[..32])
+        .unwrap()
+        .checked_add(&Fq::modulus())
+        .unwrap()
+        .to_big_endian(&mut shifted[..32])
+        .unwrap();
+    assert!(decode::<G1>(&g) == [Some(G1::one()), Some(G1::one())]);
+    assert!(decode::<G1>(&shifted) == [None, None]);
+    assert!(decode::<AffineG1>(&shifted) == [None, None]);
+
+    // off the curve
+    let mut off = [0u8; 64];
+    off[31] = 1;
+    off[63] = 1;
+    assert!(decode::<G1>(&off) == [None, None]);
+    assert!(decode::<AffineG1>(&off) == [None, None]);
+
+    // on the twist but outside the prime-order subgroup
+    let mut x = Fq2::one();
+    let y = loop {
+        if let Some(y) = (x * x * x + G2::b()).sqrt() {
+            break y;
+        }
+        x += Fq2::one();
+    };
+    assert!(AffineG2::new(x, y).is_err());
+    let mut twist = [0u8; 128];
+    twist[..64].copy_from_slice(&x.to_evm_bytes());
+    twist[64..].copy_from_slice(&y.to_evm_bytes());
+    assert!(decode::<G2>(&twist) == [None, None]);
+    assert!(decode::<AffineG2>(&twist) == [None, None]);
+
+    // all zeros is infinity for the projective types only
+    assert!(decode::<G1>(&[0u8; 64]) == [Some(G1::zero()), Some(G1::zero())]);
+    assert!(decode::<G2>(&[0u8; 128]) == [Some(G2::zero()), Some(G2::zero())]);
+    assert!(decode::<AffineG1>(&[0u8; 64]) == [None, None]);
+    assert!(decode::<AffineG2>(&[0u8; 128]) == [None, None]);
+
+    // zero is not in the target group
+    assert!(decode::<Gt>(&[0u8; 384]) == [None, None]);
+}
+
+#[test]
+fn serde_rejects_wrong_lengths() {
+    let one = crate::ethereum::encode_g1(G1::one());
+    for len in [0, 31, 33] {
+        assert!(decode::<Fr>(&[0u8; 33][..len]) == [None, None]);
+    }
+    assert!(decode::<G1>(&one[..63]) == [None, None]);
+    let mut long = [0u8; 65];
+    long[..64].copy_from_slice(&one);
+    assert!(decode::<G1>(&long) == [None, None]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn serde_length_messages() {
+    use alloc::string::ToString;
+
+    let short = serde_json::from_str::<Fr>("[1, 2, 3]").unwrap_err().to_string();
+    assert!(short.contains("invalid length 3, expected 32 bytes"), "{}", short);
+    let json = serde_json::to_string(&[0u8; 33][..]).unwrap();
+    let long = serde_json::from_str::<Fq>(&json).unwrap_err().to_string();
+    assert!(long.contains("invalid length 33, expected 32 bytes"), "{}", long);
+    let bytes = bincode::serialize(&[0u8; 31][..]).unwrap();
+    let error = bincode::deserialize::<Fq>(&bytes).unwrap_err().to_string();
+    assert!(error.contains("invalid length 31, expected 32 bytes"), "{}", error);
+}
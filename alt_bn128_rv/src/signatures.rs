@@ -0,0 +1,4 @@
+//! Signature schemes built from the primitives of this crate.
+
+pub mod bls;
+pub mod schnorr;
@@ -0,0 +1,191 @@
+//! Schnorr signatures over G1: with `pk = sk * g1`, a signature on `m` is
+//! `(R, s)` with `R = k * g1` and `s = k + c * sk` for the challenge
+//! `c = H(R, pk, m)`, and verifies if `s * g1 == R + c * pk`. Verification
+//! costs two scalar multiplications and no pairings.
+//!
+//! The nonce `k` is derived from the secret key and the message as in
+//! RFC 6979, section 3.2, with HMAC-SHA-256 and `q = r`, so signing needs
+//! no randomness and the same message always gets the same signature.
+//! The challenge is squeezed from a `Transcript`.
+
+use crate::arith::U256;
+use crate::transcript::Transcript;
+use crate::{fields, AffineG1, EntropySource, Fr, G1PrecomputedTable, Group, G1};
+use core::fmt;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidSignature,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidSignature => f.write_str("invalid signature"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct SecretKey(Fr);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub AffineG1);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub r: G1,
+    pub s: Fr,
+}
+
+/// HMAC-SHA-256 of the concatenation of `parts`.
+fn hmac(key: &[u8; 32], parts: &[&[u8]]) -> [u8; 32] {
+    let mut pad = [0x36u8; 64];
+    for (p, k) in pad.iter_mut().zip(key.iter()) {
+        *p ^= k;
+    }
+    let mut inner = Sha256::new();
+    inner.update(pad);
+    for part in parts {
+        inner.update(part);
+    }
+    for p in pad.iter_mut() {
+        *p ^= 0x36 ^ 0x5c;
+    }
+    let mut outer = Sha256::new();
+    outer.update(pad);
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+/// RFC 6979 `bits2int` for the 254-bit r: the top 254 bits of `bytes`.
+fn bits2int(bytes: &[u8; 32]) -> U256 {
+    let mut x = U256::from_slice(bytes).expect("slice is 32 bytes; qed");
+    x.0[0] = (x.0[0] >> 2) | (x.0[1] << 126);
+    x.0[1] >>= 2;
+    x
+}
+
+fn be_bytes(x: &U256) -> [u8; 32] {
+    let mut buf = [0u8; 32];
+    x.to_big_endian(&mut buf).expect("buffer is 32 bytes; qed");
+    buf
+}
+
+/// The RFC 6979 nonce for `sk` and the SHA-256 digest of the message.
+fn nonce(sk: &Fr, digest: &[u8; 32]) -> Fr {
+    let x = be_bytes(&sk.into_u256());
+    let h = bits2int(digest) % fields::Fr::modulus();
+    let h = be_bytes(&h);
+    let mut v = [0x01u8; 32];
+    let mut k = [0x00u8; 32];
+    k = hmac(&k, &[&v, &[0x00], &x, &h]);
+    v = hmac(&k, &[&v]);
+    k = hmac(&k, &[&v, &[0x01], &x, &h]);
+    v = hmac(&k, &[&v]);
+    loop {
+        // one HMAC output covers the 254 bits of r
+        v = hmac(&k, &[&v]);
+        match Fr::new(bits2int(&v)) {
+            Some(nonce) if !nonce.is_zero() => return nonce,
+            _ => {
+                k = hmac(&k, &[&v, &[0x00]]);
+                v = hmac(&k, &[&v]);
+            }
+        }
+    }
+}
+
+fn challenge(r: &G1, public_key: &PublicKey, message: &[u8]) -> Fr {
+    let mut t = Transcript::new(b"alt_bn128 schnorr v1");
+    t.append_g1(b"r", r);
+    t.append_g1(b"pk", &public_key.0.into());
+    t.append_message(b"message", message);
+    t.challenge_scalar(b"c")
+}
+
+impl SecretKey {
+    /// `None` for zero, whose public key would be the identity.
+    pub fn new(sk: Fr) -> Option<Self> {
+        if sk.is_zero() {
+            return None;
+        }
+        Some(SecretKey(sk))
+    }
+
+    pub fn random<E: EntropySource + ?Sized>(rng: &mut E) -> Self {
+        loop {
+            if let Some(sk) = Self::new(Fr::random(rng)) {
+                return sk;
+            }
+        }
+    }
+
+    /// `sk * g1`, in constant time: `sign` derives it on every call.
+    pub fn public_key(&self) -> PublicKey {
+        let pk = G1::one().mul_ct(self.0);
+        PublicKey(AffineG1::from_jacobian(pk).expect("secret key is nonzero; qed"))
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        let k = nonce(&self.0, &Sha256::digest(message).into());
+        let r = G1::one().mul_ct(k);
+        let c = challenge(&r, &self.public_key(), message);
+        Signature {
+            r,
+            s: k + c * self.0,
+        }
+    }
+}
+
+/// Checks `s * g1 == R + c * pk`.
+pub fn verify(public_key: &PublicKey, message: &[u8], signature: &Signature) -> Result<(), Error> {
+    let c = challenge(&signature.r, public_key, message);
+    let lhs = G1PrecomputedTable::generator().mul(signature.s);
+    if lhs == signature.r + G1::from(public_key.0) * c {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+#[test]
+fn sign_and_verify() {
+    let sk = SecretKey::new(Fr::from_str("31415926535").unwrap()).unwrap();
+    let pk = sk.public_key();
+    assert_eq!(G1::from(pk.0), G1::one() * sk.0);
+    let sig = sk.sign(b"transfer 10");
+    assert_eq!(verify(&pk, b"transfer 10", &sig), Ok(()));
+    // deterministic, and the nonce depends on the message
+    assert_eq!(sk.sign(b"transfer 10"), sig);
+    assert_ne!(sk.sign(b"transfer 11").r, sig.r);
+    assert_eq!(
+        verify(&pk, b"transfer 11", &sig),
+        Err(Error::InvalidSignature)
+    );
+    let other = SecretKey::new(Fr::from_str("2").unwrap()).unwrap();
+    assert_eq!(
+        verify(&other.public_key(), b"transfer 10", &sig),
+        Err(Error::InvalidSignature)
+    );
+    let forged = Signature {
+        r: sig.r + G1::one(),
+        s: sig.s + Fr::one(),
+    };
+    assert_eq!(
+        verify(&pk, b"transfer 10", &forged),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn hmac_sha256() {
+    // RFC 4231, test case 2, with the key zero-padded to 32 bytes
+    let mut key = [0u8; 32];
+    key[..4].copy_from_slice(b"Jefe");
+    let mac = hmac(&key, &[b"what do ya want ", b"for nothing?"]);
+    assert_eq!(mac[..8], [0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e]);
+}
@@ -0,0 +1,163 @@
+//! Decimal string coordinates in the layout of snarkjs JSON files: a G1
+//! point is `["x", "y", "1"]` and a G2 point
+//! `[["x.real", "x.imaginary"], ["y.real", "y.imaginary"], ["1", "0"]]`.
+//!
+//! The parsers are stricter than `Fq::from_str`: numbers must be
+//! non-empty, plain decimal and below the modulus, and the z coordinate
+//! must be one.
+
+use crate::arith::U256;
+use crate::{AffineG1, AffineG2, CurveError, Fq, Fq2, Fr};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Parses a canonical decimal number below 2^256.
+fn parse_decimal(s: &str) -> Option<U256> {
+    if s.is_empty() {
+        return None;
+    }
+    let mut limbs = [0u64; 4];
+    for c in s.bytes() {
+        let mut carry = match c {
+            b'0'..=b'9' => (c - b'0') as u128,
+            _ => return None,
+        };
+        for limb in limbs.iter_mut() {
+            let t = *limb as u128 * 10 + carry;
+            *limb = t as u64;
+            carry = t >> 64;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+    Some(U256([
+        limbs[0] as u128 | (limbs[1] as u128) << 64,
+        limbs[2] as u128 | (limbs[3] as u128) << 64,
+    ]))
+}
+
+#[cfg(feature = "alloc")]
+fn to_decimal(x: U256) -> String {
+    let mut limbs = [
+        x.0[0] as u64,
+        (x.0[0] >> 64) as u64,
+        x.0[1] as u64,
+        (x.0[1] >> 64) as u64,
+    ];
+    // 2^256 has 78 decimal digits
+    let mut digits = [0u8; 78];
+    let mut len = 0;
+    loop {
+        let mut rem = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let t = rem << 64 | *limb as u128;
+            *limb = (t / 10) as u64;
+            rem = t % 10;
+        }
+        digits[len] = b'0' + rem as u8;
+        len += 1;
+        if limbs.iter().all(|&l| l == 0) {
+            break;
+        }
+    }
+    digits[..len].iter().rev().map(|&d| d as char).collect()
+}
+
+fn parse_fq(s: &str) -> Result<Fq, CurveError> {
+    let x = parse_decimal(s).ok_or(CurveError::InvalidEncoding)?;
+    Fq::from_u256(x).map_err(|_| CurveError::InvalidEncoding)
+}
+
+fn parse_fq2<S: AsRef<str>>(c: &[S; 2]) -> Result<Fq2, CurveError> {
+    Ok(Fq2::new(parse_fq(c[0].as_ref())?, parse_fq(c[1].as_ref())?))
+}
+
+impl Fr {
+    /// Parses a canonical decimal scalar, as in snarkjs public signals.
+    pub fn from_decimal_str(s: &str) -> Option<Self> {
+        parse_decimal(s).and_then(Fr::new)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_decimal_string(&self) -> String {
+        to_decimal(self.into_u256())
+    }
+}
+
+impl AffineG1 {
+    pub fn from_decimal_strings<S: AsRef<str>>(coords: &[S; 3]) -> Result<Self, CurveError> {
+        if parse_fq(coords[2].as_ref())? != Fq::one() {
+            return Err(CurveError::InvalidEncoding);
+        }
+        let x = parse_fq(coords[0].as_ref())?;
+        let y = parse_fq(coords[1].as_ref())?;
+        AffineG1::new(x, y).map_err(|_| CurveError::NotMember)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_decimal_strings(&self) -> [String; 3] {
+        [
+            to_decimal(self.x().into_u256()),
+            to_decimal(self.y().into_u256()),
+            String::from("1"),
+        ]
+    }
+}
+
+impl AffineG2 {
+    pub fn from_decimal_strings<S: AsRef<str>>(coords: &[[S; 2]; 3]) -> Result<Self, CurveError> {
+        if parse_fq2(&coords[2])? != Fq2::one() {
+            return Err(CurveError::InvalidEncoding);
+        }
+        let x = parse_fq2(&coords[0])?;
+        let y = parse_fq2(&coords[1])?;
+        AffineG2::new(x, y).map_err(|_| CurveError::NotMember)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_decimal_strings(&self) -> [[String; 2]; 3] {
+        let fq2 = |c: Fq2| {
+            [
+                to_decimal(c.real().into_u256()),
+                to_decimal(c.imaginary().into_u256()),
+            ]
+        };
+        [
+            fq2(self.x()),
+            fq2(self.y()),
+            [String::from("1"), String::from("0")],
+        ]
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decimal_round_trip() {
+    use crate::{Group, G2};
+
+    let p = AffineG2::from_jacobian(G2::one()).unwrap();
+    let strings = p.to_decimal_strings();
+    assert_eq!(
+        strings[0][0],
+        "10857046999023057135944570762232829481370756359578518086990519993285655852781"
+    );
+    assert!(AffineG2::from_decimal_strings(&strings).ok() == Some(p));
+
+    let g = AffineG1::generator();
+    assert_eq!(g.to_decimal_strings(), ["1", "2", "1"]);
+    assert_eq!(
+        AffineG1::from_decimal_strings(&["1", "2", "1"]).ok(),
+        Some(g)
+    );
+    assert!(AffineG1::from_decimal_strings(&["1", "2", "2"]).is_err());
+
+    let r_minus_1 = "21888242871839275222246405745257275088548364400416034343698204186575808495616";
+    assert_eq!(-Fr::one(), Fr::from_decimal_str(r_minus_1).unwrap());
+    assert_eq!((-Fr::one()).to_decimal_string(), r_minus_1);
+    assert!(Fr::from_decimal_str(
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617"
+    )
+    .is_none());
+    assert!(Fr::from_decimal_str("").is_none());
+}
@@ -0,0 +1,199 @@
+//! An elliptic curve VRF on G1, after RFC 9381: the output for input
+//! `alpha` under `pk = sk * g1` derives from `gamma = sk * H(pk, alpha)`,
+//! and the proof is `gamma` with a DLEQ proof that
+//! `log_g1(pk) == log_H(gamma)`. Anyone holding `pk` can check that the
+//! output is the unique one for `alpha`; nobody without `sk` can predict
+//! it.
+//!
+//! `H(pk, alpha)` is `hash_to_curve_g1(SHA-256(pk || alpha), H2C_DST)`
+//! with `pk` compressed. The nonce is `hash_to_field` of `sk || H` under
+//! `NONCE_DST`, so proving is deterministic. Proofs encode as 97 bytes:
+//! compressed `gamma`, then `c` and `s` big-endian.
+
+use crate::arith::U256;
+use crate::dleq;
+use crate::hash_to_curve::hash_to_curve_g1;
+use crate::{AffineG1, EntropySource, Fr, Group, G1};
+use core::fmt;
+use sha2::{Digest, Sha256};
+
+pub const H2C_DST: &[u8] = b"alt_bn128 vrf v1 hash_to_curve";
+pub const NONCE_DST: &[u8] = b"alt_bn128 vrf v1 nonce";
+const SUITE: &[u8] = b"alt_bn128 vrf v1";
+
+pub const PROOF_BYTES: usize = 97;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The proof bytes are not a canonical encoding.
+    Encoding,
+    InvalidProof,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::Encoding => "malformed proof encoding",
+            Error::InvalidProof => "invalid proof",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct SecretKey(Fr);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub AffineG1);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub gamma: AffineG1,
+    pub dleq: dleq::Proof,
+}
+
+fn hash_to_point(public_key: &PublicKey, alpha: &[u8]) -> G1 {
+    let mut h = Sha256::new();
+    h.update(public_key.0.to_compressed());
+    h.update(alpha);
+    let digest: [u8; 32] = h.finalize().into();
+    hash_to_curve_g1(&digest, H2C_DST)
+}
+
+fn scalar_bytes(x: &Fr) -> [u8; 32] {
+    let mut buf = [0u8; 32];
+    x.into_u256()
+        .to_big_endian(&mut buf)
+        .expect("buffer is 32 bytes; qed");
+    buf
+}
+
+fn scalar_from_bytes(bytes: &[u8]) -> Result<Fr, Error> {
+    let x = U256::from_slice(bytes).map_err(|_| Error::Encoding)?;
+    Fr::new(x).ok_or(Error::Encoding)
+}
+
+impl SecretKey {
+    /// `None` for zero, whose public key would be the identity.
+    pub fn new(sk: Fr) -> Option<Self> {
+        if sk.is_zero() {
+            return None;
+        }
+        Some(SecretKey(sk))
+    }
+
+    pub fn random<E: EntropySource + ?Sized>(rng: &mut E) -> Self {
+        loop {
+            if let Some(sk) = Self::new(Fr::random(rng)) {
+                return sk;
+            }
+        }
+    }
+
+    /// `sk * g1`, in constant time.
+    pub fn public_key(&self) -> PublicKey {
+        let pk = G1::one().mul_ct(self.0);
+        PublicKey(AffineG1::from_jacobian(pk).expect("secret key is nonzero; qed"))
+    }
+
+    pub fn prove(&self, alpha: &[u8]) -> Proof {
+        let public_key = self.public_key();
+        let h = hash_to_point(&public_key, alpha);
+        let h_affine = AffineG1::from_jacobian(h).expect("hash_to_curve output is finite; qed");
+        let mut msg = [0u8; 65];
+        msg[..32].copy_from_slice(&scalar_bytes(&self.0));
+        msg[32..].copy_from_slice(&h_affine.to_compressed());
+        let mut nonce = [Fr::zero()];
+        Fr::hash_to_field(&msg, NONCE_DST, &mut nonce)
+            .expect("one scalar is a valid output length; qed");
+        let gamma = h.mul_ct(self.0);
+        Proof {
+            gamma: AffineG1::from_jacobian(gamma)
+                .expect("secret key is nonzero and G1 has prime order; qed"),
+            dleq: dleq::prove(
+                G1::one(),
+                h,
+                public_key.0.into(),
+                gamma,
+                self.0,
+                nonce[0],
+            ),
+        }
+    }
+}
+
+impl Proof {
+    pub fn to_bytes(&self) -> [u8; PROOF_BYTES] {
+        let mut out = [0u8; PROOF_BYTES];
+        out[..33].copy_from_slice(&self.gamma.to_compressed());
+        out[33..65].copy_from_slice(&scalar_bytes(&self.dleq.c));
+        out[65..].copy_from_slice(&scalar_bytes(&self.dleq.s));
+        out
+    }
+
+    /// Inverse of `to_bytes`, rejecting every other encoding.
+    pub fn from_bytes(bytes: &[u8; PROOF_BYTES]) -> Result<Proof, Error> {
+        let gamma = G1::from_compressed(&bytes[..33]).map_err(|_| Error::Encoding)?;
+        Ok(Proof {
+            gamma: AffineG1::from_jacobian(gamma).ok_or(Error::Encoding)?,
+            dleq: dleq::Proof {
+                c: scalar_from_bytes(&bytes[33..65])?,
+                s: scalar_from_bytes(&bytes[65..])?,
+            },
+        })
+    }
+}
+
+/// The VRF output of a proof, `SHA-256(suite || 0x03 || gamma || 0x00)`.
+/// Only meaningful once the proof has been verified.
+pub fn proof_to_hash(proof: &Proof) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(SUITE);
+    h.update([0x03]);
+    h.update(proof.gamma.to_compressed());
+    h.update([0x00]);
+    h.finalize().into()
+}
+
+/// Checks `proof` for `alpha` under `public_key` and returns the output.
+pub fn verify(public_key: &PublicKey, alpha: &[u8], proof: &Proof) -> Result<[u8; 32], Error> {
+    let h = hash_to_point(public_key, alpha);
+    if dleq::verify(
+        G1::one(),
+        h,
+        public_key.0.into(),
+        proof.gamma.into(),
+        &proof.dleq,
+    ) {
+        Ok(proof_to_hash(proof))
+    } else {
+        Err(Error::InvalidProof)
+    }
+}
+
+#[test]
+fn prove_and_verify() {
+    let sk = SecretKey::new(Fr::from_str("271828182845904523536").unwrap()).unwrap();
+    let pk = sk.public_key();
+    assert_eq!(G1::from(pk.0), G1::one() * sk.0);
+    let proof = sk.prove(b"round 12");
+    let beta = verify(&pk, b"round 12", &proof).unwrap();
+    assert_eq!(beta, proof_to_hash(&proof));
+    assert_eq!(sk.prove(b"round 12"), proof);
+    assert_ne!(proof_to_hash(&sk.prove(b"round 13")), beta);
+    assert_eq!(verify(&pk, b"round 13", &proof), Err(Error::InvalidProof));
+
+    let bytes = proof.to_bytes();
+    assert_eq!(Proof::from_bytes(&bytes), Ok(proof));
+    let mut high = bytes;
+    high[65..].copy_from_slice(&[0xff; 32]);
+    assert_eq!(Proof::from_bytes(&high), Err(Error::Encoding));
+
+    // a different gamma with a valid encoding fails the DLEQ check
+    let other = Proof {
+        gamma: AffineG1::generator(),
+        dleq: proof.dleq,
+    };
+    assert_eq!(verify(&pk, b"round 12", &other), Err(Error::InvalidProof));
+}
@@ -0,0 +1,44 @@
+//! `zeroize::Zeroize` for secret-bearing values, wiping the Montgomery-form
+//! limbs in place; points become all-zero coordinates. The types are `Copy`
+//! and cannot implement `Drop`, so `ZeroizeOnDrop` is not available: hold
+//! long-lived secrets in `zeroize::Zeroizing<Fr>` to wipe them on drop, and
+//! keep in mind that copies made along the way are not wiped.
+
+use crate::groups::{AffineG, GroupParams, G};
+use crate::{AffineG1, AffineG2, Fq, Fq2, Fr, G1, G2};
+use zeroize::Zeroize;
+
+impl<P: GroupParams> Zeroize for G<P>
+where
+    P::Base: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.x_mut().zeroize();
+        self.y_mut().zeroize();
+        self.z_mut().zeroize();
+    }
+}
+
+impl<P: GroupParams> Zeroize for AffineG<P>
+where
+    P::Base: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.x_mut().zeroize();
+        self.y_mut().zeroize();
+    }
+}
+
+macro_rules! wrapper_zeroize {
+    ($($t:ty),*) => {
+        $(
+            impl Zeroize for $t {
+                fn zeroize(&mut self) {
+                    self.0.zeroize();
+                }
+            }
+        )*
+    };
+}
+
+wrapper_zeroize!(Fr, Fq, Fq2, G1, G2, AffineG1, AffineG2);
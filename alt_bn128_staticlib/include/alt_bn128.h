@@ -0,0 +1,40 @@
+#ifndef ALT_BN128_H
+#define ALT_BN128_H
+
+#include <stdint.h>
+
+/* The EIP-196/197 precompiles. Return 0 on success. */
+uint32_t alt_bn128_add(const uint8_t* data, uint32_t data_len, uint8_t* output);
+uint32_t alt_bn128_mul(const uint8_t* data, uint32_t data_len, uint8_t* output);
+uint32_t alt_bn128_pairing(const uint8_t* data, uint32_t data_len, uint8_t* output);
+
+/*
+ * Field and group operations over fixed-size big-endian buffers: 32 bytes
+ * per field element or scalar, 64 bytes per G1 point (x || y) and 128
+ * bytes per G2 point (x || y, each imaginary part first). All zeros is the
+ * point at infinity. Outputs may overlap inputs and are written only on
+ * success.
+ */
+#define ALT_BN128_OK 0
+#define ALT_BN128_INVALID_INPUT 1
+#define ALT_BN128_PAIRING_FAILED 2
+#define ALT_BN128_NOT_INVERTIBLE 3
+
+uint32_t alt_bn128_fr_add(const uint8_t* a, const uint8_t* b, uint8_t* out);
+uint32_t alt_bn128_fr_sub(const uint8_t* a, const uint8_t* b, uint8_t* out);
+uint32_t alt_bn128_fr_mul(const uint8_t* a, const uint8_t* b, uint8_t* out);
+uint32_t alt_bn128_fr_inverse(const uint8_t* a, uint8_t* out);
+uint32_t alt_bn128_fq_add(const uint8_t* a, const uint8_t* b, uint8_t* out);
+uint32_t alt_bn128_fq_sub(const uint8_t* a, const uint8_t* b, uint8_t* out);
+uint32_t alt_bn128_fq_mul(const uint8_t* a, const uint8_t* b, uint8_t* out);
+uint32_t alt_bn128_fq_inverse(const uint8_t* a, uint8_t* out);
+
+uint32_t alt_bn128_g1_add(const uint8_t* a, const uint8_t* b, uint8_t* out);
+uint32_t alt_bn128_g1_mul(const uint8_t* p, const uint8_t* scalar, uint8_t* out);
+uint32_t alt_bn128_g2_add(const uint8_t* a, const uint8_t* b, uint8_t* out);
+uint32_t alt_bn128_g2_mul(const uint8_t* p, const uint8_t* scalar, uint8_t* out);
+
+/* ALT_BN128_OK if the product of the pairings of the data_len / 192 pairs is one. */
+uint32_t alt_bn128_pairing_check(const uint8_t* data, uint32_t data_len);
+
+#endif
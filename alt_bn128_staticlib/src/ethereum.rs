@@ -1,53 +1,65 @@
+unsafe fn input<'a>(data: *const u8, data_len: u32) -> &'a [u8] {
+    if data_len == 0 {
+        &[]
+    } else {
+        core::slice::from_raw_parts(data, data_len as usize)
+    }
+}
+
+/// The ECADD precompile. Returns 0 and writes the 64-byte sum on success.
+///
+/// # Safety
+///
+/// `data` must be readable for `data_len` bytes; it may be null if
+/// `data_len` is zero. `output` must be writable for 64 bytes.
 #[no_mangle]
-pub extern "C" fn alt_bn128_add(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
-    unsafe {
-        let mut buf0 = [0u8; 128];
-        for i in 0..if data_len > 128 { 128 } else { data_len } {
-            buf0[i as usize] = *data.offset(i as isize);
-        }
-        let mut buf1 = [0u8; 64];
-        if let Err(_) = alt_bn128::ethereum::alt_bn128_add(&buf0, &mut buf1) {
-            return 1;
-        }
-        for i in 0..64 {
-            output.offset(i as isize).write(buf1[i as usize]);
+pub unsafe extern "C" fn alt_bn128_add(data: *const u8, data_len: u32, output: *mut u8) -> u32 {
+    match alt_bn128::ethereum::alt_bn128_add(input(data, data_len)) {
+        Ok(buf) => {
+            core::ptr::copy_nonoverlapping(buf.as_ptr(), output, 64);
+            0
         }
-        return 0;
+        Err(_) => 1,
     }
 }
 
+/// The ECMUL precompile. Returns 0 and writes the 64-byte product on
+/// success.
+///
+/// # Safety
+///
+/// `data` must be readable for `data_len` bytes; it may be null if
+/// `data_len` is zero. `output` must be writable for 64 bytes.
 #[no_mangle]
-pub extern "C" fn alt_bn128_mul(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
-    unsafe {
-        let mut buf0 = [0u8; 96];
-        for i in 0..if data_len > 96 { 96 } else { data_len } {
-            buf0[i as usize] = *data.offset(i as isize);
+pub unsafe extern "C" fn alt_bn128_mul(data: *const u8, data_len: u32, output: *mut u8) -> u32 {
+    match alt_bn128::ethereum::alt_bn128_mul(input(data, data_len)) {
+        Ok(buf) => {
+            core::ptr::copy_nonoverlapping(buf.as_ptr(), output, 64);
+            0
         }
-        let mut buf1 = [0u8; 64];
-        if let Err(_) = alt_bn128::ethereum::alt_bn128_mul(&buf0, &mut buf1) {
-            return 1;
-        }
-        for i in 0..64 {
-            output.offset(i as isize).write(buf1[i as usize]);
-        }
-        return 0;
+        Err(_) => 1,
     }
 }
 
+/// The ECPAIRING precompile. Returns 0 and writes the 32-byte result word
+/// on success.
+///
+/// # Safety
+///
+/// `data` must be readable for `data_len` bytes; it may be null if
+/// `data_len` is zero. `output` must be writable for 32 bytes.
 #[no_mangle]
-pub extern "C" fn alt_bn128_pairing(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
-    unsafe {
-        let mut buf0 = [0u8; 3072];
-        for i in 0..if data_len > 3072 { 3072 } else { data_len } {
-            buf0[i as usize] = *data.offset(i as isize);
-        }
-        let mut buf1 = [0u8; 32];
-        if let Err(_) = alt_bn128::ethereum::alt_bn128_pairing(&buf0, &mut buf1) {
-            return 1;
-        }
-        for i in 0..32 {
-            output.offset(i as isize).write(buf1[i as usize]);
+pub unsafe extern "C" fn alt_bn128_pairing(
+    data: *const u8,
+    data_len: u32,
+    output: *mut u8,
+) -> u32 {
+    match alt_bn128::ethereum::alt_bn128_pairing(input(data, data_len)) {
+        Ok(ok) => {
+            let buf = alt_bn128::ethereum::pairing_output(ok);
+            core::ptr::copy_nonoverlapping(buf.as_ptr(), output, 32);
+            0
         }
-        return 0;
+        Err(_) => 1,
     }
 }
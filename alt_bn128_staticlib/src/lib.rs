@@ -4,6 +4,10 @@
 
 pub mod ethereum;
 
+// The field, group and pairing check functions of `alt_bn128::ffi` are
+// exported from the archive too; include/alt_bn128.h declares them all.
+pub use alt_bn128::ffi;
+
 fn exit(code: i8) -> ! {
     unsafe {
         asm!("mv a0, {0}",